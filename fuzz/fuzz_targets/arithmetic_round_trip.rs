@@ -0,0 +1,51 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use polynom::polynomial::Polynomial;
+
+/// Decodes little-endian `f64`s, zeroing non-finite, huge or tiny values so tolerances stay meaningful.
+fn coefficients(data: &[u8]) -> Vec<f64> {
+    data.chunks_exact(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            f64::from_le_bytes(bytes)
+        })
+        .map(|value| {
+            if value.is_finite() && value.abs() < 1e6 && value.abs() > 1e-100 {
+                value
+            } else {
+                0f64
+            }
+        })
+        .collect()
+}
+
+fn assert_normalized(polynomial: &Polynomial) {
+    assert!(!polynomial.coefficients.is_empty());
+    if polynomial.coefficients.len() > 1 {
+        assert_ne!(*polynomial.coefficients.last().unwrap(), 0f64);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let split = data.len() / 2 / 8 * 8;
+    let a = Polynomial::new(coefficients(&data[..split]), 'x');
+    let b = Polynomial::new(coefficients(&data[split..]), 'x');
+
+    let sum = a.clone() + b.clone();
+    assert_normalized(&sum);
+    assert!(sum.coefficients.len() <= a.coefficients.len().max(b.coefficients.len()));
+
+    // (a + b) - b == a within rounding
+    let round_trip = sum - b.clone();
+    assert_normalized(&round_trip);
+    for i in 0..a.coefficients.len().max(round_trip.coefficients.len()) {
+        let expected = a.coefficients.get(i).copied().unwrap_or(0f64);
+        let actual = round_trip.coefficients.get(i).copied().unwrap_or(0f64);
+        let scale = expected.abs() + b.coefficients.get(i).copied().unwrap_or(0f64).abs();
+        assert!((actual - expected).abs() <= 4f64 * f64::EPSILON * scale);
+    }
+
+    assert_eq!((a.clone() - a).coefficients, vec![0f64]);
+});
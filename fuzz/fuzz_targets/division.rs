@@ -0,0 +1,45 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use polynom::polynomial::Polynomial;
+
+/// Decodes little-endian `f64`s, zeroing non-finite, huge or tiny values so tolerances stay meaningful.
+fn coefficients(data: &[u8]) -> Vec<f64> {
+    data.chunks_exact(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            f64::from_le_bytes(bytes)
+        })
+        .map(|value| {
+            if value.is_finite() && value.abs() < 1e3 && value.abs() > 1e-3 {
+                value
+            } else {
+                0f64
+            }
+        })
+        .collect()
+}
+
+fuzz_target!(|data: &[u8]| {
+    let split = data.len() / 2 / 8 * 8;
+    let dividend = Polynomial::new(coefficients(&data[..split]), 'x');
+    let divisor = Polynomial::new(coefficients(&data[split..]), 'x');
+    if divisor.degree() < 0 {
+        return;
+    }
+
+    let (quotient, remainder) = dividend.div_rem(&divisor);
+    assert!(remainder.degree() < divisor.degree());
+
+    // quotient * divisor + remainder reproduces the dividend at a few sample points
+    let leading = divisor.coefficients[divisor.coefficients.len() - 1].abs();
+    for &x in &[-1f64, -0.5, 0f64, 0.5, 1f64] {
+        let rebuilt = quotient.multiply(divisor.clone()).evaluate_at(x) + remainder.evaluate_at(x);
+        let expected = dividend.evaluate_at(x);
+        let scale: f64 = dividend.coefficients.iter().map(|c| c.abs()).sum::<f64>()
+            * (1f64 + divisor.coefficients.iter().map(|c| c.abs()).sum::<f64>() / leading)
+                .powi(dividend.coefficients.len() as i32);
+        assert!((rebuilt - expected).abs() <= 1e-9 * scale.max(1f64));
+    }
+});
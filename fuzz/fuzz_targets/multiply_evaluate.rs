@@ -0,0 +1,47 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use polynom::polynomial::Polynomial;
+
+/// Decodes little-endian `f64`s, zeroing non-finite, huge or tiny values so tolerances stay meaningful.
+fn coefficients(data: &[u8]) -> Vec<f64> {
+    data.chunks_exact(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            f64::from_le_bytes(bytes)
+        })
+        .map(|value| {
+            if value.is_finite() && value.abs() < 1e6 && value.abs() > 1e-100 {
+                value
+            } else {
+                0f64
+            }
+        })
+        .collect()
+}
+
+fn magnitude(polynomial: &Polynomial, x: f64) -> f64 {
+    polynomial
+        .coefficients
+        .iter()
+        .rev()
+        .fold(0f64, |sum, coeff| sum * x.abs() + coeff.abs())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let split = data.len() / 2 / 8 * 8;
+    let a = Polynomial::new(coefficients(&data[..split]), 'x');
+    let b = Polynomial::new(coefficients(&data[split..]), 'x');
+
+    let product = a.multiply(b.clone());
+    assert!(product.coefficients.len() <= a.coefficients.len() + b.coefficients.len() - 1);
+
+    // (a * b)(x) == a(x) * b(x) within rounding, for x in [-1, 1]
+    let n = (a.coefficients.len() + b.coefficients.len()) as f64;
+    for &x in &[-1f64, -0.5, 0f64, 0.25, 1f64] {
+        let expected = a.evaluate_at(x) * b.evaluate_at(x);
+        let bound = 4f64 * n * f64::EPSILON * magnitude(&a, x) * magnitude(&b, x);
+        assert!((product.evaluate_at(x) - expected).abs() <= bound);
+    }
+});
@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use polynom::parse::{InputFormat, PolynomialReader};
+use polynom::polynomial::Polynomial;
+
+fuzz_target!(|data: &[u8]| {
+    // Arbitrary text must never panic the parser
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = Polynomial::parse_exact(text);
+    }
+    for format in [InputFormat::Text, InputFormat::JsonLines] {
+        PolynomialReader::new(data, format).for_each(drop);
+    }
+
+    // Any non-NaN coefficients must survive a round trip bit for bit
+    let coefficients: Vec<f64> = data
+        .chunks_exact(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            f64::from_le_bytes(bytes)
+        })
+        .filter(|value| !value.is_nan())
+        .collect();
+    let polynomial = Polynomial::new(coefficients, 'x');
+    let parsed = Polynomial::parse_exact(&polynomial.to_string_exact()).unwrap();
+    let bits = |p: &Polynomial| p.coefficients.iter().map(|c| c.to_bits()).collect::<Vec<_>>();
+    assert_eq!(bits(&parsed), bits(&polynomial));
+});
@@ -0,0 +1,1328 @@
+//! Real algebraic numbers, held exactly as an integer polynomial together with an interval
+//! that isolates one of its real roots.
+//!
+//! If `p(α) = 0` and `q(β) = 0`, then `α + β` is a root of `Res_y(p(y), q(x - y))` and `αβ`
+//! is a root of `Res_y(p(y), y^n q(x / y))`. These resultants are computed modulo several
+//! primes and lifted with [`crt_reconstruct`], so no big integers are needed as long as the
+//! coefficients fit an `i128`. Interval endpoints are `f64`, and the sign of a polynomial at
+//! an endpoint is only trusted when it exceeds the rounding error bound of the evaluation,
+//! so refinement stops once the root is pinned down to double precision. Beyond that,
+//! [`refine_root_exact`] evaluates the polynomial exactly at dyadic points with big integers,
+//! and can enclose a root to any precision; behind the `rug` feature,
+//! `Polynomial::refine_root_mpfr` does the same for floating-point coefficients.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Neg;
+
+use crate::bigint::BigInt;
+use crate::gfp::GfpPolynomial;
+use crate::integer::crt_reconstruct;
+use crate::linalg;
+use crate::number_theory::{is_prime, multiply_mod, power_mod};
+use crate::polynomial::Polynomial;
+use crate::rational::Rational;
+
+/// Largest number of bits a resultant coefficient may need, leaving room for the sign in the
+/// symmetric range of [`crt_reconstruct`] below the `u128` modulus.
+const MAX_COEFFICIENT_BITS: f64 = 120f64;
+
+/// The primes used for modular resultants are taken downwards from here.
+const FIRST_PRIME_CANDIDATE: u64 = 1 << 31;
+
+/// How many times [`refine_root_exact`] doubles its search interval, starting from one unit
+/// in the last place of the approximation, before giving up on finding a sign change.
+const MAX_BRACKET_DOUBLINGS: usize = 64;
+
+/// Errors that can occur when constructing or combining [`AlgebraicNumber`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgebraicError {
+    /// The defining polynomial is zero.
+    ZeroPolynomial,
+    /// The polynomial does not clearly change sign across the interval.
+    NoRootInInterval,
+    /// The interval contains more than one root of the polynomial.
+    MultipleRootsInInterval,
+    /// An intermediate coefficient does not fit an `i128`.
+    CoefficientOverflow,
+    /// The result could not be isolated from the other roots of its polynomial at double
+    /// precision.
+    PrecisionExhausted,
+    /// A coefficient is infinite or NaN.
+    NonFiniteCoefficient,
+}
+
+impl fmt::Display for AlgebraicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlgebraicError::ZeroPolynomial => write!(f, "the defining polynomial is zero"),
+            AlgebraicError::NoRootInInterval => {
+                write!(f, "the polynomial does not change sign across the interval")
+            }
+            AlgebraicError::MultipleRootsInInterval => {
+                write!(f, "the interval contains more than one root")
+            }
+            AlgebraicError::CoefficientOverflow => {
+                write!(f, "a coefficient does not fit in an i128")
+            }
+            AlgebraicError::PrecisionExhausted => {
+                write!(f, "the root cannot be isolated at double precision")
+            }
+            AlgebraicError::NonFiniteCoefficient => write!(f, "a coefficient is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for AlgebraicError {}
+
+/// A real algebraic number: the unique root of an integer polynomial inside an interval.
+///
+/// The defining polynomial is kept square-free and primitive with a positive leading
+/// coefficient, so the root is simple and the polynomial changes sign across the interval.
+/// It is the minimal polynomial whenever the one given was irreducible, but the results of
+/// arithmetic may keep extra factors, since only repeated factors are removed.
+///
+/// Numbers compare by value: equal numbers with different defining polynomials compare
+/// equal, detected through the common factor of the two polynomials.
+#[derive(Debug, Clone)]
+pub struct AlgebraicNumber {
+    polynomial: Vec<i128>,
+    interval: (f64, f64),
+}
+
+impl AlgebraicNumber {
+    /// Returns the root of the polynomial with coefficients `coefficients`, lowest degree
+    /// first, that lies strictly inside `interval`.
+    ///
+    /// The polynomial must have clearly opposite signs at the two endpoints, and
+    /// [`Polynomial::real_roots`] must find no other root inside the interval.
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    ///
+    /// let sqrt_2 = AlgebraicNumber::new(&[-2, 0, 1], (1f64, 2f64)).unwrap();
+    /// assert!((sqrt_2.to_f64() - 2f64.sqrt()).abs() < 1e-14);
+    /// ```
+    pub fn new(
+        coefficients: &[i64],
+        interval: (f64, f64),
+    ) -> Result<AlgebraicNumber, AlgebraicError> {
+        let polynomial = trim(coefficients.iter().map(|&c| c as i128).collect());
+        if is_zero(&polynomial) {
+            return Err(AlgebraicError::ZeroPolynomial);
+        }
+
+        AlgebraicNumber::from_square_free(square_free(&polynomial)?, interval)
+    }
+
+    /// Returns the rational number `value` as an algebraic number of degree one.
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    /// use polynom::rational::Rational;
+    ///
+    /// let half = AlgebraicNumber::from_rational(Rational::new(1, 2));
+    /// assert_eq!(half.polynomial(), &[-1, 2]);
+    /// assert_eq!(half.to_f64(), 0.5);
+    /// ```
+    pub fn from_rational(value: Rational) -> AlgebraicNumber {
+        let center = value.to_f64();
+        // Wide enough that the linear polynomial is clearly nonzero at both ends
+        let half_width = 0.5 / value.denominator as f64 + 32f64 * f64::EPSILON * center.abs();
+
+        AlgebraicNumber {
+            polynomial: vec![-(value.numerator as i128), value.denominator as i128],
+            interval: (center - half_width, center + half_width),
+        }
+    }
+
+    /// Returns the integer `value` as an algebraic number of degree one.
+    pub fn from_integer(value: i64) -> AlgebraicNumber {
+        AlgebraicNumber::from_rational(Rational::new(value, 1))
+    }
+
+    /// Returns every real root of the polynomial with coefficients `coefficients`, lowest
+    /// degree first, in increasing order.
+    ///
+    /// The roots are located with [`Polynomial::real_roots`] and separated at the midpoints
+    /// between neighbours; roots too close together to separate at double precision give
+    /// an error.
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    ///
+    /// // x^3 - 2x has the roots -√2, 0 and √2
+    /// let roots = AlgebraicNumber::real_roots(&[0, -2, 0, 1]).unwrap();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!((roots[0].to_f64() + 2f64.sqrt()).abs() < 1e-14);
+    /// assert_eq!(roots[1], AlgebraicNumber::from_integer(0));
+    /// ```
+    pub fn real_roots(coefficients: &[i64]) -> Result<Vec<AlgebraicNumber>, AlgebraicError> {
+        let polynomial = trim(coefficients.iter().map(|&c| c as i128).collect());
+        if is_zero(&polynomial) {
+            return Err(AlgebraicError::ZeroPolynomial);
+        }
+        let polynomial = square_free(&polynomial)?;
+
+        let roots = to_float_polynomial(&polynomial).real_roots();
+        if roots.is_empty() {
+            return Ok(Vec::new());
+        }
+        let bound = cauchy_bound(&polynomial) + 1f64;
+        let mut cuts = vec![-bound];
+        cuts.extend(
+            roots
+                .windows(2)
+                .map(|pair| pair[0] + (pair[1] - pair[0]) / 2f64),
+        );
+        cuts.push(bound);
+
+        cuts.windows(2)
+            .map(|pair| AlgebraicNumber::from_square_free(polynomial.clone(), (pair[0], pair[1])))
+            .collect()
+    }
+
+    /// Checks that `interval` isolates a root of the square-free `polynomial`.
+    fn from_square_free(
+        polynomial: Vec<i128>,
+        interval: (f64, f64),
+    ) -> Result<AlgebraicNumber, AlgebraicError> {
+        let (low, high) = if interval.0 <= interval.1 {
+            interval
+        } else {
+            (interval.1, interval.0)
+        };
+        match (sign_at(&polynomial, low), sign_at(&polynomial, high)) {
+            (Some(a), Some(b)) if a != b => {}
+            _ => return Err(AlgebraicError::NoRootInInterval),
+        }
+        let roots_inside = to_float_polynomial(&polynomial)
+            .real_roots()
+            .into_iter()
+            .filter(|&x| low < x && x < high)
+            .count();
+        if roots_inside > 1 {
+            return Err(AlgebraicError::MultipleRootsInInterval);
+        }
+
+        // Drop the factor x unless zero is the isolated root, so that only zero itself has
+        // a vanishing constant term
+        let polynomial = if polynomial[0] != 0 {
+            polynomial
+        } else if low < 0f64 && 0f64 < high {
+            vec![0, 1]
+        } else {
+            polynomial[1..].to_vec()
+        };
+
+        Ok(AlgebraicNumber {
+            polynomial,
+            interval: (low, high),
+        })
+    }
+
+    /// Returns the coefficients of the defining polynomial, lowest degree first.
+    pub fn polynomial(&self) -> &[i128] {
+        &self.polynomial
+    }
+
+    /// Returns the open interval `(low, high)` containing the number.
+    pub fn interval(&self) -> (f64, f64) {
+        self.interval
+    }
+
+    /// Returns the degree of the defining polynomial.
+    pub fn degree(&self) -> usize {
+        self.polynomial.len() - 1
+    }
+
+    /// Returns `true` if the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.polynomial == [0, 1]
+    }
+
+    /// Halves the isolating interval, returning `false` if it cannot be narrowed any
+    /// further at double precision.
+    ///
+    /// When the sign at the midpoint is too close to zero to trust, the middle half of the
+    /// interval is kept instead, provided the signs at its ends are clear.
+    pub fn refine(&mut self) -> bool {
+        let (low, high) = self.interval;
+        let middle = low + (high - low) / 2f64;
+        if !(low < middle && middle < high) {
+            return false;
+        }
+        let low_sign = sign_at(&self.polynomial, low);
+        match sign_at(&self.polynomial, middle) {
+            Some(sign) if Some(sign) == low_sign => self.interval.0 = middle,
+            Some(_) => self.interval.1 = middle,
+            None => {
+                let quarter = (high - low) / 4f64;
+                let (left, right) = (middle - quarter, middle + quarter);
+                let left_sign = sign_at(&self.polynomial, left);
+                let right_sign = sign_at(&self.polynomial, right);
+                if left_sign != low_sign || right_sign.is_none() || right_sign == low_sign {
+                    return false;
+                }
+                self.interval = (left, right);
+            }
+        }
+
+        true
+    }
+
+    /// Refines the isolating interval until it is no wider than `width`, or as far as
+    /// double precision allows
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    ///
+    /// let mut sqrt_2 = AlgebraicNumber::new(&[-2, 0, 1], (0f64, 4f64)).unwrap();
+    /// sqrt_2.refine_to(1e-6);
+    /// let (low, high) = sqrt_2.interval();
+    /// assert!(high - low <= 1e-6);
+    /// assert!(low < 2f64.sqrt() && 2f64.sqrt() < high);
+    /// ```
+    pub fn refine_to(&mut self, width: f64) {
+        while self.interval.1 - self.interval.0 > width {
+            if !self.refine() {
+                break;
+            }
+        }
+    }
+
+    /// Returns the midpoint of the fully refined interval.
+    ///
+    /// The interval stops shrinking where the defining polynomial is within its rounding
+    /// error bound of zero, which for a well-conditioned root is a few dozen units in the
+    /// last place.
+    pub fn to_f64(&self) -> f64 {
+        let mut refined = self.clone();
+        refined.refine_to(0f64);
+        let (low, high) = refined.interval;
+        low + (high - low) / 2f64
+    }
+
+    /// Returns `self + other`
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    ///
+    /// let sqrt_2 = AlgebraicNumber::new(&[-2, 0, 1], (1f64, 2f64)).unwrap();
+    /// let sqrt_3 = AlgebraicNumber::new(&[-3, 0, 1], (1f64, 2f64)).unwrap();
+    /// let sum = sqrt_2.checked_add(&sqrt_3).unwrap();
+    /// assert_eq!(sum.polynomial(), &[1, 0, -10, 0, 1]);
+    /// assert!((sum.to_f64() - (2f64.sqrt() + 3f64.sqrt())).abs() < 1e-14);
+    /// ```
+    pub fn checked_add(&self, other: &AlgebraicNumber) -> Result<AlgebraicNumber, AlgebraicError> {
+        let polynomial = resultant(&self.polynomial, &other.polynomial, Combination::Sum)?;
+        combine(self, other, polynomial, |a, b| (a.0 + b.0, a.1 + b.1))
+    }
+
+    /// Returns `self - other`.
+    pub fn checked_sub(&self, other: &AlgebraicNumber) -> Result<AlgebraicNumber, AlgebraicError> {
+        self.checked_add(&-other.clone())
+    }
+
+    /// Returns `self * other`
+    /// # Example
+    /// ```
+    /// use polynom::algebraic::AlgebraicNumber;
+    ///
+    /// let sqrt_2 = AlgebraicNumber::new(&[-2, 0, 1], (1f64, 2f64)).unwrap();
+    /// let product = sqrt_2.checked_mul(&sqrt_2).unwrap();
+    /// assert_eq!(product, AlgebraicNumber::from_integer(2));
+    /// ```
+    pub fn checked_mul(&self, other: &AlgebraicNumber) -> Result<AlgebraicNumber, AlgebraicError> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(AlgebraicNumber::from_integer(0));
+        }
+        let polynomial = resultant(&self.polynomial, &other.polynomial, Combination::Product)?;
+        combine(self, other, polynomial, |a, b| {
+            let products = [a.0 * b.0, a.0 * b.1, a.1 * b.0, a.1 * b.1];
+            (
+                products.iter().cloned().fold(f64::INFINITY, f64::min),
+                products.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            )
+        })
+    }
+
+    /// Compares the two numbers exactly, refining both until their intervals separate or a
+    /// common root of their polynomials is found inside both.
+    fn compare(&self, other: &AlgebraicNumber) -> Ordering {
+        let common = integer_gcd(&self.polynomial, &other.polynomial).unwrap_or_else(|_| vec![1]);
+        let mut a = self.clone();
+        let mut b = other.clone();
+        loop {
+            if a.interval.1 <= b.interval.0 {
+                return Ordering::Less;
+            }
+            if b.interval.1 <= a.interval.0 {
+                return Ordering::Greater;
+            }
+            if common.len() > 1 {
+                let low = a.interval.0.max(b.interval.0);
+                let high = a.interval.1.min(b.interval.1);
+                if let (Some(x), Some(y)) = (sign_at(&common, low), sign_at(&common, high)) {
+                    if x != y {
+                        return Ordering::Equal;
+                    }
+                }
+            }
+
+            let refined_a = a.refine();
+            let refined_b = b.refine();
+            if !refined_a && !refined_b {
+                // Indistinguishable at double precision
+                let midpoint =
+                    |n: &AlgebraicNumber| n.interval.0 + (n.interval.1 - n.interval.0) / 2f64;
+                return midpoint(&a)
+                    .partial_cmp(&midpoint(&b))
+                    .unwrap_or(Ordering::Equal);
+            }
+        }
+    }
+}
+
+impl Neg for AlgebraicNumber {
+    type Output = AlgebraicNumber;
+
+    fn neg(self) -> AlgebraicNumber {
+        // p(-x), with the sign fixed so the leading coefficient stays positive
+        let degree = self.polynomial.len() - 1;
+        let polynomial = self
+            .polynomial
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| if (degree - k) % 2 == 1 { -c } else { c })
+            .collect();
+
+        AlgebraicNumber {
+            polynomial,
+            interval: (-self.interval.1, -self.interval.0),
+        }
+    }
+}
+
+impl PartialEq for AlgebraicNumber {
+    fn eq(&self, other: &AlgebraicNumber) -> bool {
+        self.compare(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AlgebraicNumber {}
+
+impl PartialOrd for AlgebraicNumber {
+    fn partial_cmp(&self, other: &AlgebraicNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlgebraicNumber {
+    fn cmp(&self, other: &AlgebraicNumber) -> Ordering {
+        self.compare(other)
+    }
+}
+
+impl From<Rational> for AlgebraicNumber {
+    fn from(value: Rational) -> AlgebraicNumber {
+        AlgebraicNumber::from_rational(value)
+    }
+}
+
+/// Looks for a nonzero integer polynomial of degree at most `max_degree`, with coefficients
+/// at most `max_coeff` in magnitude, that has `x` as a root to within the rounding of `x`.
+///
+/// Degrees are tried in increasing order, so the polynomial returned has the lowest degree
+/// possible. For each degree `d` the integer relations among `1, x, ..., x^d` are found by
+/// LLL reduction of the lattice spanned by the rows `(e_i, C x^i)`, with the weight `C`
+/// chosen so that the rounding error of the relation is comparable to the coefficients.
+/// The result is primitive with a positive leading coefficient. Returns `None` if `x` is
+/// not finite or no such polynomial is found; with double precision input, only relations
+/// with small coefficients and low degree can be recovered reliably.
+/// # Example
+/// ```
+/// use polynom::algebraic::recognize_algebraic;
+///
+/// // √2 + √3 is a root of x^4 - 10x^2 + 1
+/// let polynomial = recognize_algebraic(2f64.sqrt() + 3f64.sqrt(), 6, 100).unwrap();
+/// assert_eq!(polynomial.coefficients, vec![1f64, 0f64, -10f64, 0f64, 1f64]);
+///
+/// assert!(recognize_algebraic(std::f64::consts::PI, 2, 100).is_none());
+/// ```
+pub fn recognize_algebraic(x: f64, max_degree: usize, max_coeff: u64) -> Option<Polynomial> {
+    if !x.is_finite() || max_degree == 0 || max_coeff == 0 {
+        return None;
+    }
+    if x == 0f64 {
+        return Some(Polynomial::from_ints(vec![0, 1], 'x'));
+    }
+
+    (1..=max_degree).find_map(|degree| {
+        let powers: Vec<f64> = (0..=degree as i32).map(|i| x.powi(i)).collect();
+        let largest = powers.iter().fold(1f64, |largest, p| largest.max(p.abs()));
+        let weight = 1f64 / (f64::EPSILON * (degree + 1) as f64 * largest);
+
+        let mut basis: Vec<Vec<f64>> = (0..=degree)
+            .map(|i| {
+                let mut row = vec![0f64; degree + 2];
+                row[i] = 1f64;
+                row[degree + 1] = weight * powers[i];
+                row
+            })
+            .collect();
+        linalg::lll_reduce(&mut basis);
+
+        basis.iter().find_map(|row| {
+            let coefficients: Vec<i128> =
+                row[..=degree].iter().map(|&c| c.round() as i128).collect();
+            let coefficients = primitive(&trim(coefficients));
+            if is_zero(&coefficients)
+                || coefficients.len() != degree + 1
+                || coefficients
+                    .iter()
+                    .any(|c| c.unsigned_abs() > max_coeff as u128)
+            {
+                return None;
+            }
+
+            // The residual may only come from the rounding of x and of the evaluation
+            let polynomial = to_float_polynomial(&coefficients);
+            let slope = polynomial.derivative().evaluate_at(x);
+            let magnitude: f64 = coefficients
+                .iter()
+                .zip(&powers)
+                .map(|(&c, p)| (c as f64 * p).abs())
+                .sum();
+            let tolerance = 16f64 * f64::EPSILON * (magnitude + (x * slope).abs());
+            if polynomial.evaluate_at(x).abs() <= tolerance {
+                Some(polynomial)
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// A closed interval with dyadic endpoints `lower / 2^scale` and `upper / 2^scale`, held
+/// exactly, as returned by [`refine_root_exact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DyadicInterval {
+    lower: BigInt,
+    upper: BigInt,
+    scale: usize,
+}
+
+impl DyadicInterval {
+    fn point(value: BigInt, scale: usize) -> DyadicInterval {
+        DyadicInterval {
+            lower: value.clone(),
+            upper: value,
+            scale,
+        }
+    }
+
+    /// Returns the lower endpoint rounded down to `digits` decimal places.
+    pub fn lower_decimal(&self, digits: usize) -> String {
+        to_decimal(&self.lower, self.scale, digits, false)
+    }
+
+    /// Returns the upper endpoint rounded up to `digits` decimal places.
+    pub fn upper_decimal(&self, digits: usize) -> String {
+        to_decimal(&self.upper, self.scale, digits, true)
+    }
+
+    /// Returns `true` if the interval is a single point, i.e. the root was hit exactly.
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Returns the midpoint of the interval, rounded to an `f64`.
+    pub fn to_f64(&self) -> f64 {
+        self.lower.add(&self.upper).to_f64_scaled(self.scale + 1)
+    }
+}
+
+/// Encloses a real root of the polynomial with coefficients `coefficients`, lowest degree
+/// first, in an interval no wider than `2^-precision_bits`, starting from the approximation
+/// `root`.
+///
+/// The polynomial is evaluated exactly at dyadic points, so the result is rigorous at any
+/// precision. The search widens around `root` until the polynomial changes sign, then
+/// bisects, so the root found is the one closest to `root` that the sign change reveals:
+/// roots of even multiplicity are never found, and neither are pairs of roots closer
+/// together than the accuracy of `root`.
+///
+/// Returns [`AlgebraicError::ZeroPolynomial`] if the polynomial is zero, and
+/// [`AlgebraicError::NoRootInInterval`] if `root` is not finite or no sign change is found
+/// within `2^64` units in the last place of it.
+/// # Example
+/// ```
+/// use polynom::algebraic::refine_root_exact;
+///
+/// let sqrt_2 = refine_root_exact(&[-2, 0, 1], 2f64.sqrt(), 200).unwrap();
+/// assert_eq!(sqrt_2.lower_decimal(40), "1.4142135623730950488016887242096980785696");
+/// assert_eq!(sqrt_2.upper_decimal(40), "1.4142135623730950488016887242096980785697");
+/// ```
+pub fn refine_root_exact(
+    coefficients: &[i64],
+    root: f64,
+    precision_bits: u32,
+) -> Result<DyadicInterval, AlgebraicError> {
+    let polynomial = coefficients
+        .iter()
+        .map(|&c| BigInt::from_i128(c as i128))
+        .collect();
+    refine_exact(polynomial, root, precision_bits)
+}
+
+#[cfg(feature = "rug")]
+impl Polynomial {
+    /// Encloses a real root of this polynomial in an interval no wider than
+    /// `2^-precision_bits`, starting from the approximation `root`, as [`refine_root_exact`]
+    /// does for integer polynomials.
+    ///
+    /// Every `f64` coefficient is a dyadic rational, so the polynomial is taken exactly as
+    /// written and evaluated exactly at dyadic points. Despite the name, this does not link
+    /// MPFR: the `rug` feature only enables it, and the arithmetic is the crate's own.
+    ///
+    /// Returns [`AlgebraicError::NonFiniteCoefficient`] if a coefficient is infinite or NaN,
+    /// and otherwise fails as [`refine_root_exact`] does.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![-0.5, 0f64, 0.25], 'x');
+    /// let sqrt_2 = polynomial.refine_root_mpfr(1.41, 200).unwrap();
+    /// assert_eq!(sqrt_2.lower_decimal(30), "1.414213562373095048801688724209");
+    /// assert_eq!(sqrt_2.upper_decimal(30), "1.414213562373095048801688724210");
+    /// ```
+    pub fn refine_root_mpfr(
+        &self,
+        root: f64,
+        precision_bits: u32,
+    ) -> Result<DyadicInterval, AlgebraicError> {
+        if self.coefficients.iter().any(|c| !c.is_finite()) {
+            return Err(AlgebraicError::NonFiniteCoefficient);
+        }
+
+        // Scale by the smallest power of two that makes every coefficient an integer
+        let parts: Vec<(i128, i32)> = self.coefficients.iter().map(|&c| decompose(c)).collect();
+        let min_exponent = parts
+            .iter()
+            .filter(|&&(mantissa, _)| mantissa != 0)
+            .map(|&(_, exponent)| exponent)
+            .min()
+            .unwrap_or(0);
+        let polynomial = parts
+            .into_iter()
+            .map(|(mantissa, exponent)| {
+                BigInt::from_i128(mantissa).shl((exponent - min_exponent).max(0) as usize)
+            })
+            .collect();
+        refine_exact(polynomial, root, precision_bits)
+    }
+}
+
+/// The bisection behind [`refine_root_exact`], on exact integer coefficients.
+fn refine_exact(
+    mut polynomial: Vec<BigInt>,
+    root: f64,
+    precision_bits: u32,
+) -> Result<DyadicInterval, AlgebraicError> {
+    while polynomial
+        .last()
+        .is_some_and(|c| c.signum() == Ordering::Equal)
+    {
+        polynomial.pop();
+    }
+    if polynomial.is_empty() {
+        return Err(AlgebraicError::ZeroPolynomial);
+    }
+    if !root.is_finite() {
+        return Err(AlgebraicError::NoRootInInterval);
+    }
+
+    // Start at a scale where one unit is at most one unit in the last place of the root
+    let (mantissa, exponent) = decompose(root);
+    let mut scale = exponent.min(0).unsigned_abs() as usize;
+    let center = BigInt::from_i128(mantissa).shl((exponent + scale as i32) as usize);
+    let center_sign = exact_sign(&polynomial, &center, scale);
+    if center_sign == Ordering::Equal {
+        return Ok(DyadicInterval::point(center, scale));
+    }
+
+    let (mut lower, mut upper, doublings) = (0..=MAX_BRACKET_DOUBLINGS)
+        .find_map(|doublings| {
+            let half_width = BigInt::from_i128(1).shl(doublings);
+            let lower = center.add(&BigInt::from_i128(-1).shl(doublings));
+            let upper = center.add(&half_width);
+            if exact_sign(&polynomial, &lower, scale) != center_sign {
+                Some((lower, center.clone(), doublings))
+            } else if exact_sign(&polynomial, &upper, scale) != center_sign {
+                Some((center.clone(), upper, doublings))
+            } else {
+                None
+            }
+        })
+        .ok_or(AlgebraicError::NoRootInInterval)?;
+    for endpoint in [&lower, &upper] {
+        if exact_sign(&polynomial, endpoint, scale) == Ordering::Equal {
+            return Ok(DyadicInterval::point(endpoint.clone(), scale));
+        }
+    }
+
+    // The interval is always 2^doublings units wide, and each step halves the unit
+    let lower_sign = exact_sign(&polynomial, &lower, scale);
+    let half_width = BigInt::from_i128(1).shl(doublings);
+    while scale < precision_bits as usize + doublings {
+        lower = lower.shl(1);
+        upper = upper.shl(1);
+        scale += 1;
+        let middle = lower.add(&half_width);
+        match exact_sign(&polynomial, &middle, scale) {
+            Ordering::Equal => return Ok(DyadicInterval::point(middle, scale)),
+            sign if sign == lower_sign => lower = middle,
+            _ => upper = middle,
+        }
+    }
+
+    Ok(DyadicInterval {
+        lower,
+        upper,
+        scale,
+    })
+}
+
+/// Returns `(m, e)` with `value = m 2^e` exactly.
+fn decompose(value: f64) -> (i128, i32) {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & ((1 << 52) - 1)) as i128;
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, biased_exponent - 1075)
+    };
+
+    if value < 0f64 {
+        (-mantissa, exponent)
+    } else {
+        (mantissa, exponent)
+    }
+}
+
+/// Returns the sign of `polynomial(numerator / 2^scale)`, computed exactly as the sign of
+/// `2^(scale n) polynomial(numerator / 2^scale)` with Horner's scheme.
+fn exact_sign(polynomial: &[BigInt], numerator: &BigInt, scale: usize) -> Ordering {
+    let (leading, rest) = polynomial.split_last().expect("polynomial is nonempty");
+    rest.iter()
+        .rev()
+        .enumerate()
+        .fold(leading.clone(), |value, (k, c)| {
+            value.mul(numerator).add(&c.shl(scale * (k + 1)))
+        })
+        .signum()
+}
+
+/// Formats `value / 2^scale` rounded to `digits` decimal places, down or up.
+fn to_decimal(value: &BigInt, scale: usize, digits: usize, ceiling: bool) -> String {
+    let power = (0..digits).fold(BigInt::from_i128(1), |power, _| {
+        power.mul(&BigInt::from_i128(10))
+    });
+    let scaled = value.mul(&power).shr_rounded(scale, ceiling);
+    let text = format!(
+        "{:0>width$}",
+        scaled.magnitude_to_string(),
+        width = digits + 1
+    );
+    let (whole, fraction) = text.split_at(text.len() - digits);
+    let sign = if scaled.is_negative() { "-" } else { "" };
+
+    if digits == 0 {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+/// Isolates the root of the square-free part of `polynomial` that the interval arithmetic
+/// `combine_intervals` places between the two operands, refining them until it is alone.
+fn combine<F: Fn((f64, f64), (f64, f64)) -> (f64, f64)>(
+    a: &AlgebraicNumber,
+    b: &AlgebraicNumber,
+    polynomial: Vec<i128>,
+    combine_intervals: F,
+) -> Result<AlgebraicNumber, AlgebraicError> {
+    let polynomial = square_free(&polynomial)?;
+    let mut a = a.clone();
+    let mut b = b.clone();
+    loop {
+        let (low, high) = combine_intervals(a.interval, b.interval);
+        // Widen outwards to cover the rounding of the interval arithmetic
+        let interval = (
+            low - 4f64 * f64::EPSILON * low.abs(),
+            high + 4f64 * f64::EPSILON * high.abs(),
+        );
+        if let Ok(number) = AlgebraicNumber::from_square_free(polynomial.clone(), interval) {
+            return Ok(number);
+        }
+
+        let refined_a = a.refine();
+        let refined_b = b.refine();
+        if !refined_a && !refined_b {
+            return Err(AlgebraicError::PrecisionExhausted);
+        }
+    }
+}
+
+/// Which resultant to take of two defining polynomials.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combination {
+    /// `Res_y(p(y), q(x - y))`, whose roots are the sums of the roots.
+    Sum,
+    /// `Res_y(p(y), y^n q(x / y))`, whose roots are the products of the roots.
+    Product,
+}
+
+/// Computes the resultant polynomial modulo enough primes to bound its coefficients, and
+/// lifts it to the integers.
+fn resultant(
+    p: &[i128],
+    q: &[i128],
+    combination: Combination,
+) -> Result<Vec<i128>, AlgebraicError> {
+    let m = p.len() - 1;
+    let n = q.len() - 1;
+    let degree = m * n;
+
+    // The resultant is lc(p)^n lc(q)^m times the monic polynomial with the combined roots,
+    // all of which lie within `radius` of the origin
+    let radius = match combination {
+        Combination::Sum => cauchy_bound(p) + cauchy_bound(q),
+        Combination::Product => cauchy_bound(p) * cauchy_bound(q),
+    };
+    let bits = n as f64 * (p[m] as f64).abs().log2()
+        + m as f64 * (q[n] as f64).abs().log2()
+        + degree as f64 * (1f64 + radius).log2();
+    if !bits.is_finite() || bits > MAX_COEFFICIENT_BITS {
+        return Err(AlgebraicError::CoefficientOverflow);
+    }
+
+    let mut images = Vec::new();
+    let mut modulus_bits = 0f64;
+    let mut candidate = FIRST_PRIME_CANDIDATE;
+    while modulus_bits <= bits + 1f64 {
+        candidate -= 1;
+        if !is_prime(candidate) {
+            continue;
+        }
+        let prime = candidate;
+        let divides = |c: i128| c.rem_euclid(prime as i128) == 0;
+        // The degrees in y must survive the reduction
+        if divides(p[m]) || divides(q[n]) || (combination == Combination::Product && divides(q[0]))
+        {
+            continue;
+        }
+        images.push(modular_resultant(p, q, combination, prime));
+        modulus_bits += (prime as f64).log2();
+    }
+
+    crt_reconstruct(&images)
+        .map(trim)
+        .map_err(|_| AlgebraicError::CoefficientOverflow)
+}
+
+/// Returns the resultant polynomial modulo `prime`, interpolated from its values at
+/// `0, 1, ..., deg p * deg q`.
+fn modular_resultant(
+    p: &[i128],
+    q: &[i128],
+    combination: Combination,
+    prime: u64,
+) -> GfpPolynomial {
+    let reduce = |c: i128| c.rem_euclid(prime as i128) as u64;
+    let a = GfpPolynomial::new(p.iter().map(|&c| reduce(c)).collect(), prime);
+    let n = q.len() - 1;
+    let degree = (p.len() - 1) * n;
+
+    let values: Vec<u64> = (0..=degree as u64)
+        .map(|x| {
+            let b = match combination {
+                Combination::Sum => {
+                    // q(x - y) by Horner's scheme in y
+                    let shift = GfpPolynomial::new(vec![x, prime - 1], prime);
+                    q.iter()
+                        .rev()
+                        .fold(GfpPolynomial::new(vec![0], prime), |acc, &c| {
+                            acc.multiply(&shift) + GfpPolynomial::new(vec![reduce(c)], prime)
+                        })
+                }
+                Combination::Product => {
+                    // sum of q_k x^k y^(n - k)
+                    let mut coefficients = vec![0u64; n + 1];
+                    let mut power = 1u64;
+                    for (k, &c) in q.iter().enumerate() {
+                        coefficients[n - k] = multiply_mod(reduce(c), power, prime);
+                        power = multiply_mod(power, x, prime);
+                    }
+                    GfpPolynomial::new(coefficients, prime)
+                }
+            };
+            field_resultant(&a, &b)
+        })
+        .collect();
+
+    interpolate(&values, prime)
+}
+
+/// Returns the resultant of two nonzero polynomials over GF(p) with the Euclidean
+/// algorithm, using `Res(a, b) = (-1)^(deg a deg b) lc(b)^(deg a - deg r) Res(b, r)` for the
+/// remainder `r` of `a` by `b`.
+fn field_resultant(a: &GfpPolynomial, b: &GfpPolynomial) -> u64 {
+    let prime = a.prime;
+    let mut a = a.clone();
+    let mut b = b.clone();
+    let mut result = 1u64;
+    loop {
+        let m = a.degree() as u64;
+        let n = b.degree() as u64;
+        let leading = b.coefficient(n as usize);
+        if n == 0 {
+            return multiply_mod(result, power_mod(leading, m, prime), prime);
+        }
+        let remainder = a.remainder(&b);
+        if remainder.is_zero() {
+            return 0;
+        }
+        if (m * n) % 2 == 1 {
+            result = (prime - result) % prime;
+        }
+        let k = remainder.degree() as u64;
+        result = multiply_mod(result, power_mod(leading, m - k, prime), prime);
+        a = b;
+        b = remainder;
+    }
+}
+
+/// Returns the polynomial over GF(p) taking the value `values[i]` at `i`, in Newton form
+/// with divided differences.
+fn interpolate(values: &[u64], prime: u64) -> GfpPolynomial {
+    let mut differences = values.to_vec();
+    for j in 1..differences.len() {
+        let inverse = power_mod(j as u64 % prime, prime - 2, prime);
+        for i in (j..differences.len()).rev() {
+            let difference = (differences[i] + prime - differences[i - 1]) % prime;
+            differences[i] = multiply_mod(difference, inverse, prime);
+        }
+    }
+
+    differences
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(GfpPolynomial::new(vec![0], prime), |acc, (i, &c)| {
+            let factor = GfpPolynomial::new(vec![(prime - i as u64 % prime) % prime, 1], prime);
+            acc.multiply(&factor) + GfpPolynomial::new(vec![c], prime)
+        })
+}
+
+/// Returns the sign of `polynomial(x)`, or `None` if the computed value is within its
+/// rounding error bound of zero.
+fn sign_at(polynomial: &[i128], x: f64) -> Option<Ordering> {
+    let (value, magnitude) = polynomial
+        .iter()
+        .rev()
+        .fold((0f64, 0f64), |(value, magnitude), &c| {
+            (value * x + c as f64, magnitude * x.abs() + (c as f64).abs())
+        });
+    // Horner's scheme plus the rounding of the coefficients themselves
+    let bound = (4 * polynomial.len() + 2) as f64 * f64::EPSILON * magnitude;
+
+    if value.abs() > bound {
+        value.partial_cmp(&0f64)
+    } else {
+        None
+    }
+}
+
+/// Returns `1 + max |c_k / c_n|`, a bound on the magnitude of every root.
+fn cauchy_bound(polynomial: &[i128]) -> f64 {
+    let n = polynomial.len() - 1;
+    let leading = (polynomial[n] as f64).abs();
+    let largest = polynomial[..n]
+        .iter()
+        .fold(0f64, |largest, &c| largest.max((c as f64).abs() / leading));
+
+    1f64 + largest
+}
+
+fn to_float_polynomial(polynomial: &[i128]) -> Polynomial {
+    Polynomial::new(polynomial.iter().map(|&c| c as f64).collect(), 'x')
+}
+
+fn trim(mut polynomial: Vec<i128>) -> Vec<i128> {
+    while polynomial.len() > 1 && polynomial.last() == Some(&0) {
+        polynomial.pop();
+    }
+    if polynomial.is_empty() {
+        polynomial.push(0);
+    }
+
+    polynomial
+}
+
+fn is_zero(polynomial: &[i128]) -> bool {
+    polynomial == [0]
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Divides out the content and makes the leading coefficient positive.
+fn primitive(polynomial: &[i128]) -> Vec<i128> {
+    let content = polynomial
+        .iter()
+        .fold(0u128, |content, &c| gcd(content, c.unsigned_abs())) as i128;
+    if content == 0 {
+        return vec![0];
+    }
+    let content = if polynomial[polynomial.len() - 1] < 0 {
+        -content
+    } else {
+        content
+    };
+
+    polynomial.iter().map(|&c| c / content).collect()
+}
+
+fn derivative(polynomial: &[i128]) -> Result<Vec<i128>, AlgebraicError> {
+    let coefficients = polynomial
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, &c)| {
+            c.checked_mul(k as i128)
+                .ok_or(AlgebraicError::CoefficientOverflow)
+        })
+        .collect::<Result<Vec<i128>, AlgebraicError>>()?;
+
+    Ok(trim(coefficients))
+}
+
+/// Returns a nonzero integer multiple of the remainder of `a` by the nonzero `b`, with the
+/// content removed after every elimination step to keep the coefficients small.
+fn pseudo_remainder(a: &[i128], b: &[i128]) -> Result<Vec<i128>, AlgebraicError> {
+    let overflow = AlgebraicError::CoefficientOverflow;
+    let divisor_degree = b.len() - 1;
+    let divisor_leading = b[divisor_degree];
+    let mut remainder = a.to_vec();
+    while !is_zero(&remainder) && remainder.len() > divisor_degree {
+        let degree = remainder.len() - 1;
+        let leading = remainder[degree];
+        // remainder = lc(b) remainder - leading x^(degree - deg b) b
+        for coeff in remainder.iter_mut() {
+            *coeff = coeff.checked_mul(divisor_leading).ok_or(overflow)?;
+        }
+        for (i, &c) in b.iter().enumerate() {
+            let term = leading.checked_mul(c).ok_or(overflow)?;
+            let index = i + degree - divisor_degree;
+            remainder[index] = remainder[index].checked_sub(term).ok_or(overflow)?;
+        }
+        remainder.pop();
+        remainder = primitive(&trim(remainder));
+    }
+
+    Ok(remainder)
+}
+
+/// Returns the primitive greatest common divisor of two integer polynomials.
+fn integer_gcd(a: &[i128], b: &[i128]) -> Result<Vec<i128>, AlgebraicError> {
+    let mut a = primitive(a);
+    let mut b = primitive(b);
+    if a.len() < b.len() {
+        std::mem::swap(&mut a, &mut b);
+    }
+    while !is_zero(&b) {
+        let remainder = pseudo_remainder(&a, &b)?;
+        a = b;
+        b = primitive(&remainder);
+    }
+
+    Ok(primitive(&a))
+}
+
+/// Returns `a / b` for integer polynomials where `b` is known to divide `a` exactly.
+fn exact_quotient(a: &[i128], b: &[i128]) -> Vec<i128> {
+    let divisor_degree = b.len() - 1;
+    let divisor_leading = b[divisor_degree];
+    let mut remainder = a.to_vec();
+    let mut quotient = vec![0i128; a.len() - divisor_degree];
+    for i in (0..quotient.len()).rev() {
+        let factor = remainder[i + divisor_degree] / divisor_leading;
+        quotient[i] = factor;
+        for (j, &c) in b.iter().enumerate() {
+            remainder[i + j] -= factor * c;
+        }
+    }
+
+    quotient
+}
+
+/// Returns the primitive square-free part of a nonzero integer polynomial.
+fn square_free(polynomial: &[i128]) -> Result<Vec<i128>, AlgebraicError> {
+    let polynomial = primitive(polynomial);
+    let repeated = integer_gcd(&polynomial, &derivative(&polynomial)?)?;
+    if repeated.len() == 1 {
+        return Ok(polynomial);
+    }
+
+    Ok(primitive(&exact_quotient(&polynomial, &repeated)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sqrt(n: i64) -> AlgebraicNumber {
+        AlgebraicNumber::new(&[-n, 0, 1], (0f64, n as f64 + 1f64)).unwrap()
+    }
+
+    #[test]
+    fn test_construction_errors() {
+        assert_eq!(
+            AlgebraicNumber::new(&[0, 0], (0f64, 1f64)).unwrap_err(),
+            AlgebraicError::ZeroPolynomial
+        );
+        // Both roots of x^2 - 2 inside, so no sign change
+        assert_eq!(
+            AlgebraicNumber::new(&[-2, 0, 1], (-2f64, 2f64)).unwrap_err(),
+            AlgebraicError::NoRootInInterval
+        );
+        // x^3 - x changes sign across (-2, 3), but has three roots inside
+        assert_eq!(
+            AlgebraicNumber::new(&[0, -1, 0, 1], (-2f64, 3f64)).unwrap_err(),
+            AlgebraicError::MultipleRootsInInterval
+        );
+    }
+
+    #[test]
+    fn test_repeated_factors_are_removed() {
+        // (x^2 - 2)^2 (x - 1)
+        let number = AlgebraicNumber::new(&[-4, 4, 4, -4, -1, 1], (1.2, 2f64)).unwrap();
+        // Only the square-free part (x^2 - 2)(x - 1) is kept
+        assert_eq!(number.polynomial(), &[2, -2, -1, 1]);
+        assert!((number.to_f64() - 2f64.sqrt()).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_real_roots_are_sorted_and_isolated() {
+        // (x^2 - 2)(x^2 - 3)
+        let roots = AlgebraicNumber::real_roots(&[6, 0, -5, 0, 1]).unwrap();
+        let values: Vec<f64> = roots.iter().map(|root| root.to_f64()).collect();
+        let expected = [-3f64.sqrt(), -2f64.sqrt(), 2f64.sqrt(), 3f64.sqrt()];
+        for (value, expected) in values.iter().zip(&expected) {
+            assert!((value - expected).abs() < 1e-13);
+        }
+        assert!(roots.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(AlgebraicNumber::real_roots(&[1, 0, 1]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_comparison() {
+        let half = AlgebraicNumber::from_rational(Rational::new(3, 2));
+        assert!(sqrt(2) < half);
+        assert!(half < sqrt(3));
+        assert!(sqrt(3) > sqrt(2));
+        assert_eq!(sqrt(4), AlgebraicNumber::from_integer(2));
+        assert_eq!(
+            -sqrt(2),
+            AlgebraicNumber::real_roots(&[-2, 0, 1]).unwrap()[0]
+        );
+    }
+
+    #[test]
+    fn test_sum_and_difference() {
+        let sum = sqrt(2).checked_add(&sqrt(3)).unwrap();
+        assert_eq!(sum.polynomial(), &[1, 0, -10, 0, 1]);
+
+        // √2 - √2 is a root of x (x^2 - 8), and is exactly zero
+        let difference = sqrt(2).checked_sub(&sqrt(2)).unwrap();
+        assert!(difference.is_zero());
+        assert_eq!(difference, AlgebraicNumber::from_integer(0));
+
+        let difference = sum.checked_sub(&sqrt(3)).unwrap();
+        assert_eq!(difference, sqrt(2));
+    }
+
+    #[test]
+    fn test_product() {
+        let product = sqrt(2).checked_mul(&sqrt(3)).unwrap();
+        assert_eq!(product, sqrt(6));
+        assert_eq!(product.polynomial(), &[-6, 0, 1]);
+
+        let negative = (-sqrt(2)).checked_mul(&sqrt(8)).unwrap();
+        assert_eq!(negative, AlgebraicNumber::from_integer(-4));
+
+        let zero = AlgebraicNumber::from_integer(0);
+        assert!(sqrt(5).checked_mul(&zero).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_golden_ratio_identity() {
+        // φ^2 = φ + 1
+        let phi = AlgebraicNumber::new(&[-1, -1, 1], (1f64, 2f64)).unwrap();
+        let square = phi.checked_mul(&phi).unwrap();
+        let successor = phi.checked_add(&AlgebraicNumber::from_integer(1)).unwrap();
+        assert_eq!(square, successor);
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let third = AlgebraicNumber::from_rational(Rational::new(1, 3));
+        let sum = third.checked_add(&third).unwrap();
+        assert_eq!(sum.polynomial(), &[-2, 3]);
+        assert_eq!(sum, AlgebraicNumber::from_rational(Rational::new(2, 3)));
+    }
+
+    #[test]
+    fn test_field_resultant_matches_sylvester() {
+        // Res(x^2 + 1, x - 2) = 5 and Res(x^2 - 1, x - 1) = 0 over GF(7)
+        let a = GfpPolynomial::new(vec![1, 0, 1], 7);
+        let b = GfpPolynomial::new(vec![5, 1], 7);
+        assert_eq!(field_resultant(&a, &b), 5);
+        let a = GfpPolynomial::new(vec![6, 0, 1], 7);
+        let b = GfpPolynomial::new(vec![6, 1], 7);
+        assert_eq!(field_resultant(&a, &b), 0);
+    }
+
+    #[test]
+    fn test_recognize_algebraic() {
+        let golden = (1f64 + 5f64.sqrt()) / 2f64;
+        assert_eq!(
+            recognize_algebraic(golden, 4, 10).unwrap().coefficients,
+            vec![-1f64, -1f64, 1f64]
+        );
+        assert_eq!(
+            recognize_algebraic(2f64.cbrt(), 4, 10)
+                .unwrap()
+                .coefficients,
+            vec![-2f64, 0f64, 0f64, 1f64]
+        );
+        assert_eq!(
+            recognize_algebraic(-2f64.sqrt(), 4, 10)
+                .unwrap()
+                .coefficients,
+            vec![-2f64, 0f64, 1f64]
+        );
+        assert_eq!(
+            recognize_algebraic(3f64 / 7f64, 4, 10)
+                .unwrap()
+                .coefficients,
+            vec![-3f64, 7f64]
+        );
+        assert_eq!(
+            recognize_algebraic(0f64, 4, 10).unwrap().coefficients,
+            vec![0f64, 1f64]
+        );
+    }
+
+    #[test]
+    fn test_recognize_algebraic_respects_limits() {
+        // √2 + √3 needs degree four
+        assert!(recognize_algebraic(2f64.sqrt() + 3f64.sqrt(), 3, 100).is_none());
+        // 100/101 needs a coefficient of 101
+        assert!(recognize_algebraic(100f64 / 101f64, 1, 100).is_none());
+        assert!(recognize_algebraic(std::f64::consts::E, 4, 20).is_none());
+        assert!(recognize_algebraic(f64::NAN, 4, 20).is_none());
+    }
+
+    #[test]
+    fn test_recognized_polynomial_defines_the_number() {
+        let x = 1f64 + 2f64.cbrt();
+        let polynomial = recognize_algebraic(x, 4, 100).unwrap();
+        let coefficients: Vec<i64> = polynomial.coefficients.iter().map(|&c| c as i64).collect();
+        let number = AlgebraicNumber::new(&coefficients, (x - 1e-9, x + 1e-9)).unwrap();
+        assert!((number.to_f64() - x).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_refine_root_exact() {
+        // The cube root of 2 from a rough approximation, and the negative root of x^2 - 2
+        let cbrt_2 = refine_root_exact(&[-2, 0, 0, 1], 1.26, 120).unwrap();
+        assert_eq!(cbrt_2.lower_decimal(30), "1.259921049894873164767210607278");
+        assert_eq!(cbrt_2.upper_decimal(30), "1.259921049894873164767210607279");
+        assert_eq!(cbrt_2.to_f64(), 2f64.cbrt());
+
+        let minus_sqrt_2 = refine_root_exact(&[-2, 0, 1], -1.4, 100).unwrap();
+        assert_eq!(minus_sqrt_2.lower_decimal(20), "-1.41421356237309504881");
+        assert_eq!(minus_sqrt_2.upper_decimal(20), "-1.41421356237309504880");
+        assert!(!minus_sqrt_2.is_exact());
+    }
+
+    #[test]
+    fn test_refine_root_exact_hits_dyadic_roots() {
+        let half = refine_root_exact(&[-1, 2], 0.3, 100).unwrap();
+        assert!(half.is_exact());
+        assert_eq!(half.lower_decimal(3), "0.500");
+        assert_eq!(half.upper_decimal(0), "1");
+
+        let zero = refine_root_exact(&[0, 1, 1], 0f64, 50).unwrap();
+        assert!(zero.is_exact());
+        assert_eq!(zero.lower_decimal(2), "0.00");
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_refine_root_mpfr() {
+        let three_quarters = Polynomial::new(vec![-0.375, 0.5], 'x')
+            .refine_root_mpfr(0.7, 100)
+            .unwrap();
+        assert!(three_quarters.is_exact());
+        assert_eq!(three_quarters.to_f64(), 0.75);
+
+        // The root of x^2 - 0.1 is the square root of the double nearest 0.1
+        let root = Polynomial::new(vec![-0.1, 0f64, 1f64], 'x')
+            .refine_root_mpfr(0.3, 120)
+            .unwrap();
+        assert_eq!(root.lower_decimal(25), "0.3162277660168379419769730");
+        assert_eq!(root.upper_decimal(25), "0.3162277660168379419769731");
+
+        let mut zero = Polynomial::new(vec![0f64], 'x');
+        assert_eq!(
+            zero.refine_root_mpfr(1f64, 10).unwrap_err(),
+            AlgebraicError::ZeroPolynomial
+        );
+        zero.coefficients.push(f64::NAN);
+        assert_eq!(
+            zero.refine_root_mpfr(1f64, 10).unwrap_err(),
+            AlgebraicError::NonFiniteCoefficient
+        );
+    }
+
+    #[test]
+    fn test_refine_root_exact_errors() {
+        assert_eq!(
+            refine_root_exact(&[0, 0], 1f64, 10).unwrap_err(),
+            AlgebraicError::ZeroPolynomial
+        );
+        // x^2 + 1 never changes sign, and neither does (x - 1)^2
+        assert_eq!(
+            refine_root_exact(&[1, 0, 1], 0f64, 10).unwrap_err(),
+            AlgebraicError::NoRootInInterval
+        );
+        assert_eq!(
+            refine_root_exact(&[1, -2, 1], 1.1, 10).unwrap_err(),
+            AlgebraicError::NoRootInInterval
+        );
+        assert_eq!(
+            refine_root_exact(&[-2, 0, 1], f64::NAN, 10).unwrap_err(),
+            AlgebraicError::NoRootInInterval
+        );
+    }
+}
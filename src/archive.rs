@@ -0,0 +1,357 @@
+//! A versioned on-disk format for storing polynomials and piecewise models.
+//!
+//! An archive is a text file whose first line is `polynom-archive MAJOR.MINOR`, followed by
+//! one tab-separated record per line. Blank lines and lines starting with `#` are ignored.
+//!
+//! | Record | Fields |
+//! | --- | --- |
+//! | `polynomial` | the polynomial, as written by [`Polynomial::to_string_exact`] |
+//! | `bounded` | lower bound, upper bound, polynomial |
+//! | `piecewise` | space-separated breakpoints, then one polynomial per piece |
+//!
+//! Numbers are written with the shortest representation that round-trips, so archives
+//! restore bit-identical models.
+//!
+//! # Compatibility
+//!
+//! Minor versions only add record kinds or append fields to existing records. Readers skip
+//! record kinds they do not recognize and ignore trailing fields, so archives written by a
+//! newer crate with the same major version still load. A newer major version is rejected
+//! with [`ArchiveError::UnsupportedVersion`].
+//!
+//! Files without a header, holding one `to_string_exact` polynomial per line, are read as
+//! version 0.0 and migrated to [`ArchiveEntry::Polynomial`] entries.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use crate::bounded::BoundedPolynomial;
+use crate::parse::ParseError;
+use crate::piecewise::PiecewisePolynomial;
+use crate::polynomial::Polynomial;
+
+/// The `(major, minor)` version written by [`write_archive`].
+pub const ARCHIVE_VERSION: (u32, u32) = (1, 0);
+
+const HEADER: &str = "polynom-archive";
+
+/// Errors that can occur while reading an archive, tagged with the 1-based line number where
+/// applicable.
+#[derive(Debug)]
+pub enum ArchiveError {
+    /// The underlying reader failed.
+    Io {
+        /// The line being read.
+        line: usize,
+        /// The error from the reader.
+        error: io::Error,
+    },
+    /// The archive was written with a newer, incompatible major version.
+    UnsupportedVersion {
+        /// The major version in the header.
+        major: u32,
+        /// The minor version in the header.
+        minor: u32,
+    },
+    /// The line is not a valid header or record.
+    MalformedRecord {
+        /// The malformed line.
+        line: usize,
+    },
+    /// A polynomial in the record could not be parsed.
+    Parse {
+        /// The line holding the record.
+        line: usize,
+        /// Why the polynomial failed to parse.
+        error: ParseError,
+    },
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchiveError::Io { line, error } => write!(f, "line {}: {}", line, error),
+            ArchiveError::UnsupportedVersion { major, minor } => write!(
+                f,
+                "archive version {}.{} is newer than the supported {}.{}",
+                major, minor, ARCHIVE_VERSION.0, ARCHIVE_VERSION.1
+            ),
+            ArchiveError::MalformedRecord { line } => write!(f, "line {}: malformed record", line),
+            ArchiveError::Parse { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ArchiveError::Io { error, .. } => Some(error),
+            ArchiveError::Parse { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A single model stored in an archive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArchiveEntry {
+    /// A plain polynomial.
+    Polynomial(Polynomial),
+    /// A polynomial restricted to a domain.
+    Bounded(BoundedPolynomial),
+    /// A piecewise polynomial.
+    Piecewise(PiecewisePolynomial),
+}
+
+/// The contents of an archive, as returned by [`read_archive`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Archive {
+    /// The `(major, minor)` version the archive was written with.
+    pub version: (u32, u32),
+    /// The recognized records, in file order.
+    pub entries: Vec<ArchiveEntry>,
+    /// The number of records of unknown kinds that were skipped.
+    pub skipped: usize,
+}
+
+/// Writes `entries` as an archive of the current [`ARCHIVE_VERSION`]
+/// # Example
+/// ```
+/// use polynom::archive::{read_archive, write_archive, ArchiveEntry};
+/// use polynom::polynomial::Polynomial;
+///
+/// let entries = vec![ArchiveEntry::Polynomial(Polynomial::new(vec![0.1, 2f64], 't'))];
+/// let mut bytes = Vec::new();
+/// write_archive(&mut bytes, &entries).unwrap();
+/// assert_eq!(
+///     String::from_utf8(bytes.clone()).unwrap(),
+///     "polynom-archive 1.0\npolynomial\tf(t) = 0.1 + 2.0t\n"
+/// );
+///
+/// let archive = read_archive(&bytes[..]).unwrap();
+/// assert_eq!(archive.entries, entries);
+/// ```
+pub fn write_archive<W: Write>(mut writer: W, entries: &[ArchiveEntry]) -> io::Result<()> {
+    writeln!(
+        writer,
+        "{} {}.{}",
+        HEADER, ARCHIVE_VERSION.0, ARCHIVE_VERSION.1
+    )?;
+    for entry in entries {
+        match entry {
+            ArchiveEntry::Polynomial(polynomial) => {
+                writeln!(writer, "polynomial\t{}", polynomial.to_string_exact())?
+            }
+            ArchiveEntry::Bounded(bounded) => writeln!(
+                writer,
+                "bounded\t{:?}\t{:?}\t{}",
+                bounded.domain.0,
+                bounded.domain.1,
+                bounded.poly.to_string_exact()
+            )?,
+            ArchiveEntry::Piecewise(piecewise) => {
+                let breakpoints: Vec<String> = piecewise
+                    .breakpoints
+                    .iter()
+                    .map(|breakpoint| format!("{:?}", breakpoint))
+                    .collect();
+                write!(writer, "piecewise\t{}", breakpoints.join(" "))?;
+                for piece in &piecewise.pieces {
+                    write!(writer, "\t{}", piece.to_string_exact())?;
+                }
+                writeln!(writer)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads an archive written by this or any compatible version of the crate, migrating
+/// headerless version 0.0 files
+/// # Example
+/// ```
+/// use polynom::archive::{read_archive, ArchiveEntry};
+///
+/// // A record kind from a later minor version is skipped
+/// let input = "polynom-archive 1.3\nbounded\t0.0\t1.0\tf(x) = 1.0 + 2.0x\nspline\t...\n";
+/// let archive = read_archive(input.as_bytes()).unwrap();
+/// assert_eq!(archive.version, (1, 3));
+/// assert_eq!(archive.skipped, 1);
+/// assert!(matches!(&archive.entries[0], ArchiveEntry::Bounded(b) if b.domain == (0f64, 1f64)));
+///
+/// // A file of bare polynomials is version 0.0
+/// let archive = read_archive("f(x) = 1.0\nf(x) = 0.0 + 1.0x\n".as_bytes()).unwrap();
+/// assert_eq!((archive.version, archive.entries.len()), ((0, 0), 2));
+/// ```
+pub fn read_archive<R: BufRead>(reader: R) -> Result<Archive, ArchiveError> {
+    let mut archive = Archive {
+        version: (0, 0),
+        entries: Vec::new(),
+        skipped: 0,
+    };
+    let mut seen_header = false;
+    for (index, text) in reader.lines().enumerate() {
+        let line = index + 1;
+        let text = text.map_err(|error| ArchiveError::Io { line, error })?;
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !seen_header {
+            seen_header = true;
+            if let Some(version) = trimmed.strip_prefix(HEADER) {
+                archive.version =
+                    parse_version(version).ok_or(ArchiveError::MalformedRecord { line })?;
+                if archive.version.0 > ARCHIVE_VERSION.0 {
+                    return Err(ArchiveError::UnsupportedVersion {
+                        major: archive.version.0,
+                        minor: archive.version.1,
+                    });
+                }
+                continue;
+            }
+        }
+
+        if archive.version.0 == 0 {
+            let polynomial = parse_polynomial(trimmed, line)?;
+            archive.entries.push(ArchiveEntry::Polynomial(polynomial));
+            continue;
+        }
+        match parse_record(trimmed, line)? {
+            Some(entry) => archive.entries.push(entry),
+            None => archive.skipped += 1,
+        }
+    }
+
+    Ok(archive)
+}
+
+/// Parses the ` MAJOR.MINOR` following the header keyword.
+fn parse_version(text: &str) -> Option<(u32, u32)> {
+    let text = text.strip_prefix(' ')?;
+    let (major, minor) = text.split_once('.')?;
+
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+fn parse_polynomial(text: &str, line: usize) -> Result<Polynomial, ArchiveError> {
+    Polynomial::parse_exact(text).map_err(|error| ArchiveError::Parse { line, error })
+}
+
+/// Parses a version 1 record, returning `None` for record kinds this version does not know.
+fn parse_record(text: &str, line: usize) -> Result<Option<ArchiveEntry>, ArchiveError> {
+    let malformed = || ArchiveError::MalformedRecord { line };
+    let mut fields = text.split('\t');
+    let number = |field: Option<&str>| field.and_then(|field| field.parse::<f64>().ok());
+
+    let entry = match fields.next() {
+        Some("polynomial") => {
+            let polynomial = parse_polynomial(fields.next().ok_or_else(malformed)?, line)?;
+            ArchiveEntry::Polynomial(polynomial)
+        }
+        Some("bounded") => {
+            let low = number(fields.next());
+            let high = number(fields.next());
+            let poly = fields.next();
+            match (low, high, poly) {
+                (Some(low), Some(high), Some(poly)) if low <= high => ArchiveEntry::Bounded(
+                    BoundedPolynomial::new(parse_polynomial(poly, line)?, (low, high)),
+                ),
+                _ => return Err(malformed()),
+            }
+        }
+        Some("piecewise") => {
+            let breakpoints: Vec<f64> = fields
+                .next()
+                .ok_or_else(malformed)?
+                .split(' ')
+                .map(|breakpoint| breakpoint.parse::<f64>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| malformed())?;
+            if breakpoints.len() < 2 || !breakpoints.windows(2).all(|pair| pair[0] < pair[1]) {
+                return Err(malformed());
+            }
+            let mut pieces = Vec::with_capacity(breakpoints.len() - 1);
+            for _ in 1..breakpoints.len() {
+                let piece = fields.next().ok_or_else(malformed)?;
+                pieces.push(parse_polynomial(piece, line)?);
+            }
+            ArchiveEntry::Piecewise(PiecewisePolynomial::new(breakpoints, pieces))
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_of_every_entry_kind() {
+        let entries = vec![
+            ArchiveEntry::Polynomial(Polynomial::new(vec![1f64 / 3f64, -2f64], 'x')),
+            ArchiveEntry::Bounded(BoundedPolynomial::new(
+                Polynomial::new(vec![0f64, 1e-300], 't'),
+                (-0.1, 7f64),
+            )),
+            ArchiveEntry::Piecewise(PiecewisePolynomial::new(
+                vec![-1f64, 0f64, 1f64],
+                vec![
+                    Polynomial::new(vec![0f64, -1f64], 'x'),
+                    Polynomial::new(vec![0f64, 1f64], 'x'),
+                ],
+            )),
+        ];
+        let mut bytes = Vec::new();
+        write_archive(&mut bytes, &entries).unwrap();
+        let archive = read_archive(&bytes[..]).unwrap();
+
+        assert_eq!(archive.version, ARCHIVE_VERSION);
+        assert_eq!(archive.entries, entries);
+        assert_eq!(archive.skipped, 0);
+    }
+
+    #[test]
+    fn test_newer_minor_versions_append_fields() {
+        let input =
+            "# fitted 2026-10-16\npolynom-archive 1.9\n\npolynomial\tf(x) = 2.0\tfit-rms=0.01\n";
+        let archive = read_archive(input.as_bytes()).unwrap();
+        assert_eq!(
+            archive.entries,
+            vec![ArchiveEntry::Polynomial(Polynomial::new(vec![2f64], 'x'))]
+        );
+    }
+
+    #[test]
+    fn test_archive_errors() {
+        let read = |text: &str| read_archive(text.as_bytes()).unwrap_err();
+
+        assert!(matches!(
+            read("polynom-archive 2.0\n"),
+            ArchiveError::UnsupportedVersion { major: 2, minor: 0 }
+        ));
+        assert!(matches!(
+            read("polynom-archive one\n"),
+            ArchiveError::MalformedRecord { line: 1 }
+        ));
+        assert!(matches!(
+            read("polynom-archive 1.0\nbounded\t1.0\t0.0\tf(x) = 1.0\n"),
+            ArchiveError::MalformedRecord { line: 2 }
+        ));
+        assert!(matches!(
+            read("polynom-archive 1.0\npiecewise\t0.0 1.0 2.0\tf(x) = 1.0\n"),
+            ArchiveError::MalformedRecord { line: 2 }
+        ));
+        assert!(matches!(
+            read("polynom-archive 1.0\npolynomial\t1 + 2x\n"),
+            ArchiveError::Parse {
+                line: 2,
+                error: ParseError::MissingHeader
+            }
+        ));
+    }
+}
@@ -0,0 +1,288 @@
+//! Polynomial arithmetic that allocates its results in a [`bumpalo::Bump`] arena.
+//!
+//! Algorithms producing long chains of short-lived temporaries, such as remainder
+//! sequences, can run on [`ArenaPolynomial`]s: every result is a slice in the arena, so
+//! the whole chain costs a handful of chunk allocations and is freed at once when the
+//! arena is reset or dropped. Convert the values worth keeping back with
+//! [`to_polynomial`](ArenaPolynomial::to_polynomial).
+
+use bumpalo::Bump;
+
+use crate::polynomial::Polynomial;
+use crate::tolerance::Tolerance;
+
+/// A polynomial whose coefficients, lowest degree first, are borrowed from an arena.
+///
+/// Like [`Polynomial`], the coefficients carry no trailing zeros and the zero polynomial is
+/// `[0]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArenaPolynomial<'a> {
+    /// Coefficients, lowest degree first, borrowed from the arena.
+    pub coefficients: &'a [f64],
+    /// The indeterminate, as in [`Polynomial::indeterminate`].
+    pub indeterminate: char,
+}
+
+impl<'a> ArenaPolynomial<'a> {
+    /// Copies `coefficients` into `bump`, dropping trailing zeros
+    /// # Example
+    /// ```
+    /// use bumpalo::Bump;
+    /// use polynom::arena::ArenaPolynomial;
+    ///
+    /// let bump = Bump::new();
+    /// let polynomial = ArenaPolynomial::new_in(&[1f64, 2f64, 0f64], 'x', &bump);
+    /// assert_eq!(polynomial.coefficients, &[1f64, 2f64]);
+    /// ```
+    pub fn new_in(
+        coefficients: &[f64],
+        indeterminate: char,
+        bump: &'a Bump,
+    ) -> ArenaPolynomial<'a> {
+        ArenaPolynomial::trimmed(bump.alloc_slice_copy(coefficients), indeterminate)
+    }
+
+    /// Copies `polynomial` into `bump`
+    pub fn from_polynomial_in(polynomial: &Polynomial, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        ArenaPolynomial::new_in(&polynomial.coefficients, polynomial.indeterminate, bump)
+    }
+
+    /// Wraps `coefficients` with their trailing zeros sliced off.
+    fn trimmed(coefficients: &'a [f64], indeterminate: char) -> ArenaPolynomial<'a> {
+        let coefficients = match coefficients.iter().rposition(|&coeff| coeff != 0f64) {
+            Some(last) => &coefficients[..=last],
+            None => &[0f64],
+        };
+        ArenaPolynomial {
+            coefficients,
+            indeterminate,
+        }
+    }
+
+    /// Returns the degree; the zero polynomial has degree `-1`
+    pub fn degree(&self) -> isize {
+        if self.coefficients == [0f64] {
+            return -1;
+        }
+        (self.coefficients.len() - 1) as isize
+    }
+
+    /// Returns the value at `determinate`, computed with Horner's scheme
+    pub fn evaluate_at(&self, determinate: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0f64, |sum, &coeff| sum * determinate + coeff)
+    }
+
+    /// Copies the polynomial out of the arena
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.to_vec(), self.indeterminate)
+    }
+
+    /// Returns `self + other`, allocated in `bump`
+    pub fn add_in(&self, other: &ArenaPolynomial<'_>, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        self.combine_in(other, 1f64, bump)
+    }
+
+    /// Returns `self - other`, allocated in `bump`
+    pub fn sub_in(&self, other: &ArenaPolynomial<'_>, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        self.combine_in(other, -1f64, bump)
+    }
+
+    /// Returns `self + sign * other`.
+    fn combine_in(
+        &self,
+        other: &ArenaPolynomial<'_>,
+        sign: f64,
+        bump: &'a Bump,
+    ) -> ArenaPolynomial<'a> {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = bump.alloc_slice_fill_with(length, |degree| {
+            self.coefficients.get(degree).unwrap_or(&0f64)
+                + sign * other.coefficients.get(degree).unwrap_or(&0f64)
+        });
+        ArenaPolynomial::trimmed(coefficients, self.indeterminate)
+    }
+
+    /// Returns `factor * self`, allocated in `bump`
+    pub fn scale_in(&self, factor: f64, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        let coefficients =
+            bump.alloc_slice_fill_iter(self.coefficients.iter().map(|coeff| factor * coeff));
+        ArenaPolynomial::trimmed(coefficients, self.indeterminate)
+    }
+
+    /// Returns `self * other`, allocated in `bump`
+    /// # Example
+    /// ```
+    /// use bumpalo::Bump;
+    /// use polynom::arena::ArenaPolynomial;
+    ///
+    /// let bump = Bump::new();
+    /// let a = ArenaPolynomial::new_in(&[1f64, 1f64], 'x', &bump);
+    /// let b = ArenaPolynomial::new_in(&[-1f64, 1f64], 'x', &bump);
+    /// assert_eq!(a.mul_in(&b, &bump).coefficients, &[-1f64, 0f64, 1f64]);
+    /// ```
+    pub fn mul_in(&self, other: &ArenaPolynomial<'_>, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        let length = self.coefficients.len() + other.coefficients.len() - 1;
+        let coefficients = bump.alloc_slice_fill_copy(length, 0f64);
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        ArenaPolynomial::trimmed(coefficients, self.indeterminate)
+    }
+
+    /// Returns the derivative, allocated in `bump`
+    pub fn derivative_in(&self, bump: &'a Bump) -> ArenaPolynomial<'a> {
+        let coefficients = bump.alloc_slice_fill_iter(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(degree, coeff)| degree as f64 * coeff),
+        );
+        ArenaPolynomial::trimmed(coefficients, self.indeterminate)
+    }
+
+    /// Returns the quotient and remainder of dividing by `divisor`, both allocated in
+    /// `bump`
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem_in(
+        &self,
+        divisor: &ArenaPolynomial<'_>,
+        bump: &'a Bump,
+    ) -> (ArenaPolynomial<'a>, ArenaPolynomial<'a>) {
+        let divisor_degree = divisor.degree();
+        assert!(divisor_degree >= 0, "division by the zero polynomial");
+        let divisor_degree = divisor_degree as usize;
+        let leading = divisor.coefficients[divisor_degree];
+
+        let remainder = bump.alloc_slice_copy(self.coefficients);
+        if remainder.len() <= divisor_degree {
+            let zero = ArenaPolynomial::trimmed(&[], self.indeterminate);
+            return (
+                zero,
+                ArenaPolynomial::trimmed(remainder, self.indeterminate),
+            );
+        }
+        let quotient = bump.alloc_slice_fill_copy(remainder.len() - divisor_degree, 0f64);
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree] / leading;
+            quotient[i] = factor;
+            for (j, coeff) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor * coeff;
+            }
+        }
+
+        // The eliminated leading terms are zero by construction, not just up to rounding
+        (
+            ArenaPolynomial::trimmed(quotient, self.indeterminate),
+            ArenaPolynomial::trimmed(&remainder[..divisor_degree], self.indeterminate),
+        )
+    }
+
+    /// Like [`Polynomial::gcd_with`], with every remainder of the Euclidean sequence
+    /// allocated in `bump`
+    /// # Example
+    /// ```
+    /// use bumpalo::Bump;
+    /// use polynom::arena::ArenaPolynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let bump = Bump::new();
+    /// // (x - 1)(x - 2) and (x - 1)(x + 3)
+    /// let a = ArenaPolynomial::new_in(&[2f64, -3f64, 1f64], 'x', &bump);
+    /// let b = ArenaPolynomial::new_in(&[-3f64, 2f64, 1f64], 'x', &bump);
+    /// let gcd = a.gcd_in(&b, &Tolerance::default(), &bump);
+    /// assert_eq!(gcd.coefficients, &[-1f64, 1f64]);
+    /// ```
+    pub fn gcd_in(
+        &self,
+        other: &ArenaPolynomial<'_>,
+        tolerance: &Tolerance,
+        bump: &'a Bump,
+    ) -> ArenaPolynomial<'a> {
+        let mut a = ArenaPolynomial::new_in(self.coefficients, self.indeterminate, bump);
+        let mut b = ArenaPolynomial::new_in(other.coefficients, self.indeterminate, bump);
+        while b.degree() >= 0 {
+            let (_, remainder) = a.div_rem_in(&b, bump);
+            let scale = b
+                .coefficients
+                .iter()
+                .fold(0f64, |largest, coeff| largest.max(coeff.abs()));
+            let last = remainder
+                .coefficients
+                .iter()
+                .rposition(|&coeff| !tolerance.is_negligible(coeff, scale))
+                .map_or(0, |last| last + 1);
+            a = b;
+            b = ArenaPolynomial::trimmed(&remainder.coefficients[..last], self.indeterminate);
+        }
+
+        let degree = a.degree();
+        if degree < 0 {
+            return a;
+        }
+        a.scale_in(1f64 / a.coefficients[degree as usize], bump)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_polynomial_arithmetic() {
+        let bump = Bump::new();
+        let p = Polynomial::new(vec![1f64, -2f64, 0f64, 3f64], 'x');
+        let q = Polynomial::new(vec![-1f64, 0.5, 2f64], 'x');
+        let a = ArenaPolynomial::from_polynomial_in(&p, &bump);
+        let b = ArenaPolynomial::from_polynomial_in(&q, &bump);
+
+        assert_eq!(a.add_in(&b, &bump).to_polynomial(), p.clone() + q.clone());
+        assert_eq!(a.sub_in(&b, &bump).to_polynomial(), p.clone() - q.clone());
+        assert_eq!(
+            a.mul_in(&b, &bump).to_polynomial(),
+            p.clone().multiply(q.clone())
+        );
+        assert_eq!(a.derivative_in(&bump).to_polynomial(), p.derivative());
+        let (quotient, remainder) = a.div_rem_in(&b, &bump);
+        let (expected_quotient, expected_remainder) = p.div_rem(&q);
+        assert_eq!(quotient.to_polynomial(), expected_quotient);
+        assert_eq!(remainder.to_polynomial(), expected_remainder);
+        assert_eq!(a.evaluate_at(2f64), p.evaluate_at(2f64));
+
+        // Cancellation leaves no trailing zeros
+        let difference = a.sub_in(&a, &bump);
+        assert_eq!(difference.coefficients, &[0f64]);
+        assert_eq!(difference.degree(), -1);
+        assert_eq!(b.div_rem_in(&a, &bump).0.degree(), -1);
+    }
+
+    #[test]
+    fn test_gcd_matches_polynomial_gcd() {
+        let bump = Bump::new();
+        let common = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+        let p = common
+            .clone()
+            .multiply(Polynomial::new(vec![3f64, 1f64, 1f64], 'x'));
+        let q = common.multiply(Polynomial::new(vec![-5f64, 1f64], 'x'));
+        let a = ArenaPolynomial::from_polynomial_in(&p, &bump);
+        let b = ArenaPolynomial::from_polynomial_in(&q, &bump);
+        let tolerance = Tolerance::new(0f64, 1e-9);
+        let gcd = a.gcd_in(&b, &tolerance, &bump).to_polynomial();
+        let expected = p.gcd_with(&q, &tolerance);
+        assert_eq!(gcd.degree(), 2);
+        assert_eq!(expected.degree(), 2);
+        for (x, y) in gcd.coefficients.iter().zip(&expected.coefficients) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        let zero = ArenaPolynomial::new_in(&[0f64], 'x', &bump);
+        assert_eq!(zero.gcd_in(&zero, &tolerance, &bump).degree(), -1);
+    }
+}
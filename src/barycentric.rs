@@ -0,0 +1,276 @@
+//! Polynomial interpolation in barycentric form.
+//!
+//! The barycentric formula evaluates the interpolant through `n` nodes in `O(n)` operations
+//! and is numerically stable for well-spread nodes such as Chebyshev points, whereas
+//! expanding the Lagrange form into monomial coefficients loses accuracy quickly as `n`
+//! grows. Convert to a [`Polynomial`] only when the coefficients themselves are needed.
+
+use std::fmt;
+
+use crate::nodes::chebyshev_points_second_kind;
+use crate::polynomial::Polynomial;
+
+/// Errors that can occur while building a [`BarycentricInterpolant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationError {
+    /// `nodes` and `values` have different lengths.
+    MismatchedLengths,
+    /// No nodes were given.
+    NoNodes,
+    /// The node at `index` repeats an earlier node.
+    RepeatedNode {
+        /// The index of the repeated node.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationError::MismatchedLengths => {
+                write!(f, "nodes and values must have the same length")
+            }
+            InterpolationError::NoNodes => write!(f, "at least one node is required"),
+            InterpolationError::RepeatedNode { index } => {
+                write!(f, "node {} repeats an earlier node", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InterpolationError {}
+
+/// The polynomial of degree below `n` through `n` points, stored as its nodes, values and
+/// barycentric weights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarycentricInterpolant {
+    nodes: Vec<f64>,
+    values: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+impl BarycentricInterpolant {
+    /// Returns the interpolant through `(nodes[i], values[i])`, computing its weights in
+    /// `O(n^2)` operations
+    /// # Example
+    /// ```
+    /// use polynom::barycentric::BarycentricInterpolant;
+    ///
+    /// // The parabola through (0, 1), (1, 2) and (2, 5) is x^2 + 1
+    /// let interpolant =
+    ///     BarycentricInterpolant::new(vec![0f64, 1f64, 2f64], vec![1f64, 2f64, 5f64]).unwrap();
+    /// assert_eq!(interpolant.evaluate_at(1f64), 2f64);
+    /// assert!((interpolant.evaluate_at(3f64) - 10f64).abs() < 1e-12);
+    /// ```
+    pub fn new(
+        nodes: Vec<f64>,
+        values: Vec<f64>,
+    ) -> Result<BarycentricInterpolant, InterpolationError> {
+        if nodes.len() != values.len() {
+            return Err(InterpolationError::MismatchedLengths);
+        }
+        if nodes.is_empty() {
+            return Err(InterpolationError::NoNodes);
+        }
+
+        // Scaling every difference by 4 / (b - a) keeps the products from overflowing or
+        // underflowing; a common factor in the weights cancels in the barycentric formula
+        let low = nodes.iter().cloned().fold(f64::INFINITY, f64::min);
+        let high = nodes.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let capacity = if high > low {
+            4f64 / (high - low)
+        } else {
+            1f64
+        };
+        let mut weights = Vec::with_capacity(nodes.len());
+        for (j, &node) in nodes.iter().enumerate() {
+            let mut product = 1f64;
+            for (k, &other) in nodes.iter().enumerate() {
+                if k != j {
+                    product *= capacity * (node - other);
+                }
+            }
+            if product == 0f64 {
+                let index = (0..nodes.len())
+                    .find(|&k| k > 0 && nodes[..k].contains(&nodes[k]))
+                    .unwrap_or(j);
+                return Err(InterpolationError::RepeatedNode { index });
+            }
+            weights.push(1f64 / product);
+        }
+        let largest = weights.iter().fold(0f64, |m, w| m.max(w.abs()));
+        for weight in weights.iter_mut() {
+            *weight /= largest;
+        }
+
+        Ok(BarycentricInterpolant {
+            nodes,
+            values,
+            weights,
+        })
+    }
+
+    /// Returns the interpolant of `f` at the `n` Chebyshev points of the second kind on
+    /// `interval`, whose weights are known in closed form
+    /// # Example
+    /// ```
+    /// use polynom::barycentric::BarycentricInterpolant;
+    ///
+    /// let interpolant = BarycentricInterpolant::chebyshev(30, (0f64, 2f64), f64::exp);
+    /// assert!((interpolant.evaluate_at(1.234) - 1.234f64.exp()).abs() < 1e-14);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn chebyshev<F: Fn(f64) -> f64>(
+        n: usize,
+        interval: (f64, f64),
+        f: F,
+    ) -> BarycentricInterpolant {
+        assert!(n > 0, "at least one node is required");
+        let nodes = chebyshev_points_second_kind(n, interval);
+        let values = nodes.iter().map(|&x| f(x)).collect();
+        let weights = (0..n)
+            .map(|j| {
+                let sign = if j % 2 == 0 { 1f64 } else { -1f64 };
+                if j == 0 || j == n - 1 {
+                    sign / 2f64
+                } else {
+                    sign
+                }
+            })
+            .collect();
+
+        BarycentricInterpolant {
+            nodes,
+            values,
+            weights,
+        }
+    }
+
+    /// Returns the interpolation nodes
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// Returns the values at the nodes
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the barycentric weights, scaled so the largest has magnitude one
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Evaluates the interpolant at `x` with the second barycentric formula in `O(n)`
+    /// operations, returning the stored value exactly at a node
+    pub fn evaluate_at(&self, x: f64) -> f64 {
+        let mut numerator = 0f64;
+        let mut denominator = 0f64;
+        for ((&node, &value), &weight) in self.nodes.iter().zip(&self.values).zip(&self.weights) {
+            let difference = x - node;
+            if difference == 0f64 {
+                return value;
+            }
+            let term = weight / difference;
+            numerator += term * value;
+            denominator += term;
+        }
+
+        numerator / denominator
+    }
+
+    /// Expands the interpolant into monomial coefficients through Newton's divided
+    /// differences.
+    ///
+    /// The monomial coefficients of a high-degree interpolant are ill-conditioned, so
+    /// prefer [`evaluate_at`](BarycentricInterpolant::evaluate_at) when only values are
+    /// needed.
+    /// # Example
+    /// ```
+    /// use polynom::barycentric::BarycentricInterpolant;
+    ///
+    /// let interpolant =
+    ///     BarycentricInterpolant::new(vec![0f64, 1f64, 2f64], vec![1f64, 2f64, 5f64]).unwrap();
+    /// assert_eq!(interpolant.to_polynomial().coefficients, vec![1f64, 0f64, 1f64]);
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial {
+        let n = self.nodes.len();
+        let mut differences = self.values.clone();
+        for level in 1..n {
+            for i in (level..n).rev() {
+                differences[i] =
+                    (differences[i] - differences[i - 1]) / (self.nodes[i] - self.nodes[i - level]);
+            }
+        }
+
+        // Horner's scheme on the Newton form d_0 + (x - x_0)(d_1 + (x - x_1)(d_2 + ...))
+        let mut coefficients = vec![differences[n - 1]];
+        for i in (0..n - 1).rev() {
+            let mut next = vec![0f64; coefficients.len() + 1];
+            for (degree, coeff) in coefficients.iter().enumerate() {
+                next[degree + 1] += coeff;
+                next[degree] -= coeff * self.nodes[i];
+            }
+            next[0] += differences[i];
+            coefficients = next;
+        }
+
+        Polynomial::new(coefficients, 'x')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::chebyshev_points_first_kind;
+
+    #[test]
+    fn test_high_degree_interpolation_stays_accurate() {
+        // Runge's function is resolved by Chebyshev nodes despite the high degree
+        let runge = |x: f64| 1f64 / (1f64 + 25f64 * x * x);
+        let nodes = chebyshev_points_first_kind(200, (-1f64, 1f64));
+        let values = nodes.iter().map(|&x| runge(x)).collect();
+        let interpolant = BarycentricInterpolant::new(nodes, values).unwrap();
+        let closed_form = BarycentricInterpolant::chebyshev(200, (-1f64, 1f64), runge);
+        for i in 0..=100 {
+            let x = -1f64 + i as f64 / 50f64;
+            assert!((interpolant.evaluate_at(x) - runge(x)).abs() < 1e-12);
+            assert!((closed_form.evaluate_at(x) - runge(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_to_polynomial_reproduces_polynomials() {
+        let polynomial = Polynomial::new(vec![2f64, -1f64, 0.5, 3f64], 'x');
+        let nodes = vec![-1f64, 0.5, 2f64, 3f64, 4f64];
+        let values = nodes.iter().map(|&x| polynomial.evaluate_at(x)).collect();
+        let interpolant = BarycentricInterpolant::new(nodes, values).unwrap();
+        let expanded = interpolant.to_polynomial();
+        assert_eq!(expanded.degree(), 3);
+        for (a, b) in expanded.coefficients.iter().zip(&polynomial.coefficients) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let constant = BarycentricInterpolant::new(vec![5f64], vec![-2f64]).unwrap();
+        assert_eq!(constant.evaluate_at(100f64), -2f64);
+        assert_eq!(constant.to_polynomial().coefficients, vec![-2f64]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            BarycentricInterpolant::new(vec![1f64], vec![]),
+            Err(InterpolationError::MismatchedLengths)
+        );
+        assert_eq!(
+            BarycentricInterpolant::new(vec![], vec![]),
+            Err(InterpolationError::NoNodes)
+        );
+        assert_eq!(
+            BarycentricInterpolant::new(vec![0f64, 1f64, 0f64], vec![1f64; 3]),
+            Err(InterpolationError::RepeatedNode { index: 2 })
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Basins of attraction of Newton's method in the complex plane.
+
+use crate::complex::{Complex, Viewport};
+use crate::polynomial::Polynomial;
+
+/// Newton steps taken from each starting point before it is declared divergent.
+pub const MAX_BASIN_ITERATIONS: usize = 100;
+
+/// Where Newton's method went from one starting point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Basin {
+    /// Index into [`BasinMap::roots`] of the root reached, or `None` if the iteration
+    /// hit a critical point or did not converge within [`MAX_BASIN_ITERATIONS`] steps.
+    pub root: Option<usize>,
+    /// Newton steps taken before convergence or failure.
+    pub iterations: usize,
+}
+
+/// The result of [`Polynomial::newton_basins`]: one [`Basin`] per viewport point, in the
+/// viewport's row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasinMap {
+    /// The starting points of the Newton iterations.
+    pub viewport: Viewport,
+    /// The distinct roots reached from any point, sorted by real then imaginary part.
+    pub roots: Vec<Complex>,
+    /// The outcome from each starting point.
+    pub basins: Vec<Basin>,
+}
+
+impl BasinMap {
+    /// Returns the basin of the point at (`row`, `column`)
+    pub fn get(&self, row: usize, column: usize) -> Basin {
+        self.basins[row * self.viewport.width + column]
+    }
+}
+
+impl Polynomial {
+    /// Runs Newton's method from every point of `grid` and records which root it converges
+    /// to and how quickly.
+    ///
+    /// Roots are discovered by the iteration itself, so [`BasinMap::roots`] only contains
+    /// roots whose basin meets the grid. Near a root of multiplicity `m` rounding limits the
+    /// iteration to roughly `m`-th root of machine precision, so limits that agree to within
+    /// `1e-3` relative to their size are identified rather than reported as separate roots.
+    /// # Example
+    /// ```
+    /// use polynom::complex::{Complex, Viewport};
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // z^2 - 1: the left half-plane flows to -1 and the right half-plane to 1
+    /// let polynomial = Polynomial::new(vec![-1f64, 0f64, 1f64], 'z');
+    /// let map = polynomial.newton_basins(&Viewport::new((-2f64, 2f64), (-1f64, 1f64), (5, 3)));
+    /// assert_eq!(map.roots.len(), 2);
+    /// assert!((map.roots[0] - Complex::from(-1f64)).norm() < 1e-12);
+    /// assert!((map.roots[1] - Complex::from(1f64)).norm() < 1e-12);
+    /// assert_eq!(map.get(1, 0).root, Some(0));
+    /// assert_eq!(map.get(1, 4).root, Some(1));
+    /// // Points on the imaginary axis never leave it
+    /// assert_eq!(map.get(0, 2).root, None);
+    /// ```
+    pub fn newton_basins(&self, grid: &Viewport) -> BasinMap {
+        debug_span!("newton_basins", width = grid.width, height = grid.height);
+        let derivative = self.derivative();
+        let mut roots: Vec<Complex> = Vec::new();
+        let mut basins: Vec<Basin> = grid
+            .points()
+            .map(|z| match self.newton_limit(&derivative, z) {
+                (Some(limit), iterations) => {
+                    let index = roots
+                        .iter()
+                        .position(|&root| same_root(root, limit))
+                        .unwrap_or_else(|| {
+                            roots.push(limit);
+                            roots.len() - 1
+                        });
+                    Basin {
+                        root: Some(index),
+                        iterations,
+                    }
+                }
+                (None, iterations) => Basin {
+                    root: None,
+                    iterations,
+                },
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..roots.len()).collect();
+        order.sort_by(|&a, &b| {
+            (roots[a].re, roots[a].im)
+                .partial_cmp(&(roots[b].re, roots[b].im))
+                .unwrap()
+        });
+        let mut rank = vec![0; roots.len()];
+        for (position, &index) in order.iter().enumerate() {
+            rank[index] = position;
+        }
+        for basin in basins.iter_mut() {
+            basin.root = basin.root.map(|index| rank[index]);
+        }
+        debug_event!(roots = roots.len(), "classified");
+
+        BasinMap {
+            viewport: *grid,
+            roots: order.into_iter().map(|index| roots[index]).collect(),
+            basins,
+        }
+    }
+
+    /// Iterates Newton's method from `z`, returning the limit if it converges and the number
+    /// of steps taken.
+    fn newton_limit(&self, derivative: &Polynomial, mut z: Complex) -> (Option<Complex>, usize) {
+        if self.degree() < 1 {
+            return (None, 0);
+        }
+        for iteration in 0..MAX_BASIN_ITERATIONS {
+            let value = self.evaluate_generic(z);
+            if value.norm() == 0f64 {
+                return (Some(z), iteration);
+            }
+            let slope = derivative.evaluate_generic(z);
+            if slope.norm() == 0f64 {
+                return (None, iteration);
+            }
+            let step = value / slope;
+            z = z - step;
+            if !(z.re.is_finite() && z.im.is_finite()) {
+                return (None, iteration + 1);
+            }
+            // Stop on a negligible step, or once p(z) is within rounding error of zero
+            if step.norm() <= 4f64 * f64::EPSILON * z.norm()
+                || self.evaluate_generic(z).norm() <= self.evaluation_tolerance(z.norm())
+            {
+                return (Some(z), iteration + 1);
+            }
+        }
+
+        (None, MAX_BASIN_ITERATIONS)
+    }
+}
+
+/// Returns `true` if two Newton limits should be treated as the same root.
+fn same_root(a: Complex, b: Complex) -> bool {
+    (a - b).norm() <= 1e-3 * (1f64 + a.norm().max(b.norm()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cube_roots_of_unity() {
+        let polynomial = Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'z');
+        let map = polynomial.newton_basins(&Viewport::new((-2f64, 2f64), (-2f64, 2f64), (41, 41)));
+        assert_eq!(map.basins.len(), 41 * 41);
+        assert_eq!(map.roots.len(), 3);
+        let half = 3f64.sqrt() / 2f64;
+        let expected = [
+            Complex::new(-0.5, -half),
+            Complex::new(-0.5, half),
+            Complex::from(1f64),
+        ];
+        for (root, expected) in map.roots.iter().zip(&expected) {
+            assert!((*root - *expected).norm() < 1e-12);
+        }
+        // Points close to a root converge to it in a few steps
+        for (index, root) in expected.iter().enumerate() {
+            let start = *root * Complex::from(1.1);
+            let (limit, iterations) = polynomial.newton_limit(&polynomial.derivative(), start);
+            assert!(same_root(limit.unwrap(), map.roots[index]));
+            assert!(iterations < 10);
+        }
+        // The origin is a critical point
+        assert_eq!(map.get(20, 20).root, None);
+        assert!(
+            map.basins
+                .iter()
+                .filter(|basin| basin.root.is_some())
+                .count()
+                > 1600
+        );
+    }
+
+    #[test]
+    fn test_multiple_root_is_not_split() {
+        // (z - 1)^3 (z + 2)
+        let polynomial = Polynomial::new(vec![-2f64, 5f64, -3f64, -1f64, 1f64], 'z');
+        let map = polynomial.newton_basins(&Viewport::new((-3f64, 3f64), (-1f64, 1f64), (13, 5)));
+        assert_eq!(map.roots.len(), 2);
+        assert!((map.roots[0] - Complex::from(-2f64)).norm() < 1e-12);
+        assert!((map.roots[1] - Complex::from(1f64)).norm() < 1e-4);
+
+        let constant = Polynomial::new(vec![3f64], 'z');
+        let map = constant.newton_basins(&Viewport::new((0f64, 1f64), (0f64, 1f64), (2, 2)));
+        assert!(map.roots.is_empty());
+        assert!(map.basins.iter().all(|basin| basin.root.is_none()));
+    }
+}
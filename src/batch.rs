@@ -0,0 +1,256 @@
+//! Evaluating many polynomials over many points through a pluggable backend.
+//!
+//! Inputs and outputs are flat buffers: coefficients are packed once into a
+//! [`PackedPolynomials`], points are a plain `&[f64]`, and results are written into a
+//! caller-provided `&mut [f64]`. This is the layout an accelerator backend uploads and
+//! downloads directly, so no per-polynomial or per-point allocation happens at the API
+//! boundary.
+//!
+//! Only the [`CpuBackend`] is implemented so far. The feature-gated GPU backend (wgpu or
+//! CUDA) has not been written yet; it will implement [`EvaluationBackend`] over these
+//! buffers and return [`BatchError::BackendUnavailable`] when no device is present, so that
+//! [`evaluate_batch`] falls back to the CPU.
+
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// Errors returned by [`EvaluationBackend::evaluate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchError {
+    /// The output buffer does not hold exactly one value per polynomial and point.
+    OutputLength {
+        /// The number of values needed, polynomials times points.
+        expected: usize,
+        /// The length of the buffer given.
+        actual: usize,
+    },
+    /// The backend could not run the evaluation, e.g. because its device is unavailable.
+    BackendUnavailable,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::OutputLength { expected, actual } => write!(
+                f,
+                "output buffer holds {} values but {} are needed",
+                actual, expected
+            ),
+            BatchError::BackendUnavailable => write!(f, "evaluation backend is unavailable"),
+        }
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+/// The coefficients of several polynomials in one contiguous buffer.
+///
+/// Polynomial `i` has coefficients `coefficients()[offsets()[i]..offsets()[i + 1]]`, lowest
+/// degree first. The offsets start at zero, never decrease and end at the length of the
+/// coefficient buffer; [`PackedPolynomials::pack`] is the only constructor, so this always
+/// holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedPolynomials {
+    coefficients: Vec<f64>,
+    offsets: Vec<usize>,
+}
+
+impl PackedPolynomials {
+    /// Packs `polynomials` in order
+    /// # Example
+    /// ```
+    /// use polynom::batch::PackedPolynomials;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let packed = PackedPolynomials::pack(&[
+    ///     Polynomial::new(vec![1f64, 2f64], 'x'),
+    ///     Polynomial::new(vec![3f64], 'x'),
+    /// ]);
+    /// assert_eq!(packed.coefficients(), &[1f64, 2f64, 3f64]);
+    /// assert_eq!(packed.offsets(), &[0, 2, 3]);
+    /// ```
+    pub fn pack(polynomials: &[Polynomial]) -> PackedPolynomials {
+        let mut coefficients = Vec::new();
+        let mut offsets = Vec::with_capacity(polynomials.len() + 1);
+        offsets.push(0);
+        for polynomial in polynomials {
+            coefficients.extend_from_slice(&polynomial.coefficients);
+            offsets.push(coefficients.len());
+        }
+
+        PackedPolynomials {
+            coefficients,
+            offsets,
+        }
+    }
+
+    /// Returns the coefficients of every packed polynomial, one after another
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the start of each polynomial in [`PackedPolynomials::coefficients`], followed by
+    /// the total number of coefficients
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the number of packed polynomials
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns true if no polynomials are packed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the coefficients of polynomial `index`
+    pub fn coefficients_of(&self, index: usize) -> &[f64] {
+        &self.coefficients[self.offsets[index]..self.offsets[index + 1]]
+    }
+}
+
+/// A device that evaluates packed polynomials over a buffer of points.
+pub trait EvaluationBackend {
+    /// Writes `p_i(points[j])` to `out[i * points.len() + j]` for every packed polynomial `i`
+    /// and point `j`.
+    fn evaluate(
+        &self,
+        polynomials: &PackedPolynomials,
+        points: &[f64],
+        out: &mut [f64],
+    ) -> Result<(), BatchError>;
+}
+
+/// Evaluates on the current thread with Horner's scheme; always available.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuBackend;
+
+impl EvaluationBackend for CpuBackend {
+    fn evaluate(
+        &self,
+        polynomials: &PackedPolynomials,
+        points: &[f64],
+        out: &mut [f64],
+    ) -> Result<(), BatchError> {
+        check_output_length(polynomials, points, out)?;
+        if points.is_empty() {
+            return Ok(());
+        }
+        for (index, row) in out.chunks_exact_mut(points.len()).enumerate() {
+            let coefficients = polynomials.coefficients_of(index);
+            for (value, &x) in row.iter_mut().zip(points) {
+                *value = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0f64, |value, coeff| value * x + coeff);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Evaluates with `backend`, falling back to the [`CpuBackend`] if it reports
+/// [`BatchError::BackendUnavailable`]
+/// # Example
+/// ```
+/// use polynom::batch::{evaluate_batch, CpuBackend, PackedPolynomials};
+/// use polynom::polynomial::Polynomial;
+///
+/// let packed = PackedPolynomials::pack(&[
+///     Polynomial::new(vec![1f64, 2f64], 'x'),
+///     Polynomial::new(vec![0f64, 0f64, 1f64], 'x'),
+/// ]);
+/// let points = [0f64, 1f64, 2f64];
+/// let mut out = vec![0f64; packed.len() * points.len()];
+/// evaluate_batch(&CpuBackend, &packed, &points, &mut out).unwrap();
+/// assert_eq!(out, vec![1f64, 3f64, 5f64, 0f64, 1f64, 4f64]);
+/// ```
+pub fn evaluate_batch<B: EvaluationBackend + ?Sized>(
+    backend: &B,
+    polynomials: &PackedPolynomials,
+    points: &[f64],
+    out: &mut [f64],
+) -> Result<(), BatchError> {
+    match backend.evaluate(polynomials, points, out) {
+        Err(BatchError::BackendUnavailable) => CpuBackend.evaluate(polynomials, points, out),
+        result => result,
+    }
+}
+
+fn check_output_length(
+    polynomials: &PackedPolynomials,
+    points: &[f64],
+    out: &[f64],
+) -> Result<(), BatchError> {
+    let expected = polynomials.len() * points.len();
+    if out.len() != expected {
+        return Err(BatchError::OutputLength {
+            expected,
+            actual: out.len(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+    use crate::test_polynomials;
+
+    struct Unavailable;
+
+    impl EvaluationBackend for Unavailable {
+        fn evaluate(
+            &self,
+            _: &PackedPolynomials,
+            _: &[f64],
+            _: &mut [f64],
+        ) -> Result<(), BatchError> {
+            Err(BatchError::BackendUnavailable)
+        }
+    }
+
+    #[test]
+    fn test_batch_matches_evaluate_at() {
+        let mut rng = SplitMix64::new(11);
+        let polynomials: Vec<Polynomial> = (0..5)
+            .map(|degree| test_polynomials::random(degree, &mut rng))
+            .collect();
+        let packed = PackedPolynomials::pack(&polynomials);
+        let points: Vec<f64> = (0..7).map(|i| i as f64 * 0.3 - 1f64).collect();
+        let mut out = vec![0f64; packed.len() * points.len()];
+
+        evaluate_batch(&Unavailable, &packed, &points, &mut out).unwrap();
+        for (i, polynomial) in polynomials.iter().enumerate() {
+            for (j, &x) in points.iter().enumerate() {
+                let expected = polynomial.evaluate_at(x);
+                assert!(
+                    (out[i * points.len() + j] - expected).abs() <= 1e-12 * (1f64 + expected.abs())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_output_length_is_checked() {
+        let packed = PackedPolynomials::pack(&[Polynomial::new(vec![1f64], 'x')]);
+        let mut out = vec![0f64; 3];
+        assert_eq!(
+            CpuBackend.evaluate(&packed, &[1f64, 2f64], &mut out),
+            Err(BatchError::OutputLength {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            CpuBackend.evaluate(&PackedPolynomials::pack(&[]), &[1f64], &mut []),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! A minimal arbitrary-precision signed integer, with just enough arithmetic for exact
+//! polynomial sign evaluation at dyadic points and decimal output.
+
+use std::cmp::Ordering;
+
+/// A signed integer stored as a sign and little-endian base `2^32` digits, without leading
+/// zero digits. Zero is never negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    pub(crate) fn zero() -> BigInt {
+        BigInt {
+            negative: false,
+            magnitude: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_i128(value: i128) -> BigInt {
+        let mut magnitude = Vec::new();
+        let mut rest = value.unsigned_abs();
+        while rest > 0 {
+            magnitude.push(rest as u32);
+            rest >>= 32;
+        }
+
+        BigInt::from_parts(value < 0, magnitude)
+    }
+
+    /// Returns the nonnegative integer with the given little-endian base `2^32` digits.
+    pub(crate) fn from_digits(digits: Vec<u32>) -> BigInt {
+        BigInt::from_parts(false, digits)
+    }
+
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub(crate) fn signum(&self) -> Ordering {
+        if self.magnitude.is_empty() {
+            Ordering::Equal
+        } else if self.negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    pub(crate) fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+
+    pub(crate) fn mul(&self, other: &BigInt) -> BigInt {
+        let mut product = vec![0u32; self.magnitude.len() + other.magnitude.len()];
+        for (i, &a) in self.magnitude.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.magnitude.iter().enumerate() {
+                let sum = product[i + j] as u64 + a as u64 * b as u64 + carry;
+                product[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            product[i + other.magnitude.len()] = carry as u32;
+        }
+
+        BigInt::from_parts(self.negative != other.negative, product)
+    }
+
+    /// Returns `self * 2^bits`.
+    pub(crate) fn shl(&self, bits: usize) -> BigInt {
+        if self.magnitude.is_empty() {
+            return BigInt::zero();
+        }
+        let (digits, bits) = (bits / 32, bits % 32);
+        let mut magnitude = vec![0u32; digits];
+        let mut carry = 0u32;
+        for &digit in &self.magnitude {
+            if bits == 0 {
+                magnitude.push(digit);
+            } else {
+                magnitude.push(digit << bits | carry);
+                carry = digit >> (32 - bits);
+            }
+        }
+        magnitude.push(carry);
+
+        BigInt::from_parts(self.negative, magnitude)
+    }
+
+    /// Returns `self / 2^bits` rounded towards negative infinity if `ceiling` is `false`,
+    /// and towards positive infinity otherwise.
+    pub(crate) fn shr_rounded(&self, bits: usize, ceiling: bool) -> BigInt {
+        let (digits, bits) = (bits / 32, bits % 32);
+        let inexact = self.magnitude.iter().take(digits).any(|&digit| digit != 0)
+            || self
+                .magnitude
+                .get(digits)
+                .is_some_and(|&digit| bits > 0 && digit << (32 - bits) != 0);
+        let high = self.magnitude.get(digits..).unwrap_or(&[]);
+        let magnitude = (0..high.len())
+            .map(|i| {
+                if bits == 0 {
+                    high[i]
+                } else {
+                    high[i] >> bits | high.get(i + 1).map_or(0, |&next| next << (32 - bits))
+                }
+            })
+            .collect();
+        let truncated = BigInt::from_parts(self.negative, magnitude);
+
+        // Truncation rounds towards zero, which is already correct on one side
+        if inexact && ceiling != self.negative {
+            let step = if ceiling { 1 } else { -1 };
+            truncated.add(&BigInt::from_i128(step))
+        } else {
+            truncated
+        }
+    }
+
+    /// Returns the nearest `f64` to `self / 2^scale`, up to a few units in the last place.
+    pub(crate) fn to_f64_scaled(&self, scale: usize) -> f64 {
+        // Only the three leading digits can affect the result
+        let skipped = self.magnitude.len().saturating_sub(3);
+        let leading = self.magnitude[skipped..]
+            .iter()
+            .rev()
+            .fold(0f64, |value, &digit| {
+                value * 4_294_967_296f64 + digit as f64
+            });
+        let exponent = 32 * skipped as i64 - scale as i64;
+        // Split the power of two so neither factor overflows before the product is formed
+        let exponent = exponent.clamp(-2200, 2200) as i32;
+        let value = leading * 2f64.powi(exponent / 2) * 2f64.powi(exponent - exponent / 2);
+
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Returns the decimal digits of the magnitude.
+    pub(crate) fn magnitude_to_string(&self) -> String {
+        const CHUNK: u64 = 1_000_000_000;
+
+        let mut rest = self.magnitude.clone();
+        let mut chunks = Vec::new();
+        while !rest.is_empty() {
+            let mut remainder = 0u64;
+            for digit in rest.iter_mut().rev() {
+                let current = remainder << 32 | *digit as u64;
+                *digit = (current / CHUNK) as u32;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder);
+            while rest.last() == Some(&0) {
+                rest.pop();
+            }
+        }
+
+        match chunks.split_last() {
+            None => "0".to_string(),
+            Some((first, rest)) => rest.iter().rev().fold(first.to_string(), |text, chunk| {
+                text + &format!("{:09}", chunk)
+            }),
+        }
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the little-endian base `2^32` digits of the magnitude, without leading zeros.
+    pub(crate) fn digits(&self) -> &[u32] {
+        &self.magnitude
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &digit) in long.iter().enumerate() {
+        let current = digit as u64 + short.get(i).map_or(0, |&d| d as u64) + carry;
+        sum.push(current as u32);
+        carry = current >> 32;
+    }
+    sum.push(carry as u32);
+
+    sum
+}
+
+/// Returns `a - b`, where `a >= b`.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &digit) in a.iter().enumerate() {
+        let mut current = digit as i64 - b.get(i).map_or(0, |&d| d as i64) - borrow;
+        borrow = 0;
+        if current < 0 {
+            current += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(current as u32);
+    }
+
+    difference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_matches_i128() {
+        let values = [
+            0i128,
+            1,
+            -1,
+            7,
+            -12345,
+            1 << 40,
+            -(1 << 63),
+            i64::MAX as i128,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigInt::from_i128(a), BigInt::from_i128(b));
+                assert_eq!(x.add(&y), BigInt::from_i128(a + b));
+                assert_eq!(x.mul(&y), BigInt::from_i128(a * b));
+            }
+            assert_eq!(BigInt::from_i128(a).shl(33), BigInt::from_i128(a << 33));
+        }
+    }
+
+    #[test]
+    fn test_shr_rounded() {
+        for &a in &[0i128, 5, -5, 8, -8, 1 << 70, -(1 << 70) - 1] {
+            let x = BigInt::from_i128(a);
+            for &bits in &[0, 2, 3, 35, 64] {
+                let floor = a.div_euclid(1 << bits);
+                let ceiling = -(-a).div_euclid(1 << bits);
+                assert_eq!(x.shr_rounded(bits, false), BigInt::from_i128(floor));
+                assert_eq!(x.shr_rounded(bits, true), BigInt::from_i128(ceiling));
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal_and_float_conversion() {
+        let value = BigInt::from_i128(-1_000_000_000_000_000_007);
+        assert_eq!(value.magnitude_to_string(), "1000000000000000007");
+        assert_eq!(BigInt::zero().magnitude_to_string(), "0");
+        assert_eq!(BigInt::from_i128(3).shl(200).to_f64_scaled(201), 1.5);
+        assert_eq!(value.to_f64_scaled(0), -1e18);
+        assert_eq!(
+            BigInt::from_digits(vec![5, 1, 0]),
+            BigInt::from_i128((1 << 32) + 5)
+        );
+        assert_eq!(
+            value.digits(),
+            BigInt::from_i128(1_000_000_000_000_000_007).digits()
+        );
+    }
+}
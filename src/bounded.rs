@@ -0,0 +1,139 @@
+//! Polynomials that are only valid on a closed interval, such as fits to sampled data.
+
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// Errors returned when evaluating a [`BoundedPolynomial`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundedError {
+    /// `x` lies outside the domain `[domain.0, domain.1]`, or is NaN.
+    OutOfDomain {
+        /// The rejected point.
+        x: f64,
+        /// The domain of the polynomial.
+        domain: (f64, f64),
+    },
+}
+
+impl fmt::Display for BoundedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundedError::OutOfDomain { x, domain } => write!(
+                f,
+                "{} is outside the domain [{}, {}]",
+                x, domain.0, domain.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BoundedError {}
+
+/// A polynomial together with the closed interval on which it may be evaluated.
+///
+/// [`evaluate_at`](BoundedPolynomial::evaluate_at) rejects points outside the domain and
+/// [`evaluate_clamped`](BoundedPolynomial::evaluate_clamped) moves them to the nearest
+/// endpoint, so a fit is never silently extrapolated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundedPolynomial {
+    /// The polynomial evaluated inside the domain.
+    pub poly: Polynomial,
+    /// The closed interval `[domain.0, domain.1]` on which the polynomial is valid.
+    pub domain: (f64, f64),
+}
+
+impl BoundedPolynomial {
+    /// Restricts `poly` to `domain`.
+    ///
+    /// # Panics
+    /// Panics if the domain is reversed or has a NaN endpoint.
+    pub fn new(poly: Polynomial, domain: (f64, f64)) -> BoundedPolynomial {
+        assert!(domain.0 <= domain.1, "domain must be a closed interval");
+        BoundedPolynomial { poly, domain }
+    }
+
+    /// Returns true if `x` lies in the domain
+    pub fn contains(&self, x: f64) -> bool {
+        self.domain.0 <= x && x <= self.domain.1
+    }
+
+    /// Evaluates the polynomial at `x`, or returns an error if `x` is outside the domain
+    /// # Example
+    /// ```
+    /// use polynom::bounded::{BoundedError, BoundedPolynomial};
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let bounded = BoundedPolynomial::new(Polynomial::new(vec![1f64, 2f64], 'x'), (0f64, 1f64));
+    /// assert_eq!(bounded.evaluate_at(0.5), Ok(2f64));
+    /// assert_eq!(
+    ///     bounded.evaluate_at(3f64),
+    ///     Err(BoundedError::OutOfDomain { x: 3f64, domain: (0f64, 1f64) })
+    /// );
+    /// ```
+    pub fn evaluate_at(&self, x: f64) -> Result<f64, BoundedError> {
+        if !self.contains(x) {
+            return Err(BoundedError::OutOfDomain {
+                x,
+                domain: self.domain,
+            });
+        }
+
+        Ok(self.poly.evaluate_at(x))
+    }
+
+    /// Evaluates the polynomial at `x` clamped to the domain, so the value is held constant
+    /// beyond each endpoint. NaN stays NaN.
+    /// # Example
+    /// ```
+    /// use polynom::bounded::BoundedPolynomial;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let bounded = BoundedPolynomial::new(Polynomial::new(vec![1f64, 2f64], 'x'), (0f64, 1f64));
+    /// assert_eq!(bounded.evaluate_clamped(-4f64), 1f64);
+    /// assert_eq!(bounded.evaluate_clamped(3f64), 3f64);
+    /// ```
+    pub fn evaluate_clamped(&self, x: f64) -> f64 {
+        self.poly.evaluate_at(x.clamp(self.domain.0, self.domain.1))
+    }
+}
+
+impl fmt::Display for BoundedPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} for {} in [{}, {}]",
+            self.poly.as_string(),
+            self.poly.indeterminate,
+            self.domain.0,
+            self.domain.1
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_reports_domain() {
+        let bounded = BoundedPolynomial::new(Polynomial::new(vec![1f64, 2f64], 't'), (-1f64, 2.5));
+        assert_eq!(bounded.to_string(), "f(t) = 1 + 2t for t in [-1, 2.5]");
+    }
+
+    #[test]
+    fn test_endpoints_and_nan() {
+        let bounded =
+            BoundedPolynomial::new(Polynomial::new(vec![0f64, 0f64, 1f64], 'x'), (1f64, 2f64));
+        assert_eq!(bounded.evaluate_at(1f64), Ok(1f64));
+        assert_eq!(bounded.evaluate_at(2f64), Ok(4f64));
+        assert!(bounded.evaluate_at(f64::NAN).is_err());
+        assert!(bounded.evaluate_clamped(f64::NAN).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_rejects_reversed_domain() {
+        BoundedPolynomial::new(Polynomial::new(vec![1f64], 'x'), (1f64, 0f64));
+    }
+}
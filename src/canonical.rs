@@ -0,0 +1,182 @@
+//! Bringing polynomials into a canonical form before hashing or comparison.
+
+use crate::polynomial::Polynomial;
+use crate::tolerance::Tolerance;
+
+/// Which normalizations [`Polynomial::canonicalize`] applies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CanonicalOptions {
+    /// Policy used to snap negligible coefficients to zero.
+    pub tolerance: Tolerance,
+    /// Divide by the leading coefficient so it becomes `1`.
+    pub monic: bool,
+    /// Negate the polynomial if its leading coefficient is negative (implied by `monic`).
+    pub positive_leading: bool,
+}
+
+impl Default for CanonicalOptions {
+    /// Exact snapping, no scaling, and a positive leading coefficient.
+    fn default() -> CanonicalOptions {
+        CanonicalOptions {
+            tolerance: Tolerance::EXACT,
+            monic: false,
+            positive_leading: true,
+        }
+    }
+}
+
+/// What [`Polynomial::canonicalize`] changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanonicalReport {
+    /// Degrees whose coefficient was `-0.0` and became `+0.0`.
+    pub signed_zeros: Vec<usize>,
+    /// Degrees whose nonzero coefficient was snapped to zero or dropped.
+    pub snapped: Vec<usize>,
+    /// The factor the polynomial was divided by: the leading coefficient when made monic,
+    /// `-1` when only the sign was flipped, and `1` otherwise.
+    pub divisor: f64,
+    /// Degree before canonicalization.
+    pub degree_before: isize,
+    /// Degree after canonicalization.
+    pub degree_after: isize,
+}
+
+impl CanonicalReport {
+    /// Returns `true` if canonicalization left the coefficients bit-for-bit unchanged.
+    pub fn is_unchanged(&self) -> bool {
+        self.signed_zeros.is_empty() && self.snapped.is_empty() && self.divisor == 1f64
+    }
+}
+
+impl Polynomial {
+    /// Returns a canonical form of `self` together with a report of what changed.
+    ///
+    /// Negative zeros become positive, negligible coefficients are snapped according to the
+    /// tolerance policy, and the result is optionally made monic or given a positive leading
+    /// coefficient. Two pipelines producing the same polynomial up to these differences then
+    /// yield identical coefficients.
+    /// # Example
+    /// ```
+    /// use polynom::canonical::CanonicalOptions;
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::{Tolerance, ZeroSnapping};
+    ///
+    /// let polynomial = Polynomial::new(vec![-0f64, 4f64, 1e-18, -2f64], 'x');
+    /// let options = CanonicalOptions {
+    ///     tolerance: Tolerance::ulps(4f64).with_snapping(ZeroSnapping::All),
+    ///     monic: true,
+    ///     ..CanonicalOptions::default()
+    /// };
+    /// let (canonical, report) = polynomial.canonicalize(&options);
+    ///
+    /// assert_eq!(canonical.coefficients, vec![0f64, -2f64, 0f64, 1f64]);
+    /// assert_eq!(report.signed_zeros, vec![0]);
+    /// assert_eq!(report.snapped, vec![2]);
+    /// assert_eq!(report.divisor, -2f64);
+    /// ```
+    pub fn canonicalize(&self, options: &CanonicalOptions) -> (Polynomial, CanonicalReport) {
+        let signed_zeros: Vec<usize> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| **coeff == 0f64 && coeff.is_sign_negative())
+            .map(|(degree, _)| degree)
+            .collect();
+        let unsigned = Polynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|&coeff| if coeff == 0f64 { 0f64 } else { coeff })
+                .collect(),
+            indeterminate: self.indeterminate,
+        };
+
+        let snapped_polynomial = unsigned.snap(&options.tolerance);
+        let snapped: Vec<usize> = unsigned
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|&(degree, &coeff)| {
+                coeff != 0f64 && snapped_polynomial.coefficients.get(degree).copied() != Some(coeff)
+            })
+            .map(|(degree, _)| degree)
+            .collect();
+
+        let leading = snapped_polynomial.coefficients[snapped_polynomial.coefficients.len() - 1];
+        let divisor = if leading == 0f64 {
+            1f64
+        } else if options.monic {
+            leading
+        } else if options.positive_leading && leading < 0f64 {
+            -1f64
+        } else {
+            1f64
+        };
+        let canonical = if divisor == 1f64 {
+            snapped_polynomial
+        } else {
+            let coefficients = snapped_polynomial
+                .coefficients
+                .iter()
+                .map(|&coeff| if coeff == 0f64 { 0f64 } else { coeff / divisor })
+                .collect();
+            Polynomial::new(coefficients, self.indeterminate)
+        };
+
+        let report = CanonicalReport {
+            signed_zeros,
+            snapped,
+            divisor,
+            degree_before: self.degree(),
+            degree_after: canonical.degree(),
+        };
+        (canonical, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options_flip_sign_only() {
+        let polynomial = Polynomial::new(vec![1f64, -3f64], 'x');
+        let (canonical, report) = polynomial.canonicalize(&CanonicalOptions::default());
+
+        assert_eq!(canonical.coefficients, vec![-1f64, 3f64]);
+        assert_eq!(report.divisor, -1f64);
+        assert!(report.snapped.is_empty());
+        assert!(!report.is_unchanged());
+    }
+
+    #[test]
+    fn test_canonical_form_is_idempotent() {
+        let polynomial = Polynomial::new(vec![0.5, -0f64, 1e-20, -4f64, 1e-19], 'x');
+        let options = CanonicalOptions {
+            tolerance: Tolerance::ulps(8f64),
+            monic: true,
+            positive_leading: false,
+        };
+        let (canonical, report) = polynomial.canonicalize(&options);
+
+        assert_eq!(report.degree_before, 4);
+        assert_eq!(report.degree_after, 3);
+        assert_eq!(report.snapped, vec![4]);
+        let (again, report) = canonical.canonicalize(&options);
+        assert_eq!(again, canonical);
+        assert!(report.is_unchanged());
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        let zero = Polynomial::new(vec![0f64], 'x');
+        let (canonical, report) = zero.canonicalize(&CanonicalOptions {
+            monic: true,
+            ..CanonicalOptions::default()
+        });
+
+        assert_eq!(canonical, zero);
+        assert!(report.is_unchanged());
+        assert_eq!(report.degree_after, -1);
+    }
+}
@@ -0,0 +1,239 @@
+//! Conversions between `Polynomial` coefficients and other numeric types.
+//!
+//! `Polynomial` stores `f64` coefficients; these conversions move coefficient vectors in and out
+//! of other representations (e.g. `f32` for GPU buffers, rounded integers or fractions for exact
+//! code). Every conversion goes through `f64`, so a [`TypedPolynomial<U>`] is exactly as precise
+//! as its `f64` source allows.
+
+use crate::polynomial::Polynomial;
+use crate::rational::Rational;
+
+/// Largest denominator used when casting a coefficient to a [`Rational`].
+const RATIONAL_MAX_DENOMINATOR: u64 = 1 << 32;
+
+/// A numeric type that polynomial coefficients can be cast to and from.
+pub trait Coefficient: Copy {
+    /// Converts from `f64`, rounding (and saturating, for integers) where the type requires it.
+    fn from_f64(value: f64) -> Self;
+    /// Converts to the nearest `f64`.
+    fn to_f64(self) -> f64;
+}
+
+impl Coefficient for f64 {
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Coefficient for f32 {
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Coefficient for i64 {
+    fn from_f64(value: f64) -> i64 {
+        value.round() as i64
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Coefficient for i32 {
+    fn from_f64(value: f64) -> i32 {
+        value.round() as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+/// Casting to a fraction takes the closest one with denominator at most `2^32`, or the nearest
+/// integer (saturating, with NaN as zero) when its numerator would not fit an `i64`.
+impl Coefficient for Rational {
+    fn from_f64(value: f64) -> Rational {
+        Rational::approximate(value, RATIONAL_MAX_DENOMINATOR)
+            .unwrap_or_else(|| Rational::new(value.round() as i64, 1))
+    }
+
+    fn to_f64(self) -> f64 {
+        Rational::to_f64(&self)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Coefficient for rust_decimal::Decimal {
+    fn from_f64(value: f64) -> rust_decimal::Decimal {
+        rust_decimal::prelude::FromPrimitive::from_f64(value).unwrap_or_default()
+    }
+
+    fn to_f64(self) -> f64 {
+        rust_decimal::prelude::ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+    }
+}
+
+/// A polynomial whose coefficients are held as some [`Coefficient`] type other than `f64`,
+/// as returned by [`Polynomial::cast`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedPolynomial<T: Coefficient> {
+    coefficients: Vec<T>,
+    indeterminate: char,
+}
+
+impl<T: Coefficient> TypedPolynomial<T> {
+    /// Returns the coefficients, lowest degree first
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the indeterminate
+    pub fn indeterminate(&self) -> char {
+        self.indeterminate
+    }
+
+    /// Returns the coefficients, lowest degree first, consuming the polynomial
+    pub fn into_coefficients(self) -> Vec<T> {
+        self.coefficients
+    }
+
+    /// Returns the coefficients cast to another [`Coefficient`] type, through `f64`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1.5, 2.5], 'x');
+    /// assert_eq!(polynomial.cast::<f32>().cast::<i32>().coefficients(), &[2, 3]);
+    /// ```
+    pub fn cast<U: Coefficient>(&self) -> TypedPolynomial<U> {
+        TypedPolynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|&coeff| U::from_f64(coeff.to_f64()))
+                .collect(),
+            indeterminate: self.indeterminate,
+        }
+    }
+
+    /// Returns the `f64` polynomial with the same coefficients and indeterminate
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::from_coefficients(&self.coefficients, self.indeterminate)
+    }
+}
+
+impl Polynomial {
+    /// Returns a Polynomial from coefficients of any [`Coefficient`] type and an indeterminate
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::from_coefficients(&[1f32, 0.5f32], 'x');
+    /// assert_eq!(polynomial.coefficients, vec![1f64, 0.5]);
+    /// ```
+    pub fn from_coefficients<T: Coefficient>(
+        coefficients: &[T],
+        indeterminate: char,
+    ) -> Polynomial {
+        let coefficients = coefficients.iter().map(|&coeff| coeff.to_f64()).collect();
+        Polynomial::new(coefficients, indeterminate)
+    }
+
+    /// Returns the polynomial with its coefficients cast to another [`Coefficient`] type
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::rational::Rational;
+    ///
+    /// let polynomial = Polynomial::new(vec![1.4, -2.6, 3f64], 'x');
+    /// assert_eq!(polynomial.cast::<i64>().coefficients(), &[1, -3, 3]);
+    /// assert_eq!(polynomial.cast::<f32>().coefficients(), &[1.4f32, -2.6f32, 3f32]);
+    /// assert_eq!(polynomial.cast::<Rational>().coefficients()[0], Rational::new(7, 5));
+    /// ```
+    pub fn cast<U: Coefficient>(&self) -> TypedPolynomial<U> {
+        TypedPolynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|&coeff| U::from_f64(coeff))
+                .collect(),
+            indeterminate: self.indeterminate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_through_f32() {
+        let polynomial = Polynomial::new(vec![0.5, 0.25, -8f64], 'x');
+        let round_trip = polynomial.cast::<f32>().to_polynomial();
+
+        assert_eq!(round_trip, polynomial);
+    }
+
+    #[test]
+    fn test_from_integer_coefficients_strips_zeros() {
+        let polynomial = Polynomial::from_coefficients(&[3i32, 0i32, 0i32], 'y');
+
+        assert_eq!(polynomial.coefficients, vec![3f64]);
+        assert_eq!(polynomial.indeterminate, 'y');
+    }
+
+    #[test]
+    fn test_cast_saturates_integers() {
+        let polynomial = Polynomial::new(vec![1e20, f64::NEG_INFINITY], 'x');
+
+        assert_eq!(
+            polynomial.cast::<i32>().into_coefficients(),
+            vec![i32::MAX, i32::MIN]
+        );
+    }
+
+    #[test]
+    fn test_cast_to_rational() {
+        let polynomial = Polynomial::new(vec![0.75, -1f64 / 3f64, 1e30, f64::NAN], 't');
+        let rational = polynomial.cast::<Rational>();
+
+        assert_eq!(
+            rational.coefficients(),
+            &[
+                Rational::new(3, 4),
+                Rational::new(-1, 3),
+                Rational::new(i64::MAX, 1),
+                Rational::new(0, 1)
+            ]
+        );
+        assert_eq!(rational.indeterminate(), 't');
+        assert_eq!(
+            Polynomial::new(vec![0.75, -0.5], 'x')
+                .cast::<Rational>()
+                .to_polynomial(),
+            Polynomial::new(vec![0.75, -0.5], 'x')
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_cast_to_decimal() {
+        let polynomial = Polynomial::new(vec![0.5, 0.25], 'x');
+        let coefficients = polynomial.cast::<rust_decimal::Decimal>();
+
+        assert_eq!(
+            coefficients.coefficients()[1],
+            rust_decimal::Decimal::new(25, 2)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! A minimal complex number type for evaluating polynomials off the real line.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::polynomial::Polynomial;
+
+/// A complex number `re + im i` with `f64` parts.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    /// The real part.
+    pub re: f64,
+    /// The imaginary part.
+    pub im: f64,
+}
+
+impl fmt::Debug for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < 0f64 {
+            write!(f, "{} - {}i", self.re, -self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl Complex {
+    /// Returns the complex number `re + im i`
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    ///
+    /// let z = Complex::new(3f64, 4f64);
+    /// assert_eq!(z.norm(), 5f64);
+    /// ```
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    /// Returns the modulus `|z|`.
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the principal square root.
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    ///
+    /// assert_eq!(Complex::new(-4f64, 0f64).sqrt(), Complex::new(0f64, 2f64));
+    /// ```
+    pub fn sqrt(&self) -> Complex {
+        let norm = self.norm();
+        let re = ((norm + self.re) / 2f64).sqrt();
+        let im = ((norm - self.re) / 2f64).sqrt().copysign(self.im);
+        Complex::new(re, im)
+    }
+
+    /// Returns the complex conjugate `re - im i`.
+    pub fn conj(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Complex {
+        Complex::new(re, 0f64)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Complex) -> Complex {
+        let denominator = other.re * other.re + other.im * other.im;
+        let numerator = self * other.conj();
+        Complex::new(numerator.re / denominator, numerator.im / denominator)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+/// A rectangular grid of sample points in the complex plane.
+///
+/// Points are numbered row-major in image order: row `0` lies on the top edge
+/// `im = im_range.1` and column `0` on the left edge `re = re_range.0`, so per-point
+/// results can be fed straight into image code. Both edges of each range are sampled; a
+/// single row or column sits at the middle of its range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The real parts of the left and right edges.
+    pub re_range: (f64, f64),
+    /// The imaginary parts of the bottom and top edges.
+    pub im_range: (f64, f64),
+    /// The number of points in each row.
+    pub width: usize,
+    /// The number of rows.
+    pub height: usize,
+}
+
+impl Viewport {
+    /// Returns the `resolution.0` by `resolution.1` grid covering `re_range` × `im_range`
+    /// # Example
+    /// ```
+    /// use polynom::complex::{Complex, Viewport};
+    ///
+    /// let viewport = Viewport::new((-1f64, 1f64), (0f64, 2f64), (3, 2));
+    /// assert_eq!(viewport.point(0, 0), Complex::new(-1f64, 2f64));
+    /// assert_eq!(viewport.point(1, 2), Complex::new(1f64, 0f64));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if either dimension of `resolution` is zero.
+    pub fn new(re_range: (f64, f64), im_range: (f64, f64), resolution: (usize, usize)) -> Viewport {
+        let (width, height) = resolution;
+        assert!(width > 0 && height > 0, "resolution must be nonzero");
+        Viewport {
+            re_range,
+            im_range,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the number of sample points
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Returns `true` if the grid has no sample points
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the point of the plane sampled at (`row`, `column`)
+    pub fn point(&self, row: usize, column: usize) -> Complex {
+        Complex::new(
+            grid_coordinate(self.re_range.0, self.re_range.1, column, self.width),
+            grid_coordinate(self.im_range.1, self.im_range.0, row, self.height),
+        )
+    }
+
+    /// Returns every sample point in row-major order
+    pub fn points(&self) -> impl Iterator<Item = Complex> + '_ {
+        (0..self.height)
+            .flat_map(move |row| (0..self.width).map(move |column| self.point(row, column)))
+    }
+}
+
+/// Returns sample `index` of `count` evenly spaced from `from` to `to` inclusive.
+fn grid_coordinate(from: f64, to: f64, index: usize, count: usize) -> f64 {
+    if count == 1 {
+        (from + to) / 2f64
+    } else {
+        from + (to - from) * index as f64 / (count - 1) as f64
+    }
+}
+
+/// Values of a polynomial sampled on a [`Viewport`], stored in its row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexGrid {
+    /// The points at which the polynomial was sampled.
+    pub viewport: Viewport,
+    /// The value at each point of the viewport.
+    pub values: Vec<Complex>,
+}
+
+impl ComplexGrid {
+    /// Returns the point of the plane sampled at (`row`, `column`)
+    pub fn point(&self, row: usize, column: usize) -> Complex {
+        self.viewport.point(row, column)
+    }
+
+    /// Returns the sample at (`row`, `column`)
+    pub fn get(&self, row: usize, column: usize) -> Complex {
+        self.values[row * self.viewport.width + column]
+    }
+
+    /// Returns `|p(z)|` for every sample, in the same order as `values`
+    pub fn magnitudes(&self) -> Vec<f64> {
+        self.values.iter().map(Complex::norm).collect()
+    }
+}
+
+impl Polynomial {
+    /// Evaluates the polynomial on a `resolution.0` by `resolution.1` grid covering
+    /// `re_range` × `im_range`, e.g. to render Julia-style or root-basin images
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // z^2 + 1 on a 3 by 3 grid over [-1, 1] × [-1, 1]
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'z');
+    /// let grid = polynomial.evaluate_grid_complex((-1f64, 1f64), (-1f64, 1f64), (3, 3));
+    /// assert_eq!(grid.point(0, 1), Complex::new(0f64, 1f64));
+    /// assert_eq!(grid.get(0, 1), Complex::new(0f64, 0f64));
+    /// assert_eq!(grid.magnitudes()[4], 1f64);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if either dimension of `resolution` is zero.
+    pub fn evaluate_grid_complex(
+        &self,
+        re_range: (f64, f64),
+        im_range: (f64, f64),
+        resolution: (usize, usize),
+    ) -> ComplexGrid {
+        let viewport = Viewport::new(re_range, im_range, resolution);
+        let values = viewport
+            .points()
+            .map(|z| self.evaluate_generic(z))
+            .collect();
+
+        ComplexGrid { viewport, values }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1f64, 2f64);
+        let b = Complex::new(3f64, -1f64);
+
+        assert_eq!(a + b, Complex::new(4f64, 1f64));
+        assert_eq!(a - b, Complex::new(-2f64, 3f64));
+        assert_eq!(a * b, Complex::new(5f64, 5f64));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(-a, Complex::new(-1f64, -2f64));
+    }
+
+    #[test]
+    fn test_evaluate_grid_complex() {
+        let polynomial = Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'z');
+        let grid = polynomial.evaluate_grid_complex((-2f64, 2f64), (-1f64, 1f64), (5, 3));
+        assert_eq!(grid.values.len(), 15);
+        assert_eq!(grid.viewport.len(), 15);
+        assert_eq!(grid.point(0, 0), Complex::new(-2f64, 1f64));
+        assert_eq!(grid.point(2, 4), Complex::new(2f64, -1f64));
+        for row in 0..grid.viewport.height {
+            for column in 0..grid.viewport.width {
+                let z = grid.point(row, column);
+                assert_eq!(grid.get(row, column), z * z * z - Complex::from(1f64));
+            }
+        }
+        // The root at z = 1 sits in the middle row
+        assert_eq!(grid.magnitudes()[grid.viewport.width + 3], 0f64);
+
+        let single = polynomial.evaluate_grid_complex((0f64, 2f64), (-1f64, 1f64), (1, 1));
+        assert_eq!(single.point(0, 0), Complex::new(1f64, 0f64));
+    }
+}
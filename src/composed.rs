@@ -0,0 +1,324 @@
+//! Compositions of polynomials that are evaluated piece by piece instead of expanded.
+//!
+//! Expanding `p(q(x))` multiplies the degrees of `p` and `q`, and the coefficients of the
+//! expansion can be far larger than those of either piece. When only values and slopes are
+//! needed, evaluating the pieces from the inside out is both cheaper and more accurate.
+//! [`Polynomial::decompose`] goes the other way, recovering the pieces from an expansion.
+
+use crate::polynomial::Polynomial;
+use crate::tolerance::Tolerance;
+
+/// Relative tolerance within which a candidate decomposition must reproduce the polynomial.
+const DECOMPOSITION_TOLERANCE: f64 = 1e-9;
+
+/// The composition `p_0 ∘ p_1 ∘ ... ∘ p_n` of its pieces, stored unexpanded.
+///
+/// `pieces[0]` is applied last, so the composition of `[p, q, r]` maps `x` to
+/// `p(q(r(x)))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposedPoly {
+    /// The polynomials being composed, outermost first.
+    pub pieces: Vec<Polynomial>,
+}
+
+impl ComposedPoly {
+    /// Returns the composition of `pieces`, outermost first.
+    ///
+    /// # Panics
+    /// Panics if `pieces` is empty.
+    pub fn new(pieces: Vec<Polynomial>) -> ComposedPoly {
+        assert!(!pieces.is_empty(), "a composition needs at least one piece");
+        ComposedPoly { pieces }
+    }
+
+    /// Returns the composition with `inner` applied before every existing piece
+    pub fn compose_with(mut self, inner: Polynomial) -> ComposedPoly {
+        self.pieces.push(inner);
+        self
+    }
+
+    /// Returns the degree of the expanded composition, the product of the degrees of the
+    /// pieces, or -1 if it is the zero polynomial.
+    pub fn degree(&self) -> isize {
+        if self.pieces.iter().any(|piece| piece.degree() <= 0) {
+            // Everything outside a constant piece only ever sees one value
+            return if self.evaluate_at(0f64) == 0f64 {
+                -1
+            } else {
+                0
+            };
+        }
+
+        self.pieces.iter().map(|piece| piece.degree()).product()
+    }
+
+    /// Evaluates the composition at `x`, innermost piece first
+    /// # Example
+    /// ```
+    /// use polynom::composed::ComposedPoly;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x^2 + 1) ∘ (2x) = 4x^2 + 1
+    /// let composed = ComposedPoly::new(vec![
+    ///     Polynomial::new(vec![1f64, 0f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 2f64], 'x'),
+    /// ]);
+    /// assert_eq!(composed.evaluate_at(3f64), 37f64);
+    /// ```
+    pub fn evaluate_at(&self, x: f64) -> f64 {
+        self.pieces
+            .iter()
+            .rev()
+            .fold(x, |value, piece| piece.evaluate_at(value))
+    }
+
+    /// Returns the value and the derivative of the composition at `x`.
+    ///
+    /// By the chain rule the derivative is the product of each piece's derivative at the
+    /// value produced by the pieces inside it.
+    /// # Example
+    /// ```
+    /// use polynom::composed::ComposedPoly;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x^2 + 1) ∘ (2x) = 4x^2 + 1, whose derivative is 8x
+    /// let composed = ComposedPoly::new(vec![
+    ///     Polynomial::new(vec![1f64, 0f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 2f64], 'x'),
+    /// ]);
+    /// assert_eq!(composed.evaluate_with_derivative(3f64), (37f64, 24f64));
+    /// ```
+    pub fn evaluate_with_derivative(&self, x: f64) -> (f64, f64) {
+        self.pieces
+            .iter()
+            .rev()
+            .fold((x, 1f64), |(value, slope), piece| {
+                let derivatives = piece.evaluate_derivatives(value, 1);
+                (derivatives[0], slope * derivatives[1])
+            })
+    }
+
+    /// Returns the derivative of the composition at `x`
+    pub fn derivative_at(&self, x: f64) -> f64 {
+        self.evaluate_with_derivative(x).1
+    }
+
+    /// Expands the composition into a single polynomial in the indeterminate of the
+    /// innermost piece
+    /// # Example
+    /// ```
+    /// use polynom::composed::ComposedPoly;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let composed = ComposedPoly::new(vec![
+    ///     Polynomial::new(vec![1f64, 0f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 2f64], 't'),
+    /// ]);
+    /// assert_eq!(composed.expand(), Polynomial::new(vec![1f64, 0f64, 4f64], 't'));
+    /// ```
+    pub fn expand(&self) -> Polynomial {
+        let mut pieces = self.pieces.iter().rev();
+        let innermost = pieces.next().unwrap().clone();
+        pieces.fold(innermost, |inner, outer| {
+            // Horner's scheme with polynomial multiplication
+            let indeterminate = inner.indeterminate;
+            outer.coefficients.iter().rev().fold(
+                Polynomial::new(vec![0f64], indeterminate),
+                |acc, &coeff| {
+                    let product = acc.multiply(inner.clone());
+                    let mut coefficients = product.coefficients;
+                    coefficients[0] += coeff;
+                    Polynomial::new(coefficients, indeterminate)
+                },
+            )
+        })
+    }
+}
+
+impl From<Polynomial> for ComposedPoly {
+    fn from(polynomial: Polynomial) -> ComposedPoly {
+        ComposedPoly::new(vec![polynomial])
+    }
+}
+
+impl Polynomial {
+    /// Attempts to write the polynomial as `g ∘ h` with both `g` and `h` of degree greater
+    /// than one, returning the composition `[g, h]`.
+    ///
+    /// Each inner degree `s` dividing the degree `n` is tried in increasing order. With `h`
+    /// normalized to be monic with `h(0) = 0`, its coefficients are fixed by the top `s`
+    /// coefficients of `p`, being those of the `n / s`-th root of the monic `p`; `g` then
+    /// follows by repeated division by `h`. The candidate is accepted when its expansion
+    /// matches `p` to a relative tolerance of `1e-9`. Returns `None` for degrees below four,
+    /// prime degrees, and polynomials with no decomposition.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^4 + 2x^3 + 3x^2 + 2x + 5 = (y^2 + 2y + 5) ∘ (x^2 + x)
+    /// let polynomial = Polynomial::new(vec![5f64, 2f64, 3f64, 2f64, 1f64], 'x');
+    /// let composed = polynomial.decompose().unwrap();
+    /// assert_eq!(composed.pieces[0].coefficients, vec![5f64, 2f64, 1f64]);
+    /// assert_eq!(composed.pieces[1].coefficients, vec![0f64, 1f64, 1f64]);
+    /// ```
+    pub fn decompose(&self) -> Option<ComposedPoly> {
+        let n = self.degree();
+        if n < 4 {
+            return None;
+        }
+        let n = n as usize;
+        let tolerance = Tolerance::new(0f64, DECOMPOSITION_TOLERANCE);
+
+        (2..=n / 2)
+            .filter(|&s| n.is_multiple_of(s))
+            .filter_map(|s| {
+                let inner = self.decomposition_inner(s);
+                let mut outer = Vec::with_capacity(n / s + 1);
+                let mut rest = self.clone();
+                for _ in 0..=n / s {
+                    let (quotient, remainder) = rest.div_rem(&inner);
+                    outer.push(remainder.coefficients[0]);
+                    rest = quotient;
+                }
+                let composed =
+                    ComposedPoly::new(vec![Polynomial::new(outer, self.indeterminate), inner]);
+                let expanded = composed.expand();
+
+                if expanded.degree() == n as isize && expanded.approx_eq(self, &tolerance) {
+                    Some(composed)
+                } else {
+                    None
+                }
+            })
+            .next()
+    }
+
+    /// Returns the monic `h` of degree `s` with `h(0) = 0` whose `n / s`-th power agrees
+    /// with the monic `p` in its top `s` coefficients.
+    fn decomposition_inner(&self, s: usize) -> Polynomial {
+        let n = self.coefficients.len() - 1;
+        let leading = self.coefficients[n];
+        let exponent = s as f64 / n as f64;
+
+        // Reversed, the top coefficients form a power series 1 + q_1 t + ... whose
+        // root f = q^exponent follows from k f_k = sum ((exponent + 1) j - k) q_j f_{k-j}
+        let q: Vec<f64> = (0..s).map(|j| self.coefficients[n - j] / leading).collect();
+        let mut f = vec![1f64; s];
+        for k in 1..s {
+            f[k] = (1..=k)
+                .map(|j| ((exponent + 1f64) * j as f64 - k as f64) * q[j] * f[k - j])
+                .sum::<f64>()
+                / k as f64;
+        }
+
+        let mut coefficients = vec![0f64; s + 1];
+        for (i, value) in f.iter().enumerate() {
+            coefficients[s - i] = *value;
+        }
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_evaluation_matches_expansion() {
+        let composed = ComposedPoly::from(test_polynomials::chebyshev(4))
+            .compose_with(Polynomial::new(vec![0.5, -1f64, 0.25], 'x'))
+            .compose_with(Polynomial::new(vec![0f64, 0.5, 0f64, 0.1], 'x'));
+        let expanded = composed.expand();
+        assert_eq!(composed.degree(), expanded.degree());
+        assert_eq!(composed.degree(), 24);
+
+        let derivative = expanded.derivative();
+        for i in 0..=20 {
+            let x = -1f64 + i as f64 / 10f64;
+            let (value, slope) = composed.evaluate_with_derivative(x);
+            assert!((value - expanded.evaluate_at(x)).abs() < 1e-12);
+            assert!((slope - derivative.evaluate_at(x)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_degree_of_degenerate_pieces() {
+        let square = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+        let constant = Polynomial::new(vec![3f64], 'x');
+        let zero = Polynomial::new(vec![0f64], 'x');
+
+        let composed = ComposedPoly::new(vec![square.clone(), constant.clone()]);
+        assert_eq!(composed.degree(), 0);
+        assert_eq!(composed.expand().coefficients, vec![9f64]);
+        assert_eq!(composed.derivative_at(1f64), 0f64);
+
+        let composed = ComposedPoly::new(vec![square.clone(), zero.clone()]);
+        assert_eq!(composed.degree(), -1);
+        assert_eq!(composed.expand().degree(), -1);
+
+        let composed = ComposedPoly::new(vec![constant, zero.clone()]);
+        assert_eq!(composed.degree(), 0);
+
+        let shift = Polynomial::new(vec![1f64, 1f64], 'x');
+        let composed = ComposedPoly::new(vec![shift, zero]);
+        assert_eq!(composed.degree(), 0);
+        assert_eq!(composed.expand().coefficients, vec![1f64]);
+    }
+
+    #[test]
+    fn test_decompose_recovers_composition() {
+        let outer = Polynomial::new(vec![-2f64, 0.5, 3f64, -1f64], 'x');
+        let inner = Polynomial::new(vec![1.5, -2f64, 0f64, 4f64], 'x');
+        let polynomial = ComposedPoly::new(vec![outer, inner]).expand();
+
+        let composed = polynomial.decompose().unwrap();
+        assert_eq!(composed.pieces[0].degree(), 3);
+        assert_eq!(composed.pieces[1].degree(), 3);
+        // The inner piece is normalized to be monic with no constant term
+        assert_eq!(composed.pieces[1].coefficients[0], 0f64);
+        assert!((composed.pieces[1].coefficients[3] - 1f64).abs() < 1e-12);
+        assert!(composed
+            .expand()
+            .approx_eq(&polynomial, &Tolerance::new(0f64, 1e-9)));
+    }
+
+    #[test]
+    fn test_decompose_prefers_smallest_inner_degree() {
+        // x^6 = (x^3) ∘ (x^2) = (x^2) ∘ (x^3)
+        let polynomial = Polynomial::new(vec![0f64, 0f64, 0f64, 0f64, 0f64, 0f64, 2f64], 'x');
+        let composed = polynomial.decompose().unwrap();
+        assert_eq!(
+            composed.pieces[0].coefficients,
+            vec![0f64, 0f64, 0f64, 2f64]
+        );
+        assert_eq!(composed.pieces[1].coefficients, vec![0f64, 0f64, 1f64]);
+    }
+
+    #[test]
+    fn test_decompose_chebyshev() {
+        // T_6 = T_2 ∘ T_3 = T_3 ∘ T_2
+        let composed = test_polynomials::chebyshev(6).decompose().unwrap();
+        assert_eq!(composed.pieces[1].degree(), 2);
+        assert!(composed
+            .expand()
+            .approx_eq(&test_polynomials::chebyshev(6), &Tolerance::new(0f64, 1e-9)));
+    }
+
+    #[test]
+    fn test_indecomposable_polynomials() {
+        // Prime degree
+        assert!(
+            Polynomial::new(vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64], 'x')
+                .decompose()
+                .is_none()
+        );
+        // Degree four, but the x term breaks the structure
+        assert!(Polynomial::new(vec![1f64, 1f64, 0f64, 0f64, 1f64], 'x')
+            .decompose()
+            .is_none());
+        assert!(Polynomial::new(vec![1f64, 0f64, 1f64], 'x')
+            .decompose()
+            .is_none());
+    }
+}
@@ -0,0 +1,229 @@
+//! Compact storage of large collections of similar polynomials.
+//!
+//! Coefficients are quantized to multiples of a fixed step, each polynomial is stored as the
+//! difference from the one before it, and the differences are written as zigzag varints.
+//! Collections whose neighbours are close, such as per-pixel calibration curves, shrink to
+//! a byte or two per coefficient, at the cost of an absolute error of at most `step / 2` in
+//! every coefficient.
+
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// Errors that can occur while encoding or decoding a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressError {
+    /// A coefficient of the polynomial at `index` is not finite, or too large to quantize
+    /// with the chosen step.
+    Unrepresentable {
+        /// The index of the polynomial in the batch.
+        index: usize,
+    },
+    /// The encoded bytes end in the middle of a value.
+    Truncated,
+    /// A value in the encoded bytes does not fit in 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for CompressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressError::Unrepresentable { index } => write!(
+                f,
+                "polynomial {} has a coefficient that cannot be quantized",
+                index
+            ),
+            CompressError::Truncated => write!(f, "encoded batch is truncated"),
+            CompressError::Overflow => write!(f, "encoded value does not fit in 64 bits"),
+        }
+    }
+}
+
+impl std::error::Error for CompressError {}
+
+/// A quantized, delta-encoded collection of polynomials produced by [`encode_batch`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodedBatch {
+    /// The quantization step; every decoded coefficient is a multiple of it.
+    pub step: f64,
+    /// The counts and quantized coefficient differences, written as zigzag varints.
+    pub bytes: Vec<u8>,
+}
+
+/// Encodes `polynomials`, quantizing every coefficient to the nearest multiple of `step`.
+///
+/// Indeterminates are not stored; decoded polynomials use `x`.
+/// # Example
+/// ```
+/// use polynom::compress::{decode_batch, encode_batch};
+/// use polynom::polynomial::Polynomial;
+///
+/// let curves: Vec<Polynomial> = (0..100)
+///     .map(|i| Polynomial::new(vec![1f64 + i as f64 * 1e-4, 0.5, -0.25], 'x'))
+///     .collect();
+/// let encoded = encode_batch(&curves, 1e-6).unwrap();
+/// // 2400 bytes of raw coefficients become about 5 bytes per curve
+/// assert!(encoded.bytes.len() < 550);
+///
+/// let decoded = decode_batch(&encoded).unwrap();
+/// assert!((decoded[42].coefficients[0] - curves[42].coefficients[0]).abs() <= 0.51e-6);
+/// ```
+///
+/// # Panics
+/// Panics if `step` is not positive and finite.
+pub fn encode_batch(polynomials: &[Polynomial], step: f64) -> Result<EncodedBatch, CompressError> {
+    assert!(
+        step > 0f64 && step.is_finite(),
+        "step must be positive and finite"
+    );
+    let mut bytes = Vec::new();
+    write_varint(&mut bytes, polynomials.len() as u64);
+    let mut previous: Vec<i64> = Vec::new();
+    for (index, polynomial) in polynomials.iter().enumerate() {
+        let quantized = polynomial
+            .coefficients
+            .iter()
+            .map(|coeff| {
+                let steps = (coeff / step).round();
+                // The bounds keep every delta between two quantized values within i64
+                if steps.abs() < 2f64.powi(62) {
+                    Ok(steps as i64)
+                } else {
+                    Err(CompressError::Unrepresentable { index })
+                }
+            })
+            .collect::<Result<Vec<i64>, CompressError>>()?;
+
+        write_varint(&mut bytes, quantized.len() as u64);
+        for (degree, value) in quantized.iter().enumerate() {
+            let delta = value - previous.get(degree).copied().unwrap_or(0);
+            write_varint(&mut bytes, zigzag(delta));
+        }
+        previous = quantized;
+    }
+
+    Ok(EncodedBatch { step, bytes })
+}
+
+/// Decodes a batch produced by [`encode_batch`]
+pub fn decode_batch(batch: &EncodedBatch) -> Result<Vec<Polynomial>, CompressError> {
+    let mut position = 0;
+    let count = read_varint(&batch.bytes, &mut position)?;
+    let mut polynomials = Vec::new();
+    let mut previous: Vec<i64> = Vec::new();
+    for _ in 0..count {
+        let length = read_varint(&batch.bytes, &mut position)?;
+        let mut quantized = Vec::new();
+        for degree in 0..length as usize {
+            let delta = unzigzag(read_varint(&batch.bytes, &mut position)?);
+            let base = previous.get(degree).copied().unwrap_or(0);
+            quantized.push(base.checked_add(delta).ok_or(CompressError::Overflow)?);
+        }
+        let coefficients = quantized
+            .iter()
+            .map(|&steps| steps as f64 * batch.step)
+            .collect();
+        polynomials.push(Polynomial::new(coefficients, 'x'));
+        previous = quantized;
+    }
+
+    Ok(polynomials)
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u64, CompressError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*position).ok_or(CompressError::Truncated)?;
+        *position += 1;
+        let low = u64::from(byte & 0x7f);
+        if shift == 63 && low > 1 {
+            return Err(CompressError::Overflow);
+        }
+        value |= low << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(CompressError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SplitMix64};
+
+    #[test]
+    fn test_round_trip_error_is_bounded_by_half_a_step() {
+        let mut rng = SplitMix64::new(23);
+        let step = 1e-5;
+        let polynomials: Vec<Polynomial> = (0..50)
+            .map(|_| {
+                let degree = (rng.next_u64() % 6) as usize;
+                let coefficients = (0..=degree)
+                    .map(|_| rng.next_in_range(-100f64, 100f64))
+                    .collect();
+                Polynomial::new(coefficients, 'x')
+            })
+            .collect();
+        let decoded = decode_batch(&encode_batch(&polynomials, step).unwrap()).unwrap();
+
+        assert_eq!(decoded.len(), polynomials.len());
+        for (original, decoded) in polynomials.iter().zip(&decoded) {
+            for (degree, coeff) in original.coefficients.iter().enumerate() {
+                let value = decoded.coefficients.get(degree).copied().unwrap_or(0f64);
+                assert!((value - coeff).abs() <= step / 2f64 * (1f64 + 1e-9));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zigzag_round_trips_extremes() {
+        for &value in &[0i64, -1, 1, i64::MIN, i64::MAX] {
+            assert_eq!(unzigzag(zigzag(value)), value);
+            let mut bytes = Vec::new();
+            write_varint(&mut bytes, zigzag(value));
+            assert_eq!(read_varint(&bytes, &mut 0), Ok(zigzag(value)));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let huge = Polynomial::new(vec![1f64, 1e300], 'x');
+        let nan = Polynomial::new(vec![f64::NAN], 'x');
+        let fine = Polynomial::new(vec![1f64], 'x');
+        assert_eq!(
+            encode_batch(&[fine.clone(), huge], 1f64),
+            Err(CompressError::Unrepresentable { index: 1 })
+        );
+        assert_eq!(
+            encode_batch(&[nan], 1f64),
+            Err(CompressError::Unrepresentable { index: 0 })
+        );
+
+        let mut encoded = encode_batch(&[fine], 1f64).unwrap();
+        encoded.bytes.pop();
+        assert_eq!(decode_batch(&encoded), Err(CompressError::Truncated));
+        let overlong = EncodedBatch {
+            step: 1f64,
+            bytes: vec![0xff; 11],
+        };
+        assert_eq!(decode_batch(&overlong), Err(CompressError::Overflow));
+    }
+}
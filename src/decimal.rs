@@ -0,0 +1,242 @@
+//! Polynomials with exact decimal coefficients, backed by `rust_decimal`.
+//!
+//! Decimal coefficients avoid binary floating-point artifacts such as `0.1 + 0.2 == 0.30000000000000004`,
+//! which matters for financial-style calculations and printed output.
+
+use std::fmt;
+use std::ops::Add;
+use std::ops::Sub;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::display::{self, FormatOptions, Term};
+use crate::polynomial::Polynomial;
+
+/// A polynomial with exact decimal `coefficients` and an `indeterminate`.
+#[derive(Clone, PartialEq)]
+pub struct DecimalPolynomial {
+    /// Coefficients of the polynomial, indexed by degree.
+    pub coefficients: Vec<Decimal>,
+    /// The `char` representation of the indeterminate, eg. _f(**x**) = 1 + 2x_
+    pub indeterminate: char,
+}
+
+impl fmt::Debug for DecimalPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DecimalPolynomial {{ coefficients: {:?}, indeterminate: '{ind}', as_string: {} }}",
+            self.coefficients,
+            self.as_string(),
+            ind = self.indeterminate
+        )
+    }
+}
+
+fn combine(
+    a: &[Decimal],
+    b: &[Decimal],
+    operation: impl Fn(Decimal, Decimal) -> Decimal,
+) -> Vec<Decimal> {
+    let length = a.len().max(b.len());
+    (0..length)
+        .map(|i| {
+            operation(
+                a.get(i).copied().unwrap_or(Decimal::ZERO),
+                b.get(i).copied().unwrap_or(Decimal::ZERO),
+            )
+        })
+        .collect()
+}
+
+impl Add for DecimalPolynomial {
+    type Output = DecimalPolynomial;
+
+    fn add(self, other: DecimalPolynomial) -> Self {
+        let coefficients = combine(&self.coefficients, &other.coefficients, |a, b| a + b);
+        DecimalPolynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+impl Sub for DecimalPolynomial {
+    type Output = DecimalPolynomial;
+
+    fn sub(self, other: DecimalPolynomial) -> Self {
+        let coefficients = combine(&self.coefficients, &other.coefficients, |a, b| a - b);
+        DecimalPolynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+impl DecimalPolynomial {
+    /// Returns a DecimalPolynomial from a vector of decimals and an indeterminate
+    /// # Example
+    /// ```
+    /// use polynom::decimal::DecimalPolynomial;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let polynomial = DecimalPolynomial::new(vec![Decimal::new(1, 1), Decimal::ZERO], 'x');
+    /// assert_eq!(polynomial.coefficients, vec![Decimal::new(1, 1)]);
+    /// ```
+    pub fn new(coefficients: Vec<Decimal>, indeterminate: char) -> DecimalPolynomial {
+        let mut polynomial = DecimalPolynomial {
+            coefficients,
+            indeterminate,
+        };
+        polynomial.normalize();
+        polynomial
+    }
+
+    /// Drops trailing zero coefficients in place, leaving `[0]` for the zero polynomial
+    pub fn normalize(&mut self) {
+        while self.coefficients.last() == Some(&Decimal::ZERO) {
+            self.coefficients.pop();
+        }
+        if self.coefficients.is_empty() {
+            self.coefficients.push(Decimal::ZERO);
+        }
+    }
+
+    /// Multiplies `self` and `other`, and returns a DecimalPolynomial with the new coefficients.
+    /// # Example
+    /// ```
+    /// use polynom::decimal::DecimalPolynomial;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let a = DecimalPolynomial::new(vec![Decimal::new(1, 1), Decimal::ONE], 'x');
+    /// let b = DecimalPolynomial::new(vec![Decimal::new(2, 1), Decimal::ONE], 'x');
+    /// assert_eq!(a.multiply(&b).as_string(), "f(x) = 0.02 + 0.3x + x^2");
+    /// ```
+    pub fn multiply(&self, other: &DecimalPolynomial) -> DecimalPolynomial {
+        let mut coefficients =
+            vec![Decimal::ZERO; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+
+        DecimalPolynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Return the exact result of evaluating the polynomial at `determinate`
+    /// # Example
+    /// ```
+    /// use polynom::decimal::DecimalPolynomial;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let polynomial = DecimalPolynomial::new(vec![Decimal::new(1, 1), Decimal::ONE], 'x');
+    /// assert_eq!(polynomial.evaluate_at(Decimal::new(2, 1)), Decimal::new(3, 1));
+    /// ```
+    pub fn evaluate_at(&self, determinate: Decimal) -> Decimal {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Decimal::ZERO, |sum, coeff| sum * determinate + coeff)
+    }
+
+    /// Return the polynomial represented as a String, with coefficients printed exactly
+    /// # Example
+    /// ```
+    /// use polynom::decimal::DecimalPolynomial;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let polynomial = DecimalPolynomial::new(vec![Decimal::new(150, 2), Decimal::new(2, 1)], 'x');
+    /// assert_eq!(polynomial.as_string(), String::from("f(x) = 1.5 + 0.2x"))
+    /// ```
+    pub fn as_string(&self) -> String {
+        self.as_string_with(&FormatOptions::DEFAULT)
+    }
+
+    /// Return the polynomial represented as a String like
+    /// [`as_string`](DecimalPolynomial::as_string), with the decimal separator and
+    /// multiplication sign chosen by `options`
+    pub fn as_string_with(&self, options: &FormatOptions) -> String {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(degree, coeff)| {
+                let magnitude = coeff.abs().normalize();
+                Term {
+                    degree,
+                    negative: coeff.is_sign_negative(),
+                    magnitude: magnitude.to_string(),
+                    unit: magnitude == Decimal::ONE,
+                }
+            });
+        display::render(self.indeterminate, terms, options)
+    }
+
+    /// Converts the coefficients to the nearest `f64`s and returns them as a `Polynomial`
+    /// # Example
+    /// ```
+    /// use polynom::decimal::DecimalPolynomial;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let polynomial = DecimalPolynomial::new(vec![Decimal::new(25, 1)], 'x');
+    /// assert_eq!(polynomial.to_polynomial().coefficients, vec![2.5]);
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|coeff| coeff.to_f64().unwrap_or(f64::NAN))
+            .collect();
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimals(values: &[&str]) -> Vec<Decimal> {
+        values.iter().map(|v| v.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_add_has_no_binary_artifacts() {
+        let a = DecimalPolynomial::new(decimals(&["0.1", "0.1"]), 'x');
+        let b = DecimalPolynomial::new(decimals(&["0.2", "0.7"]), 'x');
+
+        assert_eq!((a + b).as_string(), String::from("f(x) = 0.3 + 0.8x"));
+    }
+
+    #[test]
+    fn test_sub_strips_trailing_zeros() {
+        let a = DecimalPolynomial::new(decimals(&["1.5", "0.3", "0.1"]), 'x');
+        let b = DecimalPolynomial::new(decimals(&["1", "0.1", "0.10"]), 'x');
+
+        assert_eq!((a - b).coefficients, decimals(&["0.5", "0.2"]));
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        let polynomial = DecimalPolynomial::new(vec![], 'x');
+
+        assert_eq!(polynomial.coefficients, vec![Decimal::ZERO]);
+        assert_eq!(polynomial.as_string(), String::from("f(x) = 0"));
+    }
+
+    #[test]
+    fn test_evaluate_at_is_exact() {
+        let polynomial = DecimalPolynomial::new(decimals(&["0.1", "0.2", "0.3"]), 'x');
+
+        assert_eq!(
+            polynomial.evaluate_at("0.1".parse().unwrap()),
+            "0.123".parse::<Decimal>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_localized_string() {
+        let polynomial = DecimalPolynomial::new(decimals(&["-0.10", "1", "2.5"]), 'x');
+        assert_eq!(
+            polynomial.as_string_with(&FormatOptions::EUROPEAN),
+            "f(x) = -0,1 + x + 2,5·x^2"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Term-by-term comparison of two polynomials.
+
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// The comparison of the coefficients of a single degree.
+///
+/// A term is missing (`None`) when its coefficient is zero or beyond the polynomial's degree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TermDiff {
+    /// Degree of the compared terms.
+    pub degree: usize,
+    /// Coefficient in the left-hand polynomial.
+    pub left: Option<f64>,
+    /// Coefficient in the right-hand polynomial.
+    pub right: Option<f64>,
+}
+
+impl TermDiff {
+    /// Returns `right - left`, treating missing terms as zero.
+    pub fn delta(&self) -> f64 {
+        self.right.unwrap_or(0f64) - self.left.unwrap_or(0f64)
+    }
+
+    /// Returns `|right - left|` relative to the larger of the two coefficients, or zero if
+    /// both terms are missing.
+    pub fn relative_error(&self) -> f64 {
+        let scale = self
+            .left
+            .unwrap_or(0f64)
+            .abs()
+            .max(self.right.unwrap_or(0f64).abs());
+        if scale == 0f64 {
+            0f64
+        } else {
+            self.delta().abs() / scale
+        }
+    }
+
+    /// Returns `true` if both coefficients are identical.
+    pub fn is_equal(&self) -> bool {
+        self.left == self.right
+    }
+}
+
+impl fmt::Display for TermDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "degree {}: ", self.degree)?;
+        match (self.left, self.right) {
+            (Some(left), Some(right)) if left == right => write!(f, "{} (equal)", left),
+            (Some(left), Some(right)) => write!(
+                f,
+                "{} vs {} (delta {:e}, relative error {:e})",
+                left,
+                right,
+                self.delta(),
+                self.relative_error()
+            ),
+            (Some(left), None) => write!(f, "{} vs missing", left),
+            (None, Some(right)) => write!(f, "missing vs {}", right),
+            (None, None) => write!(f, "missing in both"),
+        }
+    }
+}
+
+/// A per-degree comparison of two polynomials, produced by [`Polynomial::diff_report`].
+///
+/// The `Display` implementation prints one line per differing term, which makes it suitable
+/// for assertion messages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffReport {
+    /// One entry per degree up to the larger of the two degrees, in ascending order.
+    pub terms: Vec<TermDiff>,
+}
+
+impl DiffReport {
+    /// Returns `true` if every pair of coefficients is identical.
+    pub fn is_identical(&self) -> bool {
+        self.terms.iter().all(TermDiff::is_equal)
+    }
+
+    /// Returns the terms whose coefficients differ.
+    pub fn differences(&self) -> impl Iterator<Item = &TermDiff> {
+        self.terms.iter().filter(|term| !term.is_equal())
+    }
+
+    /// Returns the largest absolute coefficient difference.
+    pub fn max_delta(&self) -> f64 {
+        self.terms
+            .iter()
+            .fold(0f64, |largest, term| largest.max(term.delta().abs()))
+    }
+
+    /// Returns the largest relative coefficient difference.
+    pub fn max_relative_error(&self) -> f64 {
+        self.terms
+            .iter()
+            .fold(0f64, |largest, term| largest.max(term.relative_error()))
+    }
+}
+
+impl fmt::Display for DiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_identical() {
+            return write!(f, "polynomials are identical");
+        }
+        let mut first = true;
+        for term in self.differences() {
+            if !first {
+                writeln!(f)?;
+            }
+            write!(f, "{}", term)?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl Polynomial {
+    /// Compares `self` (left) with `other` (right) degree by degree
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let a = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// let b = Polynomial::new(vec![0f64, 2f64, 3.5], 'x');
+    /// let report = a.diff_report(&b);
+    ///
+    /// assert_eq!(report.differences().count(), 2);
+    /// assert_eq!(report.max_delta(), 1f64);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "degree 0: 1 vs missing\ndegree 2: 3 vs 3.5 (delta 5e-1, relative error 1.4285714285714285e-1)"
+    /// );
+    /// ```
+    pub fn diff_report(&self, other: &Polynomial) -> DiffReport {
+        let term = |polynomial: &Polynomial, degree: usize| {
+            polynomial
+                .coefficients
+                .get(degree)
+                .copied()
+                .filter(|&coeff| coeff != 0f64)
+        };
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let terms = (0..length)
+            .map(|degree| TermDiff {
+                degree,
+                left: term(self, degree),
+                right: term(other, degree),
+            })
+            .collect();
+
+        DiffReport { terms }
+    }
+}
+
+/// Asserts that two polynomials are equal, optionally within a [`Tolerance`], printing a
+/// term-wise [`DiffReport`] on failure.
+///
+/// Without a tolerance the coefficients must match exactly. A custom message may follow
+/// the tolerance, as with `assert_eq!`.
+///
+/// [`Tolerance`]: crate::tolerance::Tolerance
+/// # Example
+/// ```
+/// use polynom::assert_poly_eq;
+/// use polynom::polynomial::Polynomial;
+/// use polynom::tolerance::Tolerance;
+///
+/// let a = Polynomial::new(vec![0.1 + 0.2, 1f64], 'x');
+/// let b = Polynomial::new(vec![0.3, 1f64], 'x');
+/// assert_poly_eq!(a, b, Tolerance::ulps(4f64));
+/// assert_poly_eq!(a, a.clone());
+/// ```
+#[macro_export]
+macro_rules! assert_poly_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_poly_eq!($left, $right, $crate::tolerance::Tolerance::EXACT)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::polynomial::Polynomial::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion failed: `left ≈ right`\n  left: {}\n right: {}\n{}",
+                        left.as_string(),
+                        right.as_string(),
+                        left.diff_report(right)
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::polynomial::Polynomial::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion failed: `left ≈ right`: {}\n  left: {}\n right: {}\n{}",
+                        format_args!($($arg)+),
+                        left.as_string(),
+                        right.as_string(),
+                        left.diff_report(right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_polynomials() {
+        let polynomial = Polynomial::new(vec![1f64, 0f64, 2f64], 'x');
+        let report = polynomial.diff_report(&polynomial);
+
+        assert!(report.is_identical());
+        assert_eq!(report.terms.len(), 3);
+        assert_eq!(report.max_relative_error(), 0f64);
+        assert_eq!(report.to_string(), "polynomials are identical");
+    }
+
+    #[test]
+    fn test_different_degrees() {
+        let a = Polynomial::new(vec![1f64], 'x');
+        let b = Polynomial::new(vec![1f64, 0f64, -4f64], 'x');
+        let report = a.diff_report(&b);
+
+        assert_eq!(report.terms.len(), 3);
+        assert_eq!(report.terms[1].left, None);
+        assert_eq!(report.terms[1].right, None);
+        assert!(report.terms[1].is_equal());
+        assert_eq!(report.terms[2].delta(), -4f64);
+        assert_eq!(report.terms[2].relative_error(), 1f64);
+        assert_eq!(report.to_string(), "degree 2: missing vs -4");
+    }
+
+    #[test]
+    #[should_panic(expected = "degree 1: 2 vs 2.5")]
+    fn test_assert_poly_eq_reports_diff() {
+        let a = Polynomial::new(vec![1f64, 2f64], 'x');
+        let b = Polynomial::new(vec![1f64, 2.5], 'x');
+
+        assert_poly_eq!(a, b, crate::tolerance::Tolerance::ulps(4f64));
+    }
+
+    #[test]
+    #[should_panic(expected = "after refit")]
+    fn test_assert_poly_eq_custom_message() {
+        let a = Polynomial::new(vec![1f64], 'x');
+
+        assert_poly_eq!(
+            a,
+            Polynomial::new(vec![2f64], 'x'),
+            crate::tolerance::Tolerance::EXACT,
+            "after {}",
+            "refit"
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! Formatting of polynomials for display, and the options that localize it.
+//!
+//! Each representation turns its nonzero coefficients into terms, which are laid out by a
+//! fixed table of rules, so every type prints zero, signs and unit coefficients the same
+//! way. [`FormatOptions`] changes the decimal separator and the multiplication sign for
+//! documents that follow other conventions, and
+//! [`to_spoken_text`](crate::polynomial::Polynomial::to_spoken_text) reads a polynomial out
+//! in words.
+
+use crate::polynomial::Polynomial;
+
+/// Locale-dependent choices for formatting a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Printed in place of the decimal point in coefficients, e.g. `,`.
+    pub decimal_separator: char,
+    /// Printed between a coefficient and the indeterminate, e.g. `·`; with `None` they are
+    /// juxtaposed, as in `2x`.
+    pub multiplication_sign: Option<char>,
+}
+
+impl FormatOptions {
+    /// A decimal point and implicit multiplication, as in `1.5 + 2x`.
+    pub const DEFAULT: FormatOptions = FormatOptions {
+        decimal_separator: '.',
+        multiplication_sign: None,
+    };
+
+    /// A decimal comma and a middle dot, as in `1,5 + 2·x`, common in continental Europe.
+    pub const EUROPEAN: FormatOptions = FormatOptions {
+        decimal_separator: ',',
+        multiplication_sign: Some('·'),
+    };
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions::DEFAULT
+    }
+}
+
+/// A nonzero term of a polynomial, split into the parts the layout rules look at.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Term {
+    /// The power of the indeterminate.
+    pub degree: usize,
+    /// Whether the coefficient is negative.
+    pub negative: bool,
+    /// The coefficient's magnitude as printed, without a sign.
+    pub magnitude: String,
+    /// Whether the magnitude is exactly one, so it can be left out of non-constant terms.
+    pub unit: bool,
+}
+
+impl Term {
+    /// Returns the term for a floating-point coefficient, or `None` if it is zero
+    pub fn from_f64(degree: usize, coeff: f64) -> Option<Term> {
+        if coeff == 0f64 {
+            return None;
+        }
+
+        Some(Term {
+            degree,
+            negative: coeff < 0f64,
+            magnitude: format!("{}", coeff.abs()),
+            unit: coeff.abs() == 1f64,
+        })
+    }
+}
+
+/// Renders `terms`, in the order given, as `f(x) = ...` under `options`.
+///
+/// | situation                    | rendered as          |
+/// |------------------------------|----------------------|
+/// | no terms                     | `0`                  |
+/// | first term, negative         | `-` before the term  |
+/// | later term                   | ` + ` or ` - `       |
+/// | degree 0                     | the magnitude        |
+/// | degree 1 or more, unit       | no magnitude         |
+/// | degree 1                     | magnitude, then `x`  |
+/// | degree `n` of 2 or more      | magnitude, then `x^n`|
+///
+/// The decimal point of each magnitude is replaced by the chosen separator, and the
+/// multiplication sign, if any, goes between a printed magnitude and the indeterminate.
+pub(crate) fn render<I: IntoIterator<Item = Term>>(
+    indeterminate: char,
+    terms: I,
+    options: &FormatOptions,
+) -> String {
+    format!(
+        "f({}) = {}",
+        indeterminate,
+        render_terms(indeterminate, terms, options)
+    )
+}
+
+/// Renders `terms` like [`render`], without the `f(x) = ` header.
+pub(crate) fn render_terms<I: IntoIterator<Item = Term>>(
+    indeterminate: char,
+    terms: I,
+    options: &FormatOptions,
+) -> String {
+    let mut body = String::new();
+    for term in terms {
+        let sign = match (body.is_empty(), term.negative) {
+            (true, false) => "",
+            (true, true) => "-",
+            (false, false) => " + ",
+            (false, true) => " - ",
+        };
+        body.push_str(sign);
+        let shows_magnitude = term.degree == 0 || !term.unit;
+        if shows_magnitude {
+            body.extend(term.magnitude.chars().map(|c| {
+                if c == '.' {
+                    options.decimal_separator
+                } else {
+                    c
+                }
+            }));
+        }
+        if let (true, true, Some(times)) = (
+            shows_magnitude,
+            term.degree > 0,
+            options.multiplication_sign,
+        ) {
+            body.push(times);
+        }
+        match term.degree {
+            0 => {}
+            1 => body.push(indeterminate),
+            degree => body.push_str(&format!("{}^{}", indeterminate, degree)),
+        }
+    }
+    if body.is_empty() {
+        body.push('0');
+    }
+
+    body
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Returns the words for `n`, which must be below one thousand.
+fn hundreds_in_words(n: u64) -> Vec<&'static str> {
+    let mut words = Vec::new();
+    if n >= 100 {
+        words.push(ONES[(n / 100) as usize]);
+        words.push("hundred");
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        words.push(TENS[(rest / 10) as usize]);
+        if !rest.is_multiple_of(10) {
+            words.push(ONES[(rest % 10) as usize]);
+        }
+    } else if rest > 0 || n == 0 {
+        words.push(ONES[rest as usize]);
+    }
+    words
+}
+
+/// Returns the words for a nonnegative integer, e.g. `twenty one thousand five`.
+fn integer_in_words(mut n: u64) -> Vec<&'static str> {
+    if n == 0 {
+        return vec!["zero"];
+    }
+    let mut groups = Vec::new();
+    let mut scale = 0;
+    while n > 0 {
+        let group = n % 1000;
+        if group != 0 {
+            let mut words = hundreds_in_words(group);
+            if scale > 0 {
+                words.push(SCALES[scale]);
+            }
+            groups.push(words);
+        }
+        n /= 1000;
+        scale += 1;
+    }
+    groups.into_iter().rev().flatten().collect()
+}
+
+/// Returns the words for a nonnegative magnitude as printed by `f64`'s `Display`, reading
+/// digits after the decimal point one by one, e.g. `two point zero five`.
+fn magnitude_in_words(magnitude: f64) -> String {
+    if magnitude.is_nan() {
+        return String::from("not a number");
+    }
+    if magnitude.is_infinite() {
+        return String::from("infinity");
+    }
+
+    let printed = format!("{}", magnitude);
+    let (whole, fraction) = match printed.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (printed.as_str(), None),
+    };
+    let digit = |c: char| ONES[c.to_digit(10).unwrap() as usize];
+    let mut words: Vec<&str> = match whole.parse::<u64>() {
+        Ok(n) => integer_in_words(n),
+        // Beyond the named scales the digits are read out one by one
+        Err(_) => whole.chars().map(digit).collect(),
+    };
+    if let Some(fraction) = fraction {
+        words.push("point");
+        words.extend(fraction.chars().map(digit));
+    }
+    words.join(" ")
+}
+
+impl Polynomial {
+    /// Returns the polynomial read out in words, highest degree first, for screen readers
+    /// and other speech output.
+    ///
+    /// Coefficients of one are left out of non-constant terms, `x^2` and `x^3` are read as
+    /// `x squared` and `x cubed`, higher powers as `x to the power of n`, and digits after
+    /// a decimal point one at a time. A negative leading coefficient is read as `negative`
+    /// so it cannot be mistaken for a subtraction.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.to_spoken_text(), "three x squared plus two x plus one");
+    ///
+    /// let polynomial = Polynomial::new(vec![0.5, 0f64, 0f64, 0f64, -1f64], 't');
+    /// assert_eq!(
+    ///     polynomial.to_spoken_text(),
+    ///     "negative t to the power of four plus zero point five"
+    /// );
+    /// ```
+    pub fn to_spoken_text(&self) -> String {
+        let mut words: Vec<String> = Vec::new();
+        for (degree, &coeff) in self.coefficients.iter().enumerate().rev() {
+            if coeff == 0f64 {
+                continue;
+            }
+            let negative = coeff < 0f64;
+            words.push(String::from(match (words.is_empty(), negative) {
+                (true, false) => "",
+                (true, true) => "negative",
+                (false, false) => "plus",
+                (false, true) => "minus",
+            }));
+            if degree == 0 || coeff.abs() != 1f64 {
+                words.push(magnitude_in_words(coeff.abs()));
+            }
+            let power = match degree {
+                0 => None,
+                1 => Some(String::new()),
+                2 => Some(String::from(" squared")),
+                3 => Some(String::from(" cubed")),
+                _ => Some(format!(
+                    " to the power of {}",
+                    integer_in_words(degree as u64).join(" ")
+                )),
+            };
+            if let Some(power) = power {
+                words.push(format!("{}{}", self.indeterminate, power));
+            }
+        }
+        if words.is_empty() {
+            return String::from("zero");
+        }
+
+        words
+            .into_iter()
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf2::Gf2Polynomial;
+    use crate::gfp::GfpPolynomial;
+
+    /// Golden outputs of `Polynomial::as_string`, coefficients in increasing degree.
+    const GOLDEN: &[(&[f64], char, &str)] = &[
+        // Zero and constants
+        (&[], 'x', "f(x) = 0"),
+        (&[0.0], 'x', "f(x) = 0"),
+        (&[-0.0], 'x', "f(x) = 0"),
+        (&[0.0, 0.0, 0.0], 'x', "f(x) = 0"),
+        (&[1.0], 'x', "f(x) = 1"),
+        (&[-1.0], 'x', "f(x) = -1"),
+        (&[2.5], 'x', "f(x) = 2.5"),
+        (&[-0.125], 'x', "f(x) = -0.125"),
+        // Single terms
+        (&[0.0, 1.0], 'x', "f(x) = x"),
+        (&[0.0, -1.0], 'x', "f(x) = -x"),
+        (&[0.0, 3.0], 'x', "f(x) = 3x"),
+        (&[0.0, -3.0], 'x', "f(x) = -3x"),
+        (&[0.0, 0.0, 1.0], 'x', "f(x) = x^2"),
+        (&[0.0, 0.0, -1.0], 'x', "f(x) = -x^2"),
+        (
+            &[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0],
+            'x',
+            "f(x) = 4x^10",
+        ),
+        // Zero degree-1 and constant terms are left out
+        (&[1.0, 0.0, 3.0], 'x', "f(x) = 1 + 3x^2"),
+        (&[0.0, 0.0, 3.0, 4.0], 'x', "f(x) = 3x^2 + 4x^3"),
+        (&[1.0, 2.0, 0.0, 3.0], 'x', "f(x) = 1 + 2x + 3x^3"),
+        (&[0.0, 2.0, 0.0, 3.0], 'x', "f(x) = 2x + 3x^3"),
+        // Negative terms
+        (&[1.0, -2.0], 'x', "f(x) = 1 - 2x"),
+        (&[-1.0, 2.0], 'x', "f(x) = -1 + 2x"),
+        (&[1.0, 2.0, -3.0], 'x', "f(x) = 1 + 2x - 3x^2"),
+        (&[1.0, -1.0, 1.0, -1.0], 'x', "f(x) = 1 - x + x^2 - x^3"),
+        (&[0.0, -1.0, 0.0, -1.0], 'x', "f(x) = -x - x^3"),
+        // All negative
+        (&[-1.0, -2.0, -3.0], 'x', "f(x) = -1 - 2x - 3x^2"),
+        (&[-1.0, -1.0, -1.0], 'x', "f(x) = -1 - x - x^2"),
+        // Unit coefficients keep their magnitude only in the constant term
+        (&[1.0, 1.0, 1.0], 'x', "f(x) = 1 + x + x^2"),
+        (&[-1.0, 0.0, 1.0], 'x', "f(x) = -1 + x^2"),
+        // Fractions, tiny and huge magnitudes print like `f64`'s `Display`
+        (&[0.5, -0.25, 0.1], 'x', "f(x) = 0.5 - 0.25x + 0.1x^2"),
+        (
+            &[1e-7, 0.0, -1e16],
+            'x',
+            "f(x) = 0.0000001 - 10000000000000000x^2",
+        ),
+        (&[-0.0, 2.0], 'x', "f(x) = 2x"),
+        // Non-finite coefficients
+        (&[f64::NAN], 'x', "f(x) = NaN"),
+        (&[1.0, f64::INFINITY], 'x', "f(x) = 1 + infx"),
+        (&[f64::NEG_INFINITY, 0.0, 1.0], 'x', "f(x) = -inf + x^2"),
+        // Other indeterminates
+        (&[1.0, -2.0, 1.0], 't', "f(t) = 1 - 2t + t^2"),
+        (&[0.0, 0.0, -7.0], 'z', "f(z) = -7z^2"),
+        (&[], 'y', "f(y) = 0"),
+    ];
+
+    #[test]
+    fn test_golden_polynomial_strings() {
+        for (coefficients, indeterminate, expected) in GOLDEN {
+            let polynomial = Polynomial::new(coefficients.to_vec(), *indeterminate);
+            assert_eq!(polynomial.as_string(), *expected, "{:?}", coefficients);
+        }
+    }
+
+    #[test]
+    fn test_finite_field_strings_share_the_rules() {
+        assert_eq!(GfpPolynomial::new(vec![0], 5).as_string(), "f(x) = 0");
+        assert_eq!(
+            GfpPolynomial::new(vec![1, 0, 4, 1], 5).as_string(),
+            "f(x) = 1 + 4x^2 + x^3"
+        );
+        assert_eq!(Gf2Polynomial::new(0b1011).as_string(), "f(x) = 1 + x + x^3");
+    }
+
+    #[test]
+    fn test_render_keeps_term_order() {
+        let terms = vec![
+            Term::from_f64(2, -1f64).unwrap(),
+            Term::from_f64(0, 3f64).unwrap(),
+        ];
+        assert_eq!(
+            render('x', terms, &FormatOptions::DEFAULT),
+            "f(x) = -x^2 + 3"
+        );
+        assert_eq!(Term::from_f64(4, -0f64), None);
+    }
+
+    #[test]
+    fn test_localized_strings() {
+        let polynomial = Polynomial::new(vec![1.5, -1f64, 0f64, 0.25], 'x');
+        assert_eq!(
+            polynomial.as_string_with(&FormatOptions::EUROPEAN),
+            "f(x) = 1,5 - x + 0,25·x^3"
+        );
+        let options = FormatOptions {
+            decimal_separator: '.',
+            multiplication_sign: Some('*'),
+        };
+        assert_eq!(
+            polynomial.as_string_with(&options),
+            "f(x) = 1.5 - x + 0.25*x^3"
+        );
+        assert_eq!(
+            polynomial.as_string_with(&FormatOptions::default()),
+            polynomial.as_string()
+        );
+        assert_eq!(
+            Polynomial::new(vec![0f64], 'x').as_string_with(&FormatOptions::EUROPEAN),
+            "f(x) = 0"
+        );
+    }
+
+    #[test]
+    fn test_spoken_text() {
+        let cases: &[(&[f64], &str)] = &[
+            (&[], "zero"),
+            (&[-0.0], "zero"),
+            (&[1.0], "one"),
+            (&[-1.0], "negative one"),
+            (&[0.0, 1.0], "x"),
+            (&[0.0, -1.0], "negative x"),
+            (&[-3.0, 0.0, -1.0], "negative x squared minus three"),
+            (&[7.0, 0.0, 0.0, 1.0], "x cubed plus seven"),
+            (&[0.0, 12.0], "twelve x"),
+            (&[2.05, -21.0], "negative twenty one x plus two point zero five"),
+            (&[1e6 + 15.0], "one million fifteen"),
+            (&[1234567.0], "one million two hundred thirty four thousand five hundred sixty seven"),
+            (&[100.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0], "x to the power of eleven plus one hundred"),
+            (&[1e20], "one zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero"),
+            (&[f64::NAN, f64::NEG_INFINITY], "negative infinity x plus not a number"),
+        ];
+        for (coefficients, expected) in cases {
+            let polynomial = Polynomial::new(coefficients.to_vec(), 'x');
+            assert_eq!(polynomial.to_spoken_text(), *expected, "{:?}", coefficients);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Chebyshev economization: lowering the degree of a polynomial while keeping the
+//! sup-norm error over an interval under control.
+
+use crate::polynomial::Polynomial;
+
+impl Polynomial {
+    /// Returns the lowest-degree polynomial found by Chebyshev economization that stays
+    /// within `max_error` of `self` everywhere on `interval`.
+    ///
+    /// The polynomial is expanded in Chebyshev polynomials on `interval`, and the highest
+    /// terms are dropped for as long as the sum of their absolute coefficients, which
+    /// bounds the error since `|T_k| <= 1`, stays within `max_error`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^3 = (3 T_1 + T_3) / 4, so dropping T_3 costs at most 1/4 on [-1, 1]
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 0f64, 1f64], 'x');
+    /// let reduced = polynomial.reduce_degree(0.25, (-1f64, 1f64));
+    /// assert_eq!(reduced.coefficients, vec![0f64, 0.75]);
+    /// ```
+    /// # Panics
+    /// Panics if the interval has zero length.
+    pub fn reduce_degree(&self, max_error: f64, interval: (f64, f64)) -> Polynomial {
+        assert!(
+            interval.0 != interval.1,
+            "cannot economize over an interval of zero length"
+        );
+
+        let unit = (-1f64, 1f64);
+        let mut series = chebyshev_series(&self.map_domain(interval, unit));
+        let mut error = 0f64;
+        while let Some(last) = series.last() {
+            if error + last.abs() > max_error {
+                break;
+            }
+            error += last.abs();
+            series.pop();
+        }
+
+        from_chebyshev_series(&series, self.indeterminate).map_domain(unit, interval)
+    }
+
+    /// Performs classic Chebyshev economization on `[-1, 1]`, dropping the `terms_to_drop`
+    /// highest Chebyshev terms, and returns the economized polynomial with a bound on the
+    /// sup-norm error it incurs on `[-1, 1]`.
+    ///
+    /// The bound is the sum of the absolute values of the dropped Chebyshev coefficients.
+    /// Use [`Polynomial::map_domain`] first to economize over a different interval, or
+    /// [`Polynomial::reduce_degree`] to drop terms up to an error budget instead.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // 1 + x + x^2 / 2 + x^3 / 6, with x^3 / 6 = (3 T_1 + T_3) / 24
+    /// let polynomial = Polynomial::new(vec![1f64, 1f64, 0.5, 1f64 / 6f64], 'x');
+    /// let (economized, error) = polynomial.economize(1);
+    /// assert_eq!(economized.degree(), 2);
+    /// assert!((economized.coefficients[1] - 1.125).abs() < 1e-15);
+    /// assert!((error - 1f64 / 24f64).abs() < 1e-15);
+    /// ```
+    pub fn economize(&self, terms_to_drop: usize) -> (Polynomial, f64) {
+        let mut series = chebyshev_series(self);
+        let keep = series.len().saturating_sub(terms_to_drop);
+        let error = series[keep..].iter().map(|c| c.abs()).sum();
+        series.truncate(keep);
+
+        (from_chebyshev_series(&series, self.indeterminate), error)
+    }
+}
+
+/// Returns the coefficients `c` with `p(x) = sum c_k T_k(x)`.
+///
+/// Horner's scheme is run in the Chebyshev basis, using `x T_0 = T_1` and
+/// `x T_k = (T_{k+1} + T_{k-1}) / 2`.
+fn chebyshev_series(polynomial: &Polynomial) -> Vec<f64> {
+    let mut series: Vec<f64> = Vec::with_capacity(polynomial.coefficients.len());
+    for &coeff in polynomial.coefficients.iter().rev() {
+        let mut next = vec![0f64; series.len() + 1];
+        for (k, value) in series.iter().enumerate() {
+            if k == 0 {
+                next[1] += value;
+            } else {
+                next[k - 1] += value / 2f64;
+                next[k + 1] += value / 2f64;
+            }
+        }
+        next[0] += coeff;
+        series = next;
+    }
+
+    series
+}
+
+/// Returns the monomial form of `sum c_k T_k(x)`.
+fn from_chebyshev_series(series: &[f64], indeterminate: char) -> Polynomial {
+    let mut coefficients = vec![0f64; series.len().max(1)];
+    let mut previous: Vec<f64> = vec![];
+    let mut current: Vec<f64> = vec![1f64];
+    for (k, c) in series.iter().enumerate() {
+        for (i, value) in current.iter().enumerate() {
+            coefficients[i] += c * value;
+        }
+
+        // T_{k+1} = 2x T_k - T_{k-1}, with T_1 = x
+        let factor = if k == 0 { 1f64 } else { 2f64 };
+        let mut next = vec![0f64; current.len() + 1];
+        for (i, value) in current.iter().enumerate() {
+            next[i + 1] += factor * value;
+        }
+        for (i, value) in previous.iter().enumerate() {
+            next[i] -= value;
+        }
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    Polynomial::new(coefficients, indeterminate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_chebyshev_series_round_trip() {
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 0.5, 3f64, -1f64, 0.25], 'x');
+        let series = chebyshev_series(&polynomial);
+        let back = from_chebyshev_series(&series, 'x');
+        for (a, b) in back.coefficients.iter().zip(&polynomial.coefficients) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_series_of_chebyshev_polynomial() {
+        let series = chebyshev_series(&test_polynomials::chebyshev(6));
+        let mut expected = vec![0f64; 7];
+        expected[6] = 1f64;
+        for (a, b) in series.iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_reduce_degree_stays_within_tolerance() {
+        // Degree 12 Taylor polynomial of exp, reduced on [2, 5]
+        let mut factorial = 1f64;
+        let coefficients: Vec<f64> = (0..=12)
+            .map(|k| {
+                if k > 0 {
+                    factorial *= k as f64;
+                }
+                1f64 / factorial
+            })
+            .collect();
+        let polynomial = Polynomial::new(coefficients, 'x');
+        let reduced = polynomial.reduce_degree(1e-3, (2f64, 5f64));
+
+        assert!(reduced.degree() < polynomial.degree());
+        let error = (reduced.clone() - polynomial.clone()).sup_norm_on(2f64, 5f64);
+        assert!(error <= 1e-3 * (1f64 + 1e-9));
+    }
+
+    #[test]
+    fn test_reduce_degree_with_zero_tolerance_keeps_degree() {
+        let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 't');
+        let reduced = polynomial.reduce_degree(0f64, (0f64, 1f64));
+        assert_eq!(reduced.degree(), 2);
+        assert_eq!(reduced.indeterminate, 't');
+    }
+
+    #[test]
+    fn test_reduce_degree_can_reach_zero() {
+        let polynomial = Polynomial::new(vec![1e-6, 1e-6], 'x');
+        let reduced = polynomial.reduce_degree(1e-5, (-1f64, 1f64));
+        assert_eq!(reduced.degree(), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reduce_degree_on_empty_interval() {
+        Polynomial::new(vec![1f64, 1f64], 'x').reduce_degree(1f64, (1f64, 1f64));
+    }
+
+    #[test]
+    fn test_economize_error_bound_holds() {
+        let polynomial = Polynomial::new(vec![0.5, -1f64, 2f64, 0.75, -0.25, 0.125], 'x');
+        for terms in 0..=6 {
+            let (economized, error) = polynomial.economize(terms);
+            assert_eq!(economized.degree(), polynomial.degree() - terms as isize);
+            let actual = (economized - polynomial.clone()).sup_norm_on(-1f64, 1f64);
+            assert!(actual <= error + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_economize_nothing_is_exact() {
+        let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'y');
+        let (economized, error) = polynomial.economize(0);
+        assert_eq!(error, 0f64);
+        assert_eq!(economized.indeterminate, 'y');
+        for (a, b) in economized.coefficients.iter().zip(&polynomial.coefficients) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_economize_more_terms_than_present() {
+        let polynomial = Polynomial::new(vec![1f64, 0f64, 2f64], 'x');
+        let (economized, error) = polynomial.economize(10);
+        assert_eq!(economized.degree(), -1);
+        // 1 + 2x^2 = 2 T_0 + T_2
+        assert!((error - 3f64).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,205 @@
+//! Generators of polynomials with "nice" properties, for producing exercises.
+//!
+//! Every generator builds its polynomial from integer linear factors, so the answer is
+//! known exactly and returned alongside it. Like the rest of the crate, the generators take
+//! an explicit [`RandomSource`], so a seed reproduces the same exercise set.
+
+use crate::number_theory::gcd;
+use crate::polynomial::Polynomial;
+use crate::random::RandomSource;
+
+/// The linear factor `a x + b` with integer coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinearFactor {
+    /// The coefficient of `x`.
+    pub a: i64,
+    /// The constant term.
+    pub b: i64,
+}
+
+impl LinearFactor {
+    /// Returns the root `-b / a` of the factor
+    pub fn root(&self) -> f64 {
+        -self.b as f64 / self.a as f64
+    }
+
+    fn to_polynomial(self) -> Polynomial {
+        Polynomial::new(vec![self.b as f64, self.a as f64], 'x')
+    }
+}
+
+/// A generated polynomial and its factorization `content * factors[0] * factors[1] * ...`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exercise {
+    /// The expanded polynomial.
+    pub polynomial: Polynomial,
+    /// The constant factor in front of the linear factors.
+    pub content: i64,
+    /// The linear factors, ordered by increasing root.
+    pub factors: Vec<LinearFactor>,
+}
+
+impl Exercise {
+    fn from_factors(content: i64, mut factors: Vec<LinearFactor>) -> Exercise {
+        factors.sort_by(|f, g| f.root().total_cmp(&g.root()));
+        let polynomial = factors.iter().fold(
+            Polynomial::new(vec![content as f64], 'x'),
+            |product, factor| product.multiply(factor.to_polynomial()),
+        );
+
+        Exercise {
+            polynomial,
+            content,
+            factors,
+        }
+    }
+
+    /// Returns the roots of the polynomial in increasing order, repeated by multiplicity
+    pub fn roots(&self) -> Vec<f64> {
+        self.factors.iter().map(LinearFactor::root).collect()
+    }
+}
+
+/// Returns an integer uniformly distributed in `[low, high]`, up to a modulo bias that is
+/// negligible for the small ranges used in exercises.
+fn integer_in_range<R: RandomSource + ?Sized>(rng: &mut R, low: i64, high: i64) -> i64 {
+    let span = (high - low) as u64 + 1;
+    low + (rng.next_u64() % span) as i64
+}
+
+/// Returns a polynomial of the given degree and leading coefficient whose roots are
+/// integers drawn from `roots` (inclusive), with possible repeats.
+/// # Example
+/// ```
+/// use polynom::exercises::with_integer_roots;
+/// use polynom::random::SplitMix64;
+///
+/// let exercise = with_integer_roots(3, 2, (-5, 5), &mut SplitMix64::new(1));
+/// assert_eq!(exercise.polynomial.degree(), 3);
+/// assert_eq!(exercise.polynomial.coefficients[3], 2f64);
+/// for root in exercise.roots() {
+///     assert_eq!(root.fract(), 0f64);
+///     assert_eq!(exercise.polynomial.evaluate_at(root), 0f64);
+/// }
+/// ```
+///
+/// # Panics
+/// Panics if `leading_coefficient` is zero or the range of roots is empty.
+pub fn with_integer_roots<R: RandomSource + ?Sized>(
+    degree: usize,
+    leading_coefficient: i64,
+    roots: (i64, i64),
+    rng: &mut R,
+) -> Exercise {
+    assert!(
+        leading_coefficient != 0,
+        "leading coefficient must be nonzero"
+    );
+    assert!(roots.0 <= roots.1, "range of roots must be nonempty");
+    let factors = (0..degree)
+        .map(|_| LinearFactor {
+            a: 1,
+            b: -integer_in_range(rng, roots.0, roots.1),
+        })
+        .collect();
+
+    Exercise::from_factors(leading_coefficient, factors)
+}
+
+/// Returns a polynomial of the given degree that factors over the integers into `content`
+/// times primitive linear factors `a x + b` with `1 <= a <= max_coefficient` and
+/// `|b| <= max_coefficient`.
+///
+/// The roots are the rationals `-b / a`, so the polynomial exercises the rational root
+/// theorem; its leading coefficient is `content` times the product of the `a`s.
+/// # Example
+/// ```
+/// use polynom::exercises::factorable_over_integers;
+/// use polynom::random::SplitMix64;
+///
+/// let exercise = factorable_over_integers(2, 1, 3, &mut SplitMix64::new(4));
+/// for factor in &exercise.factors {
+///     assert!(1 <= factor.a && factor.a <= 3 && factor.b.abs() <= 3);
+/// }
+/// for coeff in &exercise.polynomial.coefficients {
+///     assert_eq!(coeff.fract(), 0f64);
+/// }
+/// ```
+///
+/// # Panics
+/// Panics if `content` is zero or `max_coefficient` is less than one.
+pub fn factorable_over_integers<R: RandomSource + ?Sized>(
+    degree: usize,
+    content: i64,
+    max_coefficient: i64,
+    rng: &mut R,
+) -> Exercise {
+    assert!(content != 0, "content must be nonzero");
+    assert!(max_coefficient >= 1, "max_coefficient must be at least one");
+    let factors = (0..degree)
+        .map(|_| loop {
+            let a = integer_in_range(rng, 1, max_coefficient);
+            let b = integer_in_range(rng, -max_coefficient, max_coefficient);
+            // Primitive factors keep the factorization unique up to sign
+            if gcd(a as u64, b.unsigned_abs()) == 1 {
+                break LinearFactor { a, b };
+            }
+        })
+        .collect();
+
+    Exercise::from_factors(content, factors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_integer_roots_stay_in_range() {
+        let mut rng = SplitMix64::new(9);
+        for degree in 0..8 {
+            let exercise = with_integer_roots(degree, -3, (-2, 4), &mut rng);
+            assert_eq!(exercise.polynomial.degree(), degree as isize);
+            assert_eq!(exercise.polynomial.coefficients[degree], -3f64);
+            let roots = exercise.roots();
+            assert_eq!(roots.len(), degree);
+            assert!(roots.windows(2).all(|pair| pair[0] <= pair[1]));
+            for root in roots {
+                assert!((-2f64..=4f64).contains(&root));
+                assert_eq!(exercise.polynomial.evaluate_at(root), 0f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_factorable_polynomials_have_rational_roots() {
+        let mut rng = SplitMix64::new(3);
+        for degree in 1..6 {
+            let exercise = factorable_over_integers(degree, 2, 4, &mut rng);
+            let leading: i64 = 2 * exercise.factors.iter().map(|f| f.a).product::<i64>();
+            assert_eq!(exercise.polynomial.coefficients[degree], leading as f64);
+            for factor in &exercise.factors {
+                // a x + b vanishes at -b / a, so a^n p(-b / a) is an exact integer zero
+                let scaled: f64 = exercise
+                    .polynomial
+                    .coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        c * ((-factor.b) as f64).powi(i as i32)
+                            * (factor.a as f64).powi((degree - i) as i32)
+                    })
+                    .sum();
+                assert_eq!(scaled, 0f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed_reproduces_exercises() {
+        let a = with_integer_roots(4, 1, (-9, 9), &mut SplitMix64::new(12));
+        let b = with_integer_roots(4, 1, (-9, 9), &mut SplitMix64::new(12));
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,300 @@
+//! Worked versions of elementary operations that record each step, for tutoring
+//! applications that show their work.
+//!
+//! Every `_explained` method returns the same result as the plain operation together with
+//! the [`Step`]s that lead to it, each pairing a sentence of rationale with the
+//! intermediate polynomial at that point.
+
+use crate::display::{self, FormatOptions, Term};
+use crate::polynomial::Polynomial;
+
+/// One step of a worked operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    /// What was done in this step and why, as a sentence.
+    pub rationale: String,
+    /// The polynomial the operation is working on after this step.
+    pub intermediate: Polynomial,
+}
+
+/// The result of an operation together with the steps that produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explained<T> {
+    /// The value the operation returned.
+    pub result: T,
+    /// The steps that produced it, in order.
+    pub steps: Vec<Step>,
+}
+
+/// Returns a single term such as `-3x^2`, or `0`.
+fn term(coeff: f64, degree: usize, indeterminate: char) -> String {
+    display::render_terms(
+        indeterminate,
+        Term::from_f64(degree, coeff),
+        &FormatOptions::DEFAULT,
+    )
+}
+
+/// Returns the terms of `polynomial` without the `f(x) = ` header.
+fn expression(polynomial: &Polynomial) -> String {
+    let terms = polynomial
+        .coefficients
+        .iter()
+        .enumerate()
+        .filter_map(|(degree, &coeff)| Term::from_f64(degree, coeff));
+    display::render_terms(polynomial.indeterminate, terms, &FormatOptions::DEFAULT)
+}
+
+impl Polynomial {
+    /// Performs polynomial long division like [`div_rem`](Polynomial::div_rem), recording
+    /// each quotient term and the remainder it leaves.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 1 = (x - 1)(x + 1)
+    /// let dividend = Polynomial::new(vec![-1f64, 0f64, 1f64], 'x');
+    /// let divisor = Polynomial::new(vec![-1f64, 1f64], 'x');
+    /// let explained = dividend.div_rem_explained(&divisor);
+    /// assert_eq!(explained.result, dividend.div_rem(&divisor));
+    /// assert_eq!(
+    ///     explained.steps[0].rationale,
+    ///     "Divide the leading term x^2 by the leading term x of the divisor to get x; \
+    ///      subtracting x times (-1 + x) leaves -1 + x"
+    /// );
+    /// assert_eq!(explained.steps[1].intermediate.coefficients, vec![0f64]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem_explained(&self, divisor: &Polynomial) -> Explained<(Polynomial, Polynomial)> {
+        let divisor_degree = divisor.degree();
+        assert!(divisor_degree >= 0, "division by the zero polynomial");
+        let divisor_degree = divisor_degree as usize;
+        let leading = divisor.coefficients[divisor_degree];
+        let indeterminate = self.indeterminate;
+
+        let mut steps = Vec::new();
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree || self.degree() < 0 {
+            steps.push(Step {
+                rationale: format!(
+                    "The dividend {} has lower degree than the divisor {}, so the quotient is 0 \
+                     and the remainder is the dividend",
+                    expression(self),
+                    expression(divisor)
+                ),
+                intermediate: self.clone(),
+            });
+            return Explained {
+                result: self.div_rem(divisor),
+                steps,
+            };
+        }
+
+        let mut quotient = vec![0f64; remainder.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let lead = remainder[i + divisor_degree];
+            let factor = lead / leading;
+            quotient[i] = factor;
+            for (j, coeff) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor * coeff;
+            }
+            remainder.truncate(i + divisor_degree);
+            let intermediate = Polynomial::new(remainder.clone(), indeterminate);
+            steps.push(Step {
+                rationale: format!(
+                    "Divide the leading term {} by the leading term {} of the divisor to get {}; \
+                     subtracting {} times ({}) leaves {}",
+                    term(lead, i + divisor_degree, indeterminate),
+                    term(leading, divisor_degree, indeterminate),
+                    term(factor, i, indeterminate),
+                    term(factor, i, indeterminate),
+                    expression(divisor),
+                    expression(&intermediate)
+                ),
+                intermediate,
+            });
+        }
+
+        Explained {
+            result: (
+                Polynomial::new(quotient, indeterminate),
+                Polynomial::new(remainder, indeterminate),
+            ),
+            steps,
+        }
+    }
+
+    /// Differentiates like [`derivative`](Polynomial::derivative), applying the power rule
+    /// to one term at a time, lowest degree first.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![5f64, 0f64, 3f64], 'x');
+    /// let explained = polynomial.derivative_explained();
+    /// assert_eq!(explained.result, polynomial.derivative());
+    /// assert_eq!(explained.steps[0].rationale, "The derivative of the constant 5 is 0");
+    /// assert_eq!(
+    ///     explained.steps[1].rationale,
+    ///     "By the power rule, the derivative of 3x^2 is 2 * 3x^1 = 6x"
+    /// );
+    /// ```
+    pub fn derivative_explained(&self) -> Explained<Polynomial> {
+        let indeterminate = self.indeterminate;
+        let mut derivative = vec![0f64];
+        let mut steps = Vec::new();
+        for (degree, &coeff) in self.coefficients.iter().enumerate() {
+            if coeff == 0f64 {
+                continue;
+            }
+            let rationale = if degree == 0 {
+                format!(
+                    "The derivative of the constant {} is 0",
+                    term(coeff, 0, indeterminate)
+                )
+            } else {
+                let differentiated = coeff * degree as f64;
+                derivative.resize(degree, 0f64);
+                derivative[degree - 1] = differentiated;
+                format!(
+                    "By the power rule, the derivative of {} is {} * {}{}^{} = {}",
+                    term(coeff, degree, indeterminate),
+                    degree,
+                    term(coeff, 0, indeterminate),
+                    indeterminate,
+                    degree - 1,
+                    term(differentiated, degree - 1, indeterminate)
+                )
+            };
+            steps.push(Step {
+                rationale,
+                intermediate: Polynomial::new(derivative.clone(), indeterminate),
+            });
+        }
+
+        Explained {
+            result: self.derivative(),
+            steps,
+        }
+    }
+
+    /// Divides by `x - root` with synthetic division, returning the quotient and the
+    /// remainder, which equals the value at `root`.
+    ///
+    /// Each step's intermediate polynomial holds the quotient terms found so far.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 3x + 5 = (x - 2)(x - 1) + 3
+    /// let polynomial = Polynomial::new(vec![5f64, -3f64, 1f64], 'x');
+    /// let explained = polynomial.synthetic_division_explained(2f64);
+    /// assert_eq!(explained.result.0.coefficients, vec![-1f64, 1f64]);
+    /// assert_eq!(explained.result.1, 3f64);
+    /// assert_eq!(explained.steps[0].rationale, "Bring down the leading coefficient 1");
+    /// assert_eq!(
+    ///     explained.steps[2].rationale,
+    ///     "Multiply -1 by 2 and add 5 to get the remainder 3"
+    /// );
+    /// ```
+    pub fn synthetic_division_explained(&self, root: f64) -> Explained<(Polynomial, f64)> {
+        let indeterminate = self.indeterminate;
+        let degree = self.coefficients.len() - 1;
+        let mut quotient = vec![0f64; degree.max(1)];
+        let mut steps = Vec::new();
+        let mut carry = 0f64;
+        for (position, &coeff) in self.coefficients.iter().enumerate().rev() {
+            let value = coeff + carry * root;
+            let rationale = if position == degree {
+                format!(
+                    "Bring down the leading coefficient {}",
+                    term(value, 0, indeterminate)
+                )
+            } else {
+                format!(
+                    "Multiply {} by {} and add {} to get {}{}",
+                    term(carry, 0, indeterminate),
+                    term(root, 0, indeterminate),
+                    term(coeff, 0, indeterminate),
+                    if position == 0 { "the remainder " } else { "" },
+                    term(value, 0, indeterminate)
+                )
+            };
+            if position > 0 {
+                quotient[position - 1] = value;
+            }
+            steps.push(Step {
+                rationale,
+                intermediate: Polynomial::new(quotient.clone(), indeterminate),
+            });
+            carry = value;
+        }
+
+        Explained {
+            result: (Polynomial::new(quotient, indeterminate), carry),
+            steps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_results_match_plain_operations() {
+        let mut rng = SplitMix64::new(5);
+        for degree in 0..6 {
+            let polynomial = test_polynomials::random(degree + 2, &mut rng);
+            let divisor = test_polynomials::random(degree / 2 + 1, &mut rng);
+
+            let explained = polynomial.div_rem_explained(&divisor);
+            assert_eq!(explained.result, polynomial.div_rem(&divisor));
+            assert_eq!(explained.steps.len(), degree + 2 - (degree / 2 + 1) + 1);
+            assert_eq!(
+                explained.steps.last().unwrap().intermediate,
+                explained.result.1
+            );
+
+            let explained = polynomial.derivative_explained();
+            assert_eq!(explained.result, polynomial.derivative());
+            assert_eq!(
+                explained.steps.last().unwrap().intermediate,
+                explained.result
+            );
+
+            let (quotient, remainder) = polynomial.synthetic_division_explained(0.5).result;
+            let divisor = Polynomial::new(vec![-0.5, 1f64], 'x');
+            let expected = polynomial.div_rem(&divisor);
+            assert!((remainder - polynomial.evaluate_at(0.5)).abs() < 1e-12);
+            assert!((remainder - expected.1.coefficients[0]).abs() < 1e-12);
+            for (a, b) in quotient.coefficients.iter().zip(&expected.0.coefficients) {
+                assert!((a - b).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let constant = Polynomial::new(vec![4f64], 'x');
+        let explained = constant.div_rem_explained(&Polynomial::new(vec![1f64, 1f64], 'x'));
+        assert_eq!(explained.steps.len(), 1);
+        assert_eq!(
+            explained.steps[0].rationale,
+            "The dividend 4 has lower degree than the divisor 1 + x, so the quotient is 0 and \
+             the remainder is the dividend"
+        );
+
+        let explained = constant.synthetic_division_explained(3f64);
+        assert_eq!(explained.result, (Polynomial::new(vec![0f64], 'x'), 4f64));
+        assert_eq!(explained.steps.len(), 1);
+
+        let zero = Polynomial::new(vec![0f64], 'x');
+        assert!(zero.derivative_explained().steps.is_empty());
+        assert_eq!(zero.div_rem_explained(&constant).steps.len(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Classical orthogonal polynomial families, cached across calls.
+//!
+//! Members are generated by their three-term recurrences the first time they are
+//! requested and kept in a process-wide cache, so asking for `P_n` again, from any thread,
+//! only clones the stored coefficients.
+
+use std::sync::RwLock;
+
+use crate::polynomial::Polynomial;
+
+/// Members above this degree are computed from the cached ones but not stored themselves.
+pub const MAX_CACHED_DEGREE: usize = 128;
+
+static CHEBYSHEV_CACHE: RwLock<Vec<Polynomial>> = RwLock::new(Vec::new());
+static LEGENDRE_CACHE: RwLock<Vec<Polynomial>> = RwLock::new(Vec::new());
+static HERMITE_CACHE: RwLock<Vec<Polynomial>> = RwLock::new(Vec::new());
+
+/// A family of orthogonal polynomials defined by a three-term recurrence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// Chebyshev polynomials of the first kind, `T_{n+1} = 2x T_n - T_{n-1}`.
+    Chebyshev,
+    /// Legendre polynomials, `(n + 1) P_{n+1} = (2n + 1) x P_n - n P_{n-1}`.
+    Legendre,
+    /// Physicists' Hermite polynomials, `H_{n+1} = 2x H_n - 2n H_{n-1}`.
+    Hermite,
+}
+
+impl Family {
+    /// Returns the member of degree `n`, from the cache when it has been computed before
+    /// # Example
+    /// ```
+    /// use polynom::families::Family;
+    ///
+    /// assert_eq!(Family::Chebyshev.member(3).coefficients, vec![0f64, -3f64, 0f64, 4f64]);
+    /// assert_eq!(Family::Legendre.member(2).coefficients, vec![-0.5, 0f64, 1.5]);
+    /// assert_eq!(Family::Hermite.member(2).coefficients, vec![-2f64, 0f64, 4f64]);
+    /// ```
+    pub fn member(self, n: usize) -> Polynomial {
+        let cache = self.cache();
+        if let Some(member) = cache.read().unwrap().get(n) {
+            return member.clone();
+        }
+
+        let mut members = cache.write().unwrap();
+        if members.is_empty() {
+            members.push(Polynomial::new(vec![1f64], 'x'));
+        }
+        while members.len() <= n.min(MAX_CACHED_DEGREE) {
+            let degree = members.len() - 1;
+            let previous = degree.checked_sub(1).map(|i| &members[i]);
+            let next = self.next_member(&members[degree], previous, degree);
+            members.push(next);
+        }
+        if n <= MAX_CACHED_DEGREE {
+            return members[n].clone();
+        }
+
+        let mut previous = members[MAX_CACHED_DEGREE - 1].clone();
+        let mut current = members[MAX_CACHED_DEGREE].clone();
+        drop(members);
+        for degree in MAX_CACHED_DEGREE..n {
+            let next = self.next_member(&current, Some(&previous), degree);
+            previous = std::mem::replace(&mut current, next);
+        }
+
+        current
+    }
+
+    /// Returns the members of degrees `0..count`
+    pub fn members(self, count: usize) -> Vec<Polynomial> {
+        (0..count).map(|n| self.member(n)).collect()
+    }
+
+    fn cache(self) -> &'static RwLock<Vec<Polynomial>> {
+        match self {
+            Family::Chebyshev => &CHEBYSHEV_CACHE,
+            Family::Legendre => &LEGENDRE_CACHE,
+            Family::Hermite => &HERMITE_CACHE,
+        }
+    }
+
+    /// Returns `alpha` and `beta` with `p_{n+1} = alpha x p_n - beta p_{n-1}`.
+    fn recurrence(self, n: usize) -> (f64, f64) {
+        let n = n as f64;
+        match self {
+            Family::Chebyshev if n == 0f64 => (1f64, 0f64),
+            Family::Chebyshev => (2f64, 1f64),
+            Family::Legendre => ((2f64 * n + 1f64) / (n + 1f64), n / (n + 1f64)),
+            Family::Hermite => (2f64, 2f64 * n),
+        }
+    }
+
+    /// Returns the member of degree `n + 1` from those of degrees `n` and `n - 1`.
+    fn next_member(
+        self,
+        current: &Polynomial,
+        previous: Option<&Polynomial>,
+        n: usize,
+    ) -> Polynomial {
+        let (alpha, beta) = self.recurrence(n);
+        let mut coefficients = vec![0f64; current.coefficients.len() + 1];
+        for (degree, coeff) in current.coefficients.iter().enumerate() {
+            coefficients[degree + 1] += alpha * coeff;
+        }
+        if let Some(previous) = previous {
+            for (degree, coeff) in previous.coefficients.iter().enumerate() {
+                coefficients[degree] -= beta * coeff;
+            }
+        }
+
+        Polynomial::new(coefficients, 'x')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_chebyshev_matches_test_polynomials() {
+        for n in 0..20 {
+            assert_eq!(Family::Chebyshev.member(n), test_polynomials::chebyshev(n));
+        }
+    }
+
+    #[test]
+    fn test_members_beyond_cache_continue_the_recurrence() {
+        let n = MAX_CACHED_DEGREE + 2;
+        assert_eq!(Family::Chebyshev.member(n), test_polynomials::chebyshev(n));
+        assert_eq!(CHEBYSHEV_CACHE.read().unwrap().len(), MAX_CACHED_DEGREE + 1);
+    }
+
+    #[test]
+    fn test_cache_is_shared_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|i| std::thread::spawn(move || Family::Hermite.members(10 + i)))
+            .collect();
+        let results: Vec<Vec<Polynomial>> =
+            handles.into_iter().map(|h| h.join().unwrap()).collect();
+        for members in &results {
+            assert_eq!(members[..10], results[0][..10]);
+        }
+        // H_3 = 8x^3 - 12x
+        assert_eq!(results[0][3].coefficients, vec![0f64, -12f64, 0f64, 8f64]);
+    }
+}
@@ -0,0 +1,1397 @@
+//! Least-squares fitting of polynomials to sampled data.
+
+use std::fmt;
+
+use crate::families::Family;
+use crate::linalg;
+use crate::polynomial::Polynomial;
+use crate::roots::{ConvergenceStatus, Diagnostics};
+use crate::stats;
+
+/// Errors that can occur while fitting a polynomial to data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FitError {
+    /// `xs` and `ys` have different lengths.
+    MismatchedLengths,
+    /// There are not enough samples for the requested degree.
+    InsufficientData,
+    /// The samples do not determine a unique fit (e.g. repeated `x` values).
+    Singular,
+    /// A confidence level outside of the open interval `(0, 1)` was requested.
+    InvalidConfidence,
+    /// A smoothing window was empty or had an even length.
+    InvalidWindow,
+    /// Fitting constraints were contradictory, redundant or more than the degree allows.
+    InvalidConstraints,
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitError::MismatchedLengths => write!(f, "xs and ys must have the same length"),
+            FitError::InsufficientData => write!(f, "not enough samples for the requested degree"),
+            FitError::Singular => write!(f, "samples do not determine a unique fit"),
+            FitError::InvalidConfidence => write!(f, "confidence must be between 0 and 1"),
+            FitError::InvalidWindow => write!(f, "window length must be odd"),
+            FitError::InvalidConstraints => {
+                write!(f, "constraints do not determine an admissible fit")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+/// The result of a least-squares fit, including the coefficient covariance matrix.
+#[derive(Debug)]
+pub struct Fit {
+    /// The fitted polynomial.
+    pub polynomial: Polynomial,
+    /// Covariance matrix of the fitted coefficients, indexed by degree.
+    pub covariance: Vec<Vec<f64>>,
+    /// Unbiased estimate of the residual variance, `SSR / (n - degree - 1)`.
+    pub residual_variance: f64,
+    /// Number of degrees of freedom left after the fit, `n - degree - 1`.
+    pub degrees_of_freedom: usize,
+}
+
+/// A point estimate together with the bounds of its confidence interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Prediction {
+    /// The predicted value.
+    pub value: f64,
+    /// Lower bound of the confidence interval.
+    pub lower: f64,
+    /// Upper bound of the confidence interval.
+    pub upper: f64,
+}
+
+/// Affine map between a data interval `[a, b]` and the unit interval `[-1, 1]`.
+///
+/// High-degree fits are computed against the unit interval, where the Chebyshev basis is well conditioned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DomainMap {
+    /// The data interval `(a, b)` that is mapped onto `[-1, 1]`.
+    pub domain: (f64, f64),
+}
+
+impl DomainMap {
+    /// Returns a map taking `[a, b]` onto `[-1, 1]`.
+    /// # Example
+    /// ```
+    /// use polynom::fit::DomainMap;
+    ///
+    /// let map = DomainMap::new(0f64, 10f64);
+    /// assert_eq!(map.to_unit(5f64), 0f64);
+    /// assert_eq!(map.from_unit(1f64), 10f64);
+    /// ```
+    pub fn new(a: f64, b: f64) -> DomainMap {
+        DomainMap { domain: (a, b) }
+    }
+
+    /// Maps `x` from the data interval onto the unit interval.
+    pub fn to_unit(&self, x: f64) -> f64 {
+        let (scale, offset) = self.scale_and_offset();
+        scale * x + offset
+    }
+
+    /// Maps `t` from the unit interval back onto the data interval.
+    pub fn from_unit(&self, t: f64) -> f64 {
+        let (a, b) = self.domain;
+        (t * (b - a) + a + b) / 2f64
+    }
+
+    /// Returns `(scale, offset)` such that `to_unit(x) == scale * x + offset`.
+    fn scale_and_offset(&self) -> (f64, f64) {
+        let (a, b) = self.domain;
+        let scale = 2f64 / (b - a);
+        (scale, -(a + b) / (b - a))
+    }
+}
+
+/// A least-squares fit expressed in the Chebyshev basis over a mapped domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebyshevFit {
+    /// Coefficients of `T_0, T_1, ...` in the unit variable `t = domain.to_unit(x)`.
+    pub coefficients: Vec<f64>,
+    /// The mapping between the data interval and `[-1, 1]`.
+    pub domain: DomainMap,
+}
+
+impl ChebyshevFit {
+    /// Evaluates the fit at `x` using Clenshaw's recurrence, without converting to the monomial basis.
+    /// # Example
+    /// ```
+    /// use polynom::fit::fit_chebyshev;
+    ///
+    /// let xs: Vec<f64> = (0..50).map(|i| i as f64 / 49f64).collect();
+    /// let ys: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+    ///
+    /// let fit = fit_chebyshev(&xs, &ys, 12).unwrap();
+    /// assert!((fit.evaluate_at(0.5) - 0.5f64.exp()).abs() < 1e-12);
+    /// ```
+    pub fn evaluate_at(&self, x: f64) -> f64 {
+        let t = self.domain.to_unit(x);
+        let mut b1 = 0f64;
+        let mut b2 = 0f64;
+        for coeff in self.coefficients.iter().skip(1).rev() {
+            let b0 = coeff + 2f64 * t * b1 - b2;
+            b2 = b1;
+            b1 = b0;
+        }
+
+        self.coefficients.first().copied().unwrap_or(0f64) + t * b1 - b2
+    }
+
+    /// Converts the fit back to a `Polynomial` in the original variable `x`; with no coefficients,
+    /// this is the zero polynomial.
+    /// # Example
+    /// ```
+    /// use polynom::fit::fit_chebyshev;
+    ///
+    /// let fit = fit_chebyshev(&[0f64, 1f64, 2f64], &[1f64, 3f64, 5f64], 1).unwrap();
+    /// let polynomial = fit.to_polynomial();
+    /// assert!((polynomial.coefficients[0] - 1f64).abs() < 1e-12);
+    /// assert!((polynomial.coefficients[1] - 2f64).abs() < 1e-12);
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial {
+        if self.coefficients.is_empty() {
+            return Polynomial::new(vec![0f64], 'x');
+        }
+        let transform = monomial_transform(&self.domain, self.coefficients.len() - 1);
+        let coefficients = transform
+            .iter()
+            .map(|row| row.iter().zip(&self.coefficients).map(|(m, c)| m * c).sum())
+            .collect();
+
+        Polynomial::new(coefficients, 'x')
+    }
+}
+
+/// Returns the matrix `M` whose column `k` holds the monomial coefficients (in `x`) of `T_k(to_unit(x))`.
+fn monomial_transform(domain: &DomainMap, degree: usize) -> Vec<Vec<f64>> {
+    let (scale, offset) = domain.scale_and_offset();
+
+    // Powers of the affine map (scale * x + offset)^j as coefficient vectors in x
+    let mut powers: Vec<Vec<f64>> = vec![vec![1f64]];
+    for j in 0..degree {
+        let previous = &powers[j];
+        let mut next = vec![0f64; previous.len() + 1];
+        for (i, coeff) in previous.iter().enumerate() {
+            next[i] += coeff * offset;
+            next[i + 1] += coeff * scale;
+        }
+        powers.push(next);
+    }
+
+    // Chebyshev polynomials in t via T_{k+1} = 2t T_k - T_{k-1}
+    let mut chebyshev: Vec<Vec<f64>> = vec![vec![1f64], vec![0f64, 1f64]];
+    for k in 1..degree {
+        let mut next = vec![0f64; k + 2];
+        for (i, coeff) in chebyshev[k].iter().enumerate() {
+            next[i + 1] += 2f64 * coeff;
+        }
+        for (i, coeff) in chebyshev[k - 1].iter().enumerate() {
+            next[i] -= coeff;
+        }
+        chebyshev.push(next);
+    }
+
+    let mut transform = vec![vec![0f64; degree + 1]; degree + 1];
+    for (k, t_k) in chebyshev.iter().take(degree + 1).enumerate() {
+        for (j, t_coeff) in t_k.iter().enumerate() {
+            for (i, p_coeff) in powers[j].iter().enumerate() {
+                transform[i][k] += t_coeff * p_coeff;
+            }
+        }
+    }
+
+    transform
+}
+
+/// Returns the Vandermonde matrix of `xs`, with row `i` holding `[1, x_i, x_i^2, ..., x_i^degree]`.
+/// # Example
+/// ```
+/// use polynom::fit::vandermonde;
+///
+/// let matrix = vandermonde(&[2f64, 3f64], 2);
+/// assert_eq!(matrix, vec![vec![1f64, 2f64, 4f64], vec![1f64, 3f64, 9f64]]);
+/// ```
+pub fn vandermonde(xs: &[f64], degree: usize) -> Vec<Vec<f64>> {
+    xs.iter()
+        .map(|&x| {
+            let mut row = Vec::with_capacity(degree + 1);
+            let mut power = 1f64;
+            for _ in 0..=degree {
+                row.push(power);
+                power *= x;
+            }
+            row
+        })
+        .collect()
+}
+
+/// Returns the Vandermonde matrix of `xs` after mapping them onto `[-1, 1]` with `domain`.
+///
+/// Scaling keeps the entries bounded by one, which greatly improves the conditioning of the matrix.
+/// # Example
+/// ```
+/// use polynom::fit::{scaled_vandermonde, DomainMap};
+///
+/// let matrix = scaled_vandermonde(&[0f64, 10f64], 2, &DomainMap::new(0f64, 10f64));
+/// assert_eq!(matrix, vec![vec![1f64, -1f64, 1f64], vec![1f64, 1f64, 1f64]]);
+/// ```
+pub fn scaled_vandermonde(xs: &[f64], degree: usize, domain: &DomainMap) -> Vec<Vec<f64>> {
+    let ts: Vec<f64> = xs.iter().map(|&x| domain.to_unit(x)).collect();
+    vandermonde(&ts, degree)
+}
+
+fn chebyshev_design_matrix(ts: &[f64], degree: usize) -> Vec<Vec<f64>> {
+    ts.iter()
+        .map(|&t| {
+            let mut row = vec![1f64; degree + 1];
+            if degree >= 1 {
+                row[1] = t;
+            }
+            for k in 2..=degree {
+                row[k] = 2f64 * t * row[k - 1] - row[k - 2];
+            }
+            row
+        })
+        .collect()
+}
+
+/// Returns the map onto `[-1, 1]` of the interval spanned by `points`, widened to unit
+/// half-width around a single repeated point.
+fn sample_domain(points: &[f64]) -> DomainMap {
+    let low = points.iter().cloned().fold(f64::INFINITY, f64::min);
+    let high = points.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if low < high {
+        DomainMap::new(low, high)
+    } else {
+        DomainMap::new(low - 1f64, low + 1f64)
+    }
+}
+
+/// Solves the least-squares problem in the Chebyshev basis, returning the fit and the `R` factor of its QR decomposition.
+fn solve(xs: &[f64], ys: &[f64], degree: usize) -> Result<(ChebyshevFit, Vec<Vec<f64>>), FitError> {
+    if xs.len() != ys.len() {
+        return Err(FitError::MismatchedLengths);
+    }
+    if xs.len() < degree + 1 {
+        return Err(FitError::InsufficientData);
+    }
+
+    let domain = sample_domain(xs);
+
+    debug_span!("fit", samples = xs.len(), degree);
+    let ts: Vec<f64> = xs.iter().map(|&x| domain.to_unit(x)).collect();
+    let (coefficients, r) = linalg::least_squares(&chebyshev_design_matrix(&ts, degree), ys)
+        .ok_or(FitError::Singular)?;
+    debug_event!(domain = ?domain.domain, "solved least-squares system");
+
+    Ok((
+        ChebyshevFit {
+            coefficients,
+            domain,
+        },
+        r,
+    ))
+}
+
+/// Fits a polynomial in the Chebyshev basis over the data interval mapped onto `[-1, 1]`.
+///
+/// This avoids the ill-conditioning of the monomial Vandermonde matrix and stays accurate for high degrees.
+/// # Example
+/// ```
+/// use polynom::fit::fit_chebyshev;
+///
+/// let xs = vec![10f64, 11f64, 12f64, 13f64];
+/// let ys = vec![1f64, 3f64, 5f64, 7f64];
+///
+/// let fit = fit_chebyshev(&xs, &ys, 1).unwrap();
+/// assert_eq!(fit.domain.domain, (10f64, 13f64));
+/// assert!((fit.evaluate_at(14f64) - 9f64).abs() < 1e-12);
+/// ```
+pub fn fit_chebyshev(xs: &[f64], ys: &[f64], degree: usize) -> Result<ChebyshevFit, FitError> {
+    solve(xs, ys, degree).map(|(fit, _)| fit)
+}
+
+/// Like [`fit_chebyshev`], but also returns [`Diagnostics`] with the root-mean-square
+/// residual of the fit.
+/// # Example
+/// ```
+/// use polynom::fit::fit_chebyshev_with_diagnostics;
+///
+/// let xs = vec![0f64, 1f64, 2f64, 3f64];
+/// let ys = vec![0f64, 1f64, 0f64, 1f64];
+///
+/// let (_, diagnostics) = fit_chebyshev_with_diagnostics(&xs, &ys, 1).unwrap();
+/// assert!((diagnostics.residual - 0.4472135954999579).abs() < 1e-12);
+/// ```
+pub fn fit_chebyshev_with_diagnostics(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+) -> Result<(ChebyshevFit, Diagnostics), FitError> {
+    let fit = fit_chebyshev(xs, ys, degree)?;
+    let diagnostics = direct_diagnostics(xs, ys, |x| fit.evaluate_at(x));
+    Ok((fit, diagnostics))
+}
+
+/// Returns the polynomial of the given degree that best fits the samples in the least-squares sense.
+///
+/// The fit is computed with [`fit_chebyshev`] and converted back to the monomial basis.
+/// # Example
+/// ```
+/// use polynom::fit::fit;
+///
+/// let xs = vec![0f64, 1f64, 2f64, 3f64];
+/// let ys = vec![1f64, 3f64, 5f64, 7f64];
+///
+/// let polynomial = fit(&xs, &ys, 1).unwrap();
+/// assert!((polynomial.coefficients[0] - 1f64).abs() < 1e-12);
+/// assert!((polynomial.coefficients[1] - 2f64).abs() < 1e-12);
+/// ```
+pub fn fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<Polynomial, FitError> {
+    Ok(fit_chebyshev(xs, ys, degree)?.to_polynomial())
+}
+
+/// Like [`fit`], but also returns [`Diagnostics`] with the root-mean-square residual of the
+/// fitted polynomial.
+/// # Example
+/// ```
+/// use polynom::fit::fit_with_diagnostics;
+/// use polynom::roots::ConvergenceStatus;
+///
+/// let xs = vec![0f64, 1f64, 2f64, 3f64];
+/// let ys = vec![1f64, 3f64, 5f64, 7f64];
+///
+/// let (_, diagnostics) = fit_with_diagnostics(&xs, &ys, 1).unwrap();
+/// assert!(diagnostics.residual < 1e-12);
+/// assert_eq!(diagnostics.status, ConvergenceStatus::Direct);
+/// ```
+pub fn fit_with_diagnostics(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+) -> Result<(Polynomial, Diagnostics), FitError> {
+    let polynomial = fit(xs, ys, degree)?;
+    let diagnostics = direct_diagnostics(xs, ys, |x| polynomial.evaluate_at(x));
+    Ok((polynomial, diagnostics))
+}
+
+/// Returns the [`Diagnostics`] of a direct fit to the samples, evaluated with `f`.
+fn direct_diagnostics<F: Fn(f64) -> f64>(xs: &[f64], ys: &[f64], f: F) -> Diagnostics {
+    let sum_of_squares: f64 = xs.iter().zip(ys).map(|(&x, y)| (f(x) - y).powi(2)).sum();
+    let residual = (sum_of_squares / xs.len().max(1) as f64).sqrt();
+    Diagnostics::new(0, residual, ConvergenceStatus::Direct)
+}
+
+/// Fits a polynomial like [`fit`], additionally returning the coefficient covariance matrix.
+///
+/// At least `degree + 2` samples are required so that the residual variance can be estimated.
+/// # Example
+/// ```
+/// use polynom::fit::fit_with_covariance;
+///
+/// let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64];
+/// let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1];
+///
+/// let fit = fit_with_covariance(&xs, &ys, 1).unwrap();
+/// assert_eq!(fit.covariance.len(), 2);
+/// assert_eq!(fit.degrees_of_freedom, 3);
+/// ```
+pub fn fit_with_covariance(xs: &[f64], ys: &[f64], degree: usize) -> Result<Fit, FitError> {
+    if xs.len() == ys.len() && xs.len() < degree + 2 {
+        return Err(FitError::InsufficientData);
+    }
+    let (chebyshev_fit, r) = solve(xs, ys, degree)?;
+
+    let polynomial = chebyshev_fit.to_polynomial();
+    let degrees_of_freedom = xs.len() - degree - 1;
+    let sum_of_squares: f64 = xs
+        .iter()
+        .zip(ys)
+        .map(|(&x, &y)| (y - polynomial.evaluate_at(x)).powi(2))
+        .sum();
+    let residual_variance = sum_of_squares / degrees_of_freedom as f64;
+    debug_event!(
+        residual_variance,
+        degrees_of_freedom,
+        "estimated residual variance"
+    );
+
+    // Monomial coefficients are M c, so Cov = sigma^2 (M R^-1) (M R^-1)^T
+    let r_inverse = linalg::invert_upper_triangular(&r);
+    let transform = linalg::multiply(
+        &monomial_transform(&chebyshev_fit.domain, degree),
+        &r_inverse,
+    );
+    let covariance = linalg::multiply_by_transpose(&transform)
+        .into_iter()
+        .map(|row| row.into_iter().map(|v| v * residual_variance).collect())
+        .collect();
+
+    Ok(Fit {
+        polynomial,
+        covariance,
+        residual_variance,
+        degrees_of_freedom,
+    })
+}
+
+impl Fit {
+    /// Returns the fitted value at `x`.
+    /// # Example
+    /// ```
+    /// use polynom::fit::fit_with_covariance;
+    ///
+    /// let fit = fit_with_covariance(&[0f64, 1f64, 2f64], &[0f64, 1f64, 2f64], 1).unwrap();
+    /// assert!((fit.predict(3f64) - 3f64).abs() < 1e-12);
+    /// ```
+    pub fn predict(&self, x: f64) -> f64 {
+        self.polynomial.evaluate_at(x)
+    }
+
+    /// Returns the fitted value at `x` with a confidence interval for the mean response at the given `confidence` level (e.g. `0.95`).
+    /// # Example
+    /// ```
+    /// use polynom::fit::fit_with_covariance;
+    ///
+    /// let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64];
+    /// let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1];
+    ///
+    /// let fit = fit_with_covariance(&xs, &ys, 1).unwrap();
+    /// let prediction = fit.predict_with_interval(2.5, 0.95).unwrap();
+    /// assert!(prediction.lower < prediction.value && prediction.value < prediction.upper);
+    /// ```
+    pub fn predict_with_interval(&self, x: f64, confidence: f64) -> Result<Prediction, FitError> {
+        if !(confidence > 0f64 && confidence < 1f64) {
+            return Err(FitError::InvalidConfidence);
+        }
+
+        let basis: Vec<f64> = (0..self.covariance.len())
+            .map(|power| x.powi(power as i32))
+            .collect();
+        let mut variance = 0f64;
+        for (i, row) in self.covariance.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                variance += basis[i] * value * basis[j];
+            }
+        }
+
+        let t = stats::student_t_quantile(0.5 + confidence / 2f64, self.degrees_of_freedom as f64);
+        let half_width = t * variance.max(0f64).sqrt();
+        let value = self.predict(x);
+
+        Ok(Prediction {
+            value,
+            lower: value - half_width,
+            upper: value + half_width,
+        })
+    }
+}
+
+/// An equality constraint `p^(derivative)(x) = value` on a fitted polynomial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraint {
+    /// The point at which the constraint applies.
+    pub x: f64,
+    /// The order of the constrained derivative; `0` constrains the value itself.
+    pub derivative: usize,
+    /// The value the derivative must take at `x`.
+    pub value: f64,
+}
+
+impl Constraint {
+    /// Returns the constraint `p^(derivative)(x) = value`
+    pub fn new(x: f64, derivative: usize, value: f64) -> Constraint {
+        Constraint {
+            x,
+            derivative,
+            value,
+        }
+    }
+
+    /// Returns the constraint `p(x) = value`
+    pub fn value_at(x: f64, value: f64) -> Constraint {
+        Constraint::new(x, 0, value)
+    }
+}
+
+/// Returns the polynomial of the given degree that best fits the samples in the
+/// least-squares sense among those satisfying every constraint exactly.
+///
+/// The constraints are eliminated from the problem before solving, so each one removes a
+/// degree of freedom: at least `degree + 1 - constraints.len()` samples are needed.
+/// # Example
+/// ```
+/// use polynom::fit::{fit_constrained, Constraint};
+///
+/// let xs = vec![0.5, 1f64, 1.5, 2f64, 2.5];
+/// let ys = vec![-0.8, -1.1, -0.7, 0.1, 1.2];
+///
+/// // Passes through the origin with a stationary point at x = 1
+/// let constraints = [Constraint::value_at(0f64, 0f64), Constraint::new(1f64, 1, 0f64)];
+/// let polynomial = fit_constrained(&xs, &ys, 2, &constraints).unwrap();
+/// assert!(polynomial.evaluate_at(0f64).abs() < 1e-12);
+/// assert!(polynomial.derivative().evaluate_at(1f64).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+/// Returns [`FitError::InvalidConstraints`] if the constraints contradict each other, are
+/// redundant, number more than `degree + 1` or constrain a derivative above `degree`.
+pub fn fit_constrained(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    constraints: &[Constraint],
+) -> Result<Polynomial, FitError> {
+    if xs.len() != ys.len() {
+        return Err(FitError::MismatchedLengths);
+    }
+    let size = degree + 1;
+    if constraints.len() > size {
+        return Err(FitError::InvalidConstraints);
+    }
+    if xs.len() + constraints.len() < size {
+        return Err(FitError::InsufficientData);
+    }
+
+    let points: Vec<f64> = xs
+        .iter()
+        .copied()
+        .chain(constraints.iter().map(|constraint| constraint.x))
+        .collect();
+    let domain = sample_domain(&points);
+    let (scale, _) = domain.scale_and_offset();
+    debug_span!(
+        "fit_constrained",
+        samples = xs.len(),
+        degree,
+        constraints = constraints.len()
+    );
+
+    // Rows [C | d] of the constraints C c = d on the Chebyshev coefficients c in t; the
+    // k-th derivative in x is scale^k times the k-th derivative in t
+    let basis = Family::Chebyshev.members(size);
+    let rows: Vec<Vec<f64>> = constraints
+        .iter()
+        .map(|constraint| {
+            let t = domain.to_unit(constraint.x);
+            let factor = scale.powi(constraint.derivative as i32);
+            let mut row: Vec<f64> = basis
+                .iter()
+                .map(|member| {
+                    member.evaluate_derivatives(t, constraint.derivative)[constraint.derivative]
+                        * factor
+                })
+                .collect();
+            row.push(constraint.value);
+            row
+        })
+        .collect();
+    solve_constrained(xs, ys, degree, domain, rows)
+}
+
+/// Like [`fit_constrained`], but also returns [`Diagnostics`] with the root-mean-square
+/// residual of the fitted polynomial.
+/// # Example
+/// ```
+/// use polynom::fit::{fit_constrained_with_diagnostics, Constraint};
+///
+/// let xs = vec![1f64, 2f64, 3f64];
+/// let ys = vec![1f64, 2f64, 3f64];
+///
+/// // A line through the origin fits exactly
+/// let constraints = [Constraint::value_at(0f64, 0f64)];
+/// let (_, diagnostics) = fit_constrained_with_diagnostics(&xs, &ys, 1, &constraints).unwrap();
+/// assert!(diagnostics.residual < 1e-12);
+/// ```
+pub fn fit_constrained_with_diagnostics(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    constraints: &[Constraint],
+) -> Result<(Polynomial, Diagnostics), FitError> {
+    let polynomial = fit_constrained(xs, ys, degree, constraints)?;
+    let diagnostics = direct_diagnostics(xs, ys, |x| polynomial.evaluate_at(x));
+    Ok((polynomial, diagnostics))
+}
+
+/// Returns the polynomial of the given degree that best fits the samples in the
+/// least-squares sense among those with the given moments over `interval`.
+///
+/// Each `(k, m)` in `moments` requires `∫ x^k p(x) dx = m` over `interval`; matching the
+/// zeroth, first and second moments of a density keeps its mass, mean and variance.
+/// # Example
+/// ```
+/// use polynom::fit::fit_moments;
+///
+/// let xs = vec![0f64, 0.25, 0.5, 0.75, 1f64];
+/// let ys = vec![0.1, 1.2, 1.4, 1.1, 0.2];
+///
+/// // A density on [0, 1] with unit mass and mean 1/2
+/// let density = fit_moments(&xs, &ys, 2, (0f64, 1f64), &[(0, 1f64), (1, 0.5)]).unwrap();
+/// let antiderivative = density.integral(0f64);
+/// assert!((antiderivative.evaluate_at(1f64) - 1f64).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+/// Returns [`FitError::InvalidConstraints`] if the moments are contradictory, repeated or
+/// more than `degree + 1`, or if `interval` is empty.
+pub fn fit_moments(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    interval: (f64, f64),
+    moments: &[(usize, f64)],
+) -> Result<Polynomial, FitError> {
+    if xs.len() != ys.len() {
+        return Err(FitError::MismatchedLengths);
+    }
+    let size = degree + 1;
+    if moments.len() > size {
+        return Err(FitError::InvalidConstraints);
+    }
+    if xs.len() + moments.len() < size {
+        return Err(FitError::InsufficientData);
+    }
+
+    let points: Vec<f64> = xs
+        .iter()
+        .copied()
+        .chain(vec![interval.0, interval.1])
+        .collect();
+    let domain = sample_domain(&points);
+    debug_span!(
+        "fit_moments",
+        samples = xs.len(),
+        degree,
+        moments = moments.len()
+    );
+
+    // Each basis member T_j(to_unit(x)) as a polynomial in x, so its moments integrate exactly
+    let basis: Vec<Polynomial> = Family::Chebyshev
+        .members(size)
+        .iter()
+        .map(|member| member.map_domain((-1f64, 1f64), domain.domain))
+        .collect();
+    let rows = moments
+        .iter()
+        .map(|&(order, value)| {
+            let mut monomial = vec![0f64; order + 1];
+            monomial[order] = 1f64;
+            let weight = Polynomial::new(monomial, 'x');
+            let mut row: Vec<f64> = basis
+                .iter()
+                .map(|member| member.multiply(weight.clone()).definite_integral(interval))
+                .collect();
+            row.push(value);
+            row
+        })
+        .collect();
+
+    solve_constrained(xs, ys, degree, domain, rows)
+}
+
+/// Fits a polynomial like [`fit`], constrained so that its integral over the span of `xs`
+/// equals the trapezoidal integral of the samples.
+/// # Example
+/// ```
+/// use polynom::fit::fit_preserving_integral;
+///
+/// let xs = vec![0f64, 1f64, 2f64, 3f64];
+/// let ys = vec![0f64, 2f64, 1f64, 3f64];
+///
+/// // The trapezoidal rule gives 1 + 1.5 + 2 = 4.5
+/// let polynomial = fit_preserving_integral(&xs, &ys, 1).unwrap();
+/// let antiderivative = polynomial.integral(0f64);
+/// assert!((antiderivative.evaluate_at(3f64) - 4.5).abs() < 1e-12);
+/// ```
+pub fn fit_preserving_integral(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+) -> Result<Polynomial, FitError> {
+    if xs.len() != ys.len() {
+        return Err(FitError::MismatchedLengths);
+    }
+    let mut samples: Vec<(f64, f64)> = xs.iter().copied().zip(ys.iter().copied()).collect();
+    samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let (low, high) = match (samples.first(), samples.last()) {
+        (Some(first), Some(last)) if first.0 < last.0 => (first.0, last.0),
+        _ => return Err(FitError::InsufficientData),
+    };
+    let area = samples
+        .windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0) * (pair[0].1 + pair[1].1) / 2f64)
+        .sum();
+
+    fit_moments(xs, ys, degree, (low, high), &[(0, area)])
+}
+
+/// Returns a polynomial of the given degree fitted to the samples in the least-squares
+/// sense that is nonnegative on `interval`.
+///
+/// Starting from the unconstrained fit, each pass finds the minimum on the interval and, if
+/// it is negative, requires the fit to touch zero there: the value is constrained to zero
+/// and, inside the interval, so is the slope. Once no constraint can be added, any remaining
+/// deficit is removed by raising the fit by a constant. The result is nonnegative but not
+/// necessarily the best nonnegative fit.
+/// # Example
+/// ```
+/// use polynom::fit::fit_nonnegative;
+///
+/// // Rates that dip slightly negative from measurement noise
+/// let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64, 5f64];
+/// let ys = vec![4.1, 0.9, -0.2, 0.1, 1.1, 3.9];
+///
+/// let polynomial = fit_nonnegative(&xs, &ys, 2, (0f64, 5f64)).unwrap();
+/// assert!(polynomial.minimum_on((0f64, 5f64)).0 >= 0f64);
+/// ```
+pub fn fit_nonnegative(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    interval: (f64, f64),
+) -> Result<Polynomial, FitError> {
+    let (low, high) = if interval.0 <= interval.1 {
+        interval
+    } else {
+        (interval.1, interval.0)
+    };
+    let mut constraints = Vec::new();
+    let mut polynomial = fit_constrained(xs, ys, degree, &constraints)?;
+    debug_span!("fit_nonnegative", samples = xs.len(), degree);
+
+    for _ in 0..=degree {
+        let (minimum, x) = polynomial.minimum_on((low, high));
+        if minimum >= 0f64 {
+            break;
+        }
+        constraints.push(Constraint::value_at(x, 0f64));
+        if low < x && x < high {
+            constraints.push(Constraint::new(x, 1, 0f64));
+        }
+        match fit_constrained(xs, ys, degree, &constraints) {
+            Ok(refitted) => polynomial = refitted,
+            Err(FitError::InvalidConstraints) | Err(FitError::InsufficientData) => break,
+            Err(error) => return Err(error),
+        }
+        debug_event!(x, minimum, "constrained fit to touch zero");
+    }
+
+    let (minimum, _) = polynomial.minimum_on((low, high));
+    if minimum < 0f64 {
+        polynomial = polynomial + Polynomial::new(vec![-minimum], 'x');
+    }
+
+    Ok(polynomial)
+}
+
+/// Solves the least-squares problem in the Chebyshev basis over `domain` subject to the
+/// augmented constraint rows `[C | d]`, which are reduced in place.
+fn solve_constrained(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    domain: DomainMap,
+    mut rows: Vec<Vec<f64>>,
+) -> Result<Polynomial, FitError> {
+    let size = degree + 1;
+    let pivots = reduce_constraints(&mut rows, size).ok_or(FitError::InvalidConstraints)?;
+    let free: Vec<usize> = (0..size).filter(|c| !pivots.contains(c)).collect();
+
+    // Substituting c_pivot = d' - C'_free c_free leaves an unconstrained problem in c_free
+    let ts: Vec<f64> = xs.iter().map(|&x| domain.to_unit(x)).collect();
+    let design = chebyshev_design_matrix(&ts, degree);
+    let mut reduced = Vec::with_capacity(design.len());
+    let mut targets = Vec::with_capacity(design.len());
+    for (row, &y) in design.iter().zip(ys) {
+        let mut target = y;
+        let mut reduced_row: Vec<f64> = free.iter().map(|&f| row[f]).collect();
+        for (constraint, &pivot) in rows.iter().zip(&pivots) {
+            target -= row[pivot] * constraint[size];
+            for (value, &f) in reduced_row.iter_mut().zip(&free) {
+                *value -= row[pivot] * constraint[f];
+            }
+        }
+        reduced.push(reduced_row);
+        targets.push(target);
+    }
+    let free_coefficients = if free.is_empty() {
+        Vec::new()
+    } else {
+        linalg::least_squares(&reduced, &targets)
+            .ok_or(FitError::Singular)?
+            .0
+    };
+
+    let mut coefficients = vec![0f64; size];
+    for (&f, &value) in free.iter().zip(&free_coefficients) {
+        coefficients[f] = value;
+    }
+    for (constraint, &pivot) in rows.iter().zip(&pivots) {
+        coefficients[pivot] = constraint[size]
+            - free
+                .iter()
+                .zip(&free_coefficients)
+                .map(|(&f, value)| constraint[f] * value)
+                .sum::<f64>();
+    }
+    debug_event!(domain = ?domain.domain, "solved constrained least-squares system");
+
+    Ok(ChebyshevFit {
+        coefficients,
+        domain,
+    }
+    .to_polynomial())
+}
+
+/// Reduces the augmented constraint rows `[C | d]` to reduced row echelon form with full
+/// pivoting, returning the pivot column of each row, or `None` if `C` is rank deficient.
+fn reduce_constraints(rows: &mut [Vec<f64>], columns: usize) -> Option<Vec<usize>> {
+    let largest = rows
+        .iter()
+        .flat_map(|row| &row[..columns])
+        .fold(0f64, |largest, value| largest.max(value.abs()));
+    let mut pivots: Vec<usize> = Vec::with_capacity(rows.len());
+    for i in 0..rows.len() {
+        let mut best = (i, 0, 0f64);
+        for (r, row) in rows.iter().enumerate().skip(i) {
+            for (c, value) in row[..columns].iter().enumerate() {
+                if !pivots.contains(&c) && value.abs() > best.2 {
+                    best = (r, c, value.abs());
+                }
+            }
+        }
+        if best.2 <= largest * 1e-12 {
+            return None;
+        }
+
+        rows.swap(i, best.0);
+        let column = best.1;
+        let pivot = rows[i][column];
+        for value in rows[i].iter_mut() {
+            *value /= pivot;
+        }
+        let pivot_row = rows[i].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if r != i && factor != 0f64 {
+                for (value, p) in row.iter_mut().zip(&pivot_row) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        pivots.push(column);
+    }
+
+    Some(pivots)
+}
+
+/// Initial coefficient covariance of an [`OnlineFitter`], large so that the first samples
+/// dominate the zero starting guess.
+const ONLINE_INITIAL_COVARIANCE: f64 = 1e8;
+
+/// A least-squares polynomial fit updated one sample at a time by recursive least squares.
+///
+/// Each update costs `O(degree^2)` regardless of how many samples came before, and no
+/// samples are stored. With a forgetting factor `lambda < 1`, a sample seen `k` updates ago
+/// is weighted by `lambda^k`, so the fit tracks slowly drifting data. Samples are mapped
+/// onto `[-1, 1]` through a [`DomainMap`] for conditioning.
+///
+/// With `lambda = 1` every sample is weighted equally and the result approximates the batch
+/// [`fit`], but does not match it exactly: the recursion starts from zero coefficients with
+/// covariance `1e8` times the identity, which acts as a ridge penalty of `1e-8` on the mapped
+/// coefficients. The two agree closely once there are comfortably more samples than
+/// coefficients and the samples determine the fit well.
+#[derive(Debug, Clone)]
+pub struct OnlineFitter {
+    map: DomainMap,
+    forgetting_factor: f64,
+    coefficients: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    samples: u64,
+    last_error: f64,
+}
+
+impl OnlineFitter {
+    /// Returns a fitter for polynomials of the given degree, for samples expected in
+    /// `domain`, forgetting old samples by `forgetting_factor` per update.
+    /// # Example
+    /// ```
+    /// use polynom::fit::OnlineFitter;
+    ///
+    /// let mut fitter = OnlineFitter::new(1, (0f64, 10f64), 1f64);
+    /// for i in 0..=10 {
+    ///     let x = i as f64;
+    ///     fitter.update(x, 3f64 * x - 2f64);
+    /// }
+    /// let polynomial = fitter.polynomial();
+    /// assert!((polynomial.coefficients[0] + 2f64).abs() < 1e-6);
+    /// assert!((polynomial.coefficients[1] - 3f64).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `forgetting_factor` is not in `(0, 1]` or `domain` has zero width.
+    pub fn new(degree: usize, domain: (f64, f64), forgetting_factor: f64) -> OnlineFitter {
+        assert!(
+            forgetting_factor > 0f64 && forgetting_factor <= 1f64,
+            "forgetting factor must be in (0, 1]"
+        );
+        assert!(domain.0 != domain.1, "domain must have nonzero width");
+        let size = degree + 1;
+        let covariance = (0..size)
+            .map(|i| {
+                let mut row = vec![0f64; size];
+                row[i] = ONLINE_INITIAL_COVARIANCE;
+                row
+            })
+            .collect();
+
+        OnlineFitter {
+            map: DomainMap::new(domain.0, domain.1),
+            forgetting_factor,
+            coefficients: vec![0f64; size],
+            covariance,
+            samples: 0,
+            last_error: 0f64,
+        }
+    }
+
+    /// Incorporates the sample `(x, y)`.
+    pub fn update(&mut self, x: f64, y: f64) {
+        let t = self.map.to_unit(x);
+        let basis: Vec<f64> = (0..self.coefficients.len())
+            .map(|power| t.powi(power as i32))
+            .collect();
+
+        // gain = P phi / (lambda + phi^T P phi)
+        let p_phi: Vec<f64> = self
+            .covariance
+            .iter()
+            .map(|row| row.iter().zip(&basis).map(|(p, b)| p * b).sum())
+            .collect();
+        let denominator =
+            self.forgetting_factor + basis.iter().zip(&p_phi).map(|(b, p)| b * p).sum::<f64>();
+        let gain: Vec<f64> = p_phi.iter().map(|p| p / denominator).collect();
+
+        let error = y - basis
+            .iter()
+            .zip(&self.coefficients)
+            .map(|(b, c)| b * c)
+            .sum::<f64>();
+        for (coeff, k) in self.coefficients.iter_mut().zip(&gain) {
+            *coeff += k * error;
+        }
+
+        // P = (P - gain (P phi)^T) / lambda, kept symmetric against rounding drift
+        let size = gain.len();
+        for i in 0..size {
+            for j in i..size {
+                let value = (self.covariance[i][j] - gain[i] * p_phi[j]) / self.forgetting_factor;
+                let mirrored =
+                    (self.covariance[j][i] - gain[j] * p_phi[i]) / self.forgetting_factor;
+                let average = (value + mirrored) / 2f64;
+                self.covariance[i][j] = average;
+                self.covariance[j][i] = average;
+            }
+        }
+        self.samples += 1;
+        self.last_error = error;
+        trace_event!(x, y, error, "online fit updated");
+    }
+
+    /// Returns the number of samples incorporated so far.
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Returns [`Diagnostics`] for the fit so far: one iteration per sample, and as the
+    /// residual the magnitude of the latest sample's error against the fit before it was
+    /// incorporated.
+    ///
+    /// A residual that stays large relative to the noise in the data means the fit is not
+    /// keeping up, e.g. because the degree is too low or the forgetting factor too close to 1.
+    /// # Example
+    /// ```
+    /// use polynom::fit::OnlineFitter;
+    ///
+    /// let mut fitter = OnlineFitter::new(1, (0f64, 10f64), 1f64);
+    /// for i in 0..=10 {
+    ///     let x = i as f64;
+    ///     fitter.update(x, 3f64 * x - 2f64);
+    /// }
+    /// let diagnostics = fitter.diagnostics();
+    /// assert_eq!(diagnostics.iterations, 11);
+    /// assert!(diagnostics.residual < 1e-6);
+    /// ```
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::new(
+            self.samples as usize,
+            self.last_error.abs(),
+            ConvergenceStatus::Direct,
+        )
+    }
+
+    /// Returns the current fit in the monomial basis of `x`.
+    pub fn polynomial(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.clone(), 'x').map_domain((-1f64, 1f64), self.map.domain)
+    }
+
+    /// Returns the current fitted value at `x`.
+    pub fn predict(&self, x: f64) -> f64 {
+        let t = self.map.to_unit(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0f64, |value, coeff| value * t + coeff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_fit_exact_quadratic() {
+        let xs = vec![-2f64, -1f64, 0f64, 1f64, 2f64];
+        let ys: Vec<f64> = xs.iter().map(|x| 1f64 + 2f64 * x + 3f64 * x * x).collect();
+        let polynomial = fit(&xs, &ys, 2).unwrap();
+
+        for (actual, expected) in polynomial.coefficients.iter().zip(&[1f64, 2f64, 3f64]) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fit_errors() {
+        assert_eq!(
+            fit(&[1f64, 2f64], &[1f64], 1).unwrap_err(),
+            FitError::MismatchedLengths
+        );
+        assert_eq!(
+            fit(&[1f64, 2f64], &[1f64, 2f64], 2).unwrap_err(),
+            FitError::InsufficientData
+        );
+        assert_eq!(
+            fit(&[1f64, 1f64, 1f64], &[1f64, 2f64, 3f64], 1).unwrap_err(),
+            FitError::Singular
+        );
+        assert_eq!(
+            fit_with_covariance(&[1f64, 2f64], &[1f64, 2f64], 1).unwrap_err(),
+            FitError::InsufficientData
+        );
+    }
+
+    #[test]
+    fn test_high_degree_fit() {
+        let xs: Vec<f64> = (0..200).map(|i| i as f64 / 199f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| (5f64 * x).sin()).collect();
+
+        let chebyshev_fit = fit_chebyshev(&xs, &ys, 18).unwrap();
+        let polynomial = fit(&xs, &ys, 18).unwrap();
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert!((chebyshev_fit.evaluate_at(x) - y).abs() < 1e-12);
+            assert!((polynomial.evaluate_at(x) - y).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_vandermonde() {
+        let matrix = vandermonde(&[-1f64, 0f64, 2f64], 3);
+
+        assert_eq!(matrix[0], vec![1f64, -1f64, 1f64, -1f64]);
+        assert_eq!(matrix[1], vec![1f64, 0f64, 0f64, 0f64]);
+        assert_eq!(matrix[2], vec![1f64, 2f64, 4f64, 8f64]);
+        assert_eq!(vandermonde(&[5f64], 0), vec![vec![1f64]]);
+    }
+
+    #[test]
+    fn test_scaled_vandermonde() {
+        let domain = DomainMap::new(2f64, 6f64);
+        let matrix = scaled_vandermonde(&[2f64, 4f64, 6f64], 2, &domain);
+
+        assert_eq!(matrix, vandermonde(&[-1f64, 0f64, 1f64], 2));
+    }
+
+    #[test]
+    fn test_fit_recovers_chebyshev_polynomial() {
+        let expected = test_polynomials::chebyshev(16);
+        let xs: Vec<f64> = (0..101).map(|i| i as f64 / 50f64 - 1f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| expected.evaluate_at(x)).collect();
+
+        let chebyshev_fit = fit_chebyshev(&xs, &ys, 16).unwrap();
+        assert!((chebyshev_fit.coefficients[16] - 1f64).abs() < 1e-10);
+        for (i, coeff) in chebyshev_fit.coefficients.iter().enumerate().take(16) {
+            assert!(coeff.abs() < 1e-10, "T_{} coefficient was {}", i, coeff);
+        }
+    }
+
+    #[test]
+    fn test_empty_chebyshev_fit_is_zero() {
+        let empty = ChebyshevFit {
+            coefficients: Vec::new(),
+            domain: DomainMap::new(0f64, 1f64),
+        };
+
+        assert_eq!(empty.evaluate_at(0.5), 0f64);
+        assert_eq!(empty.to_polynomial(), Polynomial::new(vec![0f64], 'x'));
+    }
+
+    #[test]
+    fn test_domain_map_round_trip() {
+        let map = DomainMap::new(-3f64, 7f64);
+
+        assert_eq!(map.to_unit(-3f64), -1f64);
+        assert_eq!(map.to_unit(7f64), 1f64);
+        assert!((map.from_unit(map.to_unit(1.5)) - 1.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_covariance_matches_simple_linear_regression() {
+        let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64];
+        let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1];
+        let fit = fit_with_covariance(&xs, &ys, 1).unwrap();
+
+        // Var(slope) = sigma^2 / Sxx, with Sxx = 10 for these xs
+        let slope_variance = fit.residual_variance / 10f64;
+        assert!((fit.covariance[1][1] - slope_variance).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_predict_with_interval() {
+        let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64];
+        let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1];
+        let fit = fit_with_covariance(&xs, &ys, 1).unwrap();
+
+        let narrow = fit.predict_with_interval(2f64, 0.5).unwrap();
+        let wide = fit.predict_with_interval(2f64, 0.99).unwrap();
+        assert!(wide.upper - wide.lower > narrow.upper - narrow.lower);
+
+        // At the mean of xs the variance reduces to sigma^2 / n
+        let half_width =
+            stats::student_t_quantile(0.975, 3f64) * (fit.residual_variance / 5f64).sqrt();
+        let prediction = fit.predict_with_interval(2f64, 0.95).unwrap();
+        assert!((prediction.upper - prediction.value - half_width).abs() < 1e-9);
+
+        assert_eq!(
+            fit.predict_with_interval(2f64, 1f64).unwrap_err(),
+            FitError::InvalidConfidence
+        );
+    }
+
+    #[test]
+    fn test_online_fit_approximates_batch_fit() {
+        let xs: Vec<f64> = (0..100).map(|i| i as f64 * 0.37).collect();
+        let ys: Vec<f64> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 0.5 * x * x - x + 4f64 + if i % 2 == 0 { 0.3 } else { -0.3 })
+            .collect();
+        let mut fitter = OnlineFitter::new(2, (0f64, 37f64), 1f64);
+        for (&x, &y) in xs.iter().zip(&ys) {
+            fitter.update(x, y);
+        }
+        let batch = fit(&xs, &ys, 2).unwrap();
+
+        assert_eq!(fitter.samples(), 100);
+        for &x in &[0f64, 10f64, 36f64] {
+            assert!((fitter.predict(x) - batch.evaluate_at(x)).abs() < 1e-6);
+            assert!((fitter.polynomial().evaluate_at(x) - fitter.predict(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_forgetting_factor_tracks_drift() {
+        let mut remembering = OnlineFitter::new(1, (0f64, 1f64), 1f64);
+        let mut forgetting = OnlineFitter::new(1, (0f64, 1f64), 0.9);
+        for i in 0..400 {
+            let x = (i % 10) as f64 / 10f64;
+            // The offset jumps from 0 to 1 halfway through
+            let y = 2f64 * x + if i < 200 { 0f64 } else { 1f64 };
+            remembering.update(x, y);
+            forgetting.update(x, y);
+        }
+
+        assert!((forgetting.predict(0f64) - 1f64).abs() < 1e-6);
+        assert!((remembering.predict(0f64) - 0.5).abs() < 1e-3);
+        assert_eq!(forgetting.diagnostics().iterations, 400);
+        assert!(forgetting.diagnostics().residual < 1e-6);
+        assert!(remembering.diagnostics().residual > 0.1);
+    }
+
+    #[test]
+    fn test_fit_diagnostics_report_rms_residual() {
+        let xs: Vec<f64> = (0..100).map(|i| i as f64 * 0.37).collect();
+        let ys: Vec<f64> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 0.5 * x * x - x + 4f64 + if i % 2 == 0 { 0.3 } else { -0.3 })
+            .collect();
+        let (polynomial, diagnostics) = fit_with_diagnostics(&xs, &ys, 2).unwrap();
+        let (chebyshev, chebyshev_diagnostics) =
+            fit_chebyshev_with_diagnostics(&xs, &ys, 2).unwrap();
+
+        assert_eq!(polynomial, fit(&xs, &ys, 2).unwrap());
+        assert_eq!(chebyshev, fit_chebyshev(&xs, &ys, 2).unwrap());
+        assert!(diagnostics.residual < 0.3 && diagnostics.residual > 0.29);
+        assert!((chebyshev_diagnostics.residual - diagnostics.residual).abs() < 1e-9);
+        assert_eq!(diagnostics.status, ConvergenceStatus::Direct);
+        assert_eq!(
+            fit_with_diagnostics(&xs, &ys[1..], 2).unwrap_err(),
+            FitError::MismatchedLengths
+        );
+    }
+
+    #[test]
+    fn test_constrained_fit_satisfies_constraints() {
+        let xs: Vec<f64> = (0..40).map(|i| i as f64 / 10f64).collect();
+        let ys: Vec<f64> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x.sin() + if i % 3 == 0 { 0.05 } else { -0.02 })
+            .collect();
+        let constraints = [
+            Constraint::value_at(0f64, 0f64),
+            Constraint::new(0f64, 1, 1f64),
+            Constraint::new(4f64, 2, -0.5),
+        ];
+        let polynomial = fit_constrained(&xs, &ys, 5, &constraints).unwrap();
+        let derivatives = polynomial.evaluate_derivatives(0f64, 1);
+
+        assert!(derivatives[0].abs() < 1e-10);
+        assert!((derivatives[1] - 1f64).abs() < 1e-10);
+        assert!((polynomial.evaluate_derivatives(4f64, 2)[2] + 0.5).abs() < 1e-10);
+        assert!((polynomial.evaluate_at(2f64) - 2f64.sin()).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_constrained_fit_without_constraints_matches_fit() {
+        let xs = vec![0f64, 1f64, 2f64, 3f64, 4f64];
+        let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1];
+        let constrained = fit_constrained(&xs, &ys, 2, &[]).unwrap();
+        let unconstrained = fit(&xs, &ys, 2).unwrap();
+        for (a, b) in constrained
+            .coefficients
+            .iter()
+            .zip(&unconstrained.coefficients)
+        {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fully_constrained_fit_interpolates() {
+        // Hermite data for x^3: p(0) = 0, p'(0) = 0, p(1) = 1, p'(1) = 3
+        let constraints = [
+            Constraint::value_at(0f64, 0f64),
+            Constraint::new(0f64, 1, 0f64),
+            Constraint::value_at(1f64, 1f64),
+            Constraint::new(1f64, 1, 3f64),
+        ];
+        let polynomial = fit_constrained(&[], &[], 3, &constraints).unwrap();
+        let expected = [0f64, 0f64, 0f64, 1f64];
+        for (a, b) in polynomial.coefficients.iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_invalid_constraints() {
+        let xs = vec![0f64, 1f64, 2f64];
+        let ys = vec![0f64, 1f64, 4f64];
+        let contradictory = [
+            Constraint::value_at(1f64, 0f64),
+            Constraint::value_at(1f64, 1f64),
+        ];
+        assert_eq!(
+            fit_constrained(&xs, &ys, 2, &contradictory),
+            Err(FitError::InvalidConstraints)
+        );
+        assert_eq!(
+            fit_constrained(&xs, &ys, 1, &[Constraint::new(0f64, 2, 1f64)]),
+            Err(FitError::InvalidConstraints)
+        );
+        assert_eq!(
+            fit_constrained(&xs[..1], &ys[..1], 2, &[Constraint::value_at(0f64, 0f64)]),
+            Err(FitError::InsufficientData)
+        );
+    }
+
+    #[test]
+    fn test_fit_moments_matches_moments() {
+        let xs: Vec<f64> = (0..=20).map(|i| -1f64 + i as f64 / 10f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| (-2f64 * x * x).exp()).collect();
+        let moments = [(0, 1f64), (1, 0f64), (2, 0.2)];
+        let polynomial = fit_moments(&xs, &ys, 4, (-1f64, 1f64), &moments).unwrap();
+
+        for &(order, value) in &moments {
+            let mut monomial = vec![0f64; order + 1];
+            monomial[order] = 1f64;
+            let moment = polynomial
+                .multiply(Polynomial::new(monomial, 'x'))
+                .definite_integral((-1f64, 1f64));
+            assert!((moment - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fit_preserving_integral_keeps_exact_fits() {
+        // A line is fitted exactly and the trapezoidal rule integrates it exactly
+        let xs = vec![3f64, 1f64, 2f64, 0f64];
+        let ys: Vec<f64> = xs.iter().map(|x| 2f64 * x - 1f64).collect();
+        let polynomial = fit_preserving_integral(&xs, &ys, 1).unwrap();
+        assert!((polynomial.coefficients[0] + 1f64).abs() < 1e-12);
+        assert!((polynomial.coefficients[1] - 2f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_moment_fit_errors() {
+        let xs = vec![0f64, 1f64, 2f64];
+        let ys = vec![0f64, 1f64, 4f64];
+        assert_eq!(
+            fit_moments(&xs, &ys, 2, (1f64, 1f64), &[(0, 1f64)]),
+            Err(FitError::InvalidConstraints)
+        );
+        assert_eq!(
+            fit_moments(&xs, &ys, 2, (0f64, 1f64), &[(0, 1f64), (0, 2f64)]),
+            Err(FitError::InvalidConstraints)
+        );
+        assert_eq!(
+            fit_preserving_integral(&[1f64, 1f64], &[0f64, 1f64], 1),
+            Err(FitError::InsufficientData)
+        );
+    }
+
+    #[test]
+    fn test_fit_nonnegative() {
+        let xs: Vec<f64> = (0..=30).map(|i| i as f64 / 10f64).collect();
+        // A density-like curve that the unconstrained fit takes below zero
+        let ys: Vec<f64> = xs
+            .iter()
+            .map(|x| if *x < 1f64 { 0f64 } else { (x - 1f64).powi(2) })
+            .collect();
+        assert!(fit(&xs, &ys, 3).unwrap().minimum_on((0f64, 3f64)).0 < 0f64);
+
+        for degree in 2..6 {
+            let polynomial = fit_nonnegative(&xs, &ys, degree, (0f64, 3f64)).unwrap();
+            assert!(polynomial.is_nonnegative_on((0f64, 3f64)));
+            assert!((polynomial.evaluate_at(3f64) - 4f64).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_fit_nonnegative_keeps_nonnegative_fits() {
+        let xs = vec![0f64, 1f64, 2f64, 3f64];
+        let ys = vec![1f64, 2f64, 5f64, 10f64];
+        assert_eq!(
+            fit_nonnegative(&xs, &ys, 2, (0f64, 3f64)).unwrap(),
+            fit(&xs, &ys, 2).unwrap()
+        );
+    }
+}
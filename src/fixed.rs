@@ -0,0 +1,147 @@
+//! Polynomials with a compile-time number of coefficients, usable in `const` contexts.
+
+use crate::polynomial::Polynomial;
+
+/// A polynomial stored inline as `N` coefficients, lowest degree first.
+///
+/// Unlike [`Polynomial`] it needs no allocation, and construction and evaluation are
+/// `const fn`, so calibration constants can be evaluated at compile time.
+/// # Example
+/// ```
+/// use polynom::fixed::FixedPolynomial;
+///
+/// // A thermocouple-style calibration curve, evaluated at compile time
+/// const CALIBRATION: FixedPolynomial<3> = FixedPolynomial::new([0.5, 2f64, 0.25]);
+/// const AT_TWO: f64 = CALIBRATION.evaluate_at(2f64);
+/// assert_eq!(AT_TWO, 5.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedPolynomial<const N: usize> {
+    /// Coefficients, lowest degree first.
+    pub coefficients: [f64; N],
+}
+
+impl<const N: usize> FixedPolynomial<N> {
+    /// Returns a polynomial with the given coefficients, lowest degree first.
+    pub const fn new(coefficients: [f64; N]) -> FixedPolynomial<N> {
+        FixedPolynomial { coefficients }
+    }
+
+    /// Returns the polynomial with all coefficients zero.
+    pub const fn zero() -> FixedPolynomial<N> {
+        FixedPolynomial {
+            coefficients: [0f64; N],
+        }
+    }
+
+    /// Returns the degree, ignoring trailing zero coefficients, or -1 for the zero polynomial
+    /// # Example
+    /// ```
+    /// use polynom::fixed::FixedPolynomial;
+    ///
+    /// const DEGREE: isize = FixedPolynomial::new([1f64, 2f64, 0f64]).degree();
+    /// assert_eq!(DEGREE, 1);
+    /// assert_eq!(FixedPolynomial::<4>::zero().degree(), -1);
+    /// ```
+    pub const fn degree(&self) -> isize {
+        let mut i = N;
+        while i > 0 {
+            if self.coefficients[i - 1] != 0f64 {
+                return i as isize - 1;
+            }
+            i -= 1;
+        }
+
+        -1
+    }
+
+    /// Return the result of evaluating the polynomial at `x` with Horner's scheme
+    /// # Example
+    /// ```
+    /// use polynom::fixed::FixedPolynomial;
+    ///
+    /// const P: FixedPolynomial<3> = FixedPolynomial::new([1f64, 2f64, 3f64]);
+    /// const VALUE: f64 = P.evaluate_at(1f64);
+    /// assert_eq!(VALUE, 6f64);
+    /// ```
+    pub const fn evaluate_at(&self, x: f64) -> f64 {
+        let mut result = 0f64;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            result = result * x + self.coefficients[i];
+        }
+
+        result
+    }
+
+    /// Returns the value and first derivative at `x`, computed together with Horner's scheme
+    /// # Example
+    /// ```
+    /// use polynom::fixed::FixedPolynomial;
+    ///
+    /// const SLOPE: (f64, f64) = FixedPolynomial::new([1f64, 2f64, 3f64]).evaluate_with_derivative(2f64);
+    /// assert_eq!(SLOPE, (17f64, 14f64));
+    /// ```
+    pub const fn evaluate_with_derivative(&self, x: f64) -> (f64, f64) {
+        let mut value = 0f64;
+        let mut derivative = 0f64;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            derivative = derivative * x + value;
+            value = value * x + self.coefficients[i];
+        }
+
+        (value, derivative)
+    }
+
+    /// Converts into a heap-allocated [`Polynomial`] in `indeterminate`
+    pub fn to_polynomial(&self, indeterminate: char) -> Polynomial {
+        Polynomial::new(self.coefficients.to_vec(), indeterminate)
+    }
+}
+
+impl<const N: usize> Default for FixedPolynomial<N> {
+    fn default() -> FixedPolynomial<N> {
+        FixedPolynomial::zero()
+    }
+}
+
+impl<const N: usize> From<FixedPolynomial<N>> for Polynomial {
+    fn from(fixed: FixedPolynomial<N>) -> Polynomial {
+        fixed.to_polynomial('x')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUBIC: FixedPolynomial<4> = FixedPolynomial::new([-6f64, 11f64, -6f64, 1f64]);
+    const ROOT_VALUES: [f64; 3] = [
+        CUBIC.evaluate_at(1f64),
+        CUBIC.evaluate_at(2f64),
+        CUBIC.evaluate_at(3f64),
+    ];
+
+    #[test]
+    fn test_const_evaluation_matches_polynomial() {
+        let polynomial = Polynomial::from(CUBIC);
+
+        assert_eq!(ROOT_VALUES, [0f64; 3]);
+        for &x in &[-1.5, 0f64, 0.25, 7f64] {
+            assert_eq!(CUBIC.evaluate_at(x), polynomial.evaluate_at(x));
+        }
+        assert_eq!(CUBIC.degree(), polynomial.degree());
+    }
+
+    #[test]
+    fn test_empty_polynomial() {
+        let empty = FixedPolynomial::<0>::default();
+
+        assert_eq!(empty.evaluate_at(3f64), 0f64);
+        assert_eq!(empty.degree(), -1);
+        assert_eq!(empty.to_polynomial('x').coefficients, vec![0f64]);
+    }
+}
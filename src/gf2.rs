@@ -0,0 +1,686 @@
+//! Polynomials over GF(2) and the linear-feedback shift registers they define.
+
+use std::fmt;
+use std::ops::Add;
+
+use crate::display::{self, FormatOptions, Term};
+
+/// A polynomial over GF(2) of degree at most 63, stored as a bit mask.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf2Polynomial {
+    /// Coefficient bits, where bit `i` is the coefficient of `x^i`.
+    pub bits: u64,
+}
+
+impl fmt::Debug for Gf2Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Gf2Polynomial {{ bits: {:#b}, as_string: {} }}",
+            self.bits,
+            self.as_string()
+        )
+    }
+}
+
+impl Add for Gf2Polynomial {
+    type Output = Gf2Polynomial;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Gf2Polynomial) -> Self {
+        // Addition in GF(2) is exclusive or
+        Gf2Polynomial::new(self.bits ^ other.bits)
+    }
+}
+
+impl Gf2Polynomial {
+    /// Returns a Gf2Polynomial from its coefficient bits
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// let polynomial = Gf2Polynomial::new(0b10011);
+    /// assert_eq!(polynomial.as_string(), String::from("f(x) = 1 + x + x^4"));
+    /// ```
+    pub fn new(bits: u64) -> Gf2Polynomial {
+        Gf2Polynomial { bits }
+    }
+
+    /// Returns the Gf2Polynomial with a coefficient of one at each of the given exponents
+    ///
+    /// # Panics
+    /// Panics if an exponent is greater than 63.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// let polynomial = Gf2Polynomial::from_exponents(&[4, 1, 0]);
+    /// assert_eq!(polynomial.bits, 0b10011);
+    /// ```
+    pub fn from_exponents(exponents: &[usize]) -> Gf2Polynomial {
+        let bits = exponents.iter().fold(0u64, |bits, &exponent| {
+            assert!(exponent < 64, "exponent must be at most 63");
+            bits ^ (1 << exponent)
+        });
+
+        Gf2Polynomial::new(bits)
+    }
+
+    /// Return the degree of the polynomial, or `-1` for the zero polynomial
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// assert_eq!(Gf2Polynomial::new(0b1011).degree(), 3);
+    /// assert_eq!(Gf2Polynomial::new(0).degree(), -1);
+    /// ```
+    pub fn degree(&self) -> isize {
+        63 - self.bits.leading_zeros() as isize
+    }
+
+    /// Return the coefficient of `x^degree`
+    pub fn coefficient(&self, degree: usize) -> bool {
+        degree < 64 && self.bits >> degree & 1 == 1
+    }
+
+    /// Return the polynomial represented as a String
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// assert_eq!(Gf2Polynomial::new(0b110).as_string(), String::from("f(x) = x + x^2"));
+    /// assert_eq!(Gf2Polynomial::new(0).as_string(), String::from("f(x) = 0"));
+    /// ```
+    pub fn as_string(&self) -> String {
+        let terms = (0..64)
+            .filter(|&degree| self.coefficient(degree))
+            .map(|degree| Term {
+                degree,
+                negative: false,
+                magnitude: String::from("1"),
+                unit: true,
+            });
+        display::render('x', terms, &FormatOptions::DEFAULT)
+    }
+
+    /// Return the remainder of `self` divided by `modulus`
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// // x^4 = x + 1 modulo x^4 + x + 1
+    /// let remainder = Gf2Polynomial::new(0b10000).remainder(&Gf2Polynomial::new(0b10011));
+    /// assert_eq!(remainder.bits, 0b11);
+    /// ```
+    pub fn remainder(&self, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        assert!(modulus.bits != 0, "modulus must be nonzero");
+        Gf2Polynomial::new(reduce(self.bits as u128, modulus.bits))
+    }
+
+    /// Return the product of `self` and `other` reduced modulo `modulus`
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// let modulus = Gf2Polynomial::new(0b1011);
+    /// let product = Gf2Polynomial::new(0b110).multiply_mod(&Gf2Polynomial::new(0b100), &modulus);
+    /// assert_eq!(product.bits, 0b101);
+    /// ```
+    pub fn multiply_mod(&self, other: &Gf2Polynomial, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        assert!(modulus.bits != 0, "modulus must be nonzero");
+        Gf2Polynomial::new(reduce(
+            carryless_multiply(self.bits, other.bits),
+            modulus.bits,
+        ))
+    }
+
+    /// Return `self^exponent` reduced modulo `modulus`
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// // x has order 15 modulo the primitive polynomial x^4 + x + 1
+    /// let x = Gf2Polynomial::new(0b10);
+    /// assert_eq!(x.power_mod(15, &Gf2Polynomial::new(0b10011)).bits, 1);
+    /// ```
+    pub fn power_mod(&self, exponent: u64, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        let mut result = Gf2Polynomial::new(1).remainder(modulus);
+        let mut base = self.remainder(modulus);
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.multiply_mod(&base, modulus);
+            }
+            base = base.multiply_mod(&base, modulus);
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Return the greatest common divisor of `self` and `other`
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// // (x + 1)(x^2 + x + 1) and (x + 1)^2
+    /// let gcd = Gf2Polynomial::new(0b1001).gcd(&Gf2Polynomial::new(0b101));
+    /// assert_eq!(gcd.bits, 0b11);
+    /// ```
+    pub fn gcd(&self, other: &Gf2Polynomial) -> Gf2Polynomial {
+        let mut a = *self;
+        let mut b = *other;
+        while b.bits != 0 {
+            let remainder = a.remainder(&b);
+            a = b;
+            b = remainder;
+        }
+
+        a
+    }
+
+    /// Returns `true` if the polynomial is irreducible over GF(2), using Rabin's test
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// assert!(Gf2Polynomial::new(0b1011).is_irreducible());
+    /// assert!(!Gf2Polynomial::new(0b101).is_irreducible());
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        let degree = self.degree();
+        if degree < 1 {
+            return false;
+        }
+        let n = degree as u64;
+        let x = Gf2Polynomial::new(0b10);
+
+        // x^(2^k) mod f, computed by repeated squaring
+        let frobenius = |k: u64| {
+            (0..k).fold(x.remainder(self), |power, _| {
+                power.multiply_mod(&power, self)
+            })
+        };
+
+        if frobenius(n) != x.remainder(self) {
+            return false;
+        }
+        crate::number_theory::prime_factors(n).iter().all(|q| {
+            let difference = frobenius(n / q) + x;
+            self.gcd(&difference).degree() == 0
+        })
+    }
+
+    /// Returns `true` if the polynomial is primitive, i.e. irreducible with `x` generating the multiplicative group of GF(2^n)
+    /// # Example
+    /// ```
+    /// use polynom::gf2::Gf2Polynomial;
+    ///
+    /// assert!(Gf2Polynomial::new(0b10011).is_primitive());
+    /// // Irreducible, but x has order 5 rather than 15
+    /// assert!(!Gf2Polynomial::new(0b11111).is_primitive());
+    /// // x is irreducible, but x is zero rather than a unit modulo itself
+    /// assert!(!Gf2Polynomial::new(0b10).is_primitive());
+    /// ```
+    pub fn is_primitive(&self) -> bool {
+        if self.bits & 1 == 0 || !self.is_irreducible() {
+            return false;
+        }
+        let order = (1u64 << self.degree()) - 1;
+        let x = Gf2Polynomial::new(0b10);
+        let one = Gf2Polynomial::new(1).remainder(self);
+
+        crate::number_theory::prime_factors(order)
+            .iter()
+            .all(|q| x.power_mod(order / q, self) != one)
+    }
+}
+
+fn carryless_multiply(a: u64, b: u64) -> u128 {
+    let mut product = 0u128;
+    let mut b = b;
+    let mut shift = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= (a as u128) << shift;
+        }
+        b >>= 1;
+        shift += 1;
+    }
+
+    product
+}
+
+fn reduce(value: u128, modulus: u64) -> u64 {
+    let degree = 63 - modulus.leading_zeros() as usize;
+    let mut value = value;
+    while value != 0 && 127 - value.leading_zeros() as usize >= degree {
+        let shift = 127 - value.leading_zeros() as usize - degree;
+        value ^= (modulus as u128) << shift;
+    }
+
+    value as u64
+}
+
+/// Returns the first primitive polynomial of the given degree over GF(2), in order of increasing bit mask.
+///
+/// A primitive characteristic polynomial gives an LFSR of maximal period `2^degree - 1`.
+/// Returns `None` unless `1 <= degree <= 63`.
+/// # Example
+/// ```
+/// use polynom::gf2::find_primitive;
+///
+/// assert_eq!(find_primitive(4).unwrap().bits, 0b10011);
+/// assert_eq!(find_primitive(8).unwrap().bits, 0b1_0001_1101);
+/// ```
+pub fn find_primitive(degree: usize) -> Option<Gf2Polynomial> {
+    if degree == 0 || degree > 63 {
+        return None;
+    }
+
+    let leading = 1u64 << degree;
+    // The constant term of a primitive polynomial is always one
+    (0..leading)
+        .step_by(2)
+        .map(|low| Gf2Polynomial::new(leading | low | 1))
+        .find(|candidate| candidate.is_primitive())
+}
+
+/// The finite field GF(2^m) defined by an irreducible polynomial, with exponential and logarithm tables.
+///
+/// Field elements are polynomials of degree less than `m`, stored as bit masks like [`Gf2Polynomial::bits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2mField {
+    modulus: Gf2Polynomial,
+    generator: u64,
+    exp: Vec<u64>,
+    log: Vec<u64>,
+}
+
+impl Gf2mField {
+    /// The largest extension degree for which tables are built.
+    pub const MAX_DEGREE: usize = 20;
+
+    /// Builds the field defined by `modulus`, or returns `None` if it is not irreducible or its degree is outside `1..=MAX_DEGREE`.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Gf2mField};
+    ///
+    /// let field = Gf2mField::new(Gf2Polynomial::new(0b1_0001_1011)).unwrap();
+    /// assert_eq!(field.order(), 256);
+    /// // The AES field: {53} * {CA} = {01}
+    /// assert_eq!(field.multiply(0x53, 0xCA), 0x01);
+    /// ```
+    pub fn new(modulus: Gf2Polynomial) -> Option<Gf2mField> {
+        let degree = modulus.degree();
+        if degree < 1 || degree as usize > Gf2mField::MAX_DEGREE || !modulus.is_irreducible() {
+            return None;
+        }
+
+        let group_order = (1u64 << degree) - 1;
+        let factors = crate::number_theory::prime_factors(group_order);
+        let one = Gf2Polynomial::new(1).remainder(&modulus);
+        let generator = (2..=group_order.max(1))
+            .map(Gf2Polynomial::new)
+            .find(|candidate| {
+                factors
+                    .iter()
+                    .all(|q| candidate.power_mod(group_order / q, &modulus) != one)
+            })
+            .map_or(1, |generator| generator.bits);
+
+        let mut exp = Vec::with_capacity(group_order as usize);
+        let mut log = vec![0u64; group_order as usize + 1];
+        let mut element = Gf2Polynomial::new(1);
+        for i in 0..group_order {
+            exp.push(element.bits);
+            log[element.bits as usize] = i;
+            element = element.multiply_mod(&Gf2Polynomial::new(generator), &modulus);
+        }
+
+        Some(Gf2mField {
+            modulus,
+            generator,
+            exp,
+            log,
+        })
+    }
+
+    /// Returns the defining polynomial of the field.
+    pub fn modulus(&self) -> Gf2Polynomial {
+        self.modulus
+    }
+
+    /// Returns the generator of the multiplicative group used for the tables (`x` when the modulus is primitive).
+    pub fn generator(&self) -> u64 {
+        self.generator
+    }
+
+    /// Returns the number of elements in the field, `2^m`.
+    pub fn order(&self) -> u64 {
+        self.exp.len() as u64 + 1
+    }
+
+    /// Returns `generator^i`.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Gf2mField};
+    ///
+    /// let field = Gf2mField::new(Gf2Polynomial::new(0b10011)).unwrap();
+    /// assert_eq!(field.exp(4), 0b0011);
+    /// assert_eq!(field.exp(15), 1);
+    /// ```
+    pub fn exp(&self, i: u64) -> u64 {
+        self.exp[(i % self.exp.len() as u64) as usize]
+    }
+
+    /// Returns the discrete logarithm of `element` to the base of the generator, or `None` for zero.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Gf2mField};
+    ///
+    /// let field = Gf2mField::new(Gf2Polynomial::new(0b10011)).unwrap();
+    /// assert_eq!(field.log(0b0011), Some(4));
+    /// assert_eq!(field.log(0), None);
+    /// ```
+    pub fn log(&self, element: u64) -> Option<u64> {
+        let element = self.reduce(element);
+        if element == 0 {
+            return None;
+        }
+
+        Some(self.log[element as usize])
+    }
+
+    fn reduce(&self, element: u64) -> u64 {
+        Gf2Polynomial::new(element).remainder(&self.modulus).bits
+    }
+
+    /// Returns `a + b` in the field.
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a ^ b)
+    }
+
+    /// Returns `a * b` in the field, using the logarithm tables.
+    pub fn multiply(&self, a: u64, b: u64) -> u64 {
+        match (self.log(a), self.log(b)) {
+            (Some(log_a), Some(log_b)) => self.exp(log_a + log_b),
+            _ => 0,
+        }
+    }
+
+    /// Returns the multiplicative inverse of `a`, or `None` for zero.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Gf2mField};
+    ///
+    /// let field = Gf2mField::new(Gf2Polynomial::new(0b1011)).unwrap();
+    /// let inverse = field.inverse(0b110).unwrap();
+    /// assert_eq!(field.multiply(0b110, inverse), 1);
+    /// ```
+    pub fn inverse(&self, a: u64) -> Option<u64> {
+        let log_a = self.log(a)?;
+        let group_order = self.exp.len() as u64;
+        Some(self.exp(group_order - log_a))
+    }
+
+    /// Returns `a / b`, or `None` if `b` is zero.
+    pub fn divide(&self, a: u64, b: u64) -> Option<u64> {
+        Some(self.multiply(a, self.inverse(b)?))
+    }
+
+    /// Returns `a^exponent` in the field.
+    pub fn power(&self, a: u64, exponent: u64) -> u64 {
+        match self.log(a) {
+            Some(log_a) => {
+                let group_order = self.exp.len() as u64;
+                let reduced = (log_a as u128 * exponent as u128 % group_order as u128) as u64;
+                self.exp(reduced)
+            }
+            None if exponent == 0 => 1,
+            None => 0,
+        }
+    }
+}
+
+/// A Fibonacci linear-feedback shift register defined by a characteristic polynomial over GF(2).
+///
+/// With characteristic polynomial `x^n + c_(n-1) x^(n-1) + ... + c_0`, the output sequence satisfies
+/// `s_(k+n) = c_(n-1) s_(k+n-1) + ... + c_0 s_k` over GF(2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lfsr {
+    taps: u64,
+    length: usize,
+    state: u64,
+}
+
+impl Lfsr {
+    /// Returns a shift register for `characteristic`, with bit `i` of `seed` holding the output `s_i`.
+    ///
+    /// # Panics
+    /// Panics if `characteristic` has degree less than one.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Lfsr};
+    ///
+    /// let lfsr = Lfsr::new(Gf2Polynomial::new(0b1011), 0b001);
+    /// let bits: Vec<bool> = lfsr.take(7).collect();
+    /// assert_eq!(bits, vec![true, false, false, true, false, true, true]);
+    /// ```
+    pub fn new(characteristic: Gf2Polynomial, seed: u64) -> Lfsr {
+        let degree = characteristic.degree();
+        assert!(
+            degree >= 1,
+            "characteristic polynomial must have degree at least 1"
+        );
+        let length = degree as usize;
+        let mask = (1u64 << length) - 1;
+
+        Lfsr {
+            taps: characteristic.bits & mask,
+            length,
+            state: seed & mask,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        let output = self.state & 1 == 1;
+        let feedback = u64::from((self.state & self.taps).count_ones() & 1);
+        self.state = (self.state >> 1) | (feedback << (self.length - 1));
+        output
+    }
+
+    /// Returns the period of the output sequence from the current state.
+    ///
+    /// The register is stepped until its state repeats, which takes at most `2^n` steps for a
+    /// characteristic polynomial of degree `n`. For a sequence that is only eventually periodic
+    /// (when the constant term is zero) this is the length of the cycle it settles into.
+    /// # Example
+    /// ```
+    /// use polynom::gf2::{Gf2Polynomial, Lfsr};
+    ///
+    /// // x^4 + x + 1 is primitive, so any nonzero seed gives a maximal-length sequence
+    /// let lfsr = Lfsr::new(Gf2Polynomial::new(0b10011), 1);
+    /// assert_eq!(lfsr.period(), 15);
+    /// ```
+    pub fn period(&self) -> u64 {
+        // Brent's cycle detection on the register state
+        let mut power = 1u64;
+        let mut period = 1u64;
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        hare.step();
+        while tortoise.state != hare.state {
+            if power == period {
+                tortoise.state = hare.state;
+                power *= 2;
+                period = 0;
+            }
+            hare.step();
+            period += 1;
+        }
+
+        period
+    }
+}
+
+impl Iterator for Lfsr {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        Some(self.step())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_is_xor() {
+        let a = Gf2Polynomial::new(0b1011);
+        let b = Gf2Polynomial::new(0b0110);
+
+        assert_eq!((a + b).bits, 0b1101);
+        assert_eq!((a + a).degree(), -1);
+    }
+
+    #[test]
+    fn test_irreducible_count() {
+        // There are 6 irreducible polynomials of degree 5 over GF(2), all of them primitive
+        let irreducible: Vec<u64> = (32..64u64)
+            .filter(|&bits| Gf2Polynomial::new(bits).is_irreducible())
+            .collect();
+
+        assert_eq!(irreducible.len(), 6);
+        assert!(irreducible
+            .iter()
+            .all(|&bits| Gf2Polynomial::new(bits).is_primitive()));
+    }
+
+    #[test]
+    fn test_primitive_degree_one() {
+        assert!(!Gf2Polynomial::new(0b10).is_primitive());
+        assert!(Gf2Polynomial::new(0b11).is_primitive());
+        assert_eq!(find_primitive(1).unwrap().bits, 0b11);
+    }
+
+    #[test]
+    fn test_primitive_count_degree_six() {
+        // phi(63) / 6 = 6 primitive polynomials among 9 irreducible ones
+        let primitive = (64..128u64)
+            .filter(|&bits| Gf2Polynomial::new(bits).is_primitive())
+            .count();
+        let irreducible = (64..128u64)
+            .filter(|&bits| Gf2Polynomial::new(bits).is_irreducible())
+            .count();
+
+        assert_eq!(primitive, 6);
+        assert_eq!(irreducible, 9);
+    }
+
+    #[test]
+    fn test_find_primitive_gives_maximal_lfsr() {
+        for degree in 1..=10 {
+            let polynomial = find_primitive(degree).unwrap();
+            assert_eq!(polynomial.degree(), degree as isize);
+            assert_eq!(Lfsr::new(polynomial, 1).period(), (1 << degree) - 1);
+        }
+        assert_eq!(find_primitive(0), None);
+        assert_eq!(find_primitive(64), None);
+    }
+
+    #[test]
+    fn test_find_primitive_high_degree() {
+        let polynomial = find_primitive(63).unwrap();
+
+        assert_eq!(polynomial.degree(), 63);
+        assert!(polynomial.is_primitive());
+    }
+
+    #[test]
+    fn test_field_tables_match_direct_arithmetic() {
+        let modulus = Gf2Polynomial::new(0b1_0001_1011);
+        let field = Gf2mField::new(modulus).unwrap();
+
+        for a in (0..256u64).step_by(7) {
+            for b in (0..256u64).step_by(11) {
+                let direct = Gf2Polynomial::new(a).multiply_mod(&Gf2Polynomial::new(b), &modulus);
+                assert_eq!(field.multiply(a, b), direct.bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_with_non_primitive_modulus() {
+        // x^4 + x^3 + x^2 + x + 1 is irreducible, but x only has order 5
+        let field = Gf2mField::new(Gf2Polynomial::new(0b11111)).unwrap();
+
+        assert_ne!(field.generator(), 0b10);
+        let mut seen: Vec<u64> = (0..15).map(|i| field.exp(i)).collect();
+        seen.sort_unstable();
+        assert_eq!(seen, (1..16).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_field_inverse_and_power() {
+        let field = Gf2mField::new(find_primitive(8).unwrap()).unwrap();
+
+        for a in 1..256u64 {
+            assert_eq!(field.multiply(a, field.inverse(a).unwrap()), 1);
+            assert_eq!(field.power(a, 255), 1);
+        }
+        assert_eq!(field.inverse(0), None);
+        assert_eq!(field.divide(5, 0), None);
+        assert_eq!(field.power(0, 0), 1);
+        assert_eq!(field.add(0b1010, 0b0110), 0b1100);
+    }
+
+    #[test]
+    fn test_field_rejects_reducible_modulus() {
+        assert!(Gf2mField::new(Gf2Polynomial::new(0b101)).is_none());
+        assert!(Gf2mField::new(find_primitive(21).unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_lfsr_recurrence() {
+        let characteristic = Gf2Polynomial::from_exponents(&[5, 2, 0]);
+        let bits: Vec<bool> = Lfsr::new(characteristic, 0b10110).take(40).collect();
+
+        for k in 0..35 {
+            assert_eq!(bits[k + 5], bits[k + 2] ^ bits[k]);
+        }
+    }
+
+    #[test]
+    fn test_lfsr_periods() {
+        // Primitive: maximal period 2^n - 1
+        assert_eq!(Lfsr::new(Gf2Polynomial::new(0b1011), 1).period(), 7);
+        assert_eq!(
+            Lfsr::new(Gf2Polynomial::from_exponents(&[5, 2, 0]), 1).period(),
+            31
+        );
+        // Irreducible but not primitive: x^4 + x^3 + x^2 + x + 1 divides x^5 - 1
+        assert_eq!(Lfsr::new(Gf2Polynomial::new(0b11111), 1).period(), 5);
+        // The all-zero state is a fixed point
+        assert_eq!(Lfsr::new(Gf2Polynomial::new(0b1011), 0).period(), 1);
+    }
+
+    #[test]
+    fn test_lfsr_sequence_repeats_with_period() {
+        let lfsr = Lfsr::new(Gf2Polynomial::new(0b10011), 0b0101);
+        let period = lfsr.period() as usize;
+        let bits: Vec<bool> = lfsr.take(3 * period).collect();
+
+        assert_eq!(bits[..period], bits[period..2 * period]);
+    }
+}
@@ -0,0 +1,562 @@
+//! Polynomials over the prime field GF(p) and arithmetic in their quotient rings.
+
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use crate::display::{self, FormatOptions, Term};
+use crate::number_theory::{add_mod, multiply_mod, power_mod, sub_mod};
+
+/// A polynomial with coefficients in GF(p) for a prime `p`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GfpPolynomial {
+    /// Coefficients reduced modulo `prime`, indexed by degree.
+    pub coefficients: Vec<u64>,
+    /// The characteristic `p` of the coefficient field.
+    pub prime: u64,
+}
+
+impl fmt::Debug for GfpPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GfpPolynomial {{ coefficients: {:?}, prime: {} }}",
+            self.coefficients, self.prime
+        )
+    }
+}
+
+fn assert_same_field(a: &GfpPolynomial, b: &GfpPolynomial) {
+    assert_eq!(a.prime, b.prime, "polynomials are over different fields");
+}
+
+impl Add for GfpPolynomial {
+    type Output = GfpPolynomial;
+
+    fn add(self, other: GfpPolynomial) -> Self {
+        assert_same_field(&self, &other);
+        let p = self.prime;
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|i| add_mod(self.coefficient(i), other.coefficient(i), p))
+            .collect();
+
+        GfpPolynomial::new(coefficients, p)
+    }
+}
+
+impl Sub for GfpPolynomial {
+    type Output = GfpPolynomial;
+
+    fn sub(self, other: GfpPolynomial) -> Self {
+        assert_same_field(&self, &other);
+        let p = self.prime;
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|i| sub_mod(self.coefficient(i), other.coefficient(i), p))
+            .collect();
+
+        GfpPolynomial::new(coefficients, p)
+    }
+}
+
+impl Mul for GfpPolynomial {
+    type Output = GfpPolynomial;
+
+    fn mul(self, other: GfpPolynomial) -> Self {
+        self.multiply(&other)
+    }
+}
+
+/// The reasons [`GfpPolynomial::sqrt_mod`] can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqrtError {
+    /// The element is not a square in the field.
+    NotASquare,
+    /// The modulus is not irreducible, so the quotient ring is not a field; square roots in
+    /// such rings are not supported.
+    ReducibleModulus,
+    /// The field size `p^n` does not fit in a `u128`.
+    FieldTooLarge,
+}
+
+impl fmt::Display for SqrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqrtError::NotASquare => write!(f, "the element is not a square"),
+            SqrtError::ReducibleModulus => write!(f, "the modulus is not irreducible"),
+            SqrtError::FieldTooLarge => write!(f, "the field size does not fit in a u128"),
+        }
+    }
+}
+
+impl std::error::Error for SqrtError {}
+
+impl GfpPolynomial {
+    /// Returns a GfpPolynomial from coefficients (reduced modulo `prime`)
+    ///
+    /// `prime` must be prime for division, gcd and square roots to be meaningful.
+    ///
+    /// # Panics
+    /// Panics if `prime` is less than two.
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// let polynomial = GfpPolynomial::new(vec![8, 5, 7], 7);
+    /// assert_eq!(polynomial.coefficients, vec![1, 5]);
+    /// ```
+    pub fn new(coefficients: Vec<u64>, prime: u64) -> GfpPolynomial {
+        assert!(prime >= 2, "prime must be at least 2");
+        let mut polynomial = GfpPolynomial {
+            coefficients,
+            prime,
+        };
+        polynomial.normalize();
+        polynomial
+    }
+
+    /// Reduces the coefficients modulo the prime and drops trailing zeros in place, leaving
+    /// `[0]` for the zero polynomial
+    pub fn normalize(&mut self) {
+        let prime = self.prime;
+        for coeff in self.coefficients.iter_mut() {
+            *coeff %= prime;
+        }
+        while self.coefficients.last() == Some(&0) {
+            self.coefficients.pop();
+        }
+        if self.coefficients.is_empty() {
+            self.coefficients.push(0);
+        }
+    }
+
+    fn constant(value: u64, prime: u64) -> GfpPolynomial {
+        GfpPolynomial::new(vec![value], prime)
+    }
+
+    /// Return the polynomial represented as a String, with coefficients in `0..prime`
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// let polynomial = GfpPolynomial::new(vec![3, 0, 1], 7);
+    /// assert_eq!(polynomial.as_string(), String::from("f(x) = 3 + x^2"));
+    /// ```
+    pub fn as_string(&self) -> String {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, &coeff)| coeff != 0)
+            .map(|(degree, &coeff)| Term {
+                degree,
+                negative: false,
+                magnitude: coeff.to_string(),
+                unit: coeff == 1,
+            });
+        display::render('x', terms, &FormatOptions::DEFAULT)
+    }
+
+    /// Return the degree of the polynomial, or `-1` for the zero polynomial
+    pub fn degree(&self) -> isize {
+        if self.is_zero() {
+            return -1;
+        }
+
+        (self.coefficients.len() - 1) as isize
+    }
+
+    /// Returns `true` for the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients == [0]
+    }
+
+    /// Return the coefficient of `x^degree`.
+    pub fn coefficient(&self, degree: usize) -> u64 {
+        self.coefficients.get(degree).copied().unwrap_or(0)
+    }
+
+    fn leading_coefficient(&self) -> u64 {
+        *self.coefficients.last().unwrap_or(&0)
+    }
+
+    /// Multiplies `self` and `other`
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// // (x + 1)(x + 2) = x^2 + 3x + 2 = x^2 + 2 over GF(3)
+    /// let product = GfpPolynomial::new(vec![1, 1], 3).multiply(&GfpPolynomial::new(vec![2, 1], 3));
+    /// assert_eq!(product.coefficients, vec![2, 0, 1]);
+    /// ```
+    pub fn multiply(&self, other: &GfpPolynomial) -> GfpPolynomial {
+        assert_same_field(self, other);
+        let p = self.prime;
+        let mut coefficients = vec![0u64; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = add_mod(coefficients[i + j], multiply_mod(a, b, p), p);
+            }
+        }
+
+        GfpPolynomial::new(coefficients, p)
+    }
+
+    /// Returns the quotient and remainder of dividing `self` by `divisor`
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// let dividend = GfpPolynomial::new(vec![1, 0, 1], 5);
+    /// let (quotient, remainder) = dividend.div_rem(&GfpPolynomial::new(vec![2, 1], 5));
+    /// assert_eq!(quotient.coefficients, vec![3, 1]);
+    /// assert_eq!(remainder.coefficients, vec![0]);
+    /// ```
+    pub fn div_rem(&self, divisor: &GfpPolynomial) -> (GfpPolynomial, GfpPolynomial) {
+        assert_same_field(self, divisor);
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let p = self.prime;
+        let divisor_degree = divisor.coefficients.len() - 1;
+        let inverse_leading = power_mod(divisor.leading_coefficient(), p - 2, p);
+
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree {
+            return (GfpPolynomial::constant(0, p), self.clone());
+        }
+        let mut quotient = vec![0u64; remainder.len() - divisor_degree];
+        for shift in (0..quotient.len()).rev() {
+            let factor = multiply_mod(remainder[shift + divisor_degree], inverse_leading, p);
+            quotient[shift] = factor;
+            for (i, &d) in divisor.coefficients.iter().enumerate() {
+                let product = multiply_mod(factor, d, p);
+                remainder[shift + i] = sub_mod(remainder[shift + i], product, p);
+            }
+        }
+
+        (
+            GfpPolynomial::new(quotient, p),
+            GfpPolynomial::new(remainder, p),
+        )
+    }
+
+    /// Returns `self` modulo `modulus`.
+    pub fn remainder(&self, modulus: &GfpPolynomial) -> GfpPolynomial {
+        self.div_rem(modulus).1
+    }
+
+    /// Returns `self * other` modulo `modulus`.
+    pub fn multiply_mod(&self, other: &GfpPolynomial, modulus: &GfpPolynomial) -> GfpPolynomial {
+        self.multiply(other).remainder(modulus)
+    }
+
+    /// Returns `self^exponent` modulo `modulus`
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// // x^2 = -1 modulo x^2 + 1 over GF(3)
+    /// let x = GfpPolynomial::new(vec![0, 1], 3);
+    /// let modulus = GfpPolynomial::new(vec![1, 0, 1], 3);
+    /// assert_eq!(x.power_mod(2, &modulus).coefficients, vec![2]);
+    /// ```
+    pub fn power_mod(&self, exponent: u128, modulus: &GfpPolynomial) -> GfpPolynomial {
+        let mut result = GfpPolynomial::constant(1, self.prime).remainder(modulus);
+        let mut base = self.remainder(modulus);
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.multiply_mod(&base, modulus);
+            }
+            base = base.multiply_mod(&base, modulus);
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `other`.
+    pub fn gcd(&self, other: &GfpPolynomial) -> GfpPolynomial {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let remainder = a.remainder(&b);
+            a = b;
+            b = remainder;
+        }
+
+        a.monic()
+    }
+
+    /// Returns the polynomial scaled so that its leading coefficient is one (the zero polynomial is unchanged).
+    pub fn monic(&self) -> GfpPolynomial {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let p = self.prime;
+        let inverse = power_mod(self.leading_coefficient(), p - 2, p);
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|&c| multiply_mod(c, inverse, p))
+            .collect();
+
+        GfpPolynomial::new(coefficients, p)
+    }
+
+    /// Returns `true` if the polynomial is irreducible over GF(p), using Rabin's test
+    /// # Example
+    /// ```
+    /// use polynom::gfp::GfpPolynomial;
+    ///
+    /// assert!(GfpPolynomial::new(vec![1, 0, 1], 3).is_irreducible());
+    /// assert!(!GfpPolynomial::new(vec![1, 0, 1], 5).is_irreducible());
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        let degree = self.degree();
+        if degree < 1 {
+            return false;
+        }
+        let n = degree as u64;
+        let p = self.prime as u128;
+        let x = GfpPolynomial::new(vec![0, 1], self.prime).remainder(self);
+
+        // x^(p^k) mod f, by repeated p-th powers
+        let frobenius = |k: u64| (0..k).fold(x.clone(), |power, _| power.power_mod(p, self));
+
+        if frobenius(n) != x {
+            return false;
+        }
+        crate::number_theory::prime_factors(n).iter().all(|q| {
+            let difference = frobenius(n / q) - x.clone();
+            self.gcd(&difference).degree() == 0
+        })
+    }
+
+    /// Returns a square root of `self` in the field `GF(p)[x] / (modulus)`.
+    ///
+    /// `modulus` must be irreducible, so that the quotient ring is the field GF(p^n). Uses
+    /// Tonelli–Shanks for odd `p`; over GF(2^n) every element has the unique root `a^(2^(n-1))`.
+    /// Reducible moduli are rejected with [`SqrtError::ReducibleModulus`] even where a root
+    /// exists, since the quotient ring is then not a field.
+    /// # Example
+    /// ```
+    /// use polynom::gfp::{GfpPolynomial, SqrtError};
+    ///
+    /// // In GF(9) = GF(3)[x] / (x^2 + 1), x is a square root of -1
+    /// let modulus = GfpPolynomial::new(vec![1, 0, 1], 3);
+    /// let minus_one = GfpPolynomial::new(vec![2], 3);
+    /// let root = minus_one.sqrt_mod(&modulus).unwrap();
+    /// assert_eq!(root.multiply_mod(&root, &modulus), minus_one);
+    ///
+    /// // x^2 + 1 = (x + 2)(x + 3) over GF(5)
+    /// let modulus = GfpPolynomial::new(vec![1, 0, 1], 5);
+    /// let four = GfpPolynomial::new(vec![4], 5);
+    /// assert_eq!(four.sqrt_mod(&modulus), Err(SqrtError::ReducibleModulus));
+    /// ```
+    pub fn sqrt_mod(&self, modulus: &GfpPolynomial) -> Result<GfpPolynomial, SqrtError> {
+        assert_same_field(self, modulus);
+        if !modulus.is_irreducible() {
+            return Err(SqrtError::ReducibleModulus);
+        }
+        let p = self.prime;
+        let a = self.remainder(modulus);
+        if a.is_zero() {
+            return Ok(a);
+        }
+        let field_size = (p as u128)
+            .checked_pow(modulus.degree() as u32)
+            .ok_or(SqrtError::FieldTooLarge)?;
+        let one = GfpPolynomial::constant(1, p);
+
+        if p == 2 {
+            return Ok(a.power_mod(field_size / 2, modulus));
+        }
+
+        // Euler's criterion
+        if a.power_mod((field_size - 1) / 2, modulus) != one {
+            return Err(SqrtError::NotASquare);
+        }
+
+        let mut s = 0u32;
+        let mut t = field_size - 1;
+        while t % 2 == 0 {
+            t /= 2;
+            s += 1;
+        }
+
+        // Every constant is a square when the degree is even, so start at x + 0, x + 1, ...,
+        // where a non-residue turns up within a few tries; the constants only matter over GF(p)
+        let non_residue = (p as u128..field_size)
+            .chain(2..p as u128)
+            .map(|index| GfpPolynomial::from_index(index, p))
+            .find(|z| z.power_mod((field_size - 1) / 2, modulus) != one)
+            .expect("half the nonzero elements of a field of odd order are non-residues");
+
+        let mut c = non_residue.power_mod(t, modulus);
+        let mut root = a.power_mod(t.div_ceil(2), modulus);
+        let mut residual = a.power_mod(t, modulus);
+        let mut m = s;
+        while residual != one {
+            let mut i = 0u32;
+            let mut probe = residual.clone();
+            while probe != one {
+                probe = probe.multiply_mod(&probe, modulus);
+                i += 1;
+            }
+            let b = c.power_mod(1u128 << (m - i - 1), modulus);
+            root = root.multiply_mod(&b, modulus);
+            c = b.multiply_mod(&b, modulus);
+            residual = residual.multiply_mod(&c, modulus);
+            m = i;
+        }
+
+        Ok(root)
+    }
+
+    /// Returns the polynomial whose coefficients are the base-`p` digits of `index`.
+    fn from_index(index: u128, prime: u64) -> GfpPolynomial {
+        let mut coefficients = Vec::new();
+        let mut index = index;
+        while index > 0 {
+            coefficients.push((index % prime as u128) as u64);
+            index /= prime as u128;
+        }
+
+        GfpPolynomial::new(coefficients, prime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = GfpPolynomial::new(vec![4, 3, 1], 5);
+        let b = GfpPolynomial::new(vec![2, 2], 5);
+
+        assert_eq!((a.clone() + b.clone()).coefficients, vec![1, 0, 1]);
+        assert_eq!((a.clone() - a.clone()).coefficients, vec![0]);
+        assert_eq!((a * b).coefficients, vec![3, 4, 3, 2]);
+    }
+
+    #[test]
+    fn test_div_rem_identity() {
+        let dividend = GfpPolynomial::new(vec![3, 1, 4, 1, 5, 9, 2, 6], 11);
+        let divisor = GfpPolynomial::new(vec![5, 3, 5], 11);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert!(remainder.degree() < divisor.degree());
+        assert_eq!(quotient * divisor + remainder, dividend);
+    }
+
+    #[test]
+    fn test_irreducible_count() {
+        // There are (3^2 - 3) / 2 = 3 monic irreducible quadratics over GF(3)
+        let count = (0..9u64)
+            .map(|i| GfpPolynomial::new(vec![i % 3, i / 3, 1], 3))
+            .filter(|candidate| candidate.is_irreducible())
+            .count();
+
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_sqrt_mod_over_every_square() {
+        // GF(49) = GF(7)[x] / (x^2 + 1)
+        let modulus = GfpPolynomial::new(vec![1, 0, 1], 7);
+        let mut squares = 0;
+        for index in 0..49u128 {
+            let a = GfpPolynomial::from_index(index, 7);
+            match a.sqrt_mod(&modulus) {
+                Ok(root) => {
+                    assert_eq!(root.multiply_mod(&root, &modulus), a);
+                    squares += 1;
+                }
+                Err(error) => {
+                    assert_eq!(error, SqrtError::NotASquare);
+                    assert!(!a.is_zero());
+                }
+            }
+        }
+
+        // Zero plus half of the 48 nonzero elements
+        assert_eq!(squares, 25);
+    }
+
+    #[test]
+    fn test_sqrt_mod_in_characteristic_two() {
+        let modulus = GfpPolynomial::new(vec![1, 1, 0, 0, 1], 2);
+        for index in 0..16u128 {
+            let a = GfpPolynomial::from_index(index, 2);
+            let root = a.sqrt_mod(&modulus).unwrap();
+            assert_eq!(root.multiply_mod(&root, &modulus), a);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_with_large_prime_and_quadratic_modulus() {
+        // 1_000_000_007 = 3 (mod 4), so x^2 + 1 is irreducible, and in 2^64 - 59 the
+        // quadratic x^2 - c is irreducible whenever c is a non-residue
+        let small = GfpPolynomial::new(vec![1, 0, 1], 1_000_000_007);
+        let prime = 18446744073709551557;
+        let large = (2..)
+            .map(|c| GfpPolynomial::new(vec![prime - c, 0, 1], prime))
+            .find(|candidate| candidate.is_irreducible())
+            .unwrap();
+
+        for modulus in &[small, large] {
+            let p = modulus.prime;
+            for element in &[vec![3, 7], vec![p - 1], vec![123_456_789, p - 2]] {
+                let root = GfpPolynomial::new(element.clone(), p);
+                let square = root.multiply_mod(&root, modulus);
+                let found = square.sqrt_mod(modulus).unwrap();
+                assert_eq!(found.multiply_mod(&found, modulus), square);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_rejects_reducible_modulus() {
+        // x^2 + 1 = (x + 2)(x + 3) over GF(5): 4 has the root 2 in the ring, but the ring
+        // is not a field, so the modulus is reported rather than a wrong "not a square"
+        let modulus = GfpPolynomial::new(vec![1, 0, 1], 5);
+        for value in &[1, 4] {
+            assert_eq!(
+                GfpPolynomial::new(vec![*value], 5).sqrt_mod(&modulus),
+                Err(SqrtError::ReducibleModulus)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_field_too_large() {
+        let prime = 18446744073709551557;
+        // About a third of the monic cubics are irreducible, so one turns up quickly
+        let modulus = (1..)
+            .map(|c| GfpPolynomial::new(vec![c, 1, 0, 1], prime))
+            .find(|candidate| candidate.is_irreducible())
+            .unwrap();
+        assert_eq!(
+            GfpPolynomial::new(vec![4], prime).sqrt_mod(&modulus),
+            Err(SqrtError::FieldTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_near_u64_max() {
+        let prime = 18446744073709551557;
+        let a = GfpPolynomial::new(vec![prime - 1, prime - 2], prime);
+        let b = GfpPolynomial::new(vec![prime - 3, 5], prime);
+
+        assert_eq!((a.clone() + b.clone()).coefficients, vec![prime - 4, 3]);
+        assert_eq!((b.clone() - a.clone()).coefficients, vec![prime - 2, 7]);
+        // (-1 - 2x)(-3 + 5x) = 3 + x - 10x^2
+        assert_eq!((a.clone() * b.clone()).coefficients, vec![3, 1, prime - 10]);
+        let (quotient, remainder) = (a.clone() * b.clone()).div_rem(&b);
+        assert_eq!((quotient, remainder.coefficients), (a, vec![0]));
+    }
+}
@@ -0,0 +1,265 @@
+//! Polynomial rolling hashes over a modulus (Rabin–Karp style), and stable content hashes of
+//! polynomials.
+//!
+//! The hash of a sequence `v_0, v_1, ..., v_(n-1)` is the polynomial
+//! `v_0 b^(n-1) + v_1 b^(n-2) + ... + v_(n-1)` evaluated modulo `m`.
+
+use std::collections::VecDeque;
+
+use crate::number_theory::{add_mod, multiply_mod, power_mod, sub_mod};
+use crate::polynomial::Polynomial;
+
+/// Returns the polynomial hash of `values` with the given `base` and `modulus`, evaluated with Horner's scheme.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+/// # Example
+/// ```
+/// use polynom::hashing::polynomial_hash;
+///
+/// // 1 * 10^2 + 2 * 10 + 3
+/// assert_eq!(polynomial_hash(&[1, 2, 3], 10, 1_000_000_007), 123);
+/// ```
+pub fn polynomial_hash(values: &[u64], base: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be nonzero");
+    values.iter().fold(0, |hash, &value| {
+        add_mod(multiply_mod(hash, base, modulus), value, modulus)
+    })
+}
+
+/// A polynomial hash over a sliding window that supports appending at the back and removing from the front.
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    base: u64,
+    modulus: u64,
+    hash: u64,
+    window: VecDeque<u64>,
+}
+
+impl RollingHash {
+    /// Returns an empty rolling hash with the given `base` and `modulus`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::hashing::RollingHash;
+    ///
+    /// let hash = RollingHash::new(256, 1_000_000_007);
+    /// assert_eq!(hash.hash(), 0);
+    /// ```
+    pub fn new(base: u64, modulus: u64) -> RollingHash {
+        assert!(modulus > 0, "modulus must be nonzero");
+        RollingHash {
+            base: base % modulus,
+            modulus,
+            hash: 0,
+            window: VecDeque::new(),
+        }
+    }
+
+    /// Appends `value` to the back of the window.
+    /// # Example
+    /// ```
+    /// use polynom::hashing::{polynomial_hash, RollingHash};
+    ///
+    /// let mut hash = RollingHash::new(31, 1_000_000_007);
+    /// hash.append(4);
+    /// hash.append(2);
+    /// assert_eq!(hash.hash(), polynomial_hash(&[4, 2], 31, 1_000_000_007));
+    /// ```
+    pub fn append(&mut self, value: u64) {
+        let value = value % self.modulus;
+        self.hash = add_mod(
+            multiply_mod(self.hash, self.base, self.modulus),
+            value,
+            self.modulus,
+        );
+        self.window.push_back(value);
+    }
+
+    /// Removes the value at the front of the window, returning it (reduced modulo the modulus).
+    /// # Example
+    /// ```
+    /// use polynom::hashing::{polynomial_hash, RollingHash};
+    ///
+    /// let mut hash = RollingHash::new(31, 1_000_000_007);
+    /// for value in &[7, 4, 2] {
+    ///     hash.append(*value);
+    /// }
+    /// assert_eq!(hash.remove_front(), Some(7));
+    /// assert_eq!(hash.hash(), polynomial_hash(&[4, 2], 31, 1_000_000_007));
+    /// ```
+    pub fn remove_front(&mut self) -> Option<u64> {
+        let value = self.window.pop_front()?;
+        let weight = power_mod(self.base, self.window.len() as u64, self.modulus);
+        let contribution = multiply_mod(value, weight, self.modulus);
+        self.hash = sub_mod(self.hash, contribution, self.modulus);
+
+        Some(value)
+    }
+
+    /// Returns the hash of the values currently in the window.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the number of values in the window.
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl Polynomial {
+    /// Returns a 64-bit hash of the coefficients that is the same on every platform and
+    /// release, for deduplicating polynomials across processes and machines.
+    ///
+    /// The hash is FNV-1a over the little-endian bit patterns of the coefficients, lowest
+    /// degree first, after dropping trailing zeros and mapping `-0.0` to `0.0` and every NaN
+    /// to a single NaN. The indeterminate is not hashed.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let a = Polynomial::new(vec![1f64, -0f64, 2f64], 'x');
+    /// let b = Polynomial::new(vec![1f64, 0f64, 2f64], 't');
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// assert_ne!(a.content_hash(), Polynomial::new(vec![2f64, 0f64, 1f64], 'x').content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let length = self
+            .coefficients
+            .iter()
+            .rposition(|&coeff| coeff != 0f64)
+            .map_or(1, |last| last + 1);
+        self.coefficients
+            .iter()
+            .take(length)
+            .map(|&coeff| {
+                if coeff.is_nan() {
+                    f64::NAN.to_bits()
+                } else {
+                    (coeff + 0f64).to_bits()
+                }
+            })
+            .flat_map(u64::to_le_bytes)
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULUS: u64 = 1_000_000_007;
+
+    #[test]
+    fn test_polynomial_hash_reduces_values() {
+        assert_eq!(polynomial_hash(&[], 31, MODULUS), 0);
+        assert_eq!(polynomial_hash(&[MODULUS + 5], 31, MODULUS), 5);
+    }
+
+    #[test]
+    fn test_sliding_window_matches_direct_hash() {
+        let text: Vec<u64> = "abracadabra".bytes().map(u64::from).collect();
+        let mut hash = RollingHash::new(256, MODULUS);
+
+        for (i, &value) in text.iter().enumerate() {
+            hash.append(value);
+            if hash.len() > 4 {
+                hash.remove_front();
+            }
+            let start = (i + 1).saturating_sub(4);
+            assert_eq!(hash.hash(), polynomial_hash(&text[start..=i], 256, MODULUS));
+        }
+    }
+
+    #[test]
+    fn test_equal_windows_have_equal_hashes() {
+        let text: Vec<u64> = "abracadabra".bytes().map(u64::from).collect();
+        let mut first = RollingHash::new(256, MODULUS);
+        let mut second = RollingHash::new(256, MODULUS);
+        for &value in &text[0..4] {
+            first.append(value);
+        }
+        for &value in &text[7..11] {
+            second.append(value);
+        }
+
+        assert_eq!(first.hash(), second.hash());
+    }
+
+    #[test]
+    fn test_modulus_near_u64_max() {
+        let modulus = u64::MAX - 58;
+        let values = [u64::MAX - 1, u64::MAX - 2, u64::MAX - 3];
+        let mut hash = RollingHash::new(u64::MAX - 100, modulus);
+        for &value in &values {
+            hash.append(value);
+        }
+        assert_eq!(
+            hash.hash(),
+            polynomial_hash(&values, u64::MAX - 100, modulus)
+        );
+
+        hash.remove_front();
+        assert_eq!(
+            hash.hash(),
+            polynomial_hash(&values[1..], u64::MAX - 100, modulus)
+        );
+        // The values reduce to 56 and 55 and the base to -42, so the hash is 56 * -42 + 55
+        assert_eq!(hash.hash(), modulus - 2297);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be nonzero")]
+    fn test_polynomial_hash_rejects_zero_modulus() {
+        polynomial_hash(&[1, 2], 31, 0);
+    }
+
+    #[test]
+    fn test_remove_front_on_empty_window() {
+        let mut hash = RollingHash::new(31, MODULUS);
+
+        assert!(hash.is_empty());
+        assert_eq!(hash.remove_front(), None);
+        assert_eq!(hash.hash(), 0);
+    }
+
+    #[test]
+    fn test_content_hash_is_pinned() {
+        // Reference values computed independently; these must never change between releases
+        let polynomial = Polynomial::new(vec![1f64, 2.5], 'x');
+        assert_eq!(polynomial.content_hash(), 0x2f20_34ea_1c68_fe1c);
+        assert_eq!(
+            Polynomial::new(vec![0f64], 'x').content_hash(),
+            0xa8c7_f832_281a_39c5
+        );
+    }
+
+    #[test]
+    fn test_content_hash_canonicalizes_bit_patterns() {
+        let unnormalized = Polynomial {
+            coefficients: vec![-0f64, 0f64],
+            indeterminate: 'x',
+        };
+        let nan = Polynomial::new(vec![f64::NAN], 'x');
+        let other_nan = Polynomial::new(vec![f64::from_bits(0x7ff0_0000_0000_0001)], 'x');
+
+        assert_eq!(
+            unnormalized.content_hash(),
+            Polynomial::new(vec![0f64], 'x').content_hash()
+        );
+        assert_eq!(nan.content_hash(), other_nan.content_hash());
+    }
+}
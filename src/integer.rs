@@ -0,0 +1,445 @@
+//! Exact arithmetic on polynomials with integer coefficients.
+
+use std::fmt;
+
+use crate::bigint::BigInt;
+use crate::gfp::GfpPolynomial;
+use crate::number_theory::{multiply_mod, power_mod, sub_mod};
+use crate::polynomial::Polynomial;
+
+/// Returns the residue of the integral float `value` modulo `modulus`, exactly.
+///
+/// # Panics
+/// Panics if `value` is not a finite integer.
+fn integer_residue(value: f64, modulus: u64) -> u64 {
+    assert!(
+        value.is_finite() && value.fract() == 0f64,
+        "coefficient {} is not an integer",
+        value
+    );
+    if value == 0f64 {
+        return 0;
+    }
+    // value = ±mantissa · 2^exponent with a 53-bit integer mantissa
+    let bits = value.abs().to_bits();
+    let biased = (bits >> 52) as i64;
+    let fraction = bits & ((1u64 << 52) - 1);
+    let (mantissa, exponent) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1u64 << 52), biased - 1075)
+    };
+    let residue = if exponent >= 0 {
+        multiply_mod(mantissa, power_mod(2, exponent as u64, modulus), modulus)
+    } else {
+        // An integer, so the shifted-out bits are zero
+        (mantissa >> -exponent) % modulus
+    };
+
+    if value < 0f64 && residue != 0 {
+        modulus - residue
+    } else {
+        residue
+    }
+}
+
+impl Polynomial {
+    /// Evaluates a polynomial with integer coefficients at `x` modulo `m`, using modular
+    /// arithmetic throughout so no precision is lost however large the true value is.
+    ///
+    /// Every coefficient is reduced exactly, including negative ones and integers too large
+    /// to be represented by `i64`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 2 at 10^12 is far beyond the range where f64 is exact
+    /// let polynomial = Polynomial::from_ints(vec![-2, 0, 1], 'x');
+    /// let x = 1_000_000_000_000;
+    /// let m = 1_000_000_007;
+    /// let expected = ((x as u128 * x as u128 - 2) % m as u128) as u64;
+    /// assert_eq!(polynomial.evaluate_mod(x, m), expected);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `m` is zero or a coefficient is not an integer.
+    pub fn evaluate_mod(&self, x: u64, m: u64) -> u64 {
+        assert!(m > 0, "modulus must be nonzero");
+        let x = x % m;
+        self.coefficients.iter().rev().fold(0, |sum, &coeff| {
+            let term = multiply_mod(sum, x, m) as u128 + integer_residue(coeff, m) as u128;
+            (term % m as u128) as u64
+        })
+    }
+
+    /// Reduces a polynomial with integer coefficients modulo `prime`, exactly
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::from_ints(vec![-1, 9, 3], 'x');
+    /// assert_eq!(polynomial.reduce_mod(7).coefficients, vec![6, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `prime` is less than 2 or a coefficient is not an integer.
+    pub fn reduce_mod(&self, prime: u64) -> GfpPolynomial {
+        assert!(prime >= 2, "prime must be at least 2");
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|&coeff| integer_residue(coeff, prime))
+            .collect();
+        GfpPolynomial::new(coefficients, prime)
+    }
+}
+
+/// Errors that can occur in [`crt_reconstruct`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// No images were given.
+    NoImages,
+    /// Two images share the given prime, so their moduli are not coprime.
+    RepeatedPrime {
+        /// The prime given more than once.
+        prime: u64,
+    },
+    /// The product of the primes does not fit in a `u128`.
+    ModulusOverflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoImages => write!(f, "at least one image is required"),
+            CrtError::RepeatedPrime { prime } => {
+                write!(f, "the prime {} is used by more than one image", prime)
+            }
+            CrtError::ModulusOverflow => write!(f, "the product of the primes overflows u128"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Reconstructs the integer polynomial whose reductions modulo distinct primes are
+/// `images`, with the Chinese Remainder Theorem applied coefficient by coefficient.
+///
+/// Each coefficient is returned in the symmetric range `(-M / 2, M / 2]`, where `M` is the
+/// product of the primes, so the result is the true polynomial whenever its coefficients
+/// are smaller than `M / 2` in magnitude. Images are combined incrementally with Garner's
+/// method, so every intermediate value stays below `M`.
+/// # Example
+/// ```
+/// use polynom::integer::crt_reconstruct;
+/// use polynom::polynomial::Polynomial;
+///
+/// let polynomial = Polynomial::from_ints(vec![-1_000_000, 3, 77_777], 'x');
+/// let images: Vec<_> = [1009, 1013, 1019].iter().map(|&p| polynomial.reduce_mod(p)).collect();
+/// assert_eq!(crt_reconstruct(&images), Ok(vec![-1_000_000, 3, 77_777]));
+/// ```
+pub fn crt_reconstruct(images: &[GfpPolynomial]) -> Result<Vec<i128>, CrtError> {
+    let first = images.first().ok_or(CrtError::NoImages)?;
+    let length = images
+        .iter()
+        .map(|image| image.coefficients.len())
+        .max()
+        .unwrap_or(1);
+    let mut modulus = first.prime as u128;
+    let mut residues: Vec<u128> = (0..length)
+        .map(|degree| first.coefficient(degree) as u128)
+        .collect();
+
+    for image in &images[1..] {
+        let prime = image.prime;
+        let modulus_mod_prime = (modulus % prime as u128) as u64;
+        if modulus_mod_prime == 0 {
+            return Err(CrtError::RepeatedPrime { prime });
+        }
+        let inverse = power_mod(modulus_mod_prime, prime - 2, prime);
+        let combined = modulus
+            .checked_mul(prime as u128)
+            .ok_or(CrtError::ModulusOverflow)?;
+        for (degree, residue) in residues.iter_mut().enumerate() {
+            // Choose t with residue + modulus t ≡ image (mod prime)
+            let current = (*residue % prime as u128) as u64;
+            let difference = sub_mod(image.coefficient(degree), current, prime);
+            let t = multiply_mod(difference, inverse, prime);
+            *residue += modulus * t as u128;
+        }
+        modulus = combined;
+    }
+
+    let half = modulus / 2;
+    let mut coefficients: Vec<i128> = residues
+        .into_iter()
+        .map(|residue| {
+            if residue > half {
+                -((modulus - residue) as i128)
+            } else {
+                residue as i128
+            }
+        })
+        .collect();
+    while coefficients.len() > 1 && coefficients.last() == Some(&0) {
+        coefficients.pop();
+    }
+
+    Ok(coefficients)
+}
+
+/// Multiplies two integer polynomials, given by their coefficients lowest degree first, with
+/// Kronecker substitution.
+///
+/// Both operands are shifted to nonnegative coefficients and packed into one large integer
+/// each by evaluating at `2^b`, for a field width `b` wide enough that no product
+/// coefficient spills into its neighbour. The two integers are multiplied and the product
+/// unpacked, and the shift is undone with prefix sums in linear time. Small coefficients
+/// pack many to a 32-bit digit, so the single integer multiplication does far less work
+/// than multiplying coefficient by coefficient. An empty slice is the zero polynomial.
+/// # Example
+/// ```
+/// use polynom::integer::kronecker_multiply;
+///
+/// // (1 - 2x)(3 + x + 4x^2) = 3 - 5x + 2x^2 - 8x^3
+/// assert_eq!(kronecker_multiply(&[1, -2], &[3, 1, 4]), vec![3, -5, 2, -8]);
+/// ```
+///
+/// # Panics
+/// Panics if a product coefficient could exceed 127 bits.
+pub fn kronecker_multiply(a: &[i64], b: &[i64]) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return vec![0];
+    }
+    let shift_a = a.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0) as u128;
+    let shift_b = b.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0) as u128;
+    // Every coefficient of the shifted product lies in [0, bound]
+    let bound = (shift_a * shift_b)
+        .checked_mul(4 * a.len().min(b.len()) as u128)
+        .filter(|&bound| bound < 1 << 127)
+        .expect("product coefficients exceed 127 bits");
+    let width = (128 - bound.leading_zeros()).max(1) as usize;
+
+    let packed_a = BigInt::from_digits(pack(a, shift_a, width));
+    let packed_b = BigInt::from_digits(pack(b, shift_b, width));
+    let product = packed_a.mul(&packed_b);
+
+    // Undo the shifts: a'b' = ab + s_b (a J) + s_a (J b) + s_a s_b (J J), where J is an
+    // all-ones polynomial of the matching length. The true coefficients fit in an i128, so
+    // wrapping arithmetic gives them exactly.
+    let (prefix_a, prefix_b) = (prefix_sums(a), prefix_sums(b));
+    let mut coefficients: Vec<i128> = (0..a.len() + b.len() - 1)
+        .map(|k| {
+            let shifted = extract(product.digits(), k * width, width) as i128;
+            let (low, high) = window(k, a.len(), b.len());
+            let sum_a = prefix_a[high] - prefix_a[low];
+            let (low_b, high_b) = window(k, b.len(), a.len());
+            let sum_b = prefix_b[high_b] - prefix_b[low_b];
+            let correction = (shift_b as i128)
+                .wrapping_mul(sum_a)
+                .wrapping_add((shift_a as i128).wrapping_mul(sum_b))
+                .wrapping_add(((shift_a * shift_b) as i128).wrapping_mul((high - low) as i128));
+            shifted.wrapping_sub(correction)
+        })
+        .collect();
+    while coefficients.len() > 1 && coefficients.last() == Some(&0) {
+        coefficients.pop();
+    }
+
+    coefficients
+}
+
+/// Returns the range `low..high` of indices `i < length` with `0 <= k - i < other`.
+fn window(k: usize, length: usize, other: usize) -> (usize, usize) {
+    let low = (k + 1).saturating_sub(other);
+    let high = (k + 1).min(length);
+    (low, high.max(low))
+}
+
+fn prefix_sums(values: &[i64]) -> Vec<i128> {
+    let mut sums = Vec::with_capacity(values.len() + 1);
+    sums.push(0i128);
+    for &value in values {
+        sums.push(sums[sums.len() - 1] + value as i128);
+    }
+    sums
+}
+
+/// Packs `coefficients + shift` into little-endian 32-bit digits, `width` bits apiece.
+fn pack(coefficients: &[i64], shift: u128, width: usize) -> Vec<u32> {
+    let mut digits = vec![0u32; (coefficients.len() * width).div_ceil(32) + 1];
+    for (index, &coeff) in coefficients.iter().enumerate() {
+        let mut value = (coeff as i128 + shift as i128) as u128;
+        let mut offset = index * width;
+        while value != 0 {
+            let (digit, bit) = (offset / 32, offset % 32);
+            digits[digit] |= (value << bit) as u32;
+            value >>= 32 - bit;
+            offset += 32 - bit;
+        }
+    }
+    digits
+}
+
+/// Returns the `width`-bit field starting at bit `offset` of `digits`.
+fn extract(digits: &[u32], offset: usize, width: usize) -> u128 {
+    let mut value = 0u128;
+    let mut taken = 0;
+    while taken < width {
+        let position = offset + taken;
+        let (digit, bit) = (position / 32, position % 32);
+        let chunk = digits.get(digit).map_or(0, |&digit| digit >> bit) as u128;
+        value |= chunk << taken;
+        taken += 32 - bit;
+    }
+    if width < 128 {
+        value &= (1u128 << width) - 1;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_residue_is_exact() {
+        let m = 1_000_000_007;
+        assert_eq!(integer_residue(-1f64, m), m - 1);
+        assert_eq!(integer_residue(-0f64, m), 0);
+        assert_eq!(integer_residue(2f64.powi(80), m), power_mod(2, 80, m));
+        assert_eq!(
+            integer_residue(-(2f64.powi(60) * 3f64), m),
+            m - multiply_mod(3, power_mod(2, 60, m), m)
+        );
+        assert_eq!(integer_residue(123_456_789_012f64, 1), 0);
+    }
+
+    #[test]
+    fn test_evaluate_mod() {
+        let polynomial = Polynomial::from_ints(vec![7, -3, 0, 5], 'x');
+        for x in 0..20u64 {
+            let exact = 7 - 3 * x as i64 + 5 * (x as i64).pow(3);
+            assert_eq!(polynomial.evaluate_mod(x, 13), exact.rem_euclid(13) as u64);
+        }
+        assert_eq!(Polynomial::new(vec![0f64], 'x').evaluate_mod(5, 7), 0);
+        assert_eq!(polynomial.evaluate_mod(u64::MAX, u64::MAX), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "not an integer")]
+    fn test_evaluate_mod_rejects_fractions() {
+        Polynomial::new(vec![0.5], 'x').evaluate_mod(1, 7);
+    }
+
+    #[test]
+    fn test_crt_reconstruct() {
+        // Coefficients far beyond the range of f64 integers, from primes with a 121-bit product
+        let primes = [2_305_843_009_213_693_951u64, 1_000_000_007, 998_244_353];
+        let expected: Vec<i128> = vec![-(1i128 << 100) + 12_345, 0, 1 << 90, -7];
+        let images: Vec<GfpPolynomial> = primes
+            .iter()
+            .map(|&p| {
+                let coefficients = expected
+                    .iter()
+                    .map(|&c| c.rem_euclid(p as i128) as u64)
+                    .collect();
+                GfpPolynomial::new(coefficients, p)
+            })
+            .collect();
+        assert_eq!(crt_reconstruct(&images), Ok(expected));
+
+        // Images of different lengths: the higher coefficients vanish modulo some primes
+        let images = vec![
+            GfpPolynomial::new(vec![1], 7),
+            GfpPolynomial::new(vec![1, 11], 11),
+        ];
+        assert_eq!(crt_reconstruct(&images), Ok(vec![1]));
+        let zero = vec![GfpPolynomial::new(vec![0], 5)];
+        assert_eq!(crt_reconstruct(&zero), Ok(vec![0]));
+    }
+
+    #[test]
+    fn test_crt_reconstruct_with_primes_near_u64_max() {
+        let primes = [18_446_744_073_709_551_557u64, 18_446_744_073_709_551_533];
+        let expected: Vec<i128> = vec![-1, -(1i128 << 126) + 5, 1 << 120];
+        let images: Vec<GfpPolynomial> = primes
+            .iter()
+            .map(|&p| {
+                let coefficients = expected
+                    .iter()
+                    .map(|&c| c.rem_euclid(p as i128) as u64)
+                    .collect();
+                GfpPolynomial::new(coefficients, p)
+            })
+            .collect();
+
+        assert_eq!(crt_reconstruct(&images), Ok(expected));
+    }
+
+    #[test]
+    fn test_crt_errors() {
+        assert_eq!(crt_reconstruct(&[]), Err(CrtError::NoImages));
+        let repeated = vec![
+            GfpPolynomial::new(vec![1], 7),
+            GfpPolynomial::new(vec![2], 7),
+        ];
+        assert_eq!(
+            crt_reconstruct(&repeated),
+            Err(CrtError::RepeatedPrime { prime: 7 })
+        );
+        let overflowing: Vec<GfpPolynomial> = [
+            2_305_843_009_213_693_951u64,
+            1_000_000_007,
+            998_244_353,
+            2_147_483_647,
+        ]
+        .iter()
+        .map(|&p| GfpPolynomial::new(vec![1], p))
+        .collect();
+        assert_eq!(
+            crt_reconstruct(&overflowing),
+            Err(CrtError::ModulusOverflow)
+        );
+    }
+
+    #[test]
+    fn test_kronecker_multiply_matches_schoolbook() {
+        let schoolbook = |a: &[i64], b: &[i64]| {
+            let mut product = vec![0i128; a.len() + b.len() - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    product[i + j] += x as i128 * y as i128;
+                }
+            }
+            while product.len() > 1 && product.last() == Some(&0) {
+                product.pop();
+            }
+            product
+        };
+        let cases: Vec<(Vec<i64>, Vec<i64>)> = vec![
+            (vec![1, 2, 3], vec![4, 5]),
+            (vec![-1, 0, 7, -3, 2], vec![0, -5, 1]),
+            (
+                vec![1 << 40, -(1 << 40)],
+                vec![(1 << 40) - 1, 3, -(1 << 41)],
+            ),
+            (
+                (0..40).map(|i| (i * 7919) % 201 - 100).collect(),
+                (0..33).map(|i| 50 - i).collect(),
+            ),
+            (vec![0, 0], vec![3]),
+            (vec![5], vec![-6]),
+        ];
+        for (a, b) in &cases {
+            assert_eq!(kronecker_multiply(a, b), schoolbook(a, b));
+        }
+        assert_eq!(kronecker_multiply(&[], &[1, 2]), vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceed 127 bits")]
+    fn test_kronecker_multiply_overflow() {
+        kronecker_multiply(&[i64::MAX, i64::MAX], &[i64::MAX, i64::MAX]);
+    }
+}
@@ -0,0 +1,204 @@
+//! Sharing identical polynomials behind reference-counted handles.
+//!
+//! Workloads that build many equal subexpressions can intern them in an [`Interner`], which
+//! hands out one [`PolyArc`] per distinct polynomial. Clones of a handle are a reference
+//! count increment, and comparing two handles from the same interner reduces to a pointer
+//! comparison.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::polynomial::Polynomial;
+
+/// A shared, immutable handle to a polynomial.
+///
+/// Equality compares the pointers first and falls back to comparing the polynomials, so
+/// handles that were not interned together still compare by value. Hashing uses
+/// [`content_hash`](Polynomial::content_hash).
+#[derive(Debug, Clone)]
+pub struct PolyArc(Arc<Polynomial>);
+
+impl PolyArc {
+    /// Wraps `polynomial` in a new handle that shares nothing with existing ones
+    pub fn new(polynomial: Polynomial) -> PolyArc {
+        PolyArc(Arc::new(polynomial))
+    }
+
+    /// Returns `true` if both handles point at the same allocation
+    pub fn ptr_eq(a: &PolyArc, b: &PolyArc) -> bool {
+        Arc::ptr_eq(&a.0, &b.0)
+    }
+
+    /// Returns the polynomial, cloning it only if other handles still share it
+    pub fn into_inner(self) -> Polynomial {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl Deref for PolyArc {
+    type Target = Polynomial;
+
+    fn deref(&self) -> &Polynomial {
+        &self.0
+    }
+}
+
+impl PartialEq for PolyArc {
+    fn eq(&self, other: &PolyArc) -> bool {
+        PolyArc::ptr_eq(self, other) || *self.0 == *other.0
+    }
+}
+
+impl Hash for PolyArc {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash().hash(state);
+    }
+}
+
+impl From<Polynomial> for PolyArc {
+    fn from(polynomial: Polynomial) -> PolyArc {
+        PolyArc::new(polynomial)
+    }
+}
+
+/// A table that deduplicates polynomials into shared [`PolyArc`] handles.
+///
+/// Two polynomials are interned to the same handle only if they are identical: the same
+/// indeterminate and bit-for-bit equal coefficients, so `0.0` and `-0.0` or differently
+/// encoded NaNs stay distinct.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    buckets: HashMap<u64, Vec<PolyArc>>,
+    len: usize,
+}
+
+impl Interner {
+    /// Returns an empty interner
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Returns the shared handle for `polynomial`, adding it to the table if no identical
+    /// polynomial has been interned
+    /// # Example
+    /// ```
+    /// use polynom::intern::{Interner, PolyArc};
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let mut interner = Interner::new();
+    /// let a = interner.intern(Polynomial::new(vec![1f64, 2f64], 'x'));
+    /// let b = interner.intern(Polynomial::new(vec![1f64, 2f64], 'x'));
+    /// assert!(PolyArc::ptr_eq(&a, &b));
+    /// assert_eq!(interner.len(), 1);
+    /// assert_eq!(a.evaluate_at(1f64), 3f64);
+    /// ```
+    pub fn intern(&mut self, polynomial: Polynomial) -> PolyArc {
+        if let Some(handle) = self.get(&polynomial) {
+            return handle;
+        }
+        let handle = PolyArc::new(polynomial);
+        self.buckets
+            .entry(handle.content_hash())
+            .or_default()
+            .push(handle.clone());
+        self.len += 1;
+
+        handle
+    }
+
+    /// Returns the handle of an interned polynomial identical to `polynomial`, if any
+    pub fn get(&self, polynomial: &Polynomial) -> Option<PolyArc> {
+        self.buckets
+            .get(&polynomial.content_hash())?
+            .iter()
+            .find(|handle| identical(handle, polynomial))
+            .cloned()
+    }
+
+    /// Returns the number of distinct polynomials in the table
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been interned
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Drops every polynomial no longer referenced outside the table, returning how many
+    /// were removed
+    /// # Example
+    /// ```
+    /// use polynom::intern::Interner;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let mut interner = Interner::new();
+    /// let kept = interner.intern(Polynomial::new(vec![1f64], 'x'));
+    /// interner.intern(Polynomial::new(vec![2f64], 'x'));
+    /// assert_eq!(interner.purge(), 1);
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    pub fn purge(&mut self) -> usize {
+        let before = self.len;
+        self.buckets.retain(|_, bucket| {
+            bucket.retain(|handle| Arc::strong_count(&handle.0) > 1);
+            !bucket.is_empty()
+        });
+        self.len = self.buckets.values().map(Vec::len).sum();
+
+        before - self.len
+    }
+}
+
+/// Returns `true` if the polynomials have the same indeterminate and bit-identical
+/// coefficients.
+fn identical(a: &Polynomial, b: &Polynomial) -> bool {
+    a.indeterminate == b.indeterminate
+        && a.coefficients.len() == b.coefficients.len()
+        && a.coefficients
+            .iter()
+            .zip(&b.coefficients)
+            .all(|(x, y)| x.to_bits() == y.to_bits())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning_is_exact() {
+        let mut interner = Interner::new();
+        let a = interner.intern(Polynomial::new(vec![0f64, 1f64], 'x'));
+        let negative_zero = interner.intern(Polynomial::new(vec![-0f64, 1f64], 'x'));
+        let other_indeterminate = interner.intern(Polynomial::new(vec![0f64, 1f64], 't'));
+        let nan = interner.intern(Polynomial::new(vec![f64::NAN, 1f64], 'x'));
+        let nan_again = interner.intern(Polynomial::new(vec![f64::NAN, 1f64], 'x'));
+        assert_eq!(interner.len(), 4);
+
+        // Bucketed together by content hash, but kept apart
+        assert!(!PolyArc::ptr_eq(&a, &negative_zero));
+        assert_eq!(a, negative_zero);
+        assert!(!PolyArc::ptr_eq(&a, &other_indeterminate));
+        // Pointer equality holds even where value equality does not
+        assert!(PolyArc::ptr_eq(&nan, &nan_again));
+        assert_eq!(nan, nan_again);
+        assert_ne!(PolyArc::new((*nan).clone()), nan);
+    }
+
+    #[test]
+    fn test_purge_and_into_inner() {
+        let mut interner = Interner::new();
+        let handle = interner.intern(Polynomial::new(vec![3f64, 4f64], 'x'));
+        assert_eq!(interner.purge(), 0);
+        assert_eq!(handle.clone().into_inner().coefficients, vec![3f64, 4f64]);
+
+        drop(handle);
+        assert_eq!(interner.purge(), 1);
+        assert!(interner.is_empty());
+        assert!(interner
+            .get(&Polynomial::new(vec![3f64, 4f64], 'x'))
+            .is_none());
+    }
+}
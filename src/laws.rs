@@ -0,0 +1,240 @@
+//! Reusable checks of the ring laws for any [`PolynomialOps`] implementation.
+//!
+//! Each check evaluates both sides of its laws on the given operands and compares them with
+//! [`PolynomialOps::approx_eq_poly`], so floating-point representations can allow for
+//! rounding. They are intended to be called from property tests with generated operands.
+
+use std::fmt;
+
+use crate::ops::PolynomialOps;
+use crate::tolerance::Tolerance;
+
+/// A ring law that did not hold for the operands checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LawViolation {
+    /// `(a + b) + c != a + (b + c)`
+    AdditiveAssociativity,
+    /// `a + b != b + a`
+    AdditiveCommutativity,
+    /// `a + 0 != a`
+    AdditiveIdentity,
+    /// `(a - b) + b != a`
+    AdditiveInverse,
+    /// `(a b) c != a (b c)`
+    MultiplicativeAssociativity,
+    /// `a b != b a`
+    MultiplicativeCommutativity,
+    /// `a 1 != a`
+    MultiplicativeIdentity,
+    /// `a (b + c) != a b + a c`
+    Distributivity,
+}
+
+impl fmt::Display for LawViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let law = match self {
+            LawViolation::AdditiveAssociativity => "addition is not associative",
+            LawViolation::AdditiveCommutativity => "addition is not commutative",
+            LawViolation::AdditiveIdentity => "zero is not an additive identity",
+            LawViolation::AdditiveInverse => "subtraction does not invert addition",
+            LawViolation::MultiplicativeAssociativity => "multiplication is not associative",
+            LawViolation::MultiplicativeCommutativity => "multiplication is not commutative",
+            LawViolation::MultiplicativeIdentity => "one is not a multiplicative identity",
+            LawViolation::Distributivity => "multiplication does not distribute over addition",
+        };
+        write!(f, "{}", law)
+    }
+}
+
+impl std::error::Error for LawViolation {}
+
+fn check<T: PolynomialOps>(
+    left: T,
+    right: T,
+    tolerance: &Tolerance,
+    law: LawViolation,
+) -> Result<(), LawViolation> {
+    if left.approx_eq_poly(&right, tolerance) {
+        Ok(())
+    } else {
+        Err(law)
+    }
+}
+
+/// Checks that addition is associative and commutative, that zero is its identity and that
+/// subtraction inverts it
+pub fn check_additive_laws<T: PolynomialOps>(
+    a: &T,
+    b: &T,
+    c: &T,
+    tolerance: &Tolerance,
+) -> Result<(), LawViolation> {
+    check(
+        a.add_poly(b).add_poly(c),
+        a.add_poly(&b.add_poly(c)),
+        tolerance,
+        LawViolation::AdditiveAssociativity,
+    )?;
+    check(
+        a.add_poly(b),
+        b.add_poly(a),
+        tolerance,
+        LawViolation::AdditiveCommutativity,
+    )?;
+    check(
+        a.add_poly(&a.zero_like()),
+        a.clone(),
+        tolerance,
+        LawViolation::AdditiveIdentity,
+    )?;
+    check(
+        a.sub_poly(b).add_poly(b),
+        a.clone(),
+        tolerance,
+        LawViolation::AdditiveInverse,
+    )
+}
+
+/// Checks that multiplication is associative and commutative and that one is its identity
+pub fn check_multiplicative_laws<T: PolynomialOps>(
+    a: &T,
+    b: &T,
+    c: &T,
+    tolerance: &Tolerance,
+) -> Result<(), LawViolation> {
+    check(
+        a.mul_poly(b).mul_poly(c),
+        a.mul_poly(&b.mul_poly(c)),
+        tolerance,
+        LawViolation::MultiplicativeAssociativity,
+    )?;
+    check(
+        a.mul_poly(b),
+        b.mul_poly(a),
+        tolerance,
+        LawViolation::MultiplicativeCommutativity,
+    )?;
+    check(
+        a.mul_poly(&a.one_like()),
+        a.clone(),
+        tolerance,
+        LawViolation::MultiplicativeIdentity,
+    )
+}
+
+/// Checks that multiplication distributes over addition
+pub fn check_distributivity<T: PolynomialOps>(
+    a: &T,
+    b: &T,
+    c: &T,
+    tolerance: &Tolerance,
+) -> Result<(), LawViolation> {
+    check(
+        a.mul_poly(&b.add_poly(c)),
+        a.mul_poly(b).add_poly(&a.mul_poly(c)),
+        tolerance,
+        LawViolation::Distributivity,
+    )
+}
+
+/// Checks every commutative ring axiom on `a`, `b` and `c`, returning the first that fails
+/// # Example
+/// ```
+/// use polynom::gfp::GfpPolynomial;
+/// use polynom::laws::check_ring_axioms;
+/// use polynom::polynomial::Polynomial;
+/// use polynom::tolerance::Tolerance;
+///
+/// let a = Polynomial::new(vec![0.1, 0.2, 0.3], 'x');
+/// let b = Polynomial::new(vec![1f64 / 3f64, -7f64], 'x');
+/// let c = Polynomial::new(vec![1e3, 0f64, 0f64, 2.5], 'x');
+/// assert_eq!(check_ring_axioms(&a, &b, &c, &Tolerance::ulps(16f64)), Ok(()));
+///
+/// let a = GfpPolynomial::new(vec![3, 1, 4], 7);
+/// let b = GfpPolynomial::new(vec![1, 5], 7);
+/// let c = GfpPolynomial::new(vec![6, 6, 6, 6], 7);
+/// assert_eq!(check_ring_axioms(&a, &b, &c, &Tolerance::EXACT), Ok(()));
+/// ```
+pub fn check_ring_axioms<T: PolynomialOps>(
+    a: &T,
+    b: &T,
+    c: &T,
+    tolerance: &Tolerance,
+) -> Result<(), LawViolation> {
+    check_additive_laws(a, b, c, tolerance)?;
+    check_multiplicative_laws(a, b, c, tolerance)?;
+    check_distributivity(a, b, c, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::Polynomial;
+    use crate::random::SplitMix64;
+    use crate::test_polynomials;
+
+    /// Multiplication that drops the cross terms, so only the identity laws hold.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Pointwise(Polynomial);
+
+    impl PolynomialOps for Pointwise {
+        fn zero_like(&self) -> Pointwise {
+            Pointwise(self.0.zero_like())
+        }
+
+        fn one_like(&self) -> Pointwise {
+            Pointwise(Polynomial::new(vec![1f64; self.0.coefficients.len()], 'x'))
+        }
+
+        fn add_poly(&self, other: &Pointwise) -> Pointwise {
+            Pointwise(self.0.add_poly(&other.0))
+        }
+
+        fn sub_poly(&self, other: &Pointwise) -> Pointwise {
+            Pointwise(self.0.sub_poly(&other.0))
+        }
+
+        fn mul_poly(&self, other: &Pointwise) -> Pointwise {
+            let coefficients = self
+                .0
+                .coefficients
+                .iter()
+                .zip(&other.0.coefficients)
+                .map(|(a, b)| a * b + 1f64)
+                .collect();
+            Pointwise(Polynomial::new(coefficients, 'x'))
+        }
+
+        fn approx_eq_poly(&self, other: &Pointwise, tolerance: &Tolerance) -> bool {
+            self.0.approx_eq_poly(&other.0, tolerance)
+        }
+    }
+
+    #[test]
+    fn test_random_polynomials_satisfy_ring_axioms() {
+        let mut rng = SplitMix64::new(17);
+        let tolerance = Tolerance::ulps(64f64);
+        for degree in 0..8 {
+            let a = test_polynomials::random(degree, &mut rng);
+            let b = test_polynomials::random(degree + 1, &mut rng);
+            let c = test_polynomials::random(degree / 2, &mut rng);
+            assert_eq!(check_ring_axioms(&a, &b, &c, &tolerance), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_violations_are_reported() {
+        let a = Pointwise(Polynomial::new(vec![1f64, 2f64], 'x'));
+        let b = Pointwise(Polynomial::new(vec![3f64, 4f64], 'x'));
+        let c = Pointwise(Polynomial::new(vec![5f64, 6f64], 'x'));
+        assert_eq!(check_additive_laws(&a, &b, &c, &Tolerance::EXACT), Ok(()));
+        assert_eq!(
+            check_multiplicative_laws(&a, &b, &c, &Tolerance::EXACT),
+            Err(LawViolation::MultiplicativeAssociativity)
+        );
+        assert_eq!(
+            check_distributivity(&a, &b, &c, &Tolerance::EXACT),
+            Err(LawViolation::Distributivity)
+        );
+    }
+}
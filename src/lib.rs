@@ -1 +1,185 @@
-pub mod polynomial;
\ No newline at end of file
+//! Polynomial arithmetic, root finding, fitting and finite-field polynomials.
+//!
+//! # Thread safety
+//!
+//! Every public type is `Send + Sync`; the test suite asserts this statically. Types that
+//! cache results, such as [`memoize::MemoizedPolynomial`], only update the cache through
+//! `&mut self`, so they need no internal locking: share them read-only across threads, or
+//! give each worker thread (for example a rayon worker) its own copy. Routines that need
+//! scratch space allocate it per call or take it as a `&mut` argument, so concurrent calls
+//! never share buffers.
+
+#[macro_use]
+mod trace;
+
+pub mod algebraic;
+pub mod archive;
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+pub mod barycentric;
+pub mod basins;
+pub mod batch;
+mod bigint;
+pub mod bounded;
+pub mod canonical;
+pub mod cast;
+pub mod complex;
+pub mod composed;
+pub mod compress;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+pub mod diff;
+pub mod display;
+pub mod economize;
+pub mod exercises;
+pub mod explain;
+pub mod families;
+pub mod fit;
+pub mod fixed;
+pub mod gf2;
+pub mod gfp;
+pub mod hashing;
+pub mod integer;
+pub mod intern;
+pub mod laws;
+mod linalg;
+pub mod lut;
+pub mod memoize;
+pub mod nodes;
+pub mod norms;
+mod number_theory;
+pub mod operators;
+pub mod ops;
+#[cfg(any(test, feature = "oracle"))]
+pub mod oracle;
+pub mod orthogonal;
+pub mod param;
+pub mod parse;
+pub mod piecewise;
+pub mod polynomial;
+pub mod prelude;
+pub mod qanalog;
+pub mod random;
+pub mod rational;
+pub mod rational_function;
+pub mod reciprocal;
+pub mod ring;
+pub mod roots;
+#[cfg(feature = "schemars")]
+mod schema;
+pub mod sequences;
+pub mod shared;
+pub mod smoothing;
+mod stats;
+pub mod summary;
+pub mod test_polynomials;
+pub mod tolerance;
+#[cfg(feature = "uom")]
+pub mod units;
+pub mod window;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_public_types_are_send_and_sync() {
+        assert_send_sync::<algebraic::AlgebraicError>();
+        assert_send_sync::<algebraic::AlgebraicNumber>();
+        assert_send_sync::<algebraic::DyadicInterval>();
+        assert_send_sync::<archive::Archive>();
+        assert_send_sync::<archive::ArchiveEntry>();
+        assert_send_sync::<archive::ArchiveError>();
+        assert_send_sync::<barycentric::BarycentricInterpolant>();
+        assert_send_sync::<barycentric::InterpolationError>();
+        assert_send_sync::<basins::Basin>();
+        assert_send_sync::<basins::BasinMap>();
+        assert_send_sync::<batch::BatchError>();
+        assert_send_sync::<batch::CpuBackend>();
+        assert_send_sync::<batch::PackedPolynomials>();
+        assert_send_sync::<bounded::BoundedError>();
+        assert_send_sync::<bounded::BoundedPolynomial>();
+        assert_send_sync::<canonical::CanonicalReport>();
+        assert_send_sync::<cast::TypedPolynomial<f64>>();
+        assert_send_sync::<complex::Complex>();
+        assert_send_sync::<complex::ComplexGrid>();
+        assert_send_sync::<complex::Viewport>();
+        assert_send_sync::<composed::ComposedPoly>();
+        assert_send_sync::<compress::CompressError>();
+        assert_send_sync::<compress::EncodedBatch>();
+        assert_send_sync::<diff::DiffReport>();
+        assert_send_sync::<diff::TermDiff>();
+        assert_send_sync::<display::FormatOptions>();
+        assert_send_sync::<exercises::Exercise>();
+        assert_send_sync::<exercises::LinearFactor>();
+        assert_send_sync::<explain::Explained<polynomial::Polynomial>>();
+        assert_send_sync::<explain::Step>();
+        assert_send_sync::<families::Family>();
+        assert_send_sync::<fit::ChebyshevFit>();
+        assert_send_sync::<fit::DomainMap>();
+        assert_send_sync::<fit::Fit>();
+        assert_send_sync::<fit::Constraint>();
+        assert_send_sync::<fit::FitError>();
+        assert_send_sync::<fit::OnlineFitter>();
+        assert_send_sync::<fit::Prediction>();
+        assert_send_sync::<fixed::FixedPolynomial<4>>();
+        assert_send_sync::<gf2::Gf2Polynomial>();
+        assert_send_sync::<gf2::Gf2mField>();
+        assert_send_sync::<gf2::Lfsr>();
+        assert_send_sync::<gfp::GfpPolynomial>();
+        assert_send_sync::<gfp::SqrtError>();
+        assert_send_sync::<hashing::RollingHash>();
+        assert_send_sync::<integer::CrtError>();
+        assert_send_sync::<intern::Interner>();
+        assert_send_sync::<intern::PolyArc>();
+        assert_send_sync::<laws::LawViolation>();
+        assert_send_sync::<lut::LookupTable>();
+        assert_send_sync::<memoize::MemoizedPolynomial>();
+        assert_send_sync::<operators::Operator>();
+        assert_send_sync::<oracle::AccuracyReport>();
+        assert_send_sync::<oracle::DoubleDouble>();
+        assert_send_sync::<orthogonal::OrthogonalizeError>();
+        assert_send_sync::<orthogonal::Weight>();
+        assert_send_sync::<param::LinearExpr>();
+        assert_send_sync::<param::ParamError>();
+        assert_send_sync::<param::ParamPolynomial>();
+        assert_send_sync::<parse::ParseError>();
+        assert_send_sync::<piecewise::PiecewisePolynomial>();
+        assert_send_sync::<polynomial::Polynomial>();
+        assert_send_sync::<polynomial::Powers>();
+        assert_send_sync::<random::SplitMix64>();
+        assert_send_sync::<rational::Rational>();
+        assert_send_sync::<rational_function::RationalFunction>();
+        assert_send_sync::<ring::Dual>();
+        assert_send_sync::<ring::Jet<3>>();
+        assert_send_sync::<roots::Diagnostics>();
+        assert_send_sync::<roots::Reconstruction>();
+        assert_send_sync::<roots::RootError>();
+        assert_send_sync::<shared::SharedPolynomial>();
+        assert_send_sync::<summary::RangeSummary>();
+        assert_send_sync::<tolerance::NotAFactor>();
+        assert_send_sync::<tolerance::Tolerance>();
+        assert_send_sync::<window::WindowTransform>();
+    }
+
+    #[cfg(feature = "bumpalo")]
+    #[test]
+    fn test_arena_types_are_send_and_sync() {
+        assert_send_sync::<arena::ArenaPolynomial<'static>>();
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_types_are_send_and_sync() {
+        assert_send_sync::<decimal::DecimalPolynomial>();
+    }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn test_unit_types_are_send_and_sync() {
+        use uom::si::f64::{Length, Time};
+        assert_send_sync::<units::UnitPolynomial<Time, Length>>();
+    }
+}
@@ -0,0 +1,218 @@
+//! Small dense linear-algebra helpers shared by the fitting routines.
+
+#![allow(clippy::needless_range_loop)]
+
+/// Solves the least-squares problem `a * x ≈ b` using Householder QR.
+///
+/// Returns the solution together with the square upper-triangular factor `R`,
+/// or `None` if `a` has fewer rows than columns or is (numerically) rank deficient.
+pub(crate) fn least_squares(a: &[Vec<f64>], b: &[f64]) -> Option<(Vec<f64>, Vec<Vec<f64>>)> {
+    let rows = a.len();
+    let cols = a.first().map_or(0, |row| row.len());
+    if cols == 0 || rows < cols || b.len() != rows {
+        return None;
+    }
+
+    let mut r: Vec<Vec<f64>> = a.to_vec();
+    let mut qtb = b.to_vec();
+
+    for k in 0..cols {
+        let norm = (k..rows).map(|i| r[i][k] * r[i][k]).sum::<f64>().sqrt();
+        if norm == 0f64 {
+            return None;
+        }
+        let alpha = if r[k][k] > 0f64 { -norm } else { norm };
+
+        // Householder vector v = x - alpha * e1
+        let mut v: Vec<f64> = (k..rows).map(|i| r[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm_sq: f64 = v.iter().map(|x| x * x).sum();
+
+        for j in k..cols {
+            let dot: f64 = (k..rows).map(|i| v[i - k] * r[i][j]).sum();
+            let factor = 2f64 * dot / v_norm_sq;
+            for i in k..rows {
+                r[i][j] -= factor * v[i - k];
+            }
+        }
+
+        let dot: f64 = (k..rows).map(|i| v[i - k] * qtb[i]).sum();
+        let factor = 2f64 * dot / v_norm_sq;
+        for i in k..rows {
+            qtb[i] -= factor * v[i - k];
+        }
+    }
+
+    let max_diagonal = (0..cols).map(|i| r[i][i].abs()).fold(0f64, f64::max);
+    let threshold = max_diagonal * f64::EPSILON * rows as f64;
+    if (0..cols).any(|i| r[i][i].abs() <= threshold) {
+        return None;
+    }
+
+    let mut solution = vec![0f64; cols];
+    for i in (0..cols).rev() {
+        let tail: f64 = (i + 1..cols).map(|j| r[i][j] * solution[j]).sum();
+        solution[i] = (qtb[i] - tail) / r[i][i];
+    }
+
+    let upper = r
+        .into_iter()
+        .take(cols)
+        .enumerate()
+        .map(|(i, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(|(j, value)| if j < i { 0f64 } else { value })
+                .collect()
+        })
+        .collect();
+
+    Some((solution, upper))
+}
+
+/// Inverts a square, non-singular upper-triangular matrix.
+pub(crate) fn invert_upper_triangular(r: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = r.len();
+    let mut inverse = vec![vec![0f64; n]; n];
+    for i in (0..n).rev() {
+        inverse[i][i] = 1f64 / r[i][i];
+        for j in i + 1..n {
+            let sum: f64 = (i + 1..=j).map(|k| r[i][k] * inverse[k][j]).sum();
+            inverse[i][j] = -sum / r[i][i];
+        }
+    }
+
+    inverse
+}
+
+/// Computes the product `a * b` of two matrices.
+pub(crate) fn multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let inner = b.len();
+    let cols = b.first().map_or(0, |row| row.len());
+    a.iter()
+        .map(|row| {
+            (0..cols)
+                .map(|j| (0..inner).map(|k| row[k] * b[k][j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+/// Computes `m * m^T` for a square matrix.
+pub(crate) fn multiply_by_transpose(m: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = m.len();
+    let mut product = vec![vec![0f64; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            product[i][j] = (0..n).map(|k| m[i][k] * m[j][k]).sum();
+        }
+    }
+
+    product
+}
+
+/// Lovász constant of the LLL reduction.
+const LLL_DELTA: f64 = 0.75;
+
+/// Upper bound on LLL swaps, guarding against cycling from rounding.
+const LLL_MAX_SWAPS: usize = 10_000;
+
+/// Reduces the rows of `basis` in place with the Lenstra–Lenstra–Lovász algorithm, so the
+/// first rows are short vectors of the lattice they span.
+///
+/// Size reduction only ever adds integer multiples of rows to each other, so columns that
+/// start out integral stay integral.
+pub(crate) fn lll_reduce(basis: &mut [Vec<f64>]) {
+    let n = basis.len();
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+    let mut k = 1;
+    let mut swaps = 0;
+    while k < n && swaps < LLL_MAX_SWAPS {
+        // Gram-Schmidt orthogonalization of the current basis
+        let mut orthogonal: Vec<Vec<f64>> = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut vector = basis[i].clone();
+            for o in &orthogonal {
+                let mu = dot(&basis[i], o) / dot(o, o);
+                for (v, x) in vector.iter_mut().zip(o) {
+                    *v -= mu * x;
+                }
+            }
+            orthogonal.push(vector);
+        }
+
+        for j in (0..k).rev() {
+            let q = (dot(&basis[k], &orthogonal[j]) / dot(&orthogonal[j], &orthogonal[j])).round();
+            if q != 0f64 {
+                let row = basis[j].clone();
+                for (v, x) in basis[k].iter_mut().zip(&row) {
+                    *v -= q * x;
+                }
+            }
+        }
+
+        let mu = dot(&basis[k], &orthogonal[k - 1]) / dot(&orthogonal[k - 1], &orthogonal[k - 1]);
+        let lower = dot(&orthogonal[k], &orthogonal[k]);
+        let upper = dot(&orthogonal[k - 1], &orthogonal[k - 1]);
+        if lower >= (LLL_DELTA - mu * mu) * upper {
+            k += 1;
+        } else {
+            basis.swap(k, k - 1);
+            k = (k - 1).max(1);
+            swaps += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_squares_square_system() {
+        let a = vec![vec![2f64, 1f64], vec![1f64, 3f64]];
+        let (solution, _) = least_squares(&a, &[3f64, 5f64]).unwrap();
+
+        assert!((solution[0] - 0.8).abs() < 1e-12);
+        assert!((solution[1] - 1.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        let a = vec![vec![1f64, 2f64], vec![2f64, 4f64], vec![3f64, 6f64]];
+
+        assert!(least_squares(&a, &[1f64, 2f64, 3f64]).is_none());
+    }
+
+    #[test]
+    fn test_invert_upper_triangular() {
+        let r = vec![vec![2f64, 4f64], vec![0f64, 5f64]];
+        let inverse = invert_upper_triangular(&r);
+
+        assert_eq!(inverse, vec![vec![0.5, -0.4], vec![0f64, 0.2]]);
+    }
+
+    #[test]
+    fn test_lll_reduce_finds_short_vectors() {
+        let mut basis = vec![
+            vec![1f64, 1f64, 1f64],
+            vec![-1f64, 0f64, 2f64],
+            vec![3f64, 5f64, 6f64],
+        ];
+        lll_reduce(&mut basis);
+
+        // The reduced basis is (0, 1, 0), (1, 0, 1), (-1, 0, 2) up to order and sign
+        let mut norms: Vec<f64> = basis
+            .iter()
+            .map(|v| v.iter().map(|x| x * x).sum())
+            .collect();
+        norms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(norms, vec![1f64, 2f64, 5f64]);
+
+        let determinant = basis[0][0] * (basis[1][1] * basis[2][2] - basis[1][2] * basis[2][1])
+            - basis[0][1] * (basis[1][0] * basis[2][2] - basis[1][2] * basis[2][0])
+            + basis[0][2] * (basis[1][0] * basis[2][1] - basis[1][1] * basis[2][0]);
+        assert_eq!(determinant.abs(), 3f64);
+    }
+}
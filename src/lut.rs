@@ -0,0 +1,101 @@
+//! Baking polynomials into lookup tables for deployment without per-sample evaluation.
+
+use crate::polynomial::Polynomial;
+
+/// Samples of a polynomial at evenly spaced points, read back by linear interpolation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookupTable {
+    /// The interval covered by the table; `values[0]` is at `range.0` and the last value at
+    /// `range.1`.
+    pub range: (f64, f64),
+    /// The polynomial sampled at evenly spaced points across `range`.
+    pub values: Vec<f64>,
+    /// An upper bound on `|p(x) - table.evaluate_at(x)|` for `x` in `range`, ignoring
+    /// rounding.
+    pub error_bound: f64,
+}
+
+impl LookupTable {
+    /// Returns the linearly interpolated value at `x`, clamped to the table's range
+    pub fn evaluate_at(&self, x: f64) -> f64 {
+        let last = self.values.len() - 1;
+        let position = (x - self.range.0) / (self.range.1 - self.range.0) * last as f64;
+        let position = position.clamp(0f64, last as f64);
+        let index = (position as usize).min(last - 1);
+        let fraction = position - index as f64;
+
+        self.values[index] + fraction * (self.values[index + 1] - self.values[index])
+    }
+}
+
+impl Polynomial {
+    /// Samples the polynomial at `entries` evenly spaced points covering `range`.
+    ///
+    /// The table's [`error_bound`](LookupTable::error_bound) is the linear interpolation
+    /// bound `h^2 / 8 · max |p''|` over the range, where `h` is the spacing between entries.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let table = polynomial.bake_lut((0f64, 1f64), 11);
+    /// assert_eq!(table.values.len(), 11);
+    /// assert!((table.error_bound - 0.0025).abs() < 1e-15);
+    /// assert!((table.evaluate_at(0.52) - 0.272).abs() < 1e-15);
+    /// assert!((table.evaluate_at(0.52) - polynomial.evaluate_at(0.52)).abs() <= table.error_bound);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `entries` is less than 2 or the range is empty or reversed.
+    pub fn bake_lut(&self, range: (f64, f64), entries: usize) -> LookupTable {
+        assert!(entries >= 2, "a lookup table needs at least two entries");
+        assert!(range.0 < range.1, "range must be nonempty");
+        let step = (range.1 - range.0) / (entries - 1) as f64;
+        let values = (0..entries)
+            .map(|i| {
+                let x = if i == entries - 1 {
+                    range.1
+                } else {
+                    range.0 + i as f64 * step
+                };
+                self.evaluate_at(x)
+            })
+            .collect();
+        let curvature = self.derivative().derivative().sup_norm_on(range.0, range.1);
+
+        LookupTable {
+            range,
+            values,
+            error_bound: step * step / 8f64 * curvature,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_error_bound_holds() {
+        let polynomial = test_polynomials::chebyshev(5);
+        let table = polynomial.bake_lut((-1f64, 1f64), 65);
+        let worst = (0..=1000)
+            .map(|i| -1f64 + i as f64 * 0.002)
+            .map(|x| (table.evaluate_at(x) - polynomial.evaluate_at(x)).abs())
+            .fold(0f64, f64::max);
+
+        assert!(worst <= table.error_bound);
+        assert!(worst > table.error_bound / 4f64);
+    }
+
+    #[test]
+    fn test_linear_polynomials_are_exact() {
+        let polynomial = Polynomial::new(vec![2f64, -3f64], 'x');
+        let table = polynomial.bake_lut((-2f64, 5f64), 2);
+        assert_eq!(table.error_bound, 0f64);
+        assert_eq!(table.values, vec![8f64, -13f64]);
+        assert_eq!(table.evaluate_at(-10f64), 8f64);
+        assert_eq!(table.evaluate_at(5f64), -13f64);
+    }
+}
@@ -0,0 +1,151 @@
+//! Caching of repeated polynomial evaluations.
+
+use std::collections::HashMap;
+
+use crate::polynomial::Polynomial;
+
+/// A `Polynomial` wrapper that remembers its most recent evaluations.
+///
+/// Useful when the same polynomial is queried repeatedly at a small set of points. When the
+/// cache is full, the least recently used point is evicted.
+#[derive(Debug)]
+pub struct MemoizedPolynomial {
+    polynomial: Polynomial,
+    capacity: usize,
+    cache: HashMap<u64, (f64, u64)>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl MemoizedPolynomial {
+    /// Wraps `polynomial`, caching up to `capacity` evaluations.
+    /// # Example
+    /// ```
+    /// use polynom::memoize::MemoizedPolynomial;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let mut memoized = MemoizedPolynomial::new(Polynomial::new(vec![1f64, 2f64], 'x'), 16);
+    /// assert_eq!(memoized.evaluate_at(3f64), 7f64);
+    /// assert_eq!(memoized.evaluate_at(3f64), 7f64);
+    /// assert_eq!(memoized.hits(), 1);
+    /// ```
+    pub fn new(polynomial: Polynomial, capacity: usize) -> MemoizedPolynomial {
+        MemoizedPolynomial {
+            polynomial,
+            capacity,
+            cache: HashMap::with_capacity(capacity),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the value at `determinate`, from the cache if it was evaluated recently.
+    pub fn evaluate_at(&mut self, determinate: f64) -> f64 {
+        self.clock += 1;
+        // Key on the bit pattern, so that -0.0 and 0.0 or different NaNs are cached separately
+        let key = determinate.to_bits();
+        if let Some(entry) = self.cache.get_mut(&key) {
+            entry.1 = self.clock;
+            self.hits += 1;
+            return entry.0;
+        }
+
+        self.misses += 1;
+        let value = self.polynomial.evaluate_at(determinate);
+        if self.capacity == 0 {
+            return value;
+        }
+        if self.cache.len() >= self.capacity {
+            let oldest = self
+                .cache
+                .iter()
+                .min_by_key(|(_, &(_, last_used))| last_used)
+                .map(|(&key, _)| key);
+            if let Some(oldest) = oldest {
+                self.cache.remove(&oldest);
+            }
+        }
+        self.cache.insert(key, (value, self.clock));
+
+        value
+    }
+
+    /// Returns the wrapped polynomial.
+    pub fn polynomial(&self) -> &Polynomial {
+        &self.polynomial
+    }
+
+    /// Unwraps the polynomial, discarding the cache.
+    pub fn into_inner(self) -> Polynomial {
+        self.polynomial
+    }
+
+    /// Returns the maximum number of cached evaluations.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of evaluations answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of evaluations that had to be computed.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Empties the cache and resets the hit and miss counters.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_match_direct_evaluation() {
+        let polynomial = Polynomial::new(vec![-1f64, 2f64, -3f64, 4f64], 'x');
+        let mut memoized =
+            MemoizedPolynomial::new(Polynomial::new(vec![-1f64, 2f64, -3f64, 4f64], 'x'), 4);
+
+        for &x in &[-5f64, 0.5, -5f64, 2f64, 0.5] {
+            assert_eq!(memoized.evaluate_at(x), polynomial.evaluate_at(x));
+        }
+        assert_eq!(memoized.hits(), 2);
+        assert_eq!(memoized.misses(), 3);
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut memoized = MemoizedPolynomial::new(Polynomial::new(vec![0f64, 1f64], 'x'), 2);
+        memoized.evaluate_at(1f64);
+        memoized.evaluate_at(2f64);
+        memoized.evaluate_at(1f64);
+        memoized.evaluate_at(3f64);
+
+        // 2 was least recently used and should have been evicted, 1 should still be cached
+        memoized.evaluate_at(1f64);
+        assert_eq!(memoized.hits(), 2);
+        memoized.evaluate_at(2f64);
+        assert_eq!(memoized.misses(), 4);
+    }
+
+    #[test]
+    fn test_zero_capacity_never_caches() {
+        let mut memoized = MemoizedPolynomial::new(Polynomial::new(vec![1f64], 'x'), 0);
+        memoized.evaluate_at(1f64);
+        memoized.evaluate_at(1f64);
+
+        assert_eq!(memoized.hits(), 0);
+        memoized.clear();
+        assert_eq!(memoized.misses(), 0);
+        assert_eq!(memoized.into_inner().coefficients, vec![1f64]);
+    }
+}
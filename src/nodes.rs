@@ -0,0 +1,148 @@
+//! Point sets for interpolation and evaluation ordering over an interval.
+//!
+//! Chebyshev points cluster towards the ends of the interval, which keeps polynomial
+//! interpolation through them well conditioned. Leja points have similar spacing but form
+//! a sequence, so an interpolant can be extended one node at a time.
+
+use crate::fit::DomainMap;
+
+/// Size of the grid of candidates that Leja points are chosen from, and so the most Leja
+/// points that can be requested.
+pub const LEJA_CANDIDATES: usize = 4097;
+
+/// Returns the `n` Chebyshev points of the first kind on `interval`, in increasing order.
+///
+/// These are the roots of `T_n` mapped from `[-1, 1]`; they do not include the endpoints.
+/// # Example
+/// ```
+/// use polynom::nodes::chebyshev_points_first_kind;
+///
+/// let points = chebyshev_points_first_kind(3, (-1f64, 1f64));
+/// assert!((points[0] + 3f64.sqrt() / 2f64).abs() < 1e-15);
+/// assert_eq!(points[1], 0f64);
+/// assert!((points[2] - 3f64.sqrt() / 2f64).abs() < 1e-15);
+/// ```
+pub fn chebyshev_points_first_kind(n: usize, interval: (f64, f64)) -> Vec<f64> {
+    let map = DomainMap::new(interval.0, interval.1);
+    (0..n)
+        .rev()
+        .map(|k| {
+            // sin form of cos((2k + 1) pi / 2n), exactly symmetric about zero
+            let t = (std::f64::consts::PI * (n as f64 - 1f64 - 2f64 * k as f64)
+                / (2f64 * n as f64))
+                .sin();
+            map.from_unit(t)
+        })
+        .collect()
+}
+
+/// Returns the `n` Chebyshev points of the second kind on `interval`, in increasing order.
+///
+/// These are the extrema of `T_{n-1}` mapped from `[-1, 1]` and include both endpoints;
+/// a single point is the midpoint of the interval.
+/// # Example
+/// ```
+/// use polynom::nodes::chebyshev_points_second_kind;
+///
+/// let points = chebyshev_points_second_kind(5, (0f64, 4f64));
+/// assert_eq!(points[0], 0f64);
+/// assert_eq!(points[2], 2f64);
+/// assert_eq!(points[4], 4f64);
+/// ```
+pub fn chebyshev_points_second_kind(n: usize, interval: (f64, f64)) -> Vec<f64> {
+    let map = DomainMap::new(interval.0, interval.1);
+    if n == 1 {
+        return vec![map.from_unit(0f64)];
+    }
+    (0..n)
+        .rev()
+        .map(|k| {
+            // sin form of cos(k pi / (n - 1)), exactly symmetric about zero
+            let t = (std::f64::consts::PI * (n as f64 - 1f64 - 2f64 * k as f64)
+                / (2f64 * (n as f64 - 1f64)))
+                .sin();
+            map.from_unit(t)
+        })
+        .collect()
+}
+
+/// Returns the first `n` Leja points of `interval`, in sequence order.
+///
+/// The sequence starts at the right endpoint and each further point maximizes the product
+/// of distances to the points before it, so every prefix is itself a well-spread node set.
+/// The maximization is carried out over a fixed grid of [`LEJA_CANDIDATES`] Chebyshev
+/// points of the second kind, so the points lie on that grid and requesting more points
+/// extends the same sequence.
+/// # Example
+/// ```
+/// use polynom::nodes::leja_points;
+///
+/// let points = leja_points(4, (-1f64, 1f64));
+/// assert_eq!(&points[..3], &[1f64, -1f64, 0f64]);
+/// assert!((points[3].abs() - 3f64.sqrt() / 3f64).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+/// Panics if `n` is greater than [`LEJA_CANDIDATES`].
+pub fn leja_points(n: usize, interval: (f64, f64)) -> Vec<f64> {
+    assert!(n <= LEJA_CANDIDATES, "too many Leja points requested");
+    if n == 0 {
+        return Vec::new();
+    }
+    let candidates = chebyshev_points_second_kind(LEJA_CANDIDATES, interval);
+    // Sums of log distances avoid overflow in the products
+    let mut log_distances = vec![0f64; candidates.len()];
+    let mut points = Vec::with_capacity(n);
+    let mut next = candidates.len() - 1;
+    for _ in 0..n {
+        let chosen = candidates[next];
+        points.push(chosen);
+        for (candidate, log_distance) in candidates.iter().zip(log_distances.iter_mut()) {
+            *log_distance += (candidate - chosen).abs().ln();
+        }
+        next = (0..candidates.len()).fold(0, |best, i| {
+            if log_distances[i] > log_distances[best] {
+                i
+            } else {
+                best
+            }
+        });
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_chebyshev_points_are_roots_and_extrema() {
+        let n = 7;
+        let t = test_polynomials::chebyshev(n);
+        for x in chebyshev_points_first_kind(n, (-1f64, 1f64)) {
+            assert!(t.evaluate_at(x).abs() < 1e-13);
+        }
+        for x in chebyshev_points_second_kind(n + 1, (-1f64, 1f64)) {
+            assert!((t.evaluate_at(x).abs() - 1f64).abs() < 1e-13);
+        }
+
+        let points = chebyshev_points_first_kind(6, (2f64, 3f64));
+        assert!(points.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(points[0] > 2f64 && points[5] < 3f64);
+        assert_eq!(chebyshev_points_second_kind(1, (2f64, 3f64)), vec![2.5]);
+        assert!(chebyshev_points_first_kind(0, (2f64, 3f64)).is_empty());
+    }
+
+    #[test]
+    fn test_leja_points_are_distinct_and_nested() {
+        let points = leja_points(12, (0f64, 10f64));
+        assert_eq!(points[0], 10f64);
+        assert_eq!(points[1], 0f64);
+        let mut sorted = points.clone();
+        sorted.sort_by(f64::total_cmp);
+        assert!(sorted.windows(2).all(|pair| pair[1] - pair[0] > 0.1));
+        assert_eq!(&leja_points(5, (0f64, 10f64))[..], &points[..5]);
+    }
+}
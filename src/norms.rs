@@ -0,0 +1,307 @@
+//! Norms of polynomials, the L² inner product, the area between graphs and the maximum
+//! error against a function over an interval.
+
+use crate::polynomial::Polynomial;
+
+/// Number of evenly spaced samples taken by `max_error_against` before refinement.
+const ERROR_SAMPLES: usize = 1000;
+
+/// Number of golden-section steps used to refine each sampled maximum.
+const GOLDEN_SECTION_STEPS: usize = 60;
+
+impl Polynomial {
+    /// Returns the sum of the absolute values of the coefficients
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![3f64, -4f64], 'x');
+    /// assert_eq!(polynomial.norm_l1(), 7f64);
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.coefficients.iter().map(|coeff| coeff.abs()).sum()
+    }
+
+    /// Returns the Euclidean norm of the coefficient vector
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![3f64, -4f64], 'x');
+    /// assert_eq!(polynomial.norm_l2(), 5f64);
+    /// ```
+    pub fn norm_l2(&self) -> f64 {
+        self.coefficients
+            .iter()
+            .map(|coeff| coeff * coeff)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns the largest absolute value among the coefficients
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![3f64, -4f64], 'x');
+    /// assert_eq!(polynomial.norm_linf(), 4f64);
+    /// ```
+    pub fn norm_linf(&self) -> f64 {
+        self.coefficients
+            .iter()
+            .fold(0f64, |largest, coeff| largest.max(coeff.abs()))
+    }
+
+    /// Returns `max |p(x)|` for `x` in the closed interval `[a, b]`.
+    ///
+    /// The maximum is attained at an endpoint or at a critical point, so only those are
+    /// evaluated.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // 1 - x^2 peaks at x = 0 on [-2, 1], but is largest in magnitude at x = -2
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, -1f64], 'x');
+    /// assert_eq!(polynomial.sup_norm_on(-0.5, 1f64), 1f64);
+    /// assert_eq!(polynomial.sup_norm_on(-2f64, 1f64), 3f64);
+    /// ```
+    pub fn sup_norm_on(&self, a: f64, b: f64) -> f64 {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        self.derivative()
+            .real_roots()
+            .into_iter()
+            .filter(|&x| low < x && x < high)
+            .chain(vec![low, high])
+            .map(|x| self.evaluate_at(x).abs())
+            .fold(0f64, f64::max)
+    }
+
+    /// Returns `(error, x)`, the largest `|p(x) - f(x)|` over `interval` and a point where
+    /// it is attained.
+    ///
+    /// The error is sampled at evenly spaced points, and each sampled local maximum is then
+    /// refined by golden-section search between its neighbouring samples. Errors that peak
+    /// between two samples without showing up as a sampled local maximum can be missed.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // 1 + x + x^2 / 2 against exp on [-1, 1] is worst at x = 1
+    /// let taylor = Polynomial::new(vec![1f64, 1f64, 0.5], 'x');
+    /// let (error, x) = taylor.max_error_against(f64::exp, (-1f64, 1f64));
+    /// assert_eq!(x, 1f64);
+    /// assert!((error - (1f64.exp() - 2.5)).abs() < 1e-15);
+    /// ```
+    pub fn max_error_against<F: Fn(f64) -> f64>(&self, f: F, interval: (f64, f64)) -> (f64, f64) {
+        let (low, high) = if interval.0 <= interval.1 {
+            interval
+        } else {
+            (interval.1, interval.0)
+        };
+        let error = |x: f64| (self.evaluate_at(x) - f(x)).abs();
+        let step = (high - low) / ERROR_SAMPLES as f64;
+        let points: Vec<f64> = (0..=ERROR_SAMPLES)
+            .map(|i| {
+                if i == ERROR_SAMPLES {
+                    high
+                } else {
+                    low + i as f64 * step
+                }
+            })
+            .collect();
+        let errors: Vec<f64> = points.iter().map(|&x| error(x)).collect();
+
+        let mut worst = (errors[0], points[0]);
+        for i in 0..points.len() {
+            let left = if i == 0 {
+                f64::NEG_INFINITY
+            } else {
+                errors[i - 1]
+            };
+            let right = errors.get(i + 1).copied().unwrap_or(f64::NEG_INFINITY);
+            if errors[i] < left || errors[i] < right {
+                continue;
+            }
+            let mut candidate = (errors[i], points[i]);
+            if 0 < i && i < points.len() - 1 {
+                let x = golden_section_max(&error, points[i - 1], points[i + 1]);
+                if error(x) > candidate.0 {
+                    candidate = (error(x), x);
+                }
+            }
+            if candidate.0 > worst.0 || worst.0.is_nan() {
+                worst = candidate;
+            }
+        }
+
+        worst
+    }
+
+    /// Returns the L² inner product `∫ p(x) q(x) dx` over `interval`, computed exactly from
+    /// the coefficients of the product.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x and x^2 are orthogonal on [-1, 1], and ∫ x·x dx = 2/3
+    /// let x = Polynomial::new(vec![0f64, 1f64], 'x');
+    /// let x2 = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// assert_eq!(x.inner_product(&x2, (-1f64, 1f64)), 0f64);
+    /// assert!((x.inner_product(&x, (-1f64, 1f64)) - 2f64 / 3f64).abs() < 1e-15);
+    /// ```
+    pub fn inner_product(&self, other: &Polynomial, interval: (f64, f64)) -> f64 {
+        self.multiply(other.clone()).definite_integral(interval)
+    }
+
+    /// Returns the L² norm `sqrt(∫ p(x)^2 dx)` over `interval`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64], 'x');
+    /// assert_eq!(polynomial.norm_l2_on((0f64, 4f64)), 2f64);
+    /// ```
+    pub fn norm_l2_on(&self, interval: (f64, f64)) -> f64 {
+        self.inner_product(self, interval).sqrt()
+    }
+
+    /// Returns the area `∫ |p(x) - q(x)| dx` enclosed between the graphs of `self` and `other`
+    /// over `[a, b]`, in either order of the bounds.
+    ///
+    /// The interval is split at the real crossings of the two graphs, so each piece is
+    /// integrated exactly without the absolute value.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^3 and x cross at -1, 0 and 1, enclosing 1/4 on each side of the origin
+    /// let cubic = Polynomial::new(vec![0f64, 0f64, 0f64, 1f64], 'x');
+    /// let line = Polynomial::new(vec![0f64, 1f64], 'x');
+    /// assert!((cubic.area_between(&line, -1f64, 1f64) - 0.5).abs() < 1e-15);
+    /// ```
+    pub fn area_between(&self, other: &Polynomial, a: f64, b: f64) -> f64 {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        let difference = self.clone() - other.clone();
+        let mut breakpoints = vec![low];
+        breakpoints.extend(
+            difference
+                .real_roots()
+                .into_iter()
+                .filter(|&x| low < x && x < high),
+        );
+        breakpoints.push(high);
+
+        breakpoints
+            .windows(2)
+            .map(|piece| difference.definite_integral((piece[0], piece[1])).abs())
+            .sum()
+    }
+
+    /// Returns `∫ p(x) dx` over `interval`, from the antiderivative evaluated with Horner's scheme.
+    pub(crate) fn definite_integral(&self, interval: (f64, f64)) -> f64 {
+        let antiderivative = |x: f64| {
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .fold(0f64, |sum, (degree, coeff)| {
+                    sum * x + coeff / (degree + 1) as f64
+                })
+                * x
+        };
+
+        antiderivative(interval.1) - antiderivative(interval.0)
+    }
+}
+
+/// Returns the point in `[a, b]` maximizing `g`, assuming `g` is unimodal there.
+fn golden_section_max<G: Fn(f64) -> f64>(g: &G, mut a: f64, mut b: f64) -> f64 {
+    let ratio = (5f64.sqrt() - 1f64) / 2f64;
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut gc, mut gd) = (g(c), g(d));
+    for _ in 0..GOLDEN_SECTION_STEPS {
+        if gc > gd {
+            b = d;
+            d = c;
+            gd = gc;
+            c = b - ratio * (b - a);
+            gc = g(c);
+        } else {
+            a = c;
+            c = d;
+            gc = gd;
+            d = a + ratio * (b - a);
+            gd = g(d);
+        }
+    }
+
+    (a + b) / 2f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_norms_of_zero_polynomial() {
+        let zero = Polynomial::new(vec![0f64], 'x');
+
+        assert_eq!(zero.norm_l1(), 0f64);
+        assert_eq!(zero.norm_l2(), 0f64);
+        assert_eq!(zero.norm_linf(), 0f64);
+        assert_eq!(zero.sup_norm_on(-1f64, 1f64), 0f64);
+        assert_eq!(zero.norm_l2_on((-1f64, 1f64)), 0f64);
+    }
+
+    #[test]
+    fn test_sup_norm_of_chebyshev() {
+        // |T_n| <= 1 on [-1, 1], with equality at the extrema
+        for n in 1..10 {
+            let polynomial = test_polynomials::chebyshev(n);
+            assert!((polynomial.sup_norm_on(-1f64, 1f64) - 1f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inner_product_of_legendre_polynomials() {
+        // P_2 = (3x^2 - 1) / 2 has ∫ P_2^2 = 2/5 on [-1, 1], and is orthogonal to 1
+        let p2 = Polynomial::new(vec![-0.5, 0f64, 1.5], 'x');
+        let one = Polynomial::new(vec![1f64], 'x');
+
+        assert!((p2.inner_product(&p2, (-1f64, 1f64)) - 0.4).abs() < 1e-15);
+        assert!(p2.inner_product(&one, (-1f64, 1f64)).abs() < 1e-15);
+        assert!((p2.definite_integral((0f64, 1f64))).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_area_between_splits_at_crossings() {
+        let parabola = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+        let zero = Polynomial::new(vec![0f64], 'x');
+        // x^2 - 1 changes sign at ±1: 2/3 below the axis on [0, 1], 4/3 above on [1, 2]
+        let shifted = Polynomial::new(vec![-1f64, 0f64, 1f64], 'x');
+        assert!((shifted.area_between(&zero, 0f64, 2f64) - 2f64).abs() < 1e-14);
+        assert!((shifted.area_between(&zero, 2f64, 0f64) - 2f64).abs() < 1e-14);
+
+        assert_eq!(parabola.area_between(&parabola, -3f64, 3f64), 0f64);
+        assert_eq!(parabola.area_between(&zero, 1f64, 1f64), 0f64);
+    }
+
+    #[test]
+    fn test_max_error_is_refined_between_samples() {
+        // x - sin(x) is odd and increasing, so its error is worst at an endpoint...
+        let x = Polynomial::new(vec![0f64, 1f64], 'x');
+        let (error, at) = x.max_error_against(f64::sin, (2f64, -2f64));
+        assert_eq!(at.abs(), 2f64);
+        assert!((error - (2f64 - 2f64.sin())).abs() < 1e-15);
+
+        // ...while a narrow peak between two samples is only found by refinement
+        let zero = Polynomial::new(vec![0f64], 'x');
+        let peak = |t: f64| 1f64 / (1f64 + 1e6 * (t - 0.50037) * (t - 0.50037));
+        let (error, at) = zero.max_error_against(peak, (0f64, 1f64));
+        assert!(peak(0.5) < 0.9);
+        assert!((error - 1f64).abs() < 1e-12);
+        assert!((at - 0.50037).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,154 @@
+//! Modular integer arithmetic shared by the finite-field and hashing modules.
+
+pub(crate) fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + b as u128) % modulus as u128) as u64
+}
+
+pub(crate) fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    add_mod(a, modulus - b % modulus, modulus)
+}
+
+pub(crate) fn multiply_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+pub(crate) fn power_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply_mod(result, base, modulus);
+        }
+        base = multiply_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+pub(crate) fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Deterministic Miller–Rabin test, exact for every `u64`.
+pub(crate) fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &p in &WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let mut d = n - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    'witness: for &a in &WITNESSES {
+        let mut x = power_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = multiply_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// Finds a nontrivial factor of an odd composite `n` with Pollard's rho (Brent's variant).
+fn pollard_rho(n: u64) -> u64 {
+    let mut increment = 1u64;
+    loop {
+        let f = |x: u64| add_mod(multiply_mod(x, x, n), increment, n);
+        let mut x = 2u64;
+        let mut y = 2u64;
+        let mut divisor = 1u64;
+        while divisor == 1 {
+            x = f(x);
+            y = f(f(y));
+            divisor = gcd(x.abs_diff(y), n);
+        }
+        if divisor != n {
+            return divisor;
+        }
+        increment += 1;
+    }
+}
+
+/// Returns the distinct prime factors of `n` in increasing order.
+pub(crate) fn prime_factors(n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut pending = vec![n];
+    while let Some(mut m) = pending.pop() {
+        if m < 2 {
+            continue;
+        }
+        while m.is_multiple_of(2) {
+            factors.push(2);
+            m /= 2;
+        }
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            factors.push(m);
+            continue;
+        }
+        let factor = pollard_rho(m);
+        pending.push(factor);
+        pending.push(m / factor);
+    }
+
+    factors.sort_unstable();
+    factors.dedup();
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u64> = (0..30).filter(|&n| is_prime(n)).collect();
+
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(3_215_031_751));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), Vec::<u64>::new());
+        assert_eq!(prime_factors(360), vec![2, 3, 5]);
+        assert_eq!(prime_factors((1 << 32) - 1), vec![3, 5, 17, 257, 65537]);
+        assert_eq!(
+            prime_factors((1 << 62) - 1),
+            vec![3, 715_827_883, 2_147_483_647]
+        );
+        assert_eq!(
+            prime_factors(u64::MAX),
+            vec![3, 5, 17, 257, 641, 65537, 6_700_417]
+        );
+        assert_eq!(
+            prime_factors(18_446_743_979_220_271_189),
+            vec![4_294_967_279, 4_294_967_291]
+        );
+    }
+}
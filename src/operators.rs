@@ -0,0 +1,172 @@
+//! Linear operators on polynomials, combined algebraically and applied symbolically.
+//!
+//! Operators such as the shift `E`, the derivative `D` and multiplication by the
+//! indeterminate `X` are built into expressions with `+`, `-` and `*` (composition, the
+//! right operand applied first) and then applied to a [`Polynomial`], which makes
+//! finite-difference identities such as `Δ = E - 1` or the Euler operator `XD` directly
+//! computable.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::polynomial::Polynomial;
+
+/// A linear operator on polynomials.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operator {
+    /// Multiplication by a constant; `Scalar(1.0)` is the identity.
+    Scalar(f64),
+    /// The shift `E^h`, taking `p(x)` to `p(x + h)`.
+    Shift(f64),
+    /// The derivative `D`.
+    Derivative,
+    /// Multiplication by the indeterminate, `X`.
+    MultiplyByX,
+    /// The sum of two operators.
+    Sum(Box<Operator>, Box<Operator>),
+    /// The composition `A B`, applying `B` first.
+    Compose(Box<Operator>, Box<Operator>),
+}
+
+impl Operator {
+    /// Returns the identity operator `1`
+    pub fn identity() -> Operator {
+        Operator::Scalar(1f64)
+    }
+
+    /// Returns the unit shift `E`, taking `p(x)` to `p(x + 1)`
+    pub fn shift() -> Operator {
+        Operator::Shift(1f64)
+    }
+
+    /// Returns the forward difference `Δ = E - 1`
+    pub fn forward_difference() -> Operator {
+        Operator::shift() - Operator::identity()
+    }
+
+    /// Returns the backward difference `∇ = 1 - E^(-1)`
+    pub fn backward_difference() -> Operator {
+        Operator::identity() - Operator::Shift(-1f64)
+    }
+
+    /// Returns the Euler operator `XD`, which scales the term `x^k` by `k`
+    pub fn euler() -> Operator {
+        Operator::MultiplyByX * Operator::Derivative
+    }
+
+    /// Returns the operator composed with itself `k` times; `pow(0)` is the identity
+    pub fn pow(&self, k: usize) -> Operator {
+        (0..k).fold(Operator::identity(), |power, _| power * self.clone())
+    }
+
+    /// Applies the operator to `polynomial`
+    /// # Example
+    /// ```
+    /// use polynom::operators::Operator;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // Δ x^2 = (x + 1)^2 - x^2 = 2x + 1
+    /// let square = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let difference = Operator::forward_difference().apply(&square);
+    /// assert_eq!(difference.coefficients, vec![1f64, 2f64]);
+    ///
+    /// // The Euler operator multiplies each term by its degree
+    /// let cubic = Polynomial::new(vec![5f64, 1f64, 1f64, 1f64], 'x');
+    /// assert_eq!(Operator::euler().apply(&cubic).coefficients, vec![0f64, 1f64, 2f64, 3f64]);
+    /// ```
+    pub fn apply(&self, polynomial: &Polynomial) -> Polynomial {
+        let indeterminate = polynomial.indeterminate;
+        let coefficients = match self {
+            Operator::Scalar(c) => polynomial
+                .coefficients
+                .iter()
+                .map(|coeff| c * coeff)
+                .collect(),
+            Operator::Shift(h) => polynomial.taylor_at(*h).coefficients,
+            Operator::Derivative => polynomial.derivative().coefficients,
+            Operator::MultiplyByX => {
+                let mut coefficients = vec![0f64];
+                coefficients.extend_from_slice(&polynomial.coefficients);
+                coefficients
+            }
+            Operator::Sum(a, b) => (a.apply(polynomial) + b.apply(polynomial)).coefficients,
+            Operator::Compose(a, b) => a.apply(&b.apply(polynomial)).coefficients,
+        };
+
+        Polynomial::new(coefficients, indeterminate)
+    }
+}
+
+impl Add for Operator {
+    type Output = Operator;
+
+    fn add(self, other: Operator) -> Operator {
+        Operator::Sum(Box::new(self), Box::new(other))
+    }
+}
+
+impl Sub for Operator {
+    type Output = Operator;
+
+    fn sub(self, other: Operator) -> Operator {
+        self + -other
+    }
+}
+
+impl Neg for Operator {
+    type Output = Operator;
+
+    fn neg(self) -> Operator {
+        match self {
+            Operator::Scalar(c) => Operator::Scalar(-c),
+            other => Operator::Scalar(-1f64) * other,
+        }
+    }
+}
+
+impl Mul for Operator {
+    type Output = Operator;
+
+    fn mul(self, other: Operator) -> Operator {
+        Operator::Compose(Box::new(self), Box::new(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finite_difference_identities() {
+        // Δ^n x^n = n! and Δ^(n + 1) x^n = 0
+        let quartic = Polynomial::new(vec![0f64, 0f64, 0f64, 0f64, 1f64], 'x');
+        let delta = Operator::forward_difference();
+        assert_eq!(delta.pow(4).apply(&quartic).coefficients, vec![24f64]);
+        assert_eq!(delta.pow(5).apply(&quartic).degree(), -1);
+
+        // ∇ = Δ E^(-1)
+        let polynomial = Polynomial::new(vec![2f64, -1f64, 3f64, 0.5], 't');
+        let nabla = Operator::backward_difference().apply(&polynomial);
+        assert_eq!((delta * Operator::Shift(-1f64)).apply(&polynomial), nabla);
+        assert_eq!(nabla.indeterminate, 't');
+
+        // E = sum D^k / k! on a cubic
+        let exponential = (0..4).fold(Operator::Scalar(0f64), |sum, k| {
+            let factorial: f64 = (1..=k).map(|i| i as f64).product();
+            sum + Operator::Scalar(1f64 / factorial) * Operator::Derivative.pow(k)
+        });
+        assert_eq!(
+            exponential.apply(&polynomial),
+            Operator::shift().apply(&polynomial)
+        );
+    }
+
+    #[test]
+    fn test_commutator() {
+        // DX - XD is the identity
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 0f64, 7f64], 'x');
+        let commutator = Operator::Derivative * Operator::MultiplyByX
+            - Operator::MultiplyByX * Operator::Derivative;
+        assert_eq!(commutator.apply(&polynomial), polynomial);
+        assert_eq!(-Operator::identity(), Operator::Scalar(-1f64));
+    }
+}
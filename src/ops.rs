@@ -0,0 +1,86 @@
+//! A common interface to the arithmetic of the crate's polynomial representations.
+
+use std::fmt;
+
+use crate::gfp::GfpPolynomial;
+use crate::polynomial::Polynomial;
+use crate::tolerance::Tolerance;
+
+/// Ring arithmetic on a polynomial representation.
+///
+/// Implemented for [`Polynomial`] and [`GfpPolynomial`]; downstream representations can
+/// implement it to run the checks in [`laws`](crate::laws) against themselves. Methods take
+/// their operands by reference and are suffixed with `_poly` so they do not clash with the
+/// representations' own `add` and `multiply` methods.
+pub trait PolynomialOps: Clone + fmt::Debug {
+    /// Returns the zero polynomial of the same ring as `self`.
+    fn zero_like(&self) -> Self;
+
+    /// Returns the constant polynomial one of the same ring as `self`.
+    fn one_like(&self) -> Self;
+
+    /// Returns `self + other`.
+    fn add_poly(&self, other: &Self) -> Self;
+
+    /// Returns `self - other`.
+    fn sub_poly(&self, other: &Self) -> Self;
+
+    /// Returns `self * other`.
+    fn mul_poly(&self, other: &Self) -> Self;
+
+    /// Returns `true` if `self` and `other` are equal within `tolerance`. Exact
+    /// representations may ignore the tolerance.
+    fn approx_eq_poly(&self, other: &Self, tolerance: &Tolerance) -> bool;
+}
+
+impl PolynomialOps for Polynomial {
+    fn zero_like(&self) -> Polynomial {
+        Polynomial::new(vec![0f64], self.indeterminate)
+    }
+
+    fn one_like(&self) -> Polynomial {
+        Polynomial::new(vec![1f64], self.indeterminate)
+    }
+
+    fn add_poly(&self, other: &Polynomial) -> Polynomial {
+        self.clone() + other.clone()
+    }
+
+    fn sub_poly(&self, other: &Polynomial) -> Polynomial {
+        self.clone() - other.clone()
+    }
+
+    fn mul_poly(&self, other: &Polynomial) -> Polynomial {
+        self.multiply(other.clone())
+    }
+
+    fn approx_eq_poly(&self, other: &Polynomial, tolerance: &Tolerance) -> bool {
+        self.approx_eq(other, tolerance)
+    }
+}
+
+impl PolynomialOps for GfpPolynomial {
+    fn zero_like(&self) -> GfpPolynomial {
+        GfpPolynomial::new(vec![0], self.prime)
+    }
+
+    fn one_like(&self) -> GfpPolynomial {
+        GfpPolynomial::new(vec![1], self.prime)
+    }
+
+    fn add_poly(&self, other: &GfpPolynomial) -> GfpPolynomial {
+        self.clone() + other.clone()
+    }
+
+    fn sub_poly(&self, other: &GfpPolynomial) -> GfpPolynomial {
+        self.clone() - other.clone()
+    }
+
+    fn mul_poly(&self, other: &GfpPolynomial) -> GfpPolynomial {
+        self.multiply(other)
+    }
+
+    fn approx_eq_poly(&self, other: &GfpPolynomial, _: &Tolerance) -> bool {
+        self == other
+    }
+}
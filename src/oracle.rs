@@ -0,0 +1,337 @@
+//! Extended-precision reference computations for measuring the accuracy of the fast `f64` paths.
+//!
+//! Values are recomputed in double-double arithmetic (about 106 bits of precision) and compared
+//! against the `f64` results in units in the last place (ULPs). Available in the crate's own tests,
+//! and to downstream users with the `oracle` feature.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::polynomial::Polynomial;
+
+/// An unevaluated sum `hi + lo` of two `f64`s with `|lo| <= ulp(hi) / 2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoubleDouble {
+    /// The leading part, which is the nearest `f64` to the represented value.
+    pub hi: f64,
+    /// The trailing error term.
+    pub lo: f64,
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+impl DoubleDouble {
+    /// Returns the double-double representing `value` exactly.
+    pub fn from_f64(value: f64) -> DoubleDouble {
+        DoubleDouble {
+            hi: value,
+            lo: 0f64,
+        }
+    }
+
+    fn normalize(hi: f64, lo: f64) -> DoubleDouble {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Returns the nearest `f64` to the represented value.
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn add(self, other: DoubleDouble) -> DoubleDouble {
+        let (sum, error) = two_sum(self.hi, other.hi);
+        DoubleDouble::normalize(sum, error + self.lo + other.lo)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn neg(self) -> DoubleDouble {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn sub(self, other: DoubleDouble) -> DoubleDouble {
+        self + -other
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn mul(self, other: DoubleDouble) -> DoubleDouble {
+        let (product, error) = two_product(self.hi, other.hi);
+        DoubleDouble::normalize(product, error + self.hi * other.lo + self.lo * other.hi)
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn div(self, other: DoubleDouble) -> DoubleDouble {
+        let quotient = self.hi / other.hi;
+        let remainder = self - other * DoubleDouble::from_f64(quotient);
+        DoubleDouble::normalize(quotient, remainder.hi / other.hi)
+    }
+}
+
+/// Returns the spacing between `value` and the next `f64` away from zero.
+pub fn ulp(value: f64) -> f64 {
+    let magnitude = value.abs();
+    if magnitude == 0f64 {
+        return f64::from_bits(1);
+    }
+
+    f64::from_bits(magnitude.to_bits() + 1) - magnitude
+}
+
+/// Returns the distance between `computed` and `exact`, measured in ULPs of `exact`.
+/// # Example
+/// ```
+/// use polynom::oracle::{ulp_error, DoubleDouble};
+///
+/// let exact = DoubleDouble::from_f64(1f64);
+/// assert_eq!(ulp_error(1f64 + f64::EPSILON, exact), 1f64);
+/// ```
+pub fn ulp_error(computed: f64, exact: DoubleDouble) -> f64 {
+    let difference = DoubleDouble::from_f64(computed) - exact;
+    difference.to_f64().abs() / ulp(exact.hi)
+}
+
+/// Evaluates `polynomial` at `x` with Horner's scheme in double-double arithmetic.
+/// # Example
+/// ```
+/// use polynom::oracle::evaluate_extended;
+/// use polynom::polynomial::Polynomial;
+///
+/// let polynomial = Polynomial::new(vec![-1f64, 0f64, 1f64], 'x');
+/// let value = evaluate_extended(&polynomial, 1f64 + f64::EPSILON);
+/// assert_eq!(value.hi, 2f64 * f64::EPSILON);
+/// assert_eq!(value.lo, f64::EPSILON * f64::EPSILON);
+/// ```
+pub fn evaluate_extended(polynomial: &Polynomial, x: f64) -> DoubleDouble {
+    let x = DoubleDouble::from_f64(x);
+    polynomial
+        .coefficients
+        .iter()
+        .rev()
+        .fold(DoubleDouble::from_f64(0f64), |accumulator, &coeff| {
+            accumulator * x + DoubleDouble::from_f64(coeff)
+        })
+}
+
+/// Polishes an approximate root of `polynomial` with Newton's method in double-double arithmetic.
+///
+/// Returns `None` if the derivative vanishes before the iteration converges (e.g. at a multiple root).
+/// # Example
+/// ```
+/// use polynom::oracle::refine_root_extended;
+/// use polynom::polynomial::Polynomial;
+///
+/// let polynomial = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+/// let root = refine_root_extended(&polynomial, 1.4).unwrap();
+/// assert_eq!(root.hi, 2f64.sqrt());
+/// ```
+pub fn refine_root_extended(polynomial: &Polynomial, approximation: f64) -> Option<DoubleDouble> {
+    let mut x = DoubleDouble::from_f64(approximation);
+    for _ in 0..100 {
+        let mut value = DoubleDouble::from_f64(0f64);
+        let mut slope = DoubleDouble::from_f64(0f64);
+        for &coeff in polynomial.coefficients.iter().rev() {
+            slope = slope * x + value;
+            value = value * x + DoubleDouble::from_f64(coeff);
+        }
+        if slope.hi == 0f64 {
+            return None;
+        }
+
+        let step = value / slope;
+        x = x - step;
+        if step.hi.abs() <= x.hi.abs() * 1e-32 || step.hi == 0f64 {
+            return Some(x);
+        }
+    }
+
+    Some(x)
+}
+
+/// The worst error found by [`evaluation_report`] or [`root_report`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccuracyReport {
+    /// The largest error observed, in ULPs of the extended-precision value.
+    pub max_ulps: f64,
+    /// The point, or computed root, at which the largest error was observed.
+    pub worst_x: f64,
+}
+
+/// Compares `Polynomial::evaluate_at` against [`evaluate_extended`] at every point in `xs`.
+/// # Example
+/// ```
+/// use polynom::oracle::evaluation_report;
+/// use polynom::polynomial::Polynomial;
+///
+/// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+/// let report = evaluation_report(&polynomial, &[0f64, 1f64, 2f64]);
+/// assert_eq!(report.max_ulps, 0f64);
+/// ```
+pub fn evaluation_report(polynomial: &Polynomial, xs: &[f64]) -> AccuracyReport {
+    let mut report = AccuracyReport {
+        max_ulps: 0f64,
+        worst_x: f64::NAN,
+    };
+    for &x in xs {
+        let error = ulp_error(polynomial.evaluate_at(x), evaluate_extended(polynomial, x));
+        if report.worst_x.is_nan() || error > report.max_ulps {
+            report = AccuracyReport {
+                max_ulps: error,
+                worst_x: x,
+            };
+        }
+    }
+
+    report
+}
+
+/// Compares every root from `Polynomial::real_roots` against the same root polished with
+/// [`refine_root_extended`].
+///
+/// Roots at which the refinement fails, such as multiple roots, are skipped. If no root is
+/// compared, `max_ulps` is zero and `worst_x` is NaN.
+/// # Example
+/// ```
+/// use polynom::oracle::root_report;
+/// use polynom::polynomial::Polynomial;
+///
+/// let polynomial = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+/// let report = root_report(&polynomial);
+/// assert!(report.max_ulps <= 1f64);
+/// ```
+pub fn root_report(polynomial: &Polynomial) -> AccuracyReport {
+    let mut report = AccuracyReport {
+        max_ulps: 0f64,
+        worst_x: f64::NAN,
+    };
+    for root in polynomial.real_roots() {
+        let exact = match refine_root_extended(polynomial, root) {
+            Some(exact) => exact,
+            None => continue,
+        };
+        let error = ulp_error(root, exact);
+        if report.worst_x.is_nan() || error > report.max_ulps {
+            report = AccuracyReport {
+                max_ulps: error,
+                worst_x: root,
+            };
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    fn sample_points(low: f64, high: f64, count: usize) -> Vec<f64> {
+        (0..count)
+            .map(|i| low + (high - low) * i as f64 / (count - 1) as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_double_double_arithmetic() {
+        let third = DoubleDouble::from_f64(1f64) / DoubleDouble::from_f64(3f64);
+        let one = third * DoubleDouble::from_f64(3f64);
+
+        assert_eq!(one.hi, 1f64);
+        assert!(one.lo.abs() < 1e-31);
+    }
+
+    #[test]
+    fn test_ulp() {
+        assert_eq!(ulp(1f64), f64::EPSILON);
+        assert_eq!(ulp(-2f64), 2f64 * f64::EPSILON);
+        assert_eq!(ulp(0f64), f64::from_bits(1));
+    }
+
+    #[test]
+    fn test_evaluation_stays_within_error_bound() {
+        // Evaluation of a degree-n polynomial may lose up to ~2n ULPs of sum |c_i x^i|
+        for polynomial in &[
+            test_polynomials::chebyshev(12),
+            test_polynomials::wilkinson(8),
+            test_polynomials::multiple_root(0.5, 6),
+        ] {
+            let n = polynomial.coefficients.len() as f64;
+            for x in sample_points(-1.5, 9.5, 301) {
+                let exact = evaluate_extended(polynomial, x);
+                let magnitude: f64 = polynomial
+                    .coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(degree, coeff)| (coeff * x.powi(degree as i32)).abs())
+                    .sum();
+                let error = (polynomial.evaluate_at(x) - exact.to_f64()).abs();
+                assert!(error <= 4f64 * n * f64::EPSILON * magnitude);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluation_report_on_well_conditioned_polynomial() {
+        let polynomial = Polynomial::new(vec![1f64, 0.5, 0.25, 0.125], 'x');
+        let report = evaluation_report(&polynomial, &sample_points(0f64, 1f64, 101));
+
+        assert!(report.max_ulps <= 4f64, "{:?}", report);
+    }
+
+    #[test]
+    fn test_root_report() {
+        // The Wilkinson roots are ill-conditioned: rounding in the evaluation near x = 6 is
+        // about 1e-7, against a slope of only 240
+        for (polynomial, max_ulps) in &[
+            (test_polynomials::chebyshev(9), 4f64),
+            (test_polynomials::wilkinson(8), 1e5),
+            (Polynomial::new(vec![-2f64, 0f64, 0f64, 1f64], 'x'), 1f64),
+        ] {
+            let report = root_report(polynomial);
+            assert!(report.max_ulps <= *max_ulps, "{:?}", report);
+            assert!(!report.worst_x.is_nan());
+        }
+
+        let no_roots = root_report(&Polynomial::new(vec![1f64, 0f64, 1f64], 'x'));
+        assert_eq!(no_roots.max_ulps, 0f64);
+        assert!(no_roots.worst_x.is_nan());
+    }
+
+    #[test]
+    fn test_refine_root_extended() {
+        let polynomial = test_polynomials::wilkinson(10);
+        let root = refine_root_extended(&polynomial, 7.1).unwrap();
+
+        assert!((root.to_f64() - 7f64).abs() < 1e-9);
+        assert!(refine_root_extended(&Polynomial::new(vec![1f64], 'x'), 0f64).is_none());
+    }
+}
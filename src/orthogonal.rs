@@ -0,0 +1,359 @@
+//! Building orthonormal families of polynomials.
+
+use std::fmt;
+
+use crate::linalg;
+use crate::polynomial::Polynomial;
+
+/// Errors that can occur while orthogonalizing a set of polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrthogonalizeError {
+    /// The polynomial at this index lies (numerically) in the span of the earlier ones.
+    LinearlyDependent {
+        /// The index of the dependent polynomial in the input.
+        index: usize,
+    },
+    /// The basis is (numerically) linearly dependent, so the expansion is not unique.
+    SingularBasis,
+}
+
+impl fmt::Display for OrthogonalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrthogonalizeError::LinearlyDependent { index } => write!(
+                f,
+                "polynomial {} is linearly dependent on the preceding ones",
+                index
+            ),
+            OrthogonalizeError::SingularBasis => write!(f, "basis is linearly dependent"),
+        }
+    }
+}
+
+impl std::error::Error for OrthogonalizeError {}
+
+/// A weight function `w` defining the inner product `<p, q> = ∫ p(x) q(x) w(x) dx`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Weight {
+    /// `w(x) = 1` on the interval `[a, b]`, giving the Legendre family on `[-1, 1]`.
+    Uniform(f64, f64),
+    /// `w(x) = 1 / sqrt(1 - x^2)` on `[-1, 1]`, giving the Chebyshev polynomials of the first kind.
+    Chebyshev,
+    /// `w(x) = exp(-x^2)` on the whole real line, giving the (physicists') Hermite polynomials.
+    Hermite,
+    /// `w(x) = exp(-x)` on `[0, ∞)`, giving the Laguerre polynomials.
+    Laguerre,
+}
+
+impl Weight {
+    /// Returns the moment `∫ x^n w(x) dx`
+    /// # Example
+    /// ```
+    /// use polynom::orthogonal::Weight;
+    ///
+    /// assert_eq!(Weight::Laguerre.moment(4), 24f64);
+    /// assert_eq!(Weight::Hermite.moment(3), 0f64);
+    /// assert_eq!(Weight::Chebyshev.moment(2), std::f64::consts::PI / 2f64);
+    /// ```
+    pub fn moment(&self, n: usize) -> f64 {
+        match *self {
+            Weight::Uniform(a, b) => {
+                let power = (n + 1) as i32;
+                (b.powi(power) - a.powi(power)) / (n + 1) as f64
+            }
+            Weight::Chebyshev | Weight::Hermite if n % 2 == 1 => 0f64,
+            // m_n = m_{n-2} (n - 1) / n, starting from m_0 = π
+            Weight::Chebyshev => (2..=n).step_by(2).fold(std::f64::consts::PI, |moment, k| {
+                moment * (k - 1) as f64 / k as f64
+            }),
+            // m_n = m_{n-2} (n - 1) / 2, starting from m_0 = sqrt(π)
+            Weight::Hermite => (2..=n)
+                .step_by(2)
+                .fold(std::f64::consts::PI.sqrt(), |moment, k| {
+                    moment * (k - 1) as f64 / 2f64
+                }),
+            Weight::Laguerre => (1..=n).fold(1f64, |moment, k| moment * k as f64),
+        }
+    }
+}
+
+/// Returns `p - scale * q`.
+fn subtract_scaled(p: &Polynomial, q: &Polynomial, scale: f64) -> Polynomial {
+    let length = p.coefficients.len().max(q.coefficients.len());
+    let coefficients = (0..length)
+        .map(|i| {
+            p.coefficients.get(i).copied().unwrap_or(0f64)
+                - scale * q.coefficients.get(i).copied().unwrap_or(0f64)
+        })
+        .collect();
+
+    Polynomial::new(coefficients, p.indeterminate)
+}
+
+/// Returns an orthonormal family spanning the same nested subspaces as `polynomials`, with
+/// respect to the L² inner product over `interval`.
+///
+/// Uses modified Gram–Schmidt with one round of re-orthogonalization, so the `i`th result is
+/// a combination of the first `i + 1` inputs. Fails if an input is (numerically) a
+/// combination of the ones before it.
+/// # Example
+/// ```
+/// use polynom::orthogonal::orthogonalize;
+/// use polynom::polynomial::Polynomial;
+///
+/// // Orthonormalizing 1, x, x^2 on [-1, 1] gives the normalized Legendre polynomials
+/// let monomials: Vec<Polynomial> = (0..3)
+///     .map(|n| {
+///         let mut coefficients = vec![0f64; n + 1];
+///         coefficients[n] = 1f64;
+///         Polynomial::new(coefficients, 'x')
+///     })
+///     .collect();
+/// let basis = orthogonalize(&monomials, (-1f64, 1f64)).unwrap();
+///
+/// // P_2 = (3x^2 - 1) / 2, scaled by sqrt(5 / 2)
+/// let scale = (2.5f64).sqrt();
+/// assert!((basis[2].coefficients[2] - 1.5 * scale).abs() < 1e-12);
+/// assert!((basis[2].coefficients[0] + 0.5 * scale).abs() < 1e-12);
+/// assert!(basis[0].inner_product(&basis[2], (-1f64, 1f64)).abs() < 1e-12);
+/// ```
+pub fn orthogonalize(
+    polynomials: &[Polynomial],
+    interval: (f64, f64),
+) -> Result<Vec<Polynomial>, OrthogonalizeError> {
+    orthogonalize_weighted(polynomials, Weight::Uniform(interval.0, interval.1))
+}
+
+/// Like [`orthogonalize`], but with respect to the inner product defined by `weight`
+/// # Example
+/// ```
+/// use polynom::orthogonal::{orthogonalize_weighted, Weight};
+/// use polynom::polynomial::Polynomial;
+///
+/// let monomials = vec![
+///     Polynomial::new(vec![1f64], 'x'),
+///     Polynomial::new(vec![0f64, 1f64], 'x'),
+///     Polynomial::new(vec![0f64, 0f64, 1f64], 'x'),
+/// ];
+/// let basis = orthogonalize_weighted(&monomials, Weight::Laguerre).unwrap();
+///
+/// // The Laguerre polynomial L_2 = (x^2 - 4x + 2) / 2 is already normalized
+/// let expected = [1f64, -2f64, 0.5];
+/// for (coeff, expected) in basis[2].coefficients.iter().zip(&expected) {
+///     assert!((coeff - expected).abs() < 1e-12);
+/// }
+/// ```
+pub fn orthogonalize_weighted(
+    polynomials: &[Polynomial],
+    weight: Weight,
+) -> Result<Vec<Polynomial>, OrthogonalizeError> {
+    let mut basis: Vec<Polynomial> = Vec::with_capacity(polynomials.len());
+    for (index, polynomial) in polynomials.iter().enumerate() {
+        let original_norm = polynomial.weighted_inner_product(polynomial, weight).sqrt();
+        let mut residual = polynomial.clone();
+        for _ in 0..2 {
+            for element in &basis {
+                let projection = residual.weighted_inner_product(element, weight);
+                residual = subtract_scaled(&residual, element, projection);
+            }
+        }
+
+        let norm = residual.weighted_inner_product(&residual, weight).sqrt();
+        if norm.is_nan() || norm <= f64::EPSILON.sqrt() * original_norm {
+            return Err(OrthogonalizeError::LinearlyDependent { index });
+        }
+        basis.push(subtract_scaled(
+            &Polynomial::new(vec![0f64], polynomial.indeterminate),
+            &residual,
+            -1f64 / norm,
+        ));
+    }
+
+    Ok(basis)
+}
+
+impl Polynomial {
+    /// Returns the inner product `∫ p(x) q(x) w(x) dx` for the given [`Weight`], computed
+    /// exactly from the moments of the weight
+    /// # Example
+    /// ```
+    /// use polynom::orthogonal::Weight;
+    /// use polynom::test_polynomials::chebyshev;
+    ///
+    /// // The Chebyshev polynomials are orthogonal under the Chebyshev weight
+    /// let (t2, t3) = (chebyshev(2), chebyshev(3));
+    /// assert_eq!(t2.weighted_inner_product(&t3, Weight::Chebyshev), 0f64);
+    /// assert!((t2.weighted_inner_product(&t2, Weight::Chebyshev) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+    /// ```
+    pub fn weighted_inner_product(&self, other: &Polynomial, weight: Weight) -> f64 {
+        if let Weight::Uniform(a, b) = weight {
+            return self.inner_product(other, (a, b));
+        }
+        self.multiply(other.clone())
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(n, coeff)| coeff * weight.moment(n))
+            .sum()
+    }
+
+    /// Returns the coefficients `c` minimizing `∫ (p(x) - Σ c_i b_i(x))^2 dx` over `interval`,
+    /// the continuous least-squares approximation of `self` by the span of `basis`.
+    ///
+    /// The basis need not be orthogonal; the normal equations are solved directly. When
+    /// `self` lies in the span, the result is its exact expansion in the basis.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // The best linear approximation of x^2 on [0, 1] is x - 1/6
+    /// let square = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let basis = vec![
+    ///     Polynomial::new(vec![1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 1f64], 'x'),
+    /// ];
+    /// let coefficients = square.project_onto(&basis, (0f64, 1f64)).unwrap();
+    /// assert!((coefficients[0] + 1f64 / 6f64).abs() < 1e-12);
+    /// assert!((coefficients[1] - 1f64).abs() < 1e-12);
+    /// ```
+    pub fn project_onto(
+        &self,
+        basis: &[Polynomial],
+        interval: (f64, f64),
+    ) -> Result<Vec<f64>, OrthogonalizeError> {
+        if basis.is_empty() {
+            return Ok(Vec::new());
+        }
+        let gram: Vec<Vec<f64>> = basis
+            .iter()
+            .map(|row| {
+                basis
+                    .iter()
+                    .map(|column| row.inner_product(column, interval))
+                    .collect()
+            })
+            .collect();
+        let right_hand_side: Vec<f64> = basis
+            .iter()
+            .map(|element| self.inner_product(element, interval))
+            .collect();
+
+        linalg::least_squares(&gram, &right_hand_side)
+            .map(|(coefficients, _)| coefficients)
+            .ok_or(OrthogonalizeError::SingularBasis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monomial(n: usize) -> Polynomial {
+        let mut coefficients = vec![0f64; n + 1];
+        coefficients[n] = 1f64;
+        Polynomial::new(coefficients, 'x')
+    }
+
+    #[test]
+    fn test_orthonormality() {
+        let interval = (0f64, 2f64);
+        let monomials: Vec<Polynomial> = (0..6).map(monomial).collect();
+        let basis = orthogonalize(&monomials, interval).unwrap();
+
+        for (i, p) in basis.iter().enumerate() {
+            assert_eq!(p.degree(), i as isize);
+            for (j, q) in basis.iter().enumerate() {
+                let expected = if i == j { 1f64 } else { 0f64 };
+                assert!((p.inner_product(q, interval) - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_linearly_dependent_input() {
+        let polynomials = vec![
+            monomial(0),
+            monomial(1),
+            Polynomial::new(vec![2f64, -3f64], 'x'),
+        ];
+
+        assert_eq!(
+            orthogonalize(&polynomials, (-1f64, 1f64)),
+            Err(OrthogonalizeError::LinearlyDependent { index: 2 })
+        );
+        assert_eq!(
+            orthogonalize(&[Polynomial::new(vec![0f64], 'x')], (-1f64, 1f64)),
+            Err(OrthogonalizeError::LinearlyDependent { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_hermite_family() {
+        let monomials: Vec<Polynomial> = (0..5).map(monomial).collect();
+        let basis = orthogonalize_weighted(&monomials, Weight::Hermite).unwrap();
+
+        // H_4 = 16x^4 - 48x^2 + 12, with squared norm 2^4 4! sqrt(π)
+        let scale = (16f64 * 24f64 * std::f64::consts::PI.sqrt()).sqrt();
+        let expected = [12f64, 0f64, -48f64, 0f64, 16f64];
+        for (coeff, expected) in basis[4].coefficients.iter().zip(&expected) {
+            assert!((coeff * scale - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_family() {
+        let monomials: Vec<Polynomial> = (0..6).map(monomial).collect();
+        let basis = orthogonalize_weighted(&monomials, Weight::Chebyshev).unwrap();
+
+        // Normalized T_n is sqrt(2 / π) T_n for n > 0
+        let scale = (std::f64::consts::PI / 2f64).sqrt();
+        let t5 = crate::test_polynomials::chebyshev(5);
+        for (coeff, expected) in basis[5].coefficients.iter().zip(&t5.coefficients) {
+            assert!((coeff * scale - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_uniform_moments_match_integral() {
+        let weight = Weight::Uniform(-0.5, 2f64);
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 0.5, 3f64], 'x');
+        let one = Polynomial::new(vec![1f64], 'x');
+        let from_moments: f64 = polynomial
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(n, coeff)| coeff * weight.moment(n))
+            .sum();
+
+        assert!((polynomial.weighted_inner_product(&one, weight) - from_moments).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_project_onto_orthonormal_basis_matches_inner_products() {
+        let interval = (-1f64, 1f64);
+        let basis = orthogonalize(&(0..4).map(monomial).collect::<Vec<_>>(), interval).unwrap();
+        let polynomial = Polynomial::new(vec![0.5, -1f64, 2f64, 0f64, 3f64], 'x');
+        let coefficients = polynomial.project_onto(&basis, interval).unwrap();
+
+        for (coefficient, element) in coefficients.iter().zip(&basis) {
+            assert!((coefficient - polynomial.inner_product(element, interval)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_project_onto_reproduces_members_of_the_span() {
+        let basis = vec![
+            Polynomial::new(vec![1f64, 1f64], 'x'),
+            Polynomial::new(vec![1f64, -1f64, 1f64], 'x'),
+        ];
+        let polynomial = Polynomial::new(vec![5f64, -1f64, 3f64], 'x');
+        let coefficients = polynomial.project_onto(&basis, (0f64, 3f64)).unwrap();
+
+        assert!((coefficients[0] - 2f64).abs() < 1e-12);
+        assert!((coefficients[1] - 3f64).abs() < 1e-12);
+        assert_eq!(
+            polynomial.project_onto(&[basis[0].clone(), basis[0].clone()], (0f64, 1f64)),
+            Err(OrthogonalizeError::SingularBasis)
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Polynomials whose coefficients are linear expressions in named parameters.
+//!
+//! A [`ParamPolynomial`] describes a family such as `a x^2 + b x + c`. Binding a parameter
+//! folds its value into the constant parts of the coefficients, and once every parameter
+//! is bound the family instantiates to a concrete [`Polynomial`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::polynomial::Polynomial;
+
+/// Errors that can occur while instantiating a [`ParamPolynomial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// No value was given for the named parameter.
+    UnboundParameter {
+        /// The name of the parameter.
+        name: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::UnboundParameter { name } => {
+                write!(f, "no value given for parameter `{}`", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// The linear expression `constant + sum weight_i name_i`.
+///
+/// Parameters with a zero weight are never stored, so equal expressions compare equal.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinearExpr {
+    /// The term independent of the parameters.
+    pub constant: f64,
+    /// The nonzero weight of each parameter, by name.
+    pub weights: BTreeMap<String, f64>,
+}
+
+impl LinearExpr {
+    /// Returns the expression with no parameters
+    pub fn constant(value: f64) -> LinearExpr {
+        LinearExpr {
+            constant: value,
+            weights: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the expression consisting of the single parameter `name`
+    pub fn parameter(name: &str) -> LinearExpr {
+        let mut weights = BTreeMap::new();
+        weights.insert(name.to_string(), 1f64);
+        LinearExpr {
+            constant: 0f64,
+            weights,
+        }
+    }
+
+    /// Returns `true` if the expression is identically zero
+    pub fn is_zero(&self) -> bool {
+        self.constant == 0f64 && self.weights.is_empty()
+    }
+
+    /// Replaces `name` by `value`, folding it into the constant
+    pub fn bind(&self, name: &str, value: f64) -> LinearExpr {
+        let mut bound = self.clone();
+        if let Some(weight) = bound.weights.remove(name) {
+            bound.constant += weight * value;
+        }
+        bound
+    }
+
+    /// Returns the value of the expression, looking parameters up in `values`
+    pub fn evaluate(&self, values: &[(&str, f64)]) -> Result<f64, ParamError> {
+        self.weights
+            .iter()
+            .try_fold(self.constant, |sum, (name, weight)| {
+                values
+                    .iter()
+                    .find(|(candidate, _)| candidate == name)
+                    .map(|&(_, value)| sum + weight * value)
+                    .ok_or_else(|| ParamError::UnboundParameter { name: name.clone() })
+            })
+    }
+
+    /// Returns `factor` times the expression
+    pub fn scale(&self, factor: f64) -> LinearExpr {
+        let mut scaled = LinearExpr::constant(self.constant * factor);
+        for (name, weight) in &self.weights {
+            insert_weight(&mut scaled.weights, name, weight * factor);
+        }
+        scaled
+    }
+}
+
+/// Adds `weight` to the weight of `name`, dropping the entry if it cancels to zero.
+fn insert_weight(weights: &mut BTreeMap<String, f64>, name: &str, weight: f64) {
+    let total = weights.get(name).copied().unwrap_or(0f64) + weight;
+    if total == 0f64 {
+        weights.remove(name);
+    } else {
+        weights.insert(name.to_string(), total);
+    }
+}
+
+impl Add for LinearExpr {
+    type Output = LinearExpr;
+
+    fn add(self, other: LinearExpr) -> LinearExpr {
+        let mut sum = self;
+        sum.constant += other.constant;
+        for (name, weight) in &other.weights {
+            insert_weight(&mut sum.weights, name, *weight);
+        }
+        sum
+    }
+}
+
+impl Neg for LinearExpr {
+    type Output = LinearExpr;
+
+    fn neg(self) -> LinearExpr {
+        self.scale(-1f64)
+    }
+}
+
+impl Sub for LinearExpr {
+    type Output = LinearExpr;
+
+    fn sub(self, other: LinearExpr) -> LinearExpr {
+        self + -other
+    }
+}
+
+impl Mul<f64> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn mul(self, factor: f64) -> LinearExpr {
+        self.scale(factor)
+    }
+}
+
+impl From<f64> for LinearExpr {
+    fn from(value: f64) -> LinearExpr {
+        LinearExpr::constant(value)
+    }
+}
+
+/// A polynomial with [`LinearExpr`] coefficients, lowest degree first.
+///
+/// Like [`Polynomial`], trailing zero coefficients are dropped and the zero polynomial has
+/// the single coefficient `0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamPolynomial {
+    /// Coefficients, lowest degree first.
+    pub coefficients: Vec<LinearExpr>,
+    /// The indeterminate, as in [`Polynomial::indeterminate`].
+    pub indeterminate: char,
+}
+
+impl ParamPolynomial {
+    /// Returns the polynomial with the given coefficients
+    /// # Example
+    /// ```
+    /// use polynom::param::{LinearExpr, ParamPolynomial};
+    ///
+    /// // a x^2 + b x + c
+    /// let family = ParamPolynomial::new(
+    ///     vec![
+    ///         LinearExpr::parameter("c"),
+    ///         LinearExpr::parameter("b"),
+    ///         LinearExpr::parameter("a"),
+    ///     ],
+    ///     'x',
+    /// );
+    /// let parabola = family.substitute(&[("a", 1f64), ("b", 0f64), ("c", -4f64)]).unwrap();
+    /// assert_eq!(parabola.coefficients, vec![-4f64, 0f64, 1f64]);
+    /// ```
+    pub fn new(coefficients: Vec<LinearExpr>, indeterminate: char) -> ParamPolynomial {
+        let mut polynomial = ParamPolynomial {
+            coefficients,
+            indeterminate,
+        };
+        while polynomial
+            .coefficients
+            .last()
+            .is_some_and(LinearExpr::is_zero)
+        {
+            polynomial.coefficients.pop();
+        }
+        if polynomial.coefficients.is_empty() {
+            polynomial.coefficients.push(LinearExpr::constant(0f64));
+        }
+        polynomial
+    }
+
+    /// Returns the names of the parameters that still occur, in sorted order
+    pub fn parameters(&self) -> BTreeSet<String> {
+        self.coefficients
+            .iter()
+            .flat_map(|coeff| coeff.weights.keys().cloned())
+            .collect()
+    }
+
+    /// Replaces the parameter `name` by `value` in every coefficient, folding it into the
+    /// constant parts
+    /// # Example
+    /// ```
+    /// use polynom::param::{LinearExpr, ParamPolynomial};
+    ///
+    /// // (a + 1) + 2a x
+    /// let family = ParamPolynomial::new(
+    ///     vec![LinearExpr::parameter("a") + 1f64.into(), LinearExpr::parameter("a") * 2f64],
+    ///     'x',
+    /// );
+    /// let bound = family.bind("a", 3f64);
+    /// assert!(bound.parameters().is_empty());
+    /// assert_eq!(bound.to_polynomial().unwrap().coefficients, vec![4f64, 6f64]);
+    /// ```
+    pub fn bind(&self, name: &str, value: f64) -> ParamPolynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|coeff| coeff.bind(name, value))
+            .collect();
+        ParamPolynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Instantiates the polynomial with the parameter values in `values`
+    pub fn substitute(&self, values: &[(&str, f64)]) -> Result<Polynomial, ParamError> {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|coeff| coeff.evaluate(values))
+            .collect::<Result<Vec<f64>, ParamError>>()?;
+        Ok(Polynomial::new(coefficients, self.indeterminate))
+    }
+
+    /// Returns the concrete polynomial once no parameters remain
+    pub fn to_polynomial(&self) -> Result<Polynomial, ParamError> {
+        self.substitute(&[])
+    }
+
+    /// Returns the product with a concrete polynomial, which keeps every coefficient linear
+    pub fn multiply(&self, other: &Polynomial) -> ParamPolynomial {
+        let mut coefficients =
+            vec![LinearExpr::default(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, coeff) in self.coefficients.iter().enumerate() {
+            for (j, factor) in other.coefficients.iter().enumerate() {
+                let term = coeff.scale(*factor);
+                coefficients[i + j] = std::mem::take(&mut coefficients[i + j]) + term;
+            }
+        }
+        ParamPolynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the derivative with respect to the indeterminate
+    pub fn derivative(&self) -> ParamPolynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(degree, coeff)| coeff.scale(degree as f64))
+            .collect();
+        ParamPolynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+impl From<Polynomial> for ParamPolynomial {
+    fn from(polynomial: Polynomial) -> ParamPolynomial {
+        let coefficients = polynomial
+            .coefficients
+            .into_iter()
+            .map(LinearExpr::constant)
+            .collect();
+        ParamPolynomial::new(coefficients, polynomial.indeterminate)
+    }
+}
+
+impl Add for ParamPolynomial {
+    type Output = ParamPolynomial;
+
+    fn add(self, other: ParamPolynomial) -> ParamPolynomial {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let mut left = self.coefficients.into_iter();
+        let mut right = other.coefficients.into_iter();
+        let coefficients = (0..length)
+            .map(|_| left.next().unwrap_or_default() + right.next().unwrap_or_default())
+            .collect();
+        ParamPolynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+impl Sub for ParamPolynomial {
+    type Output = ParamPolynomial;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: ParamPolynomial) -> ParamPolynomial {
+        let negated = other.coefficients.into_iter().map(|coeff| -coeff).collect();
+        self + ParamPolynomial::new(negated, other.indeterminate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn family() -> ParamPolynomial {
+        // (c - 1) + b x + 2a x^2
+        ParamPolynomial::new(
+            vec![
+                LinearExpr::parameter("c") - LinearExpr::constant(1f64),
+                LinearExpr::parameter("b"),
+                LinearExpr::parameter("a") * 2f64,
+            ],
+            't',
+        )
+    }
+
+    #[test]
+    fn test_substitution_commutes_with_operations() {
+        let values = [("a", 0.5), ("b", -3f64), ("c", 4f64)];
+        let concrete = family().substitute(&values).unwrap();
+        assert_eq!(concrete.coefficients, vec![3f64, -3f64, 1f64]);
+        assert_eq!(concrete.indeterminate, 't');
+
+        let factor = Polynomial::new(vec![1f64, -2f64], 't');
+        let product = family().multiply(&factor).substitute(&values).unwrap();
+        assert_eq!(product.coefficients, concrete.multiply(factor).coefficients);
+        assert_eq!(
+            family()
+                .derivative()
+                .substitute(&values)
+                .unwrap()
+                .coefficients,
+            concrete.derivative().coefficients
+        );
+    }
+
+    #[test]
+    fn test_folding_and_cancellation() {
+        let sum = family() + ParamPolynomial::from(Polynomial::new(vec![1f64], 't'));
+        assert_eq!(sum.coefficients[0], LinearExpr::parameter("c"));
+        let parameters: Vec<String> = sum.parameters().into_iter().collect();
+        assert_eq!(parameters, vec!["a", "b", "c"]);
+
+        // Cancelling the leading parameter lowers the degree
+        let difference = family() - family().bind("b", 1f64);
+        assert_eq!(difference.coefficients.len(), 2);
+        assert_eq!(
+            difference.coefficients[1],
+            LinearExpr::parameter("b") - 1f64.into()
+        );
+        assert_eq!(
+            (family() - family()).coefficients,
+            vec![LinearExpr::default()]
+        );
+
+        assert_eq!(
+            family().bind("a", 1f64).substitute(&[("b", 1f64)]),
+            Err(ParamError::UnboundParameter {
+                name: String::from("c")
+            })
+        );
+    }
+}
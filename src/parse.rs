@@ -0,0 +1,608 @@
+//! Reading polynomials back from text, one at a time or from line-oriented streams.
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::polynomial::Polynomial;
+
+/// Errors that can occur while parsing a polynomial from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text does not start with a `f(x) = ` header naming the indeterminate.
+    MissingHeader,
+    /// The term for the given degree is not of the form `c`, `cx` or `cx^k` respectively.
+    MalformedTerm {
+        /// The degree of the malformed term.
+        degree: usize,
+    },
+    /// The coefficient of the term for the given degree is not a valid number.
+    InvalidCoefficient {
+        /// The degree of the term with the invalid coefficient.
+        degree: usize,
+    },
+    /// A JSON line is malformed at the given byte offset.
+    InvalidJson {
+        /// The byte offset in the line at which parsing failed.
+        offset: usize,
+    },
+    /// Canonical text does not start with an indeterminate followed by `:`.
+    MissingIndeterminate,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingHeader => write!(f, "expected a header of the form `f(x) = `"),
+            ParseError::MalformedTerm { degree } => {
+                write!(f, "malformed term of degree {}", degree)
+            }
+            ParseError::InvalidCoefficient { degree } => {
+                write!(f, "invalid coefficient for the term of degree {}", degree)
+            }
+            ParseError::InvalidJson { offset } => write!(f, "invalid JSON at byte {}", offset),
+            ParseError::MissingIndeterminate => {
+                write!(f, "expected an indeterminate followed by `:`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Polynomial {
+    /// Returns a string representation that [`parse_exact`](Polynomial::parse_exact) turns back
+    /// into bit-identical coefficients.
+    ///
+    /// Every term is written, in ascending degree, with the shortest decimal representation
+    /// that round-trips, so the output is suitable for configuration files.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![0.1, 0f64, -1e-300], 'x');
+    /// assert_eq!(polynomial.to_string_exact(), "f(x) = 0.1 + 0.0x + -1e-300x^2");
+    /// ```
+    pub fn to_string_exact(&self) -> String {
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(degree, coeff)| match degree {
+                0 => format!("{:?}", coeff),
+                1 => format!("{:?}{}", coeff, self.indeterminate),
+                _ => format!("{:?}{}^{}", coeff, self.indeterminate, degree),
+            })
+            .collect();
+
+        format!("f({}) = {}", self.indeterminate, terms.join(" + "))
+    }
+
+    /// Parses the output of [`to_string_exact`](Polynomial::to_string_exact).
+    ///
+    /// Terms must appear in ascending degree with none omitted. Coefficients may use any
+    /// syntax accepted by `f64::from_str`, including exponents, `inf` and `NaN`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64 / 3f64, -2f64, 1e300], 't');
+    /// let parsed = Polynomial::parse_exact(&polynomial.to_string_exact()).unwrap();
+    /// assert_eq!(parsed, polynomial);
+    /// assert_eq!(parsed.indeterminate, 't');
+    /// ```
+    pub fn parse_exact(text: &str) -> Result<Polynomial, ParseError> {
+        let text = text.trim();
+        let rest = text.strip_prefix("f(").ok_or(ParseError::MissingHeader)?;
+        let mut chars = rest.chars();
+        let indeterminate = chars.next().ok_or(ParseError::MissingHeader)?;
+        let body = chars
+            .as_str()
+            .strip_prefix(") = ")
+            .ok_or(ParseError::MissingHeader)?;
+
+        let mut coefficients = Vec::new();
+        for (degree, term) in body.split(" + ").enumerate() {
+            let coefficient = match degree {
+                0 => Some(term),
+                1 => term.strip_suffix(indeterminate),
+                _ => term.strip_suffix(&format!("{}^{}", indeterminate, degree)[..]),
+            }
+            .ok_or(ParseError::MalformedTerm { degree })?;
+            let value = coefficient
+                .parse::<f64>()
+                .map_err(|_| ParseError::InvalidCoefficient { degree })?;
+            coefficients.push(value);
+        }
+
+        // Keep a lone constant as written, so that even the sign of a zero survives
+        if coefficients.len() == 1 {
+            return Ok(Polynomial {
+                coefficients,
+                indeterminate,
+            });
+        }
+        Ok(Polynomial::new(coefficients, indeterminate))
+    }
+
+    /// Returns the canonical compact text form, e.g. `x: 1 2 0 3` for `1 + 2x + 3x^3`.
+    ///
+    /// The format is the indeterminate, a colon, and every coefficient in ascending degree
+    /// separated by single spaces. Integral coefficients are written without a fractional
+    /// part and all others with the shortest decimal representation that round-trips, so
+    /// [`from_canonical_text`](Polynomial::from_canonical_text) recovers bit-identical
+    /// coefficients and a change to one coefficient changes one word of the line. The zero
+    /// polynomial is written `x: 0`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 0f64, 3f64], 'x');
+    /// assert_eq!(polynomial.to_canonical_text(), "x: 1 2 0 3");
+    /// let polynomial = Polynomial::new(vec![0.5, -1e-300], 't');
+    /// assert_eq!(polynomial.to_canonical_text(), "t: 0.5 -1e-300");
+    /// ```
+    pub fn to_canonical_text(&self) -> String {
+        let mut text = format!("{}:", self.indeterminate);
+        for &coeff in &self.coefficients {
+            text.push(' ');
+            if coeff.fract() == 0f64 && coeff.abs() < 1e16 {
+                text.push_str(&format!("{}", coeff));
+            } else {
+                text.push_str(&format!("{:?}", coeff));
+            }
+        }
+
+        text
+    }
+
+    /// Parses the canonical text form written by
+    /// [`to_canonical_text`](Polynomial::to_canonical_text).
+    ///
+    /// Coefficients may be separated by any amount of whitespace and use any syntax
+    /// accepted by `f64::from_str`; trailing zeros are dropped.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::from_canonical_text("x: 1 2 0 3").unwrap();
+    /// assert_eq!(polynomial.coefficients, vec![1f64, 2f64, 0f64, 3f64]);
+    /// assert_eq!(Polynomial::from_canonical_text(&polynomial.to_canonical_text()), Ok(polynomial));
+    /// ```
+    pub fn from_canonical_text(text: &str) -> Result<Polynomial, ParseError> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        let indeterminate = chars.next().ok_or(ParseError::MissingIndeterminate)?;
+        let body = chars
+            .as_str()
+            .strip_prefix(':')
+            .ok_or(ParseError::MissingIndeterminate)?;
+
+        let coefficients = body
+            .split_whitespace()
+            .enumerate()
+            .map(|(degree, word)| {
+                word.parse::<f64>()
+                    .map_err(|_| ParseError::InvalidCoefficient { degree })
+            })
+            .collect::<Result<Vec<f64>, ParseError>>()?;
+        if coefficients.is_empty() {
+            return Err(ParseError::MalformedTerm { degree: 0 });
+        }
+
+        Ok(Polynomial::new(coefficients, indeterminate))
+    }
+}
+
+/// The layout of a batch input stream, with one polynomial per line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// The [`to_string_exact`](Polynomial::to_string_exact) format, e.g. `f(x) = 1.0 + 2.0x`.
+    ///
+    /// Lines starting with `#` are comments.
+    Text,
+    /// JSON lines holding either an array of coefficients, lowest degree first, or an object
+    /// `{"coefficients": [...], "indeterminate": "t"}` whose indeterminate defaults to `x`.
+    JsonLines,
+    /// The [`to_canonical_text`](Polynomial::to_canonical_text) format, e.g. `x: 1 2`.
+    ///
+    /// Lines starting with `#` are comments.
+    Canonical,
+}
+
+/// Errors that can occur while reading polynomials from a stream, tagged with the 1-based
+/// line number at which they occurred.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The underlying reader failed.
+    Io {
+        /// The line being read.
+        line: usize,
+        /// The error from the reader.
+        error: io::Error,
+    },
+    /// The line could not be parsed.
+    Parse {
+        /// The line that failed to parse.
+        line: usize,
+        /// Why the line failed to parse.
+        error: ParseError,
+    },
+}
+
+impl ReadError {
+    /// Returns the 1-based line number at which the error occurred.
+    pub fn line(&self) -> usize {
+        match self {
+            ReadError::Io { line, .. } | ReadError::Parse { line, .. } => *line,
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io { line, error } => write!(f, "line {}: {}", line, error),
+            ReadError::Parse { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io { error, .. } => Some(error),
+            ReadError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// An iterator parsing one polynomial per line from a buffered reader.
+///
+/// Blank lines are skipped. Parsing continues after an error, so callers can either stop at
+/// the first failure or collect every bad line.
+/// # Example
+/// ```
+/// use polynom::parse::{InputFormat, PolynomialReader};
+///
+/// let input = "[1, 2]\n\n{\"coefficients\": [0, 0, 3], \"indeterminate\": \"t\"}\n[1, oops]\n";
+/// let results: Vec<_> = PolynomialReader::new(input.as_bytes(), InputFormat::JsonLines).collect();
+///
+/// assert_eq!(results.len(), 3);
+/// assert_eq!(results[1].as_ref().unwrap().coefficients, vec![0f64, 0f64, 3f64]);
+/// assert_eq!(results[2].as_ref().unwrap_err().line(), 4);
+/// ```
+pub struct PolynomialReader<R> {
+    lines: io::Lines<R>,
+    format: InputFormat,
+    line: usize,
+}
+
+impl<R: BufRead> PolynomialReader<R> {
+    /// Returns a reader parsing lines of `reader` in the given format.
+    pub fn new(reader: R, format: InputFormat) -> PolynomialReader<R> {
+        PolynomialReader {
+            lines: reader.lines(),
+            format,
+            line: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PolynomialReader<R> {
+    type Item = Result<Polynomial, ReadError>;
+
+    fn next(&mut self) -> Option<Result<Polynomial, ReadError>> {
+        loop {
+            let text = self.lines.next()?;
+            self.line += 1;
+            let line = self.line;
+            let text = match text {
+                Ok(text) => text,
+                Err(error) => return Some(Err(ReadError::Io { line, error })),
+            };
+
+            let trimmed = text.trim();
+            let comments = self.format != InputFormat::JsonLines;
+            if trimmed.is_empty() || (comments && trimmed.starts_with('#')) {
+                continue;
+            }
+            let parsed = match self.format {
+                InputFormat::Text => Polynomial::parse_exact(trimmed),
+                InputFormat::JsonLines => parse_json_line(trimmed),
+                InputFormat::Canonical => Polynomial::from_canonical_text(trimmed),
+            };
+            return Some(parsed.map_err(|error| ReadError::Parse { line, error }));
+        }
+    }
+}
+
+/// Reads every polynomial from `reader`, stopping at the first error
+/// # Example
+/// ```
+/// use polynom::parse::{read_polynomials, InputFormat};
+///
+/// let input = "# calibration curves\nf(x) = 1.0 + 2.0x\nf(y) = 0.5\n";
+/// let polynomials = read_polynomials(input.as_bytes(), InputFormat::Text).unwrap();
+/// assert_eq!(polynomials.len(), 2);
+/// assert_eq!(polynomials[1].indeterminate, 'y');
+///
+/// let error = read_polynomials("f(x) = 1.0\n1 + 2x\n".as_bytes(), InputFormat::Text).unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: expected a header of the form `f(x) = `");
+/// ```
+pub fn read_polynomials<R: BufRead>(
+    reader: R,
+    format: InputFormat,
+) -> Result<Vec<Polynomial>, ReadError> {
+    PolynomialReader::new(reader, format).collect()
+}
+
+/// A cursor over the bytes of a single JSON line.
+struct JsonCursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> JsonCursor<'a> {
+    fn error(&self) -> ParseError {
+        ParseError::InvalidJson {
+            offset: self.position,
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        while self.position < self.bytes.len() && self.bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.peek() != Some(byte) {
+            return Err(self.error());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<f64, ParseError> {
+        self.peek();
+        let start = self.position;
+        while self.position < self.bytes.len()
+            && matches!(
+                self.bytes[self.position],
+                b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'
+            )
+        {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|text| text.parse::<f64>().ok())
+            .ok_or(ParseError::InvalidJson { offset: start })
+    }
+
+    /// Reads a string without escape sequences, which polynomial files never need.
+    fn string(&mut self) -> Result<&'a str, ParseError> {
+        self.expect(b'"')?;
+        let start = self.position;
+        while self.position < self.bytes.len() && self.bytes[self.position] != b'"' {
+            if self.bytes[self.position] == b'\\' {
+                return Err(self.error());
+            }
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.position])
+            .map_err(|_| ParseError::InvalidJson { offset: start })?;
+        self.expect(b'"')?;
+        Ok(text)
+    }
+
+    fn number_array(&mut self) -> Result<Vec<f64>, ParseError> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(values);
+        }
+        loop {
+            values.push(self.number()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(values);
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+}
+
+/// Parses an array of coefficients or a `{"coefficients", "indeterminate"}` object.
+fn parse_json_line(text: &str) -> Result<Polynomial, ParseError> {
+    let mut cursor = JsonCursor {
+        bytes: text.as_bytes(),
+        position: 0,
+    };
+    let mut indeterminate = 'x';
+    let coefficients = match cursor.peek() {
+        Some(b'[') => cursor.number_array()?,
+        Some(b'{') => {
+            cursor.position += 1;
+            let mut coefficients = None;
+            loop {
+                cursor.peek();
+                let key_offset = cursor.position;
+                match cursor.string()? {
+                    "coefficients" => {
+                        cursor.expect(b':')?;
+                        coefficients = Some(cursor.number_array()?);
+                    }
+                    "indeterminate" => {
+                        cursor.expect(b':')?;
+                        cursor.peek();
+                        let value_offset = cursor.position;
+                        let mut chars = cursor.string()?.chars();
+                        indeterminate = match (chars.next(), chars.next()) {
+                            (Some(c), None) => c,
+                            _ => {
+                                return Err(ParseError::InvalidJson {
+                                    offset: value_offset,
+                                })
+                            }
+                        };
+                    }
+                    _ => return Err(ParseError::InvalidJson { offset: key_offset }),
+                }
+                match cursor.peek() {
+                    Some(b',') => cursor.position += 1,
+                    Some(b'}') => {
+                        cursor.position += 1;
+                        break;
+                    }
+                    _ => return Err(cursor.error()),
+                }
+            }
+            coefficients.ok_or_else(|| cursor.error())?
+        }
+        _ => return Err(cursor.error()),
+    };
+    if cursor.peek().is_some() {
+        return Err(cursor.error());
+    }
+
+    Ok(Polynomial::new(coefficients, indeterminate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SplitMix64};
+
+    #[test]
+    fn test_round_trip_is_bit_identical() {
+        let mut rng = SplitMix64::new(5);
+        for _ in 0..200 {
+            let coefficients: Vec<f64> = (0..6)
+                .map(|_| f64::from_bits(rng.next_u64()))
+                .filter(|value| !value.is_nan())
+                .collect();
+            let polynomial = Polynomial::new(coefficients, 'x');
+            let parsed = Polynomial::parse_exact(&polynomial.to_string_exact()).unwrap();
+
+            let bits = |p: &Polynomial| {
+                p.coefficients
+                    .iter()
+                    .map(|c| c.to_bits())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(bits(&parsed), bits(&polynomial));
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        let polynomial = Polynomial {
+            coefficients: vec![-0f64],
+            indeterminate: 'x',
+        };
+        let parsed = Polynomial::parse_exact(&polynomial.to_string_exact()).unwrap();
+        assert!(parsed.coefficients[0].is_sign_negative());
+
+        let polynomial = Polynomial::new(vec![f64::NEG_INFINITY, f64::NAN], 'e');
+        let parsed = Polynomial::parse_exact(&polynomial.to_string_exact()).unwrap();
+        assert_eq!(parsed.coefficients[0], f64::NEG_INFINITY);
+        assert!(parsed.coefficients[1].is_nan());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Polynomial::parse_exact("1 + 2x"),
+            Err(ParseError::MissingHeader)
+        );
+        assert_eq!(
+            Polynomial::parse_exact("f(x) = 1.0 + 2.0x^2"),
+            Err(ParseError::MalformedTerm { degree: 1 })
+        );
+        assert_eq!(
+            Polynomial::parse_exact("f(x) = 1.0 + 2.0x + threex^2"),
+            Err(ParseError::InvalidCoefficient { degree: 2 })
+        );
+    }
+
+    #[test]
+    fn test_json_lines_errors() {
+        let parse = |text: &str| parse_json_line(text).unwrap_err();
+
+        assert_eq!(parse("[1, 2"), ParseError::InvalidJson { offset: 5 });
+        assert_eq!(parse("[1, 2] 3"), ParseError::InvalidJson { offset: 7 });
+        assert_eq!(
+            parse("{\"degree\": 2}"),
+            ParseError::InvalidJson { offset: 1 }
+        );
+        assert_eq!(
+            parse("{\"indeterminate\": \"xy\", \"coefficients\": []}"),
+            ParseError::InvalidJson { offset: 18 }
+        );
+        assert_eq!(
+            parse("{\"indeterminate\": \"t\"}"),
+            ParseError::InvalidJson { offset: 22 }
+        );
+        assert_eq!(parse_json_line(" [ ] ").unwrap().coefficients, vec![0f64]);
+    }
+
+    #[test]
+    fn test_reader_collects_all_errors() {
+        let input = "f(x) = 1.0\nf(x) = 2.0 + x\n\n  # comment\nf(x) = 1.0 + 2.0x\nnonsense\n";
+        let results: Vec<_> = PolynomialReader::new(input.as_bytes(), InputFormat::Text).collect();
+        let lines: Vec<usize> = results
+            .iter()
+            .filter_map(|result| result.as_ref().err().map(ReadError::line))
+            .collect();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(lines, vec![2, 6]);
+    }
+
+    #[test]
+    fn test_canonical_text() {
+        let polynomial = Polynomial::new(vec![-0f64, 1e16, 1f64 / 3f64, f64::INFINITY, -7f64], 'y');
+        let text = polynomial.to_canonical_text();
+        assert_eq!(text, "y: -0 1e16 0.3333333333333333 inf -7");
+        let parsed = Polynomial::from_canonical_text(&text).unwrap();
+        assert_eq!(parsed.indeterminate, 'y');
+        for (a, b) in parsed.coefficients.iter().zip(&polynomial.coefficients) {
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+
+        let zero = Polynomial::new(vec![0f64], 'x');
+        assert_eq!(zero.to_canonical_text(), "x: 0");
+        assert_eq!(
+            Polynomial::from_canonical_text("  x:\t1   0 0 \n"),
+            Ok(Polynomial::new(vec![1f64], 'x'))
+        );
+
+        assert_eq!(
+            Polynomial::from_canonical_text("x 1 2"),
+            Err(ParseError::MissingIndeterminate)
+        );
+        assert_eq!(
+            Polynomial::from_canonical_text(""),
+            Err(ParseError::MissingIndeterminate)
+        );
+        assert_eq!(
+            Polynomial::from_canonical_text("x:"),
+            Err(ParseError::MalformedTerm { degree: 0 })
+        );
+        assert_eq!(
+            Polynomial::from_canonical_text("x: 1 two"),
+            Err(ParseError::InvalidCoefficient { degree: 1 })
+        );
+
+        let input = "# gains\nx: 1 2\n\nt: 0.5\n";
+        let polynomials = read_polynomials(input.as_bytes(), InputFormat::Canonical).unwrap();
+        assert_eq!(polynomials.len(), 2);
+        assert_eq!(polynomials[1].indeterminate, 't');
+    }
+}
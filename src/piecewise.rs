@@ -0,0 +1,172 @@
+//! Piecewise polynomials over a partition of an interval.
+
+use crate::polynomial::Polynomial;
+
+/// A function equal to `pieces[i]` on `[breakpoints[i], breakpoints[i + 1]]`.
+///
+/// Breakpoints are strictly increasing and there is one fewer piece than breakpoints. At an
+/// interior breakpoint the piece to its right is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewisePolynomial {
+    /// The ends of the pieces, strictly increasing.
+    pub breakpoints: Vec<f64>,
+    /// The polynomial used on each interval between consecutive breakpoints.
+    pub pieces: Vec<Polynomial>,
+}
+
+impl PiecewisePolynomial {
+    /// Returns the piecewise polynomial with the given breakpoints and pieces.
+    ///
+    /// # Panics
+    /// Panics if there is not exactly one fewer piece than breakpoints, or if the
+    /// breakpoints are not strictly increasing.
+    pub fn new(breakpoints: Vec<f64>, pieces: Vec<Polynomial>) -> PiecewisePolynomial {
+        assert!(
+            !pieces.is_empty() && breakpoints.len() == pieces.len() + 1,
+            "need one more breakpoint than pieces"
+        );
+        assert!(
+            breakpoints.windows(2).all(|pair| pair[0] < pair[1]),
+            "breakpoints must be strictly increasing"
+        );
+        PiecewisePolynomial {
+            breakpoints,
+            pieces,
+        }
+    }
+
+    /// Returns the interval `(first breakpoint, last breakpoint)` the function is defined on
+    pub fn domain(&self) -> (f64, f64) {
+        (
+            self.breakpoints[0],
+            self.breakpoints[self.breakpoints.len() - 1],
+        )
+    }
+
+    /// Returns the piece that applies at `x`, or `None` outside the domain
+    pub fn piece_at(&self, x: f64) -> Option<&Polynomial> {
+        let (low, high) = self.domain();
+        if x.is_nan() || x < low || x > high {
+            return None;
+        }
+        let index = self.breakpoints[1..self.breakpoints.len() - 1]
+            .iter()
+            .take_while(|&&breakpoint| breakpoint <= x)
+            .count();
+
+        Some(&self.pieces[index])
+    }
+
+    /// Evaluates the function at `x`, or returns `None` outside the domain
+    /// # Example
+    /// ```
+    /// use polynom::piecewise::PiecewisePolynomial;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // |x| on [-1, 1]
+    /// let abs = PiecewisePolynomial::new(
+    ///     vec![-1f64, 0f64, 1f64],
+    ///     vec![
+    ///         Polynomial::new(vec![0f64, -1f64], 'x'),
+    ///         Polynomial::new(vec![0f64, 1f64], 'x'),
+    ///     ],
+    /// );
+    /// assert_eq!(abs.evaluate_at(-0.5), Some(0.5));
+    /// assert_eq!(abs.evaluate_at(0.25), Some(0.25));
+    /// assert_eq!(abs.evaluate_at(2f64), None);
+    /// ```
+    pub fn evaluate_at(&self, x: f64) -> Option<f64> {
+        self.piece_at(x).map(|piece| piece.evaluate_at(x))
+    }
+}
+
+impl Polynomial {
+    /// Returns `max(p, q)` over `interval` as a [`PiecewisePolynomial`], with breakpoints at
+    /// the crossings of the two graphs inside the interval.
+    ///
+    /// Adjacent pieces where the same polynomial is larger are merged, so a point where the
+    /// graphs only touch does not introduce a breakpoint.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // max(x^2, x) on [-1, 2] is x^2, then x on [0, 1], then x^2 again
+    /// let parabola = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let line = Polynomial::new(vec![0f64, 1f64], 'x');
+    /// let envelope = parabola.piecewise_max(&line, (-1f64, 2f64));
+    ///
+    /// assert_eq!(envelope.pieces, vec![parabola.clone(), line.clone(), parabola]);
+    /// assert_eq!(envelope.evaluate_at(0.5), Some(0.5));
+    /// assert_eq!(envelope.evaluate_at(1.5), Some(2.25));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the interval is empty or reversed.
+    pub fn piecewise_max(&self, other: &Polynomial, interval: (f64, f64)) -> PiecewisePolynomial {
+        let (low, high) = interval;
+        assert!(low < high, "interval must be nonempty");
+        let difference = self.clone() - other.clone();
+        let mut candidates = vec![low];
+        candidates.extend(
+            difference
+                .real_roots()
+                .into_iter()
+                .filter(|&x| low < x && x < high),
+        );
+        candidates.push(high);
+
+        let mut breakpoints = vec![low];
+        let mut pieces: Vec<Polynomial> = Vec::new();
+        for pair in candidates.windows(2) {
+            let midpoint = (pair[0] + pair[1]) / 2f64;
+            let larger = if difference.evaluate_at(midpoint) >= 0f64 {
+                self
+            } else {
+                other
+            };
+            if pieces.last() == Some(larger) {
+                *breakpoints.last_mut().unwrap() = pair[1];
+            } else {
+                pieces.push(larger.clone());
+                breakpoints.push(pair[1]);
+            }
+        }
+
+        PiecewisePolynomial::new(breakpoints, pieces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_max_merges_tangencies() {
+        // x^2 touches 0 at the origin without crossing it
+        let parabola = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+        let zero = Polynomial::new(vec![0f64], 'x');
+        let envelope = parabola.piecewise_max(&zero, (-1f64, 1f64));
+        assert_eq!(envelope.breakpoints, vec![-1f64, 1f64]);
+        assert_eq!(envelope.pieces, vec![parabola]);
+    }
+
+    #[test]
+    fn test_piecewise_max_dominates_both() {
+        let cubic = Polynomial::new(vec![0f64, -1f64, 0f64, 1f64], 'x');
+        let line = Polynomial::new(vec![0.25, 0.5], 'x');
+        let envelope = cubic.piecewise_max(&line, (-2f64, 2f64));
+        assert_eq!(envelope.domain(), (-2f64, 2f64));
+        for i in 0..=40 {
+            let x = -2f64 + i as f64 * 0.1;
+            let expected = cubic.evaluate_at(x).max(line.evaluate_at(x));
+            assert!((envelope.evaluate_at(x).unwrap() - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_rejects_unsorted_breakpoints() {
+        let one = Polynomial::new(vec![1f64], 'x');
+        PiecewisePolynomial::new(vec![1f64, 0f64], vec![one]);
+    }
+}
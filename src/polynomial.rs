@@ -1,23 +1,15 @@
 use std::fmt;
 use std::ops::Add;
+use std::ops::Div;
+use std::ops::Rem;
 use std::ops::Sub;
+use std::ops::{Bound, RangeBounds};
 
-fn strip_from_end<T: PartialEq + Clone + Default>(list: Vec<T>, object: T) -> Vec<T> {
-    let mut new_list = list.clone();
-    let mut strip_amount: usize = 0;
-    for item in list.iter().rev() {
-        if *item == object {
-            strip_amount += 1;
-        } else {
-            break;
-        }
-    }
-    let default: T = Default::default();
-    new_list.resize(list.len() - strip_amount, default);
-    new_list
-}
+use crate::display::{self, FormatOptions, Term};
+use crate::tolerance::Tolerance;
 
 /// A simple polynomial representation with `coefficients` and an `indeterminate`.
+#[derive(Clone, PartialEq)]
 pub struct Polynomial {
     /// Coefficients of Polynomial. The index of each coefficient indicates its degree, for example in `vec![1, 2]`, the first value is explicitly `1x^0`, the second is `2x^1`, etc.
     pub coefficients: Vec<f64>,
@@ -75,6 +67,30 @@ impl Sub for Polynomial {
     }
 }
 
+impl Div for Polynomial {
+    type Output = Polynomial;
+
+    /// Returns the quotient of [`Polynomial::div_rem`], discarding the remainder.
+    ///
+    /// # Panics
+    /// Panics if `other` is the zero polynomial.
+    fn div(self, other: Polynomial) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl Rem for Polynomial {
+    type Output = Polynomial;
+
+    /// Returns the remainder of [`Polynomial::div_rem`].
+    ///
+    /// # Panics
+    /// Panics if `other` is the zero polynomial.
+    fn rem(self, other: Polynomial) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
 impl Polynomial {
     /// Returns a Polynomial from a vector of floats and an indeterminate
     /// # Example
@@ -85,19 +101,12 @@ impl Polynomial {
     /// assert_eq!(polynomial.coefficients, vec![1f64, 2f64, 3f64]);
     /// ```
     pub fn new(coefficients: Vec<f64>, indeterminate: char) -> Polynomial {
-        let stripped_coefficients = strip_from_end(coefficients, 0f64);
-        // Zero degree special case
-        if stripped_coefficients.len() == 0 {
-            return Polynomial {
-                coefficients: vec![0f64],
-                indeterminate,
-            };
-        }
-
-        Polynomial {
-            coefficients: stripped_coefficients,
+        let mut polynomial = Polynomial {
+            coefficients,
             indeterminate,
-        }
+        };
+        polynomial.normalize();
+        polynomial
     }
 
     /// Returns a Polynomial from a vector of integers and an indeterminate
@@ -109,20 +118,43 @@ impl Polynomial {
     /// assert_eq!(polynomial.coefficients, vec![1f64, 2f64, 3f64]);
     /// ```
     pub fn from_ints(coefficients: Vec<i64>, indeterminate: char) -> Polynomial {
-        let stripped_coefficients = strip_from_end(coefficients, 0i64);
-        // Zero degree special case
-        if stripped_coefficients.len() == 0 {
-            return Polynomial {
-                coefficients: vec![0f64],
-                indeterminate,
-            };
-        }
+        let float_coefficients = coefficients.iter().map(|&x| x as f64).collect();
+        Polynomial::new(float_coefficients, indeterminate)
+    }
 
-        let float_coefficients = stripped_coefficients.iter().map(|&x| x as f64).collect();
+    /// Drops trailing coefficients that are negligible under [`Tolerance::default()`], i.e.
+    /// exactly zero, in place, leaving `[0]` for the zero polynomial.
+    ///
+    /// Every constructor normalizes, and so every operation returning a new polynomial
+    /// (`+`, `-`, `multiply`, `div_rem`, ...) shares this policy; use
+    /// [`snap`](Polynomial::snap) to also drop nearly zero coefficients. Calling this is only
+    /// needed after editing `coefficients` directly. Negative zero counts as zero.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let mut polynomial = Polynomial::new(vec![1f64, 2f64], 'x');
+    /// polynomial.coefficients[1] = 0f64;
+    /// polynomial.coefficients.push(-0f64);
+    /// polynomial.normalize();
+    /// assert_eq!(polynomial.coefficients, vec![1f64]);
+    /// ```
+    pub fn normalize(&mut self) {
+        self.truncate_negligible(&Tolerance::default());
+    }
 
-        Polynomial {
-            coefficients: float_coefficients,
-            indeterminate,
+    /// Drops trailing coefficients for which `is_zero` holds, leaving `[0]` if none remain.
+    /// This is the single place where the representation of zero is decided.
+    pub(crate) fn truncate_trailing<F: Fn(f64) -> bool>(&mut self, is_zero: F) {
+        while self
+            .coefficients
+            .last()
+            .is_some_and(|&coeff| is_zero(coeff))
+        {
+            self.coefficients.pop();
+        }
+        if self.coefficients.is_empty() {
+            self.coefficients.push(0f64);
         }
     }
 
@@ -194,38 +226,173 @@ impl Polynomial {
         sum
     }
 
-    /// Return the polynomial represented as a String
+    /// Returns `[p(x), p'(x), ..., p^(k)(x)]`, the value and first `k` derivatives at `x`.
+    ///
+    /// Uses the generalized Horner scheme (repeated synthetic division), which needs no
+    /// intermediate derivative polynomials. Derivatives beyond the degree are zero.
     /// # Example
     /// ```
     /// use polynom::polynomial::Polynomial;
     ///
-    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
-    /// assert_eq!(polynomial.as_string(), String::from("f(x) = 1 + 2x + 3x^2"))
+    /// // p(x) = x^3 - 2x, so p' = 3x^2 - 2, p'' = 6x, p''' = 6
+    /// let polynomial = Polynomial::new(vec![0f64, -2f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.evaluate_derivatives(2f64, 4), vec![4f64, 10f64, 12f64, 6f64, 0f64]);
     /// ```
-    pub fn as_string(&self) -> String {
-        let mut terms = String::new();
-        for (degree, coeff) in self.coefficients.iter().enumerate() {
-            if degree == 0 {
-                terms = format!("{}", coeff);
-                continue;
-            }
+    pub fn evaluate_derivatives(&self, x: f64, k: usize) -> Vec<f64> {
+        let mut derivatives = self.taylor_coefficients(x, k + 1);
+        let mut factorial = 1f64;
+        for (j, derivative) in derivatives.iter_mut().enumerate().skip(1) {
+            factorial *= j as f64;
+            *derivative *= factorial;
+        }
+        derivatives.resize(k + 1, 0f64);
 
-            if degree == 1 {
-                terms = format!("{} + {}{}", terms, coeff, self.indeterminate);
-                continue;
-            }
+        derivatives
+    }
 
-            if *coeff == 0f64 {
-                continue;
+    /// Returns the polynomial re-expressed in powers of `(x - a)`: the `k`th coefficient of
+    /// the result is `p^(k)(a) / k!`, so evaluating it at `t` gives `p(a + t)`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 = (x - 1)^2 + 2(x - 1) + 1
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.taylor_at(1f64).coefficients, vec![1f64, 2f64, 1f64]);
+    /// ```
+    pub fn taylor_at(&self, a: f64) -> Polynomial {
+        Polynomial::new(
+            self.taylor_coefficients(a, self.coefficients.len()),
+            self.indeterminate,
+        )
+    }
+
+    /// Returns the first `count` Taylor coefficients `p^(j)(x) / j!` by repeated synthetic division.
+    fn taylor_coefficients(&self, x: f64, count: usize) -> Vec<f64> {
+        let mut scratch = self.coefficients.clone();
+        let n = scratch.len();
+        let count = count.min(n);
+        for j in 0..count {
+            // After this pass, scratch[j] holds p^(j)(x) / j!
+            for i in (j..n - 1).rev() {
+                scratch[i] += x * scratch[i + 1];
             }
+        }
+        scratch.truncate(count);
+
+        scratch
+    }
 
-            terms = format!("{} + {}{}^{}", terms, coeff, self.indeterminate, degree);
+    /// Evaluates every polynomial in `polynomials` at the same `x`, returning the values in order.
+    ///
+    /// The polynomials are evaluated together with interleaved Horner steps, one pass over the
+    /// degrees shared by all of them, which suits a polynomial with its derivatives or a basis set.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let basis = vec![
+    ///     Polynomial::new(vec![1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![-1f64, 0f64, 2f64], 'x'),
+    /// ];
+    /// assert_eq!(Polynomial::evaluate_bundle(&basis, 3f64), vec![1f64, 3f64, 17f64]);
+    /// ```
+    pub fn evaluate_bundle(polynomials: &[Polynomial], x: f64) -> Vec<f64> {
+        let length = polynomials
+            .iter()
+            .map(|polynomial| polynomial.coefficients.len())
+            .max()
+            .unwrap_or(0);
+        let mut values = vec![0f64; polynomials.len()];
+        for degree in (0..length).rev() {
+            for (value, polynomial) in values.iter_mut().zip(polynomials) {
+                if let Some(coeff) = polynomial.coefficients.get(degree) {
+                    *value = *value * x + coeff;
+                }
+            }
         }
 
-        format!("f({}) = {}", self.indeterminate, terms)
+        values
+    }
+
+    /// Evaluates the polynomial at `x` together with the standard deviation of that value,
+    /// returning `(p(x), sigma)`.
+    ///
+    /// `coeff_sigmas[i]` is the standard deviation of the coefficient of `x^i`; the
+    /// coefficients are treated as independent, so `sigma^2 = Σ (coeff_sigmas[i] x^i)^2`.
+    /// Missing sigmas count as exact coefficients, and extra sigmas apply to coefficients
+    /// that are zero but still uncertain.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // p(x) = 1 + 2x with sigmas 0.3 and 0.2: at x = 2, sigma = sqrt(0.3^2 + 0.4^2)
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64], 'x');
+    /// let (value, sigma) = polynomial.evaluate_with_uncertainty(2f64, &[0.3, 0.2]);
+    /// assert_eq!(value, 5f64);
+    /// assert!((sigma - 0.5).abs() < 1e-15);
+    /// ```
+    pub fn evaluate_with_uncertainty(&self, x: f64, coeff_sigmas: &[f64]) -> (f64, f64) {
+        let value = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0f64, |value, coeff| value * x + coeff);
+        let variance = coeff_sigmas
+            .iter()
+            .rev()
+            .fold(0f64, |variance, sigma| variance * x * x + sigma * sigma);
+
+        (value, variance.sqrt())
+    }
+
+    /// Return the polynomial represented as a String
+    ///
+    /// Zero terms are left out, negative terms are subtracted and unit coefficients are
+    /// omitted, so the zero polynomial prints as `f(x) = 0`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.as_string(), String::from("f(x) = 1 + 2x + 3x^2"));
+    ///
+    /// let polynomial = Polynomial::new(vec![-1f64, 0f64, 1f64, -2.5], 'x');
+    /// assert_eq!(polynomial.as_string(), String::from("f(x) = -1 + x^2 - 2.5x^3"));
+    /// ```
+    pub fn as_string(&self) -> String {
+        self.as_string_with(&FormatOptions::DEFAULT)
+    }
+
+    /// Return the polynomial represented as a String like [`as_string`](Polynomial::as_string),
+    /// with the decimal separator and multiplication sign chosen by `options`
+    /// # Example
+    /// ```
+    /// use polynom::display::FormatOptions;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![0.5, 2f64, -1f64], 'x');
+    /// assert_eq!(
+    ///     polynomial.as_string_with(&FormatOptions::EUROPEAN),
+    ///     String::from("f(x) = 0,5 + 2·x - x^2")
+    /// );
+    /// ```
+    pub fn as_string_with(&self, options: &FormatOptions) -> String {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter_map(|(degree, &coeff)| Term::from_f64(degree, coeff));
+        display::render(self.indeterminate, terms, options)
     }
 
     /// Return an integer representation of the degree of the Polynomial
+    ///
+    /// Leading coefficients are skipped while they are negligible under
+    /// [`Tolerance::default()`], as in [`normalize`](Polynomial::normalize); use
+    /// [`degree_with`](Polynomial::degree_with) for a looser tolerance. The zero polynomial
+    /// has degree `-1`.
     /// # Example
     /// ```
     /// use polynom::polynomial::Polynomial;
@@ -234,12 +401,403 @@ impl Polynomial {
     /// assert_eq!(polynomial.degree(), 2)
     /// ```
     pub fn degree(&self) -> isize {
-        // Special case zero polynomial
-        if self.coefficients == vec![0f64] {
-            return -1;
+        self.degree_with(&Tolerance::default())
+    }
+
+    /// Returns the polynomial `q` with `q(y) = p(x)`, where `y` is `x` carried from the interval `from` onto the interval `to` by the affine map between them.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // p(x) = x on [0, 10], re-expressed on [-1, 1]
+    /// let polynomial = Polynomial::new(vec![0f64, 1f64], 'x');
+    /// let mapped = polynomial.map_domain((0f64, 10f64), (-1f64, 1f64));
+    /// assert_eq!(mapped.coefficients, vec![5f64, 5f64]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if either interval has zero length.
+    pub fn map_domain(&self, from: (f64, f64), to: (f64, f64)) -> Polynomial {
+        assert!(
+            from.0 != from.1 && to.0 != to.1,
+            "cannot map between intervals of zero length"
+        );
+        // x = scale * y + offset takes `to` back onto `from`
+        let scale = (from.1 - from.0) / (to.1 - to.0);
+        let offset = from.0 - to.0 * scale;
+
+        self.compose_affine(scale, offset)
+    }
+
+    /// Returns `p(scale * x + offset)`, expanded with Horner's scheme.
+    fn compose_affine(&self, scale: f64, offset: f64) -> Polynomial {
+        let mut result: Vec<f64> = vec![0f64];
+        for coeff in self.coefficients.iter().rev() {
+            let mut next = vec![0f64; result.len() + 1];
+            for (i, value) in result.iter().enumerate() {
+                next[i] += value * offset;
+                next[i + 1] += value * scale;
+            }
+            next[0] += coeff;
+            result = next;
+        }
+
+        Polynomial::new(result, self.indeterminate)
+    }
+
+    /// Returns `p(x^k)`, spreading the coefficients `k` degrees apart
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.substitute_power(2).coefficients, vec![1f64, 0f64, 2f64, 0f64, 3f64]);
+    /// ```
+    pub fn substitute_power(&self, k: usize) -> Polynomial {
+        // x^0 = 1, so every term collapses onto the constant
+        if k == 0 {
+            return Polynomial::new(vec![self.coefficients.iter().sum()], self.indeterminate);
+        }
+
+        let mut coefficients = vec![0f64; (self.coefficients.len() - 1) * k + 1];
+        for (degree, coeff) in self.coefficients.iter().enumerate() {
+            coefficients[degree * k] = *coeff;
+        }
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the polynomial formed by every `k`-th coefficient starting at degree `phase`, i.e. the polyphase component `q` with `p(x) = sum x^phase q(x^k)`
+    ///
+    /// # Panics
+    /// Panics if `k` is zero.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64, 5f64], 'x');
+    /// assert_eq!(polynomial.decimate(2, 0).coefficients, vec![1f64, 3f64, 5f64]);
+    /// assert_eq!(polynomial.decimate(2, 1).coefficients, vec![2f64, 4f64]);
+    /// ```
+    pub fn decimate(&self, k: usize, phase: usize) -> Polynomial {
+        assert!(k > 0, "decimation factor must be nonzero");
+        let coefficients = self
+            .coefficients
+            .iter()
+            .skip(phase)
+            .step_by(k)
+            .cloned()
+            .collect();
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the terms of `self` whose degrees fall in `range`, each kept at its own degree
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64], 'x');
+    /// assert_eq!(polynomial.slice_degrees(1..3).coefficients, vec![0f64, 2f64, 3f64]);
+    /// assert_eq!(polynomial.slice_degrees(2..).coefficients, vec![0f64, 0f64, 3f64, 4f64]);
+    /// ```
+    pub fn slice_degrees<R: RangeBounds<usize>>(&self, range: R) -> Polynomial {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        }
+        .min(self.coefficients.len());
+        if start >= end {
+            return Polynomial::new(vec![0f64], self.indeterminate);
+        }
+
+        let mut coefficients = vec![0f64; end];
+        coefficients[start..].copy_from_slice(&self.coefficients[start..end]);
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the terms of degree below `k`, i.e. `p mod x^k`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.low_part(2).coefficients, vec![1f64, 2f64]);
+    /// ```
+    pub fn low_part(&self, k: usize) -> Polynomial {
+        self.slice_degrees(..k)
+    }
+
+    /// Returns the terms of degree `k` and above, kept at their degrees, so that
+    /// `low_part(k) + high_part(k)` is `self`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.high_part(2).coefficients, vec![0f64, 0f64, 3f64]);
+    /// ```
+    pub fn high_part(&self, k: usize) -> Polynomial {
+        self.slice_degrees(k..)
+    }
+
+    /// Splits `self` into `(high, low)` with `p = high · x^k + low`, where `low` has degree
+    /// below `k`: the quotient and remainder of division by `x^k`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64], 'x');
+    /// let (high, low) = polynomial.split_at_power(2);
+    /// assert_eq!(high.coefficients, vec![3f64, 4f64]);
+    /// assert_eq!(low.coefficients, vec![1f64, 2f64]);
+    /// ```
+    pub fn split_at_power(&self, k: usize) -> (Polynomial, Polynomial) {
+        let k = k.min(self.coefficients.len());
+        let (low, high) = self.coefficients.split_at(k);
+        let high = if high.is_empty() {
+            vec![0f64]
+        } else {
+            high.to_vec()
+        };
+
+        (
+            Polynomial::new(high, self.indeterminate),
+            Polynomial::new(low.to_vec(), self.indeterminate),
+        )
+    }
+
+    /// Returns an iterator over the powers `p^0, p^1, p^2, ...`, each computed from the
+    /// previous one with a single multiplication
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 1f64], 'x');
+    /// let powers: Vec<Vec<f64>> = polynomial.powers().take(3).map(|p| p.coefficients).collect();
+    /// assert_eq!(powers, vec![vec![1f64], vec![1f64, 1f64], vec![1f64, 2f64, 1f64]]);
+    /// ```
+    pub fn powers(&self) -> Powers {
+        Powers {
+            base: self.clone(),
+            current: None,
+        }
+    }
+
+    /// Return the derivative of the Polynomial
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64], 'x');
+    /// assert_eq!(polynomial.derivative().coefficients, vec![2f64, 6f64]);
+    /// ```
+    pub fn derivative(&self) -> Polynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(degree, coeff)| coeff * degree as f64)
+            .collect();
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the antiderivative whose value at zero is `constant`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // ∫ (2 + 6x) dx = 1 + 2x + 3x^2 with constant 1
+    /// let polynomial = Polynomial::new(vec![2f64, 6f64], 'x');
+    /// assert_eq!(polynomial.integral(1f64).coefficients, vec![1f64, 2f64, 3f64]);
+    /// ```
+    pub fn integral(&self, constant: f64) -> Polynomial {
+        let mut coefficients = vec![constant];
+        coefficients.extend(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(degree, coeff)| coeff / (degree + 1) as f64),
+        );
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the tangent line to the graph at `x0`, `y = p(x0) + p'(x0) (x - x0)`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // The tangent to x^2 at x = 1 is y = 2x - 1
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.tangent_at(1f64).coefficients, vec![-1f64, 2f64]);
+    /// ```
+    pub fn tangent_at(&self, x0: f64) -> Polynomial {
+        let derivatives = self.evaluate_derivatives(x0, 1);
+        let (value, slope) = (derivatives[0], derivatives[1]);
+
+        Polynomial::new(vec![value - slope * x0, slope], self.indeterminate)
+    }
+
+    /// Returns the normal line to the graph at `x0`, perpendicular to the tangent, or `None`
+    /// where the tangent is horizontal and the normal is vertical
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // The normal to x^2 at x = 1 is y = -x / 2 + 3 / 2
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.normal_at(1f64).unwrap().coefficients, vec![1.5, -0.5]);
+    /// assert!(polynomial.normal_at(0f64).is_none());
+    /// ```
+    pub fn normal_at(&self, x0: f64) -> Option<Polynomial> {
+        let derivatives = self.evaluate_derivatives(x0, 1);
+        let (value, slope) = (derivatives[0], derivatives[1]);
+        if slope == 0f64 {
+            return None;
         }
+        let normal_slope = -1f64 / slope;
 
-        (self.coefficients.len() - 1) as isize
+        Some(Polynomial::new(
+            vec![value - normal_slope * x0, normal_slope],
+            self.indeterminate,
+        ))
+    }
+
+    /// Returns the quotient and remainder of Euclidean division by `divisor`, so that
+    /// `self = quotient * divisor + remainder` with `remainder` of lower degree than `divisor`.
+    ///
+    /// Both results are normalized like [`Polynomial::new`], and keep the indeterminate of `self`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^3 - 2x + 5 = (x - 1)(x^2 + x - 1) + 4
+    /// let dividend = Polynomial::new(vec![5f64, -2f64, 0f64, 1f64], 'x');
+    /// let divisor = Polynomial::new(vec![-1f64, 1f64], 'x');
+    /// let (quotient, remainder) = dividend.div_rem(&divisor);
+    /// assert_eq!(quotient.coefficients, vec![-1f64, 1f64, 1f64]);
+    /// assert_eq!(remainder.coefficients, vec![4f64]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        let divisor_degree = divisor.degree();
+        assert!(divisor_degree >= 0, "division by the zero polynomial");
+        let divisor_degree = divisor_degree as usize;
+        let leading = divisor.coefficients[divisor_degree];
+
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree {
+            return (
+                Polynomial::new(vec![0f64], self.indeterminate),
+                Polynomial::new(remainder, self.indeterminate),
+            );
+        }
+        let mut quotient = vec![0f64; remainder.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree] / leading;
+            quotient[i] = factor;
+            for (j, coeff) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor * coeff;
+            }
+        }
+        // The eliminated leading terms are zero by construction, not just up to rounding
+        remainder.truncate(divisor_degree);
+
+        (
+            Polynomial::new(quotient, self.indeterminate),
+            Polynomial::new(remainder, self.indeterminate),
+        )
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `other`, treating only exactly
+    /// zero remainders as zero, as [`Tolerance::default()`] does
+    ///
+    /// Rounding usually hides common factors with inexact coefficients; use
+    /// [`gcd_with`](Polynomial::gcd_with) with a nonzero tolerance for those.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x - 1)(x + 3)(x - 2) and (x - 1)(x + 3)(x + 5) share (x - 1)(x + 3) = x^2 + 2x - 3
+    /// let a = Polynomial::new(vec![6f64, -7f64, 0f64, 1f64], 'x');
+    /// let b = Polynomial::new(vec![-15f64, 7f64, 7f64, 1f64], 'x');
+    /// let gcd = a.gcd(&b);
+    /// for (coeff, expected) in gcd.coefficients.iter().zip(&[-3f64, 2f64, 1f64]) {
+    ///     assert!((coeff - expected).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn gcd(&self, other: &Polynomial) -> Polynomial {
+        self.gcd_with(other, &Tolerance::default())
+    }
+
+    /// Like [`gcd`](Polynomial::gcd), but treats a remainder coefficient as zero when it is
+    /// negligible under `tolerance` relative to the largest coefficient of the divisor
+    ///
+    /// The gcd of two zero polynomials is zero.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// // The common factor x - 0.1 is only exact up to rounding
+    /// let a = Polynomial::new(vec![-0.1, 1f64], 'x').multiply(Polynomial::new(vec![0.3, 1f64], 'x'));
+    /// let b = Polynomial::new(vec![-0.1, 1f64], 'x').multiply(Polynomial::new(vec![-0.7, 1f64], 'x'));
+    /// let gcd = a.gcd_with(&b, &Tolerance::new(0f64, 1e-9));
+    /// assert_eq!(gcd.degree(), 1);
+    /// assert!((gcd.coefficients[0] + 0.1).abs() < 1e-12);
+    /// ```
+    pub fn gcd_with(&self, other: &Polynomial, tolerance: &Tolerance) -> Polynomial {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while b.degree() >= 0 {
+            let (_, mut remainder) = a.div_rem(&b);
+            let scale = b.norm_linf();
+            remainder.truncate_trailing(|coeff| tolerance.is_negligible(coeff, scale));
+            remainder.indeterminate = self.indeterminate;
+            a = b;
+            b = remainder;
+        }
+
+        let degree = a.degree();
+        if degree < 0 {
+            return a;
+        }
+        let leading = a.coefficients[degree as usize];
+        let coefficients = a.coefficients.iter().map(|coeff| coeff / leading).collect();
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+}
+
+/// The endless iterator over `p^0, p^1, p^2, ...` returned by [`Polynomial::powers`].
+#[derive(Debug, Clone)]
+pub struct Powers {
+    base: Polynomial,
+    current: Option<Polynomial>,
+}
+
+impl Iterator for Powers {
+    type Item = Polynomial;
+
+    fn next(&mut self) -> Option<Polynomial> {
+        let mut power = match self.current.take() {
+            None => Polynomial::new(vec![1f64], self.base.indeterminate),
+            Some(previous) => previous.multiply(self.base.clone()),
+        };
+        power.indeterminate = self.base.indeterminate;
+        self.current = Some(power.clone());
+
+        Some(power)
     }
 }
 
@@ -248,14 +806,19 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_strip_from_end() {
-        assert_eq!(
-            strip_from_end(vec![1, 2, 0, 3, 0, 0, 0], 0),
-            vec![1, 2, 0, 3]
-        );
+    fn test_normalize() {
+        let mut polynomial = Polynomial::from_ints(vec![1, 2, 0, 3], 'x');
+        polynomial.coefficients.extend(vec![0f64, -0f64, 0f64]);
+        polynomial.normalize();
+        assert_eq!(polynomial.coefficients, vec![1f64, 2f64, 0f64, 3f64]);
+
+        polynomial.coefficients.clear();
+        polynomial.normalize();
+        assert_eq!(polynomial.coefficients, vec![0f64]);
     }
+
     #[test]
-    fn test_strip_from_end_on_polynomial() {
+    fn test_new_normalizes() {
         let polynomial = Polynomial::new(vec![1f64, 2f64, 0f64, 3f64, 0f64], 'x');
         assert_eq!(polynomial.coefficients, vec![1f64, 2f64, 0f64, 3f64]);
     }
@@ -412,4 +975,281 @@ mod tests {
 
         assert_eq!(result.coefficients, vec![0f64, 0f64, 0f64, 0f64, -4f64])
     }
+
+    #[test]
+    fn test_map_domain() {
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 3f64], 'x');
+        let mapped = polynomial.map_domain((2f64, 6f64), (-1f64, 1f64));
+
+        for &x in &[2f64, 3f64, 4.5, 6f64] {
+            let y = (x - 4f64) / 2f64;
+            assert!((mapped.evaluate_at(y) - polynomial.evaluate_at(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_map_domain_round_trip() {
+        let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64], 't');
+        let round_trip = polynomial
+            .map_domain((0f64, 1f64), (-1f64, 1f64))
+            .map_domain((-1f64, 1f64), (0f64, 1f64));
+
+        assert_eq!(round_trip.indeterminate, 't');
+        for (actual, expected) in round_trip.coefficients.iter().zip(&polynomial.coefficients) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot map between intervals of zero length")]
+    fn test_map_domain_rejects_degenerate_interval() {
+        Polynomial::new(vec![1f64, 1f64], 'x').map_domain((0f64, 1f64), (2f64, 2f64));
+    }
+
+    #[test]
+    fn test_substitute_power() {
+        let polynomial = Polynomial::new(vec![1f64, -1f64, 2f64], 'x');
+        let substituted = polynomial.substitute_power(3);
+
+        assert_eq!(substituted.degree(), 6);
+        assert_eq!(substituted.evaluate_at(2f64), polynomial.evaluate_at(8f64));
+        assert_eq!(
+            polynomial.substitute_power(1).coefficients,
+            polynomial.coefficients
+        );
+        assert_eq!(polynomial.substitute_power(0).coefficients, vec![2f64]);
+    }
+
+    #[test]
+    fn test_decimate_recombines_polyphase_components() {
+        let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64, 7f64], 'x');
+        let x = 1.5f64;
+
+        let recombined: f64 = (0..3)
+            .map(|phase| {
+                let component = polynomial.decimate(3, phase);
+                x.powi(phase as i32) * component.substitute_power(3).evaluate_at(x)
+            })
+            .sum();
+
+        assert!((recombined - polynomial.evaluate_at(x)).abs() < 1e-12);
+        assert_eq!(polynomial.decimate(3, 7).coefficients, vec![0f64]);
+    }
+
+    #[test]
+    fn test_derivative() {
+        let polynomial = Polynomial::new(vec![5f64, -1f64, 0f64, 4f64], 'x');
+
+        assert_eq!(
+            polynomial.derivative().coefficients,
+            vec![-1f64, 0f64, 12f64]
+        );
+        assert_eq!(polynomial.derivative().indeterminate, 'x');
+        assert_eq!(
+            Polynomial::new(vec![5f64], 'x').derivative().coefficients,
+            vec![0f64]
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // (x^3 - 2x + 5) = (x - 1)(x^2 + x - 1) + 4
+        let dividend = Polynomial::new(vec![5f64, -2f64, 0f64, 1f64], 'x');
+        let divisor = Polynomial::new(vec![-1f64, 1f64], 'x');
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert_eq!(quotient.coefficients, vec![-1f64, 1f64, 1f64]);
+        assert_eq!(remainder.coefficients, vec![4f64]);
+
+        let (quotient, remainder) = divisor.div_rem(&dividend);
+        assert_eq!(quotient.degree(), -1);
+        assert_eq!(remainder, divisor);
+    }
+
+    #[test]
+    fn test_evaluate_bundle_matches_individual_evaluation() {
+        let polynomials = vec![
+            Polynomial::new(vec![2f64, -3f64, 0.5, 1f64], 'x'),
+            Polynomial::new(vec![0f64], 'x'),
+            Polynomial::new(vec![-7f64, 0.25], 'x'),
+        ];
+        for &x in &[-2f64, 0f64, 0.5, 3f64] {
+            let values = Polynomial::evaluate_bundle(&polynomials, x);
+            for (value, polynomial) in values.iter().zip(&polynomials) {
+                assert!((value - polynomial.evaluate_at(x)).abs() < 1e-12);
+            }
+        }
+        assert!(Polynomial::evaluate_bundle(&[], 1f64).is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_derivatives_matches_repeated_differentiation() {
+        let polynomial = Polynomial::new(vec![1f64, -4f64, 0.5, 2f64, -1f64, 0.25], 'x');
+        for &x in &[-1.5, 0f64, 0.75, 2f64] {
+            let derivatives = polynomial.evaluate_derivatives(x, 7);
+            let mut current = polynomial.clone();
+            for derivative in derivatives {
+                assert!((derivative - current.evaluate_at(x)).abs() < 1e-10);
+                current = current.derivative();
+            }
+        }
+        assert_eq!(polynomial.evaluate_derivatives(1f64, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_taylor_at_shifts_the_argument() {
+        let polynomial = Polynomial::new(vec![3f64, -1f64, 0.5, 2f64, -0.25], 'x');
+        for &a in &[-2f64, 0f64, 1.5] {
+            let shifted = polynomial.taylor_at(a);
+            assert_eq!(shifted.degree(), polynomial.degree());
+            for &t in &[-1f64, 0.25, 2f64] {
+                assert!((shifted.evaluate_at(t) - polynomial.evaluate_at(a + t)).abs() < 1e-10);
+            }
+        }
+        assert_eq!(polynomial.taylor_at(0f64), polynomial);
+    }
+
+    #[test]
+    fn test_div_and_rem_operators() {
+        let dividend = Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'x');
+        let divisor = Polynomial::new(vec![-1f64, 1f64], 'x');
+
+        assert_eq!(
+            (dividend.clone() / divisor.clone()).coefficients,
+            vec![1f64, 1f64, 1f64]
+        );
+        assert_eq!((dividend % divisor).coefficients, vec![0f64]);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn test_division_by_zero_polynomial() {
+        let _ = Polynomial::new(vec![1f64], 'x') / Polynomial::new(vec![0f64], 'x');
+    }
+
+    #[test]
+    fn test_gcd_edge_cases() {
+        let p = Polynomial::new(vec![2f64, 4f64], 'x');
+        let zero = Polynomial::new(vec![0f64], 'x');
+        let coprime = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+
+        assert_eq!(p.gcd(&zero).coefficients, vec![0.5, 1f64]);
+        assert_eq!(zero.gcd(&p).coefficients, vec![0.5, 1f64]);
+        assert_eq!(p.gcd(&coprime).coefficients, vec![1f64]);
+        assert_eq!(zero.gcd(&zero).degree(), -1);
+    }
+
+    #[test]
+    fn test_integral_inverts_derivative() {
+        let polynomial = Polynomial::new(vec![4f64, -1f64, 0.5, 3f64], 'x');
+
+        assert_eq!(polynomial.integral(0f64).derivative(), polynomial);
+        assert_eq!(polynomial.derivative().integral(4f64), polynomial);
+        assert_eq!(Polynomial::new(vec![0f64], 'x').integral(0f64).degree(), -1);
+    }
+
+    #[test]
+    fn test_tangent_and_normal_are_perpendicular() {
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 0.5, 0.25], 'x');
+        for &x0 in &[-3f64, 0.5, 2f64] {
+            let tangent = polynomial.tangent_at(x0);
+            let normal = polynomial.normal_at(x0).unwrap();
+
+            assert!((tangent.evaluate_at(x0) - polynomial.evaluate_at(x0)).abs() < 1e-12);
+            assert!((normal.evaluate_at(x0) - polynomial.evaluate_at(x0)).abs() < 1e-12);
+            assert!((tangent.coefficients[1] * normal.coefficients[1] + 1f64).abs() < 1e-12);
+        }
+        // Tangent to a constant is the constant itself
+        assert_eq!(
+            Polynomial::new(vec![3f64], 'x')
+                .tangent_at(5f64)
+                .coefficients,
+            vec![3f64]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_with_uncertainty() {
+        let polynomial = Polynomial::new(vec![3f64, 0f64, -1f64], 'x');
+
+        // Exact coefficients give an exact value
+        assert_eq!(
+            polynomial.evaluate_with_uncertainty(2f64, &[]),
+            (-1f64, 0f64)
+        );
+
+        // Only the uncertain constant term contributes, whatever x is
+        assert_eq!(polynomial.evaluate_with_uncertainty(10f64, &[0.5]).1, 0.5);
+
+        // A zero cubic term with sigma 0.1 contributes 0.1 * |x|^3
+        let (value, sigma) = polynomial.evaluate_with_uncertainty(-2f64, &[0f64, 0f64, 0f64, 0.1]);
+        assert_eq!(value, -1f64);
+        assert!((sigma - 0.8).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_slice_degrees() {
+        let polynomial = Polynomial::new(vec![1f64, -2f64, 0f64, 4f64, 5f64], 'x');
+        for k in 0..7 {
+            let low = polynomial.low_part(k);
+            let high = polynomial.high_part(k);
+            assert_eq!(low + high, polynomial);
+        }
+        assert_eq!(
+            polynomial.slice_degrees(..).coefficients,
+            polynomial.coefficients
+        );
+        assert_eq!(polynomial.slice_degrees(2..=2).degree(), -1);
+        assert_eq!(
+            polynomial.slice_degrees(1..=3).coefficients,
+            vec![0f64, -2f64, 0f64, 4f64]
+        );
+        assert_eq!(
+            polynomial
+                .slice_degrees((Bound::Excluded(2), Bound::Excluded(3)))
+                .degree(),
+            -1
+        );
+        assert_eq!(polynomial.slice_degrees(9..).degree(), -1);
+        assert_eq!(polynomial.low_part(0).degree(), -1);
+        assert_eq!(polynomial.high_part(0), polynomial);
+        assert_eq!(polynomial.slice_degrees(1..).indeterminate, 'x');
+    }
+
+    #[test]
+    fn test_split_at_power() {
+        let polynomial = Polynomial::new(vec![1f64, 0f64, -3f64, 2f64], 'x');
+        for k in 0..6 {
+            let (high, low) = polynomial.split_at_power(k);
+            assert!(low.degree() < k as isize);
+            let mut power = vec![0f64; k + 1];
+            power[k] = 1f64;
+            let power = Polynomial::new(power, 'x');
+            assert_eq!(polynomial.div_rem(&power).0, high);
+            assert_eq!(high.multiply(power) + low, polynomial);
+        }
+
+        // Trailing zeros of the low half are normalized away
+        let (high, low) = polynomial.split_at_power(2);
+        assert_eq!(high.coefficients, vec![-3f64, 2f64]);
+        assert_eq!(low.coefficients, vec![1f64]);
+        assert_eq!(polynomial.split_at_power(0).1.degree(), -1);
+        assert_eq!(polynomial.split_at_power(4).0.degree(), -1);
+    }
+
+    #[test]
+    fn test_powers() {
+        let polynomial = Polynomial::new(vec![-1f64, 0f64, 2f64], 't');
+        let mut expected = Polynomial::new(vec![1f64], 't');
+        for power in polynomial.powers().take(6) {
+            assert_eq!(power, expected);
+            expected = expected.multiply(polynomial.clone());
+            expected.indeterminate = 't';
+        }
+        assert_eq!(polynomial.powers().nth(5).unwrap().degree(), 10);
+
+        let zero = Polynomial::new(vec![0f64], 'x');
+        let degrees: Vec<isize> = zero.powers().take(3).map(|p| p.degree()).collect();
+        assert_eq!(degrees, vec![0, -1, -1]);
+    }
 }
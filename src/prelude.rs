@@ -0,0 +1,49 @@
+//! The most commonly used types, traits and macros, for glob import.
+//!
+//! Everything here is also available from its own module; the prelude only gathers the
+//! names most programs need into a single import that stays stable as modules are added.
+//! # Example
+//! ```
+//! use polynom::prelude::*;
+//!
+//! let a = Polynomial::new(vec![1f64, 1f64], 'x');
+//! let b = Polynomial::new(vec![-1f64, 1f64], 'x');
+//! assert_poly_eq!(a.mul_poly(&b), Polynomial::new(vec![-1f64, 0f64, 1f64], 'x'));
+//!
+//! let value = a.evaluate_generic(Dual::new(2f64, 1f64));
+//! assert_eq!((value.value, value.derivative), (3f64, 1f64));
+//! ```
+
+pub use crate::algebraic::{AlgebraicError, AlgebraicNumber};
+pub use crate::assert_poly_eq;
+pub use crate::batch::EvaluationBackend;
+pub use crate::complex::Complex;
+pub use crate::composed::ComposedPoly;
+pub use crate::families::Family;
+pub use crate::fit::{ChebyshevFit, FitError};
+pub use crate::gf2::Gf2Polynomial;
+pub use crate::gfp::GfpPolynomial;
+pub use crate::ops::PolynomialOps;
+pub use crate::piecewise::PiecewisePolynomial;
+pub use crate::polynomial::Polynomial;
+pub use crate::random::{RandomSource, SplitMix64};
+pub use crate::rational::Rational;
+pub use crate::rational_function::RationalFunction;
+pub use crate::ring::{Dual, Jet, Ring};
+pub use crate::roots::{Root, RootError, RootMethod, RootOptions};
+pub use crate::tolerance::{Tolerance, ZeroSnapping};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prelude_brings_traits_into_scope() {
+        let a = Polynomial::new(vec![1f64, 2f64], 'x');
+        let b = Polynomial::new(vec![3f64, 0f64, 1f64], 'x');
+        assert_poly_eq!(a.mul_poly(&b), a.multiply(b.clone()));
+
+        let value = b.evaluate_generic(Dual::new(2f64, 1f64));
+        assert_eq!(value.derivative, 4f64);
+    }
+}
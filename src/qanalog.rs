@@ -0,0 +1,173 @@
+//! q-analogs from combinatorics: q-integers, q-factorials and Gaussian binomial coefficients,
+//! as polynomials in `q` with exact integer coefficients.
+//!
+//! The `_coefficients` functions return the exact coefficients, lowest degree first, as
+//! `u64`; the others return the same polynomial as a [`Polynomial`] in `q`, whose
+//! coefficients are exact as long as they stay below `2^53`.
+
+use crate::polynomial::Polynomial;
+
+/// Returns the coefficients of the q-integer `[n]_q = 1 + q + ... + q^(n - 1)`; `[0]_q` is zero.
+pub fn q_integer_coefficients(n: usize) -> Vec<u64> {
+    if n == 0 {
+        return vec![0];
+    }
+    vec![1; n]
+}
+
+/// Returns the coefficients of the q-factorial `[n]_q! = [1]_q [2]_q ... [n]_q`
+/// # Example
+/// ```
+/// use polynom::qanalog::q_factorial_coefficients;
+///
+/// // [3]_q! = (1)(1 + q)(1 + q + q^2), which counts permutations of 3 by inversions
+/// assert_eq!(q_factorial_coefficients(3), vec![1, 2, 2, 1]);
+/// ```
+///
+/// # Panics
+/// Panics if a coefficient overflows `u64`.
+pub fn q_factorial_coefficients(n: usize) -> Vec<u64> {
+    let mut coefficients = vec![1u64];
+    for i in 2..=n {
+        // Multiplying by [i]_q replaces each coefficient with a sliding window sum
+        let mut next = vec![0u64; coefficients.len() + i - 1];
+        let mut window = 0u64;
+        for (degree, slot) in next.iter_mut().enumerate() {
+            if let Some(&coeff) = coefficients.get(degree) {
+                window = window
+                    .checked_add(coeff)
+                    .expect("q-factorial coefficient overflow");
+            }
+            if degree >= i {
+                window -= coefficients[degree - i];
+            }
+            *slot = window;
+        }
+        coefficients = next;
+    }
+
+    coefficients
+}
+
+/// Returns the coefficients of the Gaussian binomial coefficient `[n choose k]_q`, which is
+/// zero when `k > n`
+/// # Example
+/// ```
+/// use polynom::qanalog::gaussian_binomial_coefficients;
+///
+/// // [4 choose 2]_q = 1 + q + 2q^2 + q^3 + q^4
+/// assert_eq!(gaussian_binomial_coefficients(4, 2), vec![1, 1, 2, 1, 1]);
+/// ```
+///
+/// # Panics
+/// Panics if a coefficient overflows `u64`.
+pub fn gaussian_binomial_coefficients(n: usize, k: usize) -> Vec<u64> {
+    if k > n {
+        return vec![0];
+    }
+    let k = k.min(n - k);
+    // row[j] holds [m choose j]_q for the current m, built with the q-Pascal rule
+    // [m choose j]_q = [m - 1 choose j - 1]_q + q^j [m - 1 choose j]_q
+    let mut row: Vec<Vec<u64>> = vec![vec![1u64]];
+    for m in 1..=n {
+        let width = k.min(m);
+        let mut next: Vec<Vec<u64>> = Vec::with_capacity(width + 1);
+        for j in 0..=width {
+            let mut coefficients = vec![0u64; j * (m - j) + 1];
+            if j > 0 {
+                for (degree, &coeff) in row[j - 1].iter().enumerate() {
+                    coefficients[degree] += coeff;
+                }
+            }
+            if j < row.len() && j < m {
+                for (degree, &coeff) in row[j].iter().enumerate() {
+                    let slot = &mut coefficients[degree + j];
+                    *slot = slot
+                        .checked_add(coeff)
+                        .expect("Gaussian binomial coefficient overflow");
+                }
+            }
+            next.push(coefficients);
+        }
+        row = next;
+    }
+
+    row.swap_remove(k)
+}
+
+/// Returns the q-integer `[n]_q` as a polynomial in `q`
+pub fn q_integer(n: usize) -> Polynomial {
+    to_polynomial(q_integer_coefficients(n))
+}
+
+/// Returns the q-factorial `[n]_q!` as a polynomial in `q`
+///
+/// # Panics
+/// Panics if a coefficient overflows `u64`.
+pub fn q_factorial(n: usize) -> Polynomial {
+    to_polynomial(q_factorial_coefficients(n))
+}
+
+/// Returns the Gaussian binomial coefficient `[n choose k]_q` as a polynomial in `q`
+/// # Example
+/// ```
+/// use polynom::qanalog::gaussian_binomial;
+///
+/// // At q = 1 it reduces to the ordinary binomial coefficient
+/// assert_eq!(gaussian_binomial(6, 3).evaluate_at(1f64), 20f64);
+/// // At q = 2 it counts the 2-dimensional subspaces of GF(2)^4
+/// assert_eq!(gaussian_binomial(4, 2).evaluate_at(2f64), 35f64);
+/// ```
+///
+/// # Panics
+/// Panics if a coefficient overflows `u64`.
+pub fn gaussian_binomial(n: usize, k: usize) -> Polynomial {
+    to_polynomial(gaussian_binomial_coefficients(n, k))
+}
+
+fn to_polynomial(coefficients: Vec<u64>) -> Polynomial {
+    Polynomial::new(
+        coefficients.into_iter().map(|coeff| coeff as f64).collect(),
+        'q',
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gaussian_binomial_is_ratio_of_factorials() {
+        // [n choose k]_q [k]_q! [n - k]_q! = [n]_q!
+        for n in 0..9 {
+            for k in 0..=n {
+                let product = gaussian_binomial(n, k)
+                    .multiply(q_factorial(k))
+                    .multiply(q_factorial(n - k));
+                assert_eq!(product.coefficients, q_factorial(n).coefficients);
+            }
+        }
+        assert_eq!(gaussian_binomial_coefficients(3, 5), vec![0]);
+        assert_eq!(gaussian_binomial_coefficients(5, 0), vec![1]);
+        assert_eq!(gaussian_binomial(3, 5).degree(), -1);
+    }
+
+    #[test]
+    fn test_symmetry_and_q_integers() {
+        for n in 1..12 {
+            assert_eq!(
+                gaussian_binomial_coefficients(n, 1),
+                q_integer_coefficients(n)
+            );
+        }
+        let coefficients = gaussian_binomial_coefficients(10, 4);
+        let mut reversed = coefficients.clone();
+        reversed.reverse();
+        assert_eq!(coefficients, reversed);
+        assert_eq!(coefficients.iter().sum::<u64>(), 210);
+        assert_eq!(q_integer(3).indeterminate, 'q');
+        assert_eq!(q_integer_coefficients(0), vec![0]);
+        // The number of permutations of 5 is the sum of the inversion counts
+        assert_eq!(q_factorial_coefficients(5).iter().sum::<u64>(), 120);
+    }
+}
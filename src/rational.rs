@@ -0,0 +1,248 @@
+//! Exact fractions, for recovering the "intended" coefficients behind floating-point values.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// A fraction `numerator / denominator` in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// The numerator, carrying the sign of the fraction.
+    pub numerator: i64,
+    /// The denominator, always positive.
+    pub denominator: i64,
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+impl Rational {
+    /// Returns `numerator / denominator` reduced to lowest terms.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero, or if the reduced fraction does not fit an `i64` once
+    /// its sign is moved to the numerator, as for `i64::MIN / -1`. See
+    /// [`Rational::checked_new`].
+    pub fn new(numerator: i64, denominator: i64) -> Rational {
+        assert!(denominator != 0, "denominator must be nonzero");
+        Rational::checked_new(numerator, denominator).expect("reduced fraction overflows i64")
+    }
+
+    /// Returns `numerator / denominator` reduced to lowest terms, or `None` if `denominator`
+    /// is zero or the reduced fraction does not fit an `i64`
+    /// # Example
+    /// ```
+    /// use polynom::rational::Rational;
+    ///
+    /// assert_eq!(Rational::checked_new(6, -8), Some(Rational::new(-3, 4)));
+    /// assert_eq!(Rational::checked_new(i64::MIN, -2), Some(Rational::new(1 << 62, 1)));
+    /// assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+    /// assert_eq!(Rational::checked_new(1, 0), None);
+    /// ```
+    pub fn checked_new(numerator: i64, denominator: i64) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        // The divisor may be 2^63, and negating may leave the range of i64
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128
+            * denominator.signum() as i128;
+
+        Some(Rational {
+            numerator: i64::try_from(numerator as i128 / divisor).ok()?,
+            denominator: i64::try_from(denominator as i128 / divisor).ok()?,
+        })
+    }
+
+    /// Returns the closest fraction to `value` whose denominator is at most `max_denominator`,
+    /// or `None` if `value` is not finite, does not fit an `i64`, or `max_denominator` is zero.
+    ///
+    /// Walks the continued-fraction expansion of `value` (the Stern–Brocot tree), taking the
+    /// best semiconvergent once the next convergent's denominator would be too large.
+    /// # Example
+    /// ```
+    /// use polynom::rational::Rational;
+    ///
+    /// assert_eq!(Rational::approximate(0.75, 100), Some(Rational::new(3, 4)));
+    /// assert_eq!(Rational::approximate(1f64 / 3f64, 100), Some(Rational::new(1, 3)));
+    /// assert_eq!(Rational::approximate(std::f64::consts::PI, 1000), Some(Rational::new(355, 113)));
+    /// assert_eq!(Rational::approximate(-std::f64::consts::PI, 100), Some(Rational::new(-311, 99)));
+    /// ```
+    pub fn approximate(value: f64, max_denominator: u64) -> Option<Rational> {
+        if !value.is_finite() || value.abs() >= i64::MAX as f64 || max_denominator == 0 {
+            return None;
+        }
+        let max_denominator = max_denominator.min(i64::MAX as u64) as u128;
+        let magnitude = value.abs();
+
+        // Convergents h/k, with (h_{-2}, k_{-2}) = (0, 1) and (h_{-1}, k_{-1}) = (1, 0)
+        let (mut h0, mut k0, mut h1, mut k1) = (0u128, 1u128, 1u128, 0u128);
+        let mut remainder = magnitude;
+        for _ in 0..64 {
+            let a = remainder.floor();
+            let a_int = a as u128;
+            let h2 = a_int.saturating_mul(h1).saturating_add(h0);
+            let k2 = a_int.saturating_mul(k1).saturating_add(k0);
+            if k2 > max_denominator {
+                // Largest semiconvergent that still fits, compared with the last convergent
+                let steps = (max_denominator - k0) / k1;
+                let (hs, ks) = (steps * h1 + h0, steps * k1 + k0);
+                let error = |h: u128, k: u128| (h as f64 / k as f64 - magnitude).abs();
+                if ks > 0 && error(hs, ks) < error(h1, k1) {
+                    h1 = hs;
+                    k1 = ks;
+                }
+                break;
+            }
+            h0 = h1;
+            k0 = k1;
+            h1 = h2;
+            k1 = k2;
+
+            let fraction = remainder - a;
+            if fraction == 0f64 || h1 as f64 / k1 as f64 == magnitude {
+                break;
+            }
+            remainder = 1f64 / fraction;
+        }
+
+        let numerator = i64::try_from(h1).ok()?;
+        let denominator = i64::try_from(k1).ok()?;
+        Some(Rational::new(
+            if value < 0f64 { -numerator } else { numerator },
+            denominator,
+        ))
+    }
+
+    /// Returns the nearest `f64` to the fraction.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Polynomial {
+    /// Returns each coefficient as the closest fraction with denominator at most
+    /// `max_denominator`, or `None` if some coefficient cannot be represented
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::rational::Rational;
+    ///
+    /// // A numeric fit of (x^2 - x) / 3 + 1/7, slightly perturbed
+    /// let fitted = Polynomial::new(vec![0.142857142857, -0.3333333334, 0.33333333329], 'x');
+    /// let exact = fitted.rationalize(100).unwrap();
+    /// assert_eq!(exact, vec![Rational::new(1, 7), Rational::new(-1, 3), Rational::new(1, 3)]);
+    /// ```
+    pub fn rationalize(&self, max_denominator: u64) -> Option<Vec<Rational>> {
+        self.coefficients
+            .iter()
+            .map(|&coeff| Rational::approximate(coeff, max_denominator))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces_and_normalizes_sign() {
+        assert_eq!(
+            Rational::new(6, -8),
+            Rational {
+                numerator: -3,
+                denominator: 4
+            }
+        );
+        assert_eq!(
+            Rational::new(0, -5),
+            Rational {
+                numerator: 0,
+                denominator: 1
+            }
+        );
+        assert_eq!(Rational::new(-6, 3).to_string(), "-2");
+        assert_eq!(Rational::new(1, 3).to_string(), "1/3");
+    }
+
+    #[test]
+    fn test_checked_new_at_the_edges_of_i64() {
+        assert_eq!(
+            Rational::checked_new(i64::MIN, i64::MIN),
+            Some(Rational::new(1, 1))
+        );
+        assert_eq!(
+            Rational::checked_new(0, i64::MIN),
+            Some(Rational::new(0, 1))
+        );
+        assert_eq!(
+            Rational::checked_new(i64::MIN, 1),
+            Some(Rational {
+                numerator: i64::MIN,
+                denominator: 1
+            })
+        );
+        assert_eq!(Rational::checked_new(1, i64::MIN), None);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "reduced fraction overflows i64")]
+    fn test_new_panics_on_overflow() {
+        Rational::new(i64::MIN, -1);
+    }
+
+    #[test]
+    fn test_approximate_edge_cases() {
+        assert_eq!(Rational::approximate(f64::NAN, 10), None);
+        assert_eq!(Rational::approximate(1e300, 10), None);
+        assert_eq!(Rational::approximate(0.5, 0), None);
+        assert_eq!(Rational::approximate(0f64, 10), Some(Rational::new(0, 1)));
+        assert_eq!(Rational::approximate(7f64, 1), Some(Rational::new(7, 1)));
+        // 0.3 rounds to 0 or 1 with denominator 1; 0.6 to 1
+        assert_eq!(Rational::approximate(0.3, 1), Some(Rational::new(0, 1)));
+        assert_eq!(Rational::approximate(0.6, 1), Some(Rational::new(1, 1)));
+    }
+
+    #[test]
+    fn test_approximate_is_best_within_bound() {
+        // Exhaustively check against every fraction with a small denominator
+        for &value in &[
+            0.2718281828,
+            std::f64::consts::SQRT_2,
+            0.577215664,
+            2.5066282746,
+        ] {
+            let best = Rational::approximate(value, 50).unwrap();
+            let best_error = (best.to_f64() - value).abs();
+            for denominator in 1..=50i64 {
+                let numerator = (value * denominator as f64).round() as i64;
+                let error = (numerator as f64 / denominator as f64 - value).abs();
+                assert!(
+                    best_error <= error + 1e-15,
+                    "{} vs {}/{}",
+                    best,
+                    numerator,
+                    denominator
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Ratios of polynomials.
+
+use crate::polynomial::Polynomial;
+use crate::tolerance::Tolerance;
+
+/// The rational function `numerator(x) / denominator(x)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction {
+    /// The polynomial above the fraction bar.
+    pub numerator: Polynomial,
+    /// The polynomial below the fraction bar.
+    pub denominator: Polynomial,
+}
+
+impl RationalFunction {
+    /// Returns the rational function `numerator / denominator`.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is the zero polynomial.
+    pub fn new(numerator: Polynomial, denominator: Polynomial) -> RationalFunction {
+        assert!(
+            denominator.degree() >= 0,
+            "denominator must not be the zero polynomial"
+        );
+        RationalFunction {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Return the result of evaluating the rational function at `x`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::rational_function::RationalFunction;
+    ///
+    /// let f = RationalFunction::new(
+    ///     Polynomial::new(vec![1f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 2f64], 'x'),
+    /// );
+    /// assert_eq!(f.evaluate_at(2f64), 0.75);
+    /// ```
+    pub fn evaluate_at(&self, x: f64) -> f64 {
+        self.numerator.evaluate_at(x) / self.denominator.evaluate_at(x)
+    }
+
+    /// Returns the partial quotients `q_0, q_1, ...` of the Euclidean continued-fraction
+    /// expansion `N / D = q_0 + 1 / (q_1 + 1 / (q_2 + ...))`.
+    ///
+    /// Each quotient comes from one step of the Euclidean algorithm on the numerator and
+    /// denominator. The expansion ends when a remainder is negligible under `tolerance`
+    /// relative to the preceding divisor; with [`Tolerance::EXACT`] only an exact zero
+    /// remainder ends it.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::rational_function::RationalFunction;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// // (x^2 + 1) / x = x + 1 / x
+    /// let f = RationalFunction::new(
+    ///     Polynomial::new(vec![1f64, 0f64, 1f64], 'x'),
+    ///     Polynomial::new(vec![0f64, 1f64], 'x'),
+    /// );
+    /// let quotients = f.continued_fraction(&Tolerance::EXACT);
+    /// assert_eq!(quotients.len(), 2);
+    /// assert_eq!(quotients[0].coefficients, vec![0f64, 1f64]);
+    /// assert_eq!(quotients[1].coefficients, vec![0f64, 1f64]);
+    /// ```
+    pub fn continued_fraction(&self, tolerance: &Tolerance) -> Vec<Polynomial> {
+        let mut quotients = Vec::new();
+        let mut dividend = self.numerator.clone();
+        let mut divisor = self.denominator.clone();
+        loop {
+            let (quotient, remainder) = dividend.div_rem(&divisor);
+            quotients.push(quotient);
+            let scale = divisor
+                .coefficients
+                .iter()
+                .fold(0f64, |largest, coeff| largest.max(coeff.abs()));
+            if remainder
+                .coefficients
+                .iter()
+                .all(|&coeff| tolerance.is_negligible(coeff, scale))
+            {
+                return quotients;
+            }
+            dividend = divisor;
+            divisor = remainder;
+        }
+    }
+
+    /// Rebuilds the rational function from the partial quotients of a continued fraction
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::rational_function::RationalFunction;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let f = RationalFunction::new(
+    ///     Polynomial::new(vec![2f64, -1f64, 0f64, 3f64], 'x'),
+    ///     Polynomial::new(vec![1f64, 1f64, 1f64], 'x'),
+    /// );
+    /// let rebuilt = RationalFunction::from_continued_fraction(&f.continued_fraction(&Tolerance::EXACT));
+    /// for &x in &[-2f64, 0.5, 3f64] {
+    ///     assert!((rebuilt.evaluate_at(x) - f.evaluate_at(x)).abs() < 1e-12);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `quotients` is empty.
+    pub fn from_continued_fraction(quotients: &[Polynomial]) -> RationalFunction {
+        let (last, rest) = quotients
+            .split_last()
+            .expect("continued fraction needs at least one quotient");
+        let mut numerator = last.clone();
+        let mut denominator = Polynomial::new(vec![1f64], last.indeterminate);
+        // q + 1 / (n / d) = (q n + d) / n
+        for quotient in rest.iter().rev() {
+            let next = quotient.multiply(numerator.clone()) + denominator;
+            denominator = numerator;
+            numerator = next;
+        }
+
+        RationalFunction::new(numerator, denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_polynomials;
+
+    #[test]
+    fn test_continued_fraction_degrees() {
+        // For generic N / D with deg N = n, deg D = n - 1, every quotient after the first is linear
+        let f = RationalFunction::new(
+            test_polynomials::wilkinson(4),
+            test_polynomials::wilkinson(4).derivative(),
+        );
+        let quotients = f.continued_fraction(&Tolerance::ulps(64f64));
+
+        assert_eq!(quotients.len(), 4);
+        assert!(quotients.iter().all(|q| q.degree() == 1));
+        let rebuilt = RationalFunction::from_continued_fraction(&quotients);
+        for &x in &[-1f64, 0.3, 1.7, 7f64] {
+            assert!((rebuilt.evaluate_at(x) - f.evaluate_at(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_proper_fraction_starts_with_zero() {
+        let f = RationalFunction::new(
+            Polynomial::new(vec![1f64], 'x'),
+            Polynomial::new(vec![1f64, 1f64], 'x'),
+        );
+        let quotients = f.continued_fraction(&Tolerance::EXACT);
+
+        assert_eq!(quotients[0].degree(), -1);
+        assert_eq!(quotients[1].coefficients, vec![1f64, 1f64]);
+    }
+}
@@ -0,0 +1,145 @@
+//! Self-reciprocal polynomials, whose coefficient lists read the same (or negated) in
+//! reverse, and the substitution `y = x + 1/x` that halves their degree.
+
+use crate::polynomial::Polynomial;
+
+impl Polynomial {
+    /// Returns `true` if the coefficients read the same forwards and backwards, that is
+    /// `x^n p(1/x) = p(x)` for the degree `n`. The roots of such a polynomial come in
+    /// pairs `r` and `1/r`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// assert!(Polynomial::new(vec![1f64, 3f64, 4f64, 3f64, 1f64], 'x').is_palindromic());
+    /// assert!(!Polynomial::new(vec![1f64, 3f64, 1f64, 3f64], 'x').is_palindromic());
+    /// ```
+    pub fn is_palindromic(&self) -> bool {
+        self.coefficients
+            .iter()
+            .zip(self.coefficients.iter().rev())
+            .all(|(a, b)| a == b)
+    }
+
+    /// Returns `true` if reversing the coefficients negates them, that is
+    /// `x^n p(1/x) = -p(x)` for the degree `n`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^3 + 2x^2 - 2x - 1
+    /// assert!(Polynomial::new(vec![-1f64, -2f64, 2f64, 1f64], 'x').is_antipalindromic());
+    /// assert!(!Polynomial::new(vec![1f64, 2f64, 1f64], 'x').is_antipalindromic());
+    /// ```
+    pub fn is_antipalindromic(&self) -> bool {
+        self.coefficients
+            .iter()
+            .zip(self.coefficients.iter().rev())
+            .all(|(a, b)| *a == -b)
+    }
+
+    /// Returns the polynomial `q` in `y` of degree `n` with `p(x) = x^n q(x + 1/x)`, if `p`
+    /// is palindromic of even degree `2n`.
+    ///
+    /// Each root `y` of `q` gives the pair of roots of `x^2 - y x + 1`, so the roots of `p`
+    /// follow from a problem of half the degree and `n` quadratics. A palindromic
+    /// polynomial of odd degree has the root `-1`; dividing by `x + 1` leaves one of even
+    /// degree.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^4 + 3x^3 + 4x^2 + 3x + 1 = x^2 ((x + 1/x)^2 + 3 (x + 1/x) + 2)
+    /// let polynomial = Polynomial::new(vec![1f64, 3f64, 4f64, 3f64, 1f64], 'x');
+    /// let reduced = polynomial.palindromic_reduction().unwrap();
+    /// assert_eq!(reduced.coefficients, vec![2f64, 3f64, 1f64]);
+    /// assert_eq!(reduced.indeterminate, 'y');
+    /// ```
+    pub fn palindromic_reduction(&self) -> Option<Polynomial> {
+        let degree = self.degree();
+        if degree < 0 || degree % 2 != 0 || !self.is_palindromic() {
+            return None;
+        }
+        let n = degree as usize / 2;
+
+        // x^k + x^-k = D_k(y), with D_1 = y and D_{k+1} = y D_k - D_{k-1} from D_0 = 2
+        let mut coefficients = vec![0f64; n + 1];
+        coefficients[0] = self.coefficients[n];
+        let mut previous = vec![2f64];
+        let mut current = vec![0f64, 1f64];
+        for k in 1..=n {
+            for (i, value) in current.iter().enumerate() {
+                coefficients[i] += self.coefficients[n + k] * value;
+            }
+
+            let mut next = vec![0f64; current.len() + 1];
+            for (i, value) in current.iter().enumerate() {
+                next[i + 1] += value;
+            }
+            for (i, value) in previous.iter().enumerate() {
+                next[i] -= value;
+            }
+            previous = std::mem::replace(&mut current, next);
+        }
+
+        Some(Polynomial::new(coefficients, 'y'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduction_agrees_with_substitution() {
+        let polynomial = Polynomial::new(vec![2f64, -1f64, 5f64, 0.5, 5f64, -1f64, 2f64], 'x');
+        let reduced = polynomial.palindromic_reduction().unwrap();
+        assert_eq!(reduced.degree(), 3);
+        for &x in &[0.5, 1.5, -2f64, 3f64] {
+            let expected = polynomial.evaluate_at(x);
+            let actual = x.powi(3) * reduced.evaluate_at(x + 1f64 / x);
+            assert!((expected - actual).abs() < 1e-10 * expected.abs().max(1f64));
+        }
+    }
+
+    #[test]
+    fn test_roots_come_from_quadratics() {
+        // Roots 2, 1/2, -3, -1/3
+        let polynomial = Polynomial::new(vec![-2f64, 1f64], 'x')
+            .multiply(Polynomial::new(vec![-1f64, 2f64], 'x'))
+            .multiply(Polynomial::new(vec![3f64, 1f64], 'x'))
+            .multiply(Polynomial::new(vec![1f64, 3f64], 'x'));
+        assert!(polynomial.is_palindromic());
+
+        let reduced = polynomial.palindromic_reduction().unwrap();
+        let mut ys = reduced.real_roots();
+        ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((ys[0] + 10f64 / 3f64).abs() < 1e-12);
+        assert!((ys[1] - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reduction_requires_even_palindromic() {
+        assert!(Polynomial::new(vec![1f64, 2f64, 2f64, 1f64], 'x')
+            .palindromic_reduction()
+            .is_none());
+        assert!(Polynomial::new(vec![1f64, 2f64, 3f64], 'x')
+            .palindromic_reduction()
+            .is_none());
+        assert_eq!(
+            Polynomial::new(vec![4f64], 'x')
+                .palindromic_reduction()
+                .unwrap()
+                .coefficients,
+            vec![4f64]
+        );
+    }
+
+    #[test]
+    fn test_antipalindromic_has_root_one() {
+        let polynomial = Polynomial::new(vec![-1f64, -2f64, 2f64, 1f64], 'x');
+        assert!(polynomial.is_antipalindromic());
+        assert!(!polynomial.is_palindromic());
+        assert_eq!(polynomial.evaluate_at(1f64), 0f64);
+    }
+}
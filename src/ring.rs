@@ -0,0 +1,289 @@
+//! Evaluation of real-coefficient polynomials over arbitrary rings.
+
+use std::ops::{Add, Mul};
+
+use crate::complex::Complex;
+use crate::polynomial::Polynomial;
+
+/// A ring that real coefficients can be embedded into, such as the complex numbers, square matrices or dual numbers.
+pub trait Ring: Clone + Add<Output = Self> + Mul<Output = Self> {
+    /// Returns `value` times the multiplicative identity, shaped like `self` where that matters (e.g. for matrices).
+    fn scalar_like(&self, value: f64) -> Self;
+}
+
+impl Ring for f64 {
+    fn scalar_like(&self, value: f64) -> f64 {
+        value
+    }
+}
+
+impl Ring for f32 {
+    fn scalar_like(&self, value: f64) -> f32 {
+        value as f32
+    }
+}
+
+impl Ring for Complex {
+    fn scalar_like(&self, value: f64) -> Complex {
+        Complex::from(value)
+    }
+}
+
+/// A dual number `value + derivative ε` with `ε^2 = 0`, for forward-mode differentiation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    /// The real part.
+    pub value: f64,
+    /// The coefficient of `ε`.
+    pub derivative: f64,
+}
+
+impl Dual {
+    /// Returns the dual number `value + derivative ε`.
+    pub fn new(value: f64, derivative: f64) -> Dual {
+        Dual { value, derivative }
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(
+            self.value * other.value,
+            self.value * other.derivative + self.derivative * other.value,
+        )
+    }
+}
+
+impl Ring for Dual {
+    fn scalar_like(&self, value: f64) -> Dual {
+        Dual::new(value, 0f64)
+    }
+}
+
+/// A truncated Taylor expansion `value + terms[0] ε + terms[1] ε^2 + ... + terms[K - 1] ε^K`
+/// with `ε^(K + 1) = 0`, for Taylor-mode differentiation to order `K`.
+///
+/// Evaluating a polynomial at [`Jet::variable(x)`](Jet::variable) yields the Taylor
+/// coefficients of `p` at `x` up to order `K`; [`Jet<1>`](Jet) behaves like [`Dual`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jet<const K: usize> {
+    /// The constant term.
+    pub value: f64,
+    /// The coefficients of `ε, ε^2, ..., ε^K`.
+    pub terms: [f64; K],
+}
+
+impl<const K: usize> Jet<K> {
+    /// Returns the jet of the constant `value`
+    pub fn constant(value: f64) -> Jet<K> {
+        Jet {
+            value,
+            terms: [0f64; K],
+        }
+    }
+
+    /// Returns the jet of the independent variable at `x`, i.e. `x + ε`
+    pub fn variable(x: f64) -> Jet<K> {
+        let mut jet = Jet::constant(x);
+        if K > 0 {
+            jet.terms[0] = 1f64;
+        }
+        jet
+    }
+
+    /// Returns the coefficient of `ε^k`, which is zero beyond order `K`
+    pub fn taylor_coefficient(&self, k: usize) -> f64 {
+        match k {
+            0 => self.value,
+            k if k <= K => self.terms[k - 1],
+            _ => 0f64,
+        }
+    }
+
+    /// Returns the `k`th derivative, `k!` times the coefficient of `ε^k`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::ring::Jet;
+    ///
+    /// // p(x) = x^4 at x = 2: p = 16, p' = 32, p'' = 48, p''' = 48
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 0f64, 0f64, 1f64], 'x');
+    /// let jet = polynomial.evaluate_generic(Jet::<3>::variable(2f64));
+    /// let derivatives: Vec<f64> = (0..=3).map(|k| jet.derivative(k)).collect();
+    /// assert_eq!(derivatives, vec![16f64, 32f64, 48f64, 48f64]);
+    /// ```
+    pub fn derivative(&self, k: usize) -> f64 {
+        let factorial: f64 = (1..=k).map(|i| i as f64).product();
+        factorial * self.taylor_coefficient(k)
+    }
+}
+
+impl<const K: usize> Add for Jet<K> {
+    type Output = Jet<K>;
+
+    fn add(self, other: Jet<K>) -> Jet<K> {
+        let mut terms = self.terms;
+        for (term, other) in terms.iter_mut().zip(&other.terms) {
+            *term += other;
+        }
+        Jet {
+            value: self.value + other.value,
+            terms,
+        }
+    }
+}
+
+impl<const K: usize> Mul for Jet<K> {
+    type Output = Jet<K>;
+
+    fn mul(self, other: Jet<K>) -> Jet<K> {
+        // The Cauchy product, truncated at order K
+        let mut terms = [0f64; K];
+        for (n, term) in terms.iter_mut().enumerate() {
+            let order = n + 1;
+            *term = (0..=order)
+                .map(|i| self.taylor_coefficient(i) * other.taylor_coefficient(order - i))
+                .sum();
+        }
+        Jet {
+            value: self.value * other.value,
+            terms,
+        }
+    }
+}
+
+impl<const K: usize> Ring for Jet<K> {
+    fn scalar_like(&self, value: f64) -> Jet<K> {
+        Jet::constant(value)
+    }
+}
+
+impl Polynomial {
+    /// Return the result of evaluating the Polynomial at an element `x` of any [`Ring`], using Horner's scheme
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::ring::Dual;
+    ///
+    /// // p(x) = 1 + x^2
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.evaluate_generic(Complex::new(0f64, 1f64)), Complex::new(0f64, 0f64));
+    ///
+    /// // Evaluating at x + ε also yields p'(x)
+    /// let result = polynomial.evaluate_generic(Dual::new(3f64, 1f64));
+    /// assert_eq!(result, Dual::new(10f64, 6f64));
+    /// ```
+    pub fn evaluate_generic<R: Ring>(&self, x: R) -> R {
+        let mut coefficients = self.coefficients.iter().rev();
+        let leading = coefficients.next().copied().unwrap_or(0f64);
+
+        coefficients.fold(x.scalar_like(leading), |sum, &coeff| {
+            sum * x.clone() + x.scalar_like(coeff)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Matrix2([[f64; 2]; 2]);
+
+    impl Add for Matrix2 {
+        type Output = Matrix2;
+
+        fn add(self, other: Matrix2) -> Matrix2 {
+            let (a, b) = (self.0, other.0);
+            Matrix2([
+                [a[0][0] + b[0][0], a[0][1] + b[0][1]],
+                [a[1][0] + b[1][0], a[1][1] + b[1][1]],
+            ])
+        }
+    }
+
+    impl Mul for Matrix2 {
+        type Output = Matrix2;
+
+        fn mul(self, other: Matrix2) -> Matrix2 {
+            let (a, b) = (self.0, other.0);
+            Matrix2([
+                [
+                    a[0][0] * b[0][0] + a[0][1] * b[1][0],
+                    a[0][0] * b[0][1] + a[0][1] * b[1][1],
+                ],
+                [
+                    a[1][0] * b[0][0] + a[1][1] * b[1][0],
+                    a[1][0] * b[0][1] + a[1][1] * b[1][1],
+                ],
+            ])
+        }
+    }
+
+    impl Ring for Matrix2 {
+        fn scalar_like(&self, value: f64) -> Matrix2 {
+            Matrix2([[value, 0f64], [0f64, value]])
+        }
+    }
+
+    #[test]
+    fn test_evaluate_generic_matches_evaluate_at() {
+        let polynomial = Polynomial::new(vec![-1f64, 2f64, -3f64, 4f64], 'x');
+
+        assert_eq!(polynomial.evaluate_generic(-5f64), -586f64);
+        assert_eq!(polynomial.evaluate_generic(2f32), 23f32);
+    }
+
+    #[test]
+    fn test_cayley_hamilton() {
+        // The characteristic polynomial of [[1, 2], [3, 4]] is x^2 - 5x - 2
+        let polynomial = Polynomial::new(vec![-2f64, -5f64, 1f64], 'x');
+        let matrix = Matrix2([[1f64, 2f64], [3f64, 4f64]]);
+
+        assert_eq!(
+            polynomial.evaluate_generic(matrix),
+            Matrix2([[0f64, 0f64], [0f64, 0f64]])
+        );
+    }
+
+    #[test]
+    fn test_dual_number_derivative() {
+        let polynomial = Polynomial::new(vec![1f64, 2f64, 3f64, 4f64], 'x');
+        let result = polynomial.evaluate_generic(Dual::new(2f64, 1f64));
+
+        assert_eq!(result.value, polynomial.evaluate_at(2f64));
+        assert_eq!(result.derivative, 2f64 + 12f64 + 48f64);
+    }
+
+    #[test]
+    fn test_jet_matches_evaluate_derivatives() {
+        let polynomial = Polynomial::new(vec![3f64, -1f64, 0.5, 2f64, -0.25, 1f64], 'x');
+        let x = 1.5;
+        let jet = polynomial.evaluate_generic(Jet::<7>::variable(x));
+        let expected = polynomial.evaluate_derivatives(x, 7);
+        for (k, derivative) in expected.iter().enumerate() {
+            assert!((jet.derivative(k) - derivative).abs() < 1e-12 * (1f64 + derivative.abs()));
+        }
+        assert_eq!(jet.taylor_coefficient(8), 0f64);
+
+        let dual = polynomial.evaluate_generic(Dual::new(x, 1f64));
+        let first_order = polynomial.evaluate_generic(Jet::<1>::variable(x));
+        assert_eq!(
+            (first_order.value, first_order.terms[0]),
+            (dual.value, dual.derivative)
+        );
+        let value_only = polynomial.evaluate_generic(Jet::<0>::variable(x));
+        assert_eq!(value_only.value, polynomial.evaluate_generic(x));
+    }
+}
@@ -0,0 +1,1733 @@
+//! Locating and refining the real roots of polynomials.
+
+use std::fmt;
+
+use crate::complex::Complex;
+use crate::polynomial::Polynomial;
+use crate::random::RandomSource;
+
+/// Maximum number of steps taken by the single-root iterations.
+const MAX_ITERATIONS: usize = 100;
+
+/// Maximum number of sweeps taken by the simultaneous Durand–Kerner iteration.
+const DURAND_KERNER_ITERATIONS: usize = 500;
+
+/// Errors that can occur while refining a root.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError {
+    /// The function has the same sign at both ends of the interval.
+    NotBracketed,
+    /// The iteration limit was reached before the tolerance was met.
+    NoConvergence,
+    /// The iteration stalled at a point where the derivative vanishes.
+    ZeroDerivative,
+    /// The iteration converged to a root that is not real.
+    NotReal,
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NotBracketed => write!(f, "interval does not bracket a sign change"),
+            RootError::NoConvergence => write!(f, "iteration did not converge"),
+            RootError::ZeroDerivative => write!(f, "derivative vanished during iteration"),
+            RootError::NotReal => write!(f, "iteration converged to a non-real root"),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// How an iterative solver decided that it had converged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvergenceStatus {
+    /// The function evaluated to exactly zero at the returned point.
+    Exact,
+    /// The step (or bracket) shrank below the requested tolerance.
+    StepTolerance,
+    /// The residual fell within the rounding error of the evaluation before the step shrank.
+    ///
+    /// At multiple or ill-conditioned roots the returned point may then be accurate to far
+    /// fewer digits than the small residual suggests.
+    ResidualTolerance,
+    /// The result was computed directly, by a closed-form formula or a linear solve, so there
+    /// was no convergence test to pass.
+    Direct,
+}
+
+/// Convergence information reported by the iterative solvers alongside their result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Diagnostics {
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Magnitude of the function at the returned point, or for a fit, the root-mean-square
+    /// residual over the samples.
+    pub residual: f64,
+    /// How convergence was detected.
+    pub status: ConvergenceStatus,
+}
+
+impl Diagnostics {
+    pub(crate) fn new(iterations: usize, residual: f64, status: ConvergenceStatus) -> Diagnostics {
+        Diagnostics {
+            iterations,
+            residual,
+            status,
+        }
+    }
+}
+
+/// Iterations available for refining a single root from an initial guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootMethod {
+    /// Newton's method, quadratically convergent near simple roots.
+    Newton,
+    /// Halley's method, cubically convergent near simple roots, using the second derivative.
+    Halley,
+    /// Laguerre's method, which converges from almost any starting point for polynomials.
+    Laguerre,
+}
+
+/// A real root reported by [`Polynomial::roots`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Root {
+    /// Computed from a well-conditioned closed-form formula (degree at most 3), up to
+    /// floating-point rounding.
+    Exact(f64),
+    /// Located by numerical iteration to the requested tolerance.
+    Approximate(f64),
+}
+
+impl Root {
+    /// Returns the location of the root.
+    pub fn value(&self) -> f64 {
+        match *self {
+            Root::Exact(value) | Root::Approximate(value) => value,
+        }
+    }
+}
+
+/// Stopping criteria for the numerical root refinement in [`Polynomial::roots_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootOptions {
+    /// Absolute tolerance on the root location; `0` refines to full precision.
+    pub tolerance: f64,
+    /// Maximum number of Brent iterations per root.
+    pub max_iterations: usize,
+}
+
+impl Default for RootOptions {
+    fn default() -> RootOptions {
+        RootOptions {
+            tolerance: 0f64,
+            max_iterations: 200,
+        }
+    }
+}
+
+/// Coefficients rebuilt from roots by [`Polynomial::refine_from_roots`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reconstruction {
+    /// The monic polynomial with the given roots.
+    pub polynomial: Polynomial,
+    /// The largest relative residual `|p(r)| / sum |c_i| |r|^i` over the roots `r`, a
+    /// backward error of the rebuilt coefficients; zero means every root is reproduced
+    /// exactly.
+    pub residual: f64,
+}
+
+/// Returns `points` in Leja order: the point of largest magnitude first, then repeatedly
+/// the point that maximizes the product of distances to the points already chosen.
+///
+/// Multiplying out linear factors in this order keeps intermediate coefficients from
+/// growing and cancelling, and the same order spreads interpolation nodes well. Ties go
+/// to the earlier point in `points`.
+/// # Example
+/// ```
+/// use polynom::roots::leja_order;
+///
+/// assert_eq!(leja_order(&[1f64, 2f64, 3f64, 4f64]), vec![4f64, 1f64, 2f64, 3f64]);
+/// ```
+pub fn leja_order(points: &[f64]) -> Vec<f64> {
+    let mut remaining = points.to_vec();
+    let mut ordered = Vec::with_capacity(points.len());
+    // Sums of log distances avoid overflow in the products
+    let mut log_distances = vec![0f64; remaining.len()];
+    while !remaining.is_empty() {
+        let index = if ordered.is_empty() {
+            (0..remaining.len()).fold(0, |best, i| {
+                if remaining[i].abs() > remaining[best].abs() {
+                    i
+                } else {
+                    best
+                }
+            })
+        } else {
+            (0..remaining.len()).fold(0, |best, i| {
+                if log_distances[i] > log_distances[best] {
+                    i
+                } else {
+                    best
+                }
+            })
+        };
+        let chosen = remaining.remove(index);
+        log_distances.remove(index);
+        for (point, log_distance) in remaining.iter().zip(log_distances.iter_mut()) {
+            *log_distance += (point - chosen).abs().ln();
+        }
+        ordered.push(chosen);
+    }
+
+    ordered
+}
+
+/// Finds a root of `f` in the interval `bracket` with Brent's method.
+///
+/// Combines inverse quadratic interpolation and the secant method with bisection, so it
+/// converges quickly for smooth functions but is never slower than bisection. `f` must have
+/// opposite signs (or a zero) at the two ends of `bracket`.
+/// # Example
+/// ```
+/// use polynom::roots::brent;
+///
+/// let root = brent(|x| x * x - 2f64, (0f64, 2f64), 1e-15, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-15);
+/// ```
+pub fn brent<F: Fn(f64) -> f64>(
+    f: F,
+    bracket: (f64, f64),
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64, RootError> {
+    brent_with_diagnostics(f, bracket, tolerance, max_iterations).map(|(root, _)| root)
+}
+
+/// Like [`brent`], but also returns [`Diagnostics`] describing the convergence.
+/// # Example
+/// ```
+/// use polynom::roots::{brent_with_diagnostics, ConvergenceStatus};
+///
+/// let (root, diagnostics) =
+///     brent_with_diagnostics(|x| x * x - 2f64, (0f64, 2f64), 1e-15, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-15);
+/// assert!(diagnostics.iterations < 100);
+/// assert_ne!(diagnostics.status, ConvergenceStatus::ResidualTolerance);
+/// ```
+pub fn brent_with_diagnostics<F: Fn(f64) -> f64>(
+    f: F,
+    bracket: (f64, f64),
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<(f64, Diagnostics), RootError> {
+    debug_span!("brent", a = bracket.0, b = bracket.1);
+    let (mut a, mut b) = bracket;
+    let mut fa = f(a);
+    let mut fb = f(b);
+    if fa == 0f64 {
+        return Ok((a, Diagnostics::new(0, 0f64, ConvergenceStatus::Exact)));
+    }
+    if fb == 0f64 {
+        return Ok((b, Diagnostics::new(0, 0f64, ConvergenceStatus::Exact)));
+    }
+    if fa.signum() == fb.signum() {
+        return Err(RootError::NotBracketed);
+    }
+
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut e = d;
+
+    for iteration in 0..max_iterations {
+        trace_event!(iteration, x = b, residual = fb);
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        // Keep b as the best estimate
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tolerance_here = 2f64 * f64::EPSILON * b.abs() + 0.5 * tolerance;
+        let midpoint = 0.5 * (c - b);
+        if midpoint.abs() <= tolerance_here || fb == 0f64 {
+            debug_event!(iterations = iteration, root = b, residual = fb, "converged");
+            let status = if fb == 0f64 {
+                ConvergenceStatus::Exact
+            } else {
+                ConvergenceStatus::StepTolerance
+            };
+            return Ok((b, Diagnostics::new(iteration, fb.abs(), status)));
+        }
+
+        if e.abs() >= tolerance_here && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                // Secant step
+                (2f64 * midpoint * s, 1f64 - s)
+            } else {
+                // Inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2f64 * midpoint * q * (q - r) - (b - a) * (r - 1f64)),
+                    (q - 1f64) * (r - 1f64) * (s - 1f64),
+                )
+            };
+            if p > 0f64 {
+                q = -q;
+            }
+            p = p.abs();
+
+            if 2f64 * p < (3f64 * midpoint * q - (tolerance_here * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = midpoint;
+                e = d;
+            }
+        } else {
+            d = midpoint;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tolerance_here {
+            d
+        } else {
+            tolerance_here.copysign(midpoint)
+        };
+        fb = f(b);
+    }
+
+    debug_event!(root = b, residual = fb, "did not converge");
+    Err(RootError::NoConvergence)
+}
+
+impl Polynomial {
+    /// Samples the polynomial at `steps + 1` evenly spaced points over `range` and returns the intervals where it changes sign
+    ///
+    /// Each returned `(a, b)` brackets at least one root. A sample that is exactly zero is reported
+    /// as the degenerate interval `(x, x)`. Roots of even multiplicity, and pairs of roots closer
+    /// together than the step size, do not produce a sign change and are not found.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x - 1)(x + 1.5)
+    /// let polynomial = Polynomial::new(vec![-1.5, 0.5, 1f64], 'x');
+    /// let brackets = polynomial.find_sign_changes((-4f64, 4f64), 8);
+    /// assert_eq!(brackets, vec![(-2f64, -1f64), (1f64, 1f64)]);
+    /// ```
+    pub fn find_sign_changes(&self, range: (f64, f64), steps: usize) -> Vec<(f64, f64)> {
+        let (low, high) = range;
+        let steps = steps.max(1);
+        let width = (high - low) / steps as f64;
+        let mut brackets = Vec::new();
+        let mut previous: Option<(f64, f64)> = None;
+
+        for i in 0..=steps {
+            let x = if i == steps {
+                high
+            } else {
+                low + width * i as f64
+            };
+            let value = self.evaluate_at(x);
+            if value == 0f64 {
+                brackets.push((x, x));
+            } else if let Some((previous_x, previous_value)) = previous {
+                if previous_value != 0f64 && previous_value.signum() != value.signum() {
+                    brackets.push((previous_x, x));
+                }
+            }
+            previous = Some((x, value));
+        }
+
+        brackets
+    }
+
+    /// Refines the root of the polynomial inside `bracket` with [`brent`]
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+    /// let root = polynomial.refine_root_brent((1f64, 2f64)).unwrap();
+    /// assert!((root - 2f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn refine_root_brent(&self, bracket: (f64, f64)) -> Result<f64, RootError> {
+        brent(|x| self.evaluate_at(x), bracket, 0f64, 200)
+    }
+
+    /// Returns `(p(x), p'(x), p''(x))`, computed together with Horner's scheme.
+    fn evaluate_with_derivatives<T>(&self, x: T) -> (T, T, T)
+    where
+        T: Copy + From<f64> + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+    {
+        let zero = T::from(0f64);
+        let (mut value, mut first, mut second) = (zero, zero, zero);
+        for &coeff in self.coefficients.iter().rev() {
+            second = second * x + first;
+            first = first * x + value;
+            value = value * x + T::from(coeff);
+        }
+
+        (value, first, second * T::from(2f64))
+    }
+
+    /// Refines a single root starting from `guess` with the chosen [`RootMethod`]
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::roots::RootMethod;
+    ///
+    /// let polynomial = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+    /// for &method in &[RootMethod::Newton, RootMethod::Halley, RootMethod::Laguerre] {
+    ///     let root = polynomial.find_root(1f64, method).unwrap();
+    ///     assert!((root - 2f64.sqrt()).abs() < 1e-15);
+    /// }
+    /// ```
+    pub fn find_root(&self, guess: f64, method: RootMethod) -> Result<f64, RootError> {
+        self.find_root_with_diagnostics(guess, method)
+            .map(|(root, _)| root)
+    }
+
+    /// Like [`find_root`](Polynomial::find_root), but also returns [`Diagnostics`] describing
+    /// the convergence
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::roots::{ConvergenceStatus, RootMethod};
+    ///
+    /// // (x - 1)^2 converges only linearly, and stops on the residual test
+    /// let polynomial = Polynomial::new(vec![1f64, -2f64, 1f64], 'x');
+    /// let (root, diagnostics) = polynomial
+    ///     .find_root_with_diagnostics(2f64, RootMethod::Newton)
+    ///     .unwrap();
+    /// assert!((root - 1f64).abs() < 1e-7);
+    /// assert_ne!(diagnostics.status, ConvergenceStatus::StepTolerance);
+    /// ```
+    pub fn find_root_with_diagnostics(
+        &self,
+        guess: f64,
+        method: RootMethod,
+    ) -> Result<(f64, Diagnostics), RootError> {
+        debug_span!("find_root", degree = self.degree(), guess, method = ?method);
+        if method == RootMethod::Laguerre {
+            let (root, diagnostics) = self.laguerre_with_diagnostics(Complex::from(guess))?;
+            if root.im.abs() > 1e-10 * root.norm().max(1f64) {
+                return Err(RootError::NotReal);
+            }
+            let residual = self.evaluate_at(root.re).abs();
+            return Ok((
+                root.re,
+                Diagnostics {
+                    residual,
+                    ..diagnostics
+                },
+            ));
+        }
+
+        let mut x = guess;
+        for iteration in 0..MAX_ITERATIONS {
+            let (value, first, second) = self.evaluate_with_derivatives(x);
+            trace_event!(iteration, x, residual = value);
+            if value == 0f64 {
+                return Ok((
+                    x,
+                    Diagnostics::new(iteration, 0f64, ConvergenceStatus::Exact),
+                ));
+            }
+            let step = match method {
+                RootMethod::Halley => {
+                    let denominator = 2f64 * first * first - value * second;
+                    if denominator == 0f64 {
+                        return Err(RootError::ZeroDerivative);
+                    }
+                    2f64 * value * first / denominator
+                }
+                _ => {
+                    if first == 0f64 {
+                        return Err(RootError::ZeroDerivative);
+                    }
+                    value / first
+                }
+            };
+            x -= step;
+            // Stop once the step is below the resolution of x, or p(x) is within rounding error of zero
+            let status = if step.abs() <= 4f64 * f64::EPSILON * x.abs() {
+                ConvergenceStatus::StepTolerance
+            } else if value.abs() <= self.evaluation_tolerance(x) {
+                ConvergenceStatus::ResidualTolerance
+            } else {
+                continue;
+            };
+            debug_event!(iterations = iteration + 1, root = x, "converged");
+            let residual = self.evaluate_at(x).abs();
+            return Ok((x, Diagnostics::new(iteration + 1, residual, status)));
+        }
+
+        debug_event!(root = x, "did not converge");
+        Err(RootError::NoConvergence)
+    }
+
+    /// Refines a (possibly complex) root starting from `guess` with Laguerre's method
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 + 1 has roots ±i
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+    /// let root = polynomial.laguerre(Complex::new(0.5, 0.5)).unwrap();
+    /// assert!((root - Complex::new(0f64, 1f64)).norm() < 1e-15);
+    /// ```
+    pub fn laguerre(&self, guess: Complex) -> Result<Complex, RootError> {
+        self.laguerre_with_diagnostics(guess).map(|(root, _)| root)
+    }
+
+    /// Like [`laguerre`](Polynomial::laguerre), but also returns [`Diagnostics`] describing
+    /// the convergence
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+    /// let (root, diagnostics) = polynomial
+    ///     .laguerre_with_diagnostics(Complex::new(0.5, 0.5))
+    ///     .unwrap();
+    /// assert!((root - Complex::new(0f64, 1f64)).norm() < 1e-15);
+    /// assert!(diagnostics.residual < 1e-15);
+    /// ```
+    pub fn laguerre_with_diagnostics(
+        &self,
+        guess: Complex,
+    ) -> Result<(Complex, Diagnostics), RootError> {
+        let degree = self.degree();
+        if degree < 1 {
+            return Err(RootError::NoConvergence);
+        }
+        debug_span!("laguerre", degree, re = guess.re, im = guess.im);
+        let n = Complex::from(degree as f64);
+        let mut x = guess;
+
+        for iteration in 0..MAX_ITERATIONS {
+            let (value, first, second) = self.evaluate_with_derivatives(x);
+            trace_event!(iteration, re = x.re, im = x.im, residual = value.norm());
+            if value.norm() == 0f64 {
+                return Ok((
+                    x,
+                    Diagnostics::new(iteration, 0f64, ConvergenceStatus::Exact),
+                ));
+            }
+            let g = first / value;
+            let h = g * g - second / value;
+            let discriminant = ((n - Complex::from(1f64)) * (n * h - g * g)).sqrt();
+            let plus = g + discriminant;
+            let minus = g - discriminant;
+            let denominator = if plus.norm() >= minus.norm() {
+                plus
+            } else {
+                minus
+            };
+            if denominator.norm() == 0f64 {
+                return Err(RootError::ZeroDerivative);
+            }
+
+            let step = n / denominator;
+            x = x - step;
+            let status = if step.norm() <= 4f64 * f64::EPSILON * x.norm() {
+                ConvergenceStatus::StepTolerance
+            } else if value.norm() <= self.evaluation_tolerance(x.norm()) {
+                ConvergenceStatus::ResidualTolerance
+            } else {
+                continue;
+            };
+            debug_event!(
+                iterations = iteration + 1,
+                re = x.re,
+                im = x.im,
+                "converged"
+            );
+            let residual = self.evaluate_with_derivatives(x).0.norm();
+            return Ok((x, Diagnostics::new(iteration + 1, residual, status)));
+        }
+
+        debug_event!(re = x.re, im = x.im, "did not converge");
+        Err(RootError::NoConvergence)
+    }
+
+    /// Returns an upper bound on the absolute value of every root (Cauchy's bound).
+    fn root_bound(&self) -> f64 {
+        let leading = self.coefficients[self.coefficients.len() - 1];
+        let largest = self.coefficients[..self.coefficients.len() - 1]
+            .iter()
+            .map(|coeff| (coeff / leading).abs())
+            .fold(0f64, f64::max);
+
+        1f64 + largest
+    }
+
+    /// Returns the rounding error bound for evaluating the polynomial at `x`.
+    pub(crate) fn evaluation_tolerance(&self, x: f64) -> f64 {
+        let magnitude: f64 = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0f64, |sum, coeff| sum * x.abs() + coeff.abs());
+
+        4f64 * self.coefficients.len() as f64 * f64::EPSILON * magnitude
+    }
+
+    /// Returns the distinct real roots of the polynomial in increasing order
+    ///
+    /// The real line is split at the critical points (the real roots of the derivative, found
+    /// recursively), so the polynomial is monotonic on each piece. Pieces whose ends have opposite
+    /// signs are refined with [`brent`]. Critical points where the polynomial vanishes to within
+    /// rounding error are reported as (multiple) roots. The zero polynomial and nonzero constants
+    /// have no roots reported.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x + 2)(x - 1)^2
+    /// let polynomial = Polynomial::new(vec![2f64, -3f64, 0f64, 1f64], 'x');
+    /// let roots = polynomial.real_roots();
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0] + 2f64).abs() < 1e-12);
+    /// assert!((roots[1] - 1f64).abs() < 1e-7);
+    /// ```
+    pub fn real_roots(&self) -> Vec<f64> {
+        self.real_roots_with_diagnostics()
+            .into_iter()
+            .map(|(root, _)| root)
+            .collect()
+    }
+
+    /// Like [`real_roots`](Polynomial::real_roots), but also returns [`Diagnostics`] for each
+    /// root
+    ///
+    /// Roots found at critical points carry [`ConvergenceStatus::ResidualTolerance`] (or
+    /// [`ConvergenceStatus::Exact`]), since only their residual was tested: these are the
+    /// multiple roots, located to far fewer digits than the simple ones.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // (x + 2)(x - 1)^2: the double root is found at a critical point, without iterating
+    /// let polynomial = Polynomial::new(vec![2f64, -3f64, 0f64, 1f64], 'x');
+    /// let roots = polynomial.real_roots_with_diagnostics();
+    /// assert!(roots[0].1.iterations > 0);
+    /// assert_eq!(roots[1].1.iterations, 0);
+    /// ```
+    pub fn real_roots_with_diagnostics(&self) -> Vec<(f64, Diagnostics)> {
+        self.real_roots_with(&RootOptions::default())
+    }
+
+    /// [`real_roots_with_diagnostics`](Polynomial::real_roots_with_diagnostics) with explicit
+    /// stopping criteria for Brent's method.
+    fn real_roots_with(&self, options: &RootOptions) -> Vec<(f64, Diagnostics)> {
+        let degree = self.degree();
+        if degree < 1 {
+            return Vec::new();
+        }
+        if degree == 1 {
+            let root = -self.coefficients[0] / self.coefficients[1];
+            let residual = self.evaluate_at(root).abs();
+            return vec![(
+                root,
+                Diagnostics::new(0, residual, ConvergenceStatus::Direct),
+            )];
+        }
+
+        debug_span!("real_roots", degree);
+        let bound = self.root_bound();
+        let mut points = vec![-bound];
+        points.extend(
+            self.derivative()
+                .real_roots_with(options)
+                .into_iter()
+                .map(|(c, _)| c)
+                .filter(|c| c.abs() < bound),
+        );
+        points.push(bound);
+
+        let is_root: Vec<bool> = points
+            .iter()
+            .map(|&x| self.evaluate_at(x).abs() <= self.evaluation_tolerance(x))
+            .collect();
+        let mut roots: Vec<(f64, Diagnostics)> = points
+            .iter()
+            .zip(&is_root)
+            .filter(|(_, &is_root)| is_root)
+            .map(|(&x, _)| {
+                let residual = self.evaluate_at(x).abs();
+                let status = if residual == 0f64 {
+                    ConvergenceStatus::Exact
+                } else {
+                    ConvergenceStatus::ResidualTolerance
+                };
+                (x, Diagnostics::new(0, residual, status))
+            })
+            .collect();
+
+        for i in 0..points.len() - 1 {
+            // Monotonic between critical points, so a root at either end rules out any other
+            if is_root[i] || is_root[i + 1] {
+                continue;
+            }
+            let (a, b) = (points[i], points[i + 1]);
+            if self.evaluate_at(a).signum() != self.evaluate_at(b).signum() {
+                let f = |x| self.evaluate_at(x);
+                if let Ok(root) =
+                    brent_with_diagnostics(f, (a, b), options.tolerance, options.max_iterations)
+                {
+                    roots.push(root);
+                }
+            }
+        }
+
+        roots.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        debug_event!(count = roots.len(), "found real roots");
+        roots
+    }
+
+    /// Approximates all complex roots at once with the Durand–Kerner (Weierstrass) iteration.
+    ///
+    /// The starting points are drawn from `rng` inside a disc enclosing every root, so seeding
+    /// it identically reproduces the result exactly. Roots are returned in no particular order,
+    /// repeated according to multiplicity; constant polynomials have none.
+    /// # Example
+    /// ```
+    /// use polynom::complex::Complex;
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::random::SplitMix64;
+    ///
+    /// // x^3 - 1 has the three cube roots of unity as roots
+    /// let polynomial = Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'x');
+    /// let roots = polynomial.durand_kerner(&mut SplitMix64::new(0)).unwrap();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!(roots.iter().any(|&root| (root - Complex::from(1f64)).norm() < 1e-12));
+    /// ```
+    pub fn durand_kerner<R: RandomSource + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Vec<Complex>, RootError> {
+        self.durand_kerner_with_diagnostics(rng)
+            .map(|(roots, _)| roots)
+    }
+
+    /// Like [`durand_kerner`](Polynomial::durand_kerner), but also returns [`Diagnostics`]
+    /// for the iteration as a whole
+    ///
+    /// The residual is the largest over all roots, and the status is
+    /// [`ConvergenceStatus::ResidualTolerance`] if any root stopped on its residual alone.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::random::SplitMix64;
+    ///
+    /// let polynomial = Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'x');
+    /// let (roots, diagnostics) = polynomial
+    ///     .durand_kerner_with_diagnostics(&mut SplitMix64::new(0))
+    ///     .unwrap();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!(diagnostics.iterations > 0);
+    /// assert!(diagnostics.residual < 1e-12);
+    /// ```
+    pub fn durand_kerner_with_diagnostics<R: RandomSource + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(Vec<Complex>, Diagnostics), RootError> {
+        let degree = self.degree();
+        if degree < 1 {
+            return Ok((
+                Vec::new(),
+                Diagnostics::new(0, 0f64, ConvergenceStatus::Direct),
+            ));
+        }
+        debug_span!("durand_kerner", degree);
+        let radius = self.root_bound();
+        let mut roots: Vec<Complex> = (0..degree)
+            .map(|_| {
+                let angle = rng.next_in_range(0f64, 2f64 * std::f64::consts::PI);
+                let magnitude = radius * rng.next_in_range(0.5, 1f64);
+                Complex::new(magnitude * angle.cos(), magnitude * angle.sin())
+            })
+            .collect();
+
+        let diagnostics = self.weierstrass_iterate(&mut roots)?;
+
+        Ok((roots, diagnostics))
+    }
+
+    /// Runs the Durand–Kerner iteration on the estimates in `roots` until every one has
+    /// converged.
+    fn weierstrass_iterate(&self, roots: &mut [Complex]) -> Result<Diagnostics, RootError> {
+        let leading = Complex::from(self.coefficients[self.coefficients.len() - 1]);
+
+        for iteration in 0..DURAND_KERNER_ITERATIONS {
+            let mut converged = true;
+            let mut residual_only = false;
+            for i in 0..roots.len() {
+                let z = roots[i];
+                let value = self.evaluate_generic(z);
+                let denominator = roots
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .fold(leading, |product, (_, &other)| product * (z - other));
+                if denominator.norm() == 0f64 {
+                    return Err(RootError::ZeroDerivative);
+                }
+
+                let step = value / denominator;
+                roots[i] = z - step;
+                if step.norm() > 4f64 * f64::EPSILON * roots[i].norm() {
+                    if value.norm() > self.evaluation_tolerance(z.norm()) {
+                        converged = false;
+                    } else {
+                        residual_only = true;
+                    }
+                }
+            }
+            if converged {
+                debug_event!(iterations = iteration + 1, "converged");
+                let residual = roots
+                    .iter()
+                    .map(|&root| self.evaluate_generic(root).norm())
+                    .fold(0f64, f64::max);
+                let status = if residual_only {
+                    ConvergenceStatus::ResidualTolerance
+                } else {
+                    ConvergenceStatus::StepTolerance
+                };
+                return Ok(Diagnostics::new(iteration + 1, residual, status));
+            }
+        }
+
+        debug_event!("did not converge");
+        Err(RootError::NoConvergence)
+    }
+
+    /// Returns the point where the graph crosses the y-axis, `p(0)`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let polynomial = Polynomial::new(vec![-4f64, 0f64, 1f64], 'x');
+    /// assert_eq!(polynomial.y_intercept(), -4f64);
+    /// ```
+    pub fn y_intercept(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// Returns the distinct points where the graph meets the x-axis, in increasing order.
+    ///
+    /// Backed by [`real_roots`](Polynomial::real_roots); roots closer together than
+    /// `tolerance` are reported once, at their mean, and roots within `tolerance` of zero
+    /// are reported as exactly zero. The zero polynomial, which vanishes everywhere, has no
+    /// isolated intercepts.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^3 - 4x = x (x - 2)(x + 2)
+    /// let polynomial = Polynomial::new(vec![0f64, -4f64, 0f64, 1f64], 'x');
+    /// let intercepts = polynomial.x_intercepts(1e-9);
+    /// assert_eq!(intercepts.len(), 3);
+    /// assert_eq!(intercepts[1], 0f64);
+    /// assert!((intercepts[2] - 2f64).abs() < 1e-12);
+    /// ```
+    pub fn x_intercepts(&self, tolerance: f64) -> Vec<f64> {
+        let mut intercepts: Vec<f64> = Vec::new();
+        let mut cluster: Vec<f64> = Vec::new();
+        for root in self.real_roots() {
+            if let Some(&last) = cluster.last() {
+                if root - last > tolerance {
+                    intercepts.push(cluster.iter().sum::<f64>() / cluster.len() as f64);
+                    cluster.clear();
+                }
+            }
+            cluster.push(root);
+        }
+        if !cluster.is_empty() {
+            intercepts.push(cluster.iter().sum::<f64>() / cluster.len() as f64);
+        }
+
+        intercepts
+            .into_iter()
+            .map(|x| if x.abs() <= tolerance { 0f64 } else { x })
+            .collect()
+    }
+
+    /// Returns the points `(x, y)` where the graphs of `self` and `other` meet, in increasing
+    /// order of `x`.
+    ///
+    /// The crossings are the [`x_intercepts`](Polynomial::x_intercepts) of `self - other`,
+    /// merged with the same `tolerance`, and `y` is `self` evaluated there. Identical
+    /// polynomials coincide everywhere and have no isolated intersections.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 meets x + 2 at x = -1 and x = 2
+    /// let parabola = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let line = Polynomial::new(vec![2f64, 1f64], 'x');
+    /// let points = parabola.intersections_with(&line, 1e-9);
+    /// assert_eq!(points.len(), 2);
+    /// assert!((points[0].0 + 1f64).abs() < 1e-12 && (points[0].1 - 1f64).abs() < 1e-12);
+    /// assert!((points[1].0 - 2f64).abs() < 1e-12 && (points[1].1 - 4f64).abs() < 1e-12);
+    /// ```
+    pub fn intersections_with(&self, other: &Polynomial, tolerance: f64) -> Vec<(f64, f64)> {
+        (self.clone() - other.clone())
+            .x_intercepts(tolerance)
+            .into_iter()
+            .map(|x| (x, self.evaluate_at(x)))
+            .collect()
+    }
+
+    /// Returns the monic polynomial with the given `roots`, multiplying out its linear
+    /// factors in [Leja order](leja_order), together with the residual of the roots in the
+    /// rebuilt coefficients.
+    ///
+    /// Checking the residual is useful when converting repeatedly between root and
+    /// coefficient forms, since coefficients of high degree can lose the roots to rounding.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let reconstruction = Polynomial::refine_from_roots(&[2f64, -1f64, 0.5]);
+    /// // (x - 2)(x + 1)(x - 0.5) = x^3 - 1.5x^2 - 1.5x + 1
+    /// assert_eq!(
+    ///     reconstruction.polynomial.coefficients,
+    ///     vec![1f64, -1.5, -1.5, 1f64]
+    /// );
+    /// assert_eq!(reconstruction.residual, 0f64);
+    /// ```
+    pub fn refine_from_roots(roots: &[f64]) -> Reconstruction {
+        let mut coefficients = vec![1f64];
+        for root in leja_order(roots) {
+            let mut next = vec![0f64; coefficients.len() + 1];
+            for (i, coeff) in coefficients.iter().enumerate() {
+                next[i] -= coeff * root;
+                next[i + 1] += coeff;
+            }
+            coefficients = next;
+        }
+        let polynomial = Polynomial::new(coefficients, 'x');
+
+        let residual = roots
+            .iter()
+            .map(|&root| {
+                let scale = polynomial
+                    .coefficients
+                    .iter()
+                    .rev()
+                    .fold(0f64, |sum, coeff| sum * root.abs() + coeff.abs());
+                let value = polynomial.evaluate_at(root).abs();
+                if scale == 0f64 {
+                    value
+                } else {
+                    value / scale
+                }
+            })
+            .fold(0f64, f64::max);
+        debug_event!(
+            degree = roots.len(),
+            residual,
+            "rebuilt coefficients from roots"
+        );
+
+        Reconstruction {
+            polynomial,
+            residual,
+        }
+    }
+
+    /// Returns `(value, x)`, the smallest value of the polynomial on the closed `interval`
+    /// and a point where it is attained.
+    ///
+    /// The minimum is attained at an endpoint or at a real root of the derivative, so only
+    /// those are evaluated.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 2x = (x - 1)^2 - 1
+    /// let polynomial = Polynomial::new(vec![0f64, -2f64, 1f64], 'x');
+    /// assert_eq!(polynomial.minimum_on((-1f64, 3f64)), (-1f64, 1f64));
+    /// assert_eq!(polynomial.minimum_on((2f64, 3f64)), (0f64, 2f64));
+    /// ```
+    pub fn minimum_on(&self, interval: (f64, f64)) -> (f64, f64) {
+        let (low, high) = if interval.0 <= interval.1 {
+            interval
+        } else {
+            (interval.1, interval.0)
+        };
+        self.derivative()
+            .real_roots()
+            .into_iter()
+            .filter(|&x| low < x && x < high)
+            .chain(vec![low, high])
+            .map(|x| (self.evaluate_at(x), x))
+            .fold((f64::INFINITY, low), |best, candidate| {
+                if candidate.0 < best.0 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// Returns `true` if the polynomial is nonnegative everywhere on the closed `interval`.
+    ///
+    /// The [`minimum_on`](Polynomial::minimum_on) the interval is compared with zero
+    /// exactly, so a polynomial that only touches zero, such as a square, can be reported
+    /// negative when rounding lands its minimum just below zero. Compare the minimum with a
+    /// tolerance in that case.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // 1 - x^2 is nonnegative exactly on [-1, 1]
+    /// let polynomial = Polynomial::new(vec![1f64, 0f64, -1f64], 'x');
+    /// assert!(polynomial.is_nonnegative_on((-1f64, 1f64)));
+    /// assert!(!polynomial.is_nonnegative_on((0f64, 1.5)));
+    /// ```
+    pub fn is_nonnegative_on(&self, interval: (f64, f64)) -> bool {
+        self.minimum_on(interval).0 >= 0f64
+    }
+
+    /// Returns the first-order sensitivities `∂r/∂c_i = -r^i / p'(r)` of the simple root
+    /// `root` to each coefficient `c_i`.
+    ///
+    /// Perturbing `c_i` by `δ` moves the root by about `δ ∂r/∂c_i`, so large entries flag
+    /// coefficients whose errors the root amplifies. Fails with
+    /// [`RootError::ZeroDerivative`] at a multiple root, whose sensitivity is unbounded.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 4 with the root 2 and p'(2) = 4
+    /// let polynomial = Polynomial::new(vec![-4f64, 0f64, 1f64], 'x');
+    /// let sensitivities = polynomial.root_sensitivities(2f64).unwrap();
+    /// assert_eq!(sensitivities, vec![-0.25, -0.5, -1f64]);
+    /// ```
+    pub fn root_sensitivities(&self, root: f64) -> Result<Vec<f64>, RootError> {
+        let (_, first, _) = self.evaluate_with_derivatives(root);
+        if first == 0f64 {
+            return Err(RootError::ZeroDerivative);
+        }
+        let mut power = 1f64;
+        Ok(self
+            .coefficients
+            .iter()
+            .map(|_| {
+                let sensitivity = -power / first;
+                power *= root;
+                sensitivity
+            })
+            .collect())
+    }
+
+    /// Returns the standard deviation of the simple root `root` caused by independent
+    /// coefficient errors with standard deviations `coeff_sigmas`, propagated to first order
+    /// through [`root_sensitivities`](Polynomial::root_sensitivities).
+    ///
+    /// `coeff_sigmas` is interpreted as in
+    /// [`evaluate_with_uncertainty`](Polynomial::evaluate_with_uncertainty).
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 4 with an uncertain constant term: sigma_r = 0.1 / |p'(2)|
+    /// let polynomial = Polynomial::new(vec![-4f64, 0f64, 1f64], 'x');
+    /// let sigma = polynomial.root_uncertainty(2f64, &[0.1]).unwrap();
+    /// assert!((sigma - 0.025).abs() < 1e-15);
+    /// ```
+    pub fn root_uncertainty(&self, root: f64, coeff_sigmas: &[f64]) -> Result<f64, RootError> {
+        let (_, first, _) = self.evaluate_with_derivatives(root);
+        if first == 0f64 {
+            return Err(RootError::ZeroDerivative);
+        }
+        let (_, sigma) = self.evaluate_with_uncertainty(root, coeff_sigmas);
+
+        Ok(sigma / first.abs())
+    }
+
+    /// Returns the distinct real roots in increasing order, using the default [`RootOptions`]
+    ///
+    /// Polynomials of degree at most 3 are solved with closed-form formulas and report
+    /// [`Root::Exact`] values, unless the discriminant is too close to zero to trust, as it
+    /// is near a double or triple root. Those and higher degrees fall back to
+    /// [`real_roots`](Polynomial::real_roots) and report [`Root::Approximate`] values.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::roots::Root;
+    ///
+    /// // x^2 - 5x + 6 = (x - 2)(x - 3)
+    /// let polynomial = Polynomial::new(vec![6f64, -5f64, 1f64], 'x');
+    /// let roots = polynomial.roots();
+    /// assert_eq!(roots, vec![Root::Exact(2f64), Root::Exact(3f64)]);
+    /// ```
+    pub fn roots(&self) -> Vec<Root> {
+        self.roots_with(&RootOptions::default())
+    }
+
+    /// Like [`roots`](Polynomial::roots), with explicit stopping criteria for the numerical fallback
+    /// # Example
+    /// ```
+    /// use polynom::roots::{Root, RootOptions};
+    /// use polynom::test_polynomials::wilkinson;
+    ///
+    /// let options = RootOptions { tolerance: 1e-6, max_iterations: 50 };
+    /// let roots = wilkinson(5).roots_with(&options);
+    /// assert_eq!(roots.len(), 5);
+    /// assert!(matches!(roots[2], Root::Approximate(x) if (x - 3f64).abs() < 1e-6));
+    /// ```
+    pub fn roots_with(&self, options: &RootOptions) -> Vec<Root> {
+        match self.degree() {
+            degree if degree < 1 => Vec::new(),
+            1..=3 => match self.closed_form_roots() {
+                Some(roots) => roots.into_iter().map(Root::Exact).collect(),
+                None => self.approximate_roots(options),
+            },
+            _ => self.approximate_roots(options),
+        }
+    }
+
+    fn approximate_roots(&self, options: &RootOptions) -> Vec<Root> {
+        self.real_roots_with(options)
+            .into_iter()
+            .map(|(root, _)| Root::Approximate(root))
+            .collect()
+    }
+
+    /// Solves polynomials of degree 1 to 3 with the linear, quadratic and cubic formulas, or
+    /// returns `None` when the discriminant is within its rounding error of zero.
+    ///
+    /// Near a double or triple root the sign of the discriminant is decided by rounding, so
+    /// the formulas would drop, split or misplace the multiple root.
+    fn closed_form_roots(&self) -> Option<Vec<f64>> {
+        let c = &self.coefficients;
+        let mut roots = match c.len() {
+            2 => vec![-c[0] / c[1]],
+            3 => {
+                let discriminant = c[1] * c[1] - 4f64 * c[2] * c[0];
+                let error = 4f64 * f64::EPSILON * (c[1] * c[1] + (4f64 * c[2] * c[0]).abs());
+                if discriminant.abs() <= error {
+                    return None;
+                } else if discriminant < 0f64 {
+                    Vec::new()
+                } else {
+                    // Avoid cancellation by never subtracting nearly equal quantities
+                    let sign = if c[1] >= 0f64 { 1f64 } else { -1f64 };
+                    let q = -0.5 * (c[1] + sign * discriminant.sqrt());
+                    vec![q / c[2], c[0] / q]
+                }
+            }
+            _ => {
+                // Depressed cubic t^3 + p t + q with x = t - a / 3
+                let (a, b, d) = (c[2] / c[3], c[1] / c[3], c[0] / c[3]);
+                let shift = a / 3f64;
+                let p = b - a * a / 3f64;
+                let q = 2f64 * a * a * a / 27f64 - a * b / 3f64 + d;
+                let discriminant = (q / 2f64).powi(2) + (p / 3f64).powi(3);
+
+                // Rounding errors of p and q, carried through to the discriminant
+                let p_error = 8f64 * f64::EPSILON * (b.abs() + a * a / 3f64);
+                let q_error = 8f64
+                    * f64::EPSILON
+                    * (2f64 * (a * a * a).abs() / 27f64 + (a * b).abs() / 3f64 + d.abs());
+                let error = (q.abs() + q_error) * q_error / 2f64
+                    + (p * p + p_error * p_error) * p_error / 9f64;
+                if discriminant.abs() <= error {
+                    return None;
+                } else if discriminant > 0f64 {
+                    let root = discriminant.sqrt();
+                    vec![(-q / 2f64 + root).cbrt() + (-q / 2f64 - root).cbrt() - shift]
+                } else {
+                    // Three distinct real roots: the trigonometric form
+                    let radius = 2f64 * (-p / 3f64).sqrt();
+                    let angle = ((3f64 * q / (2f64 * p)) * (-3f64 / p).sqrt()).acos() / 3f64;
+                    (0..3)
+                        .map(|k| {
+                            radius * (angle - 2f64 * std::f64::consts::PI * k as f64 / 3f64).cos()
+                                - shift
+                        })
+                        .collect()
+                }
+            }
+        };
+
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Some(roots)
+    }
+}
+
+/// Returns all complex roots of each polynomial, repeated according to multiplicity.
+///
+/// Meant for solving many small polynomials, e.g. one per pixel or time step: linear and
+/// quadratic polynomials are solved in closed form, and higher degrees run the
+/// Durand–Kerner iteration from deterministic starting points on a circle enclosing the
+/// roots. The iteration runs in a scratch buffer shared by all polynomials solved on the
+/// same thread, so each result is allocated once, at its final size. Constant polynomials
+/// have no roots.
+///
+/// With the `rayon` feature, the batch is split into one chunk per available thread, each
+/// with its own scratch buffer. The results are the same either way.
+///
+/// Each entry is a `Result` rather than a bare list of roots because the iteration can fail
+/// for one polynomial, e.g. with [`RootError::NoConvergence`] on tightly clustered roots,
+/// without affecting the rest of the batch.
+/// # Example
+/// ```
+/// use polynom::complex::Complex;
+/// use polynom::polynomial::Polynomial;
+/// use polynom::roots::roots_batch;
+///
+/// let polynomials = vec![
+///     Polynomial::new(vec![1f64, 0f64, 1f64], 'x'),
+///     Polynomial::new(vec![-1f64, 0f64, 0f64, 1f64], 'x'),
+/// ];
+/// let roots = roots_batch(&polynomials);
+/// let quadratic = roots[0].as_ref().unwrap();
+/// assert!(quadratic.contains(&Complex::new(0f64, 1f64)));
+/// let cubic = roots[1].as_ref().unwrap();
+/// assert!(cubic.iter().any(|&root| (root - Complex::from(1f64)).norm() < 1e-12));
+/// ```
+pub fn roots_batch(polynomials: &[Polynomial]) -> Vec<Result<Vec<Complex>, RootError>> {
+    #[cfg(feature = "rayon")]
+    {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = polynomials.len().div_ceil(threads).max(1);
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = polynomials
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || roots_batch_sequential(chunk)))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("root finding does not panic"))
+                .collect()
+        })
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        roots_batch_sequential(polynomials)
+    }
+}
+
+/// Solves each polynomial in turn, reusing one scratch buffer for the iteration.
+fn roots_batch_sequential(polynomials: &[Polynomial]) -> Vec<Result<Vec<Complex>, RootError>> {
+    let mut scratch: Vec<Complex> = Vec::new();
+    polynomials
+        .iter()
+        .map(|polynomial| {
+            let c = &polynomial.coefficients;
+            match polynomial.degree() {
+                degree if degree < 1 => Ok(Vec::new()),
+                1 => Ok(vec![Complex::from(-c[0] / c[1])]),
+                2 => {
+                    let (a, b) = (Complex::from(c[2]), Complex::from(c[1]));
+                    let discriminant = Complex::from(c[1] * c[1] - 4f64 * c[2] * c[0]).sqrt();
+                    // Avoid cancellation by never subtracting nearly equal quantities
+                    let q = if c[1] >= 0f64 {
+                        -(b + discriminant) / Complex::from(2f64)
+                    } else {
+                        (discriminant - b) / Complex::from(2f64)
+                    };
+                    if q.norm() == 0f64 {
+                        return Ok(vec![Complex::from(0f64); 2]);
+                    }
+                    Ok(vec![q / a, Complex::from(c[0]) / q])
+                }
+                degree => {
+                    let radius = polynomial.root_bound();
+                    scratch.clear();
+                    scratch.extend((0..degree).map(|k| {
+                        // Offset the angles so that no start lies on the real axis
+                        let angle = 2f64 * std::f64::consts::PI * k as f64 / degree as f64 + 0.4;
+                        Complex::new(radius * angle.cos(), radius * angle.sin())
+                    }));
+                    polynomial.weierstrass_iterate(&mut scratch)?;
+                    Ok(scratch.to_vec())
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+    use crate::test_polynomials;
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn test_find_sign_changes_brackets_every_root() {
+        let polynomial = test_polynomials::wilkinson(5);
+        let brackets = polynomial.find_sign_changes((0.25, 5.75), 11);
+
+        assert_eq!(brackets.len(), 5);
+        for (root, (a, b)) in (1..=5).zip(brackets) {
+            assert!(a < root as f64 && (root as f64) < b);
+        }
+    }
+
+    #[test]
+    fn test_find_sign_changes_misses_double_roots() {
+        let polynomial = test_polynomials::multiple_root(1f64, 2);
+
+        assert!(polynomial.find_sign_changes((0.1, 2.1), 10).is_empty());
+    }
+
+    #[test]
+    fn test_find_sign_changes_without_roots() {
+        let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+
+        assert!(polynomial
+            .find_sign_changes((-10f64, 10f64), 100)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_find_root_methods_on_wilkinson() {
+        let polynomial = test_polynomials::wilkinson(6);
+
+        for &method in &[RootMethod::Newton, RootMethod::Halley, RootMethod::Laguerre] {
+            let root = polynomial.find_root(3.2, method).unwrap();
+            assert!((root - 3f64).abs() < 1e-12, "{:?} gave {}", method, root);
+        }
+    }
+
+    #[test]
+    fn test_find_root_errors() {
+        let polynomial = Polynomial::new(vec![1f64, 0f64, 1f64], 'x');
+
+        assert_eq!(
+            polynomial.find_root(0f64, RootMethod::Newton).unwrap_err(),
+            RootError::ZeroDerivative
+        );
+        assert_eq!(
+            polynomial.find_root(0.3, RootMethod::Laguerre).unwrap_err(),
+            RootError::NotReal
+        );
+    }
+
+    #[test]
+    fn test_laguerre_converges_from_far_away() {
+        // Laguerre's method converges from any real start for polynomials with only real roots
+        let polynomial = test_polynomials::chebyshev(12);
+        let root = polynomial.laguerre(Complex::from(1e6)).unwrap();
+
+        assert!(root.im.abs() < 1e-12);
+        assert!((root.re - (std::f64::consts::PI / 24f64).cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_brent_errors() {
+        assert_eq!(
+            brent(|x| x * x + 1f64, (-1f64, 1f64), 1e-12, 100).unwrap_err(),
+            RootError::NotBracketed
+        );
+        assert_eq!(
+            brent(|x| x.powi(3) - 2f64, (0f64, 1e6), 0f64, 3).unwrap_err(),
+            RootError::NoConvergence
+        );
+    }
+
+    #[test]
+    fn test_brent_transcendental() {
+        let root = brent(|x| x.cos() - x, (0f64, 1f64), 1e-15, 100).unwrap();
+
+        assert!((root.cos() - root).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_real_roots_wilkinson() {
+        let roots = test_polynomials::wilkinson(10).real_roots();
+
+        assert_eq!(roots.len(), 10);
+        for (i, root) in roots.iter().enumerate() {
+            assert!((root - (i + 1) as f64).abs() < 1e-8, "{:?}", roots);
+        }
+    }
+
+    #[test]
+    fn test_real_roots_chebyshev() {
+        let roots = test_polynomials::chebyshev(9).real_roots();
+
+        assert_eq!(roots.len(), 9);
+        for (k, root) in roots.iter().rev().enumerate() {
+            let expected = ((2 * k + 1) as f64 * std::f64::consts::PI / 18f64).cos();
+            assert!((root - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_real_roots_multiple_roots() {
+        let triple = test_polynomials::multiple_root(2f64, 3).real_roots();
+        let mixed = (test_polynomials::multiple_root(-1f64, 2)
+            .multiply(test_polynomials::clustered_roots(3f64, 1f64, 2)))
+        .real_roots();
+
+        assert_eq!(triple.len(), 1);
+        assert!((triple[0] - 2f64).abs() < 1e-4);
+        assert_eq!(mixed.len(), 3);
+        assert!((mixed[0] + 1f64).abs() < 1e-7);
+        assert!((mixed[1] - 2.5).abs() < 1e-12);
+        assert!((mixed[2] - 3.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_real_roots_edge_cases() {
+        assert!(Polynomial::new(vec![0f64], 'x').real_roots().is_empty());
+        assert!(Polynomial::new(vec![3f64], 'x').real_roots().is_empty());
+        assert!(Polynomial::new(vec![1f64, 0f64, 1f64], 'x')
+            .real_roots()
+            .is_empty());
+        assert_eq!(
+            Polynomial::new(vec![0f64, 0f64, 1f64], 'x').real_roots(),
+            vec![0f64]
+        );
+        assert_eq!(
+            Polynomial::new(vec![-4f64, 2f64], 'x').real_roots(),
+            vec![2f64]
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_distinguish_simple_and_multiple_roots() {
+        let simple = test_polynomials::wilkinson(4);
+        let (_, fast) = simple
+            .find_root_with_diagnostics(2.2, RootMethod::Newton)
+            .unwrap();
+        assert!(fast.iterations > 0 && fast.iterations < 10);
+
+        let multiple = test_polynomials::multiple_root(1f64, 3);
+        let (root, diagnostics) = multiple
+            .find_root_with_diagnostics(1.5, RootMethod::Newton)
+            .unwrap();
+        assert_eq!(diagnostics.status, ConvergenceStatus::ResidualTolerance);
+        assert!(diagnostics.iterations > 2 * fast.iterations);
+        assert_eq!(diagnostics.residual, multiple.evaluate_at(root).abs());
+    }
+
+    #[test]
+    fn test_brent_diagnostics_exact_endpoint() {
+        let (root, diagnostics) =
+            brent_with_diagnostics(|x| x - 1f64, (1f64, 3f64), 1e-12, 100).unwrap();
+
+        assert_eq!(root, 1f64);
+        assert_eq!(
+            diagnostics,
+            Diagnostics::new(0, 0f64, ConvergenceStatus::Exact)
+        );
+    }
+
+    #[test]
+    fn test_real_roots_diagnostics_flag_multiple_roots() {
+        // (x - 1)^2 (x - 3)
+        let polynomial = test_polynomials::multiple_root(1f64, 2)
+            .multiply(Polynomial::new(vec![-3f64, 1f64], 'x'));
+        let roots = polynomial.real_roots_with_diagnostics();
+
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots[0].1.iterations, 0);
+        assert_ne!(roots[0].1.status, ConvergenceStatus::StepTolerance);
+        assert!(roots[1].1.iterations > 0);
+        assert_ne!(roots[1].1.status, ConvergenceStatus::ResidualTolerance);
+        assert_eq!(
+            roots.iter().map(|&(root, _)| root).collect::<Vec<_>>(),
+            polynomial.real_roots()
+        );
+    }
+
+    #[test]
+    fn test_durand_kerner_diagnostics() {
+        let polynomial = test_polynomials::wilkinson(6);
+        let (roots, diagnostics) = polynomial
+            .durand_kerner_with_diagnostics(&mut SplitMix64::new(1))
+            .unwrap();
+
+        assert_eq!(
+            roots,
+            polynomial.durand_kerner(&mut SplitMix64::new(1)).unwrap()
+        );
+        assert!(diagnostics.iterations > 0);
+        let largest = roots
+            .iter()
+            .map(|&root| polynomial.evaluate_generic(root).norm())
+            .fold(0f64, f64::max);
+        assert_eq!(diagnostics.residual, largest);
+    }
+
+    #[test]
+    fn test_durand_kerner_is_reproducible() {
+        let polynomial = test_polynomials::wilkinson(8);
+        let first = polynomial.durand_kerner(&mut SplitMix64::new(3)).unwrap();
+        let second = polynomial.durand_kerner(&mut SplitMix64::new(3)).unwrap();
+
+        assert_eq!(first, second);
+        let mut real: Vec<f64> = first.iter().map(|root| root.re).collect();
+        real.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (i, root) in real.iter().enumerate() {
+            assert!((root - (i + 1) as f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_durand_kerner_random_polynomials() {
+        let mut rng = SplitMix64::new(11);
+        for _ in 0..20 {
+            let polynomial = test_polynomials::random(6, &mut rng);
+            let roots = polynomial.durand_kerner(&mut rng).unwrap();
+            assert_eq!(roots.len(), 6);
+            for &root in &roots {
+                assert!(polynomial.evaluate_generic(root).norm() < 1e-10);
+            }
+        }
+        assert!(Polynomial::new(vec![2f64], 'x')
+            .durand_kerner(&mut rng)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_intercepts() {
+        let clustered = test_polynomials::clustered_roots(1f64, 1e-3, 3);
+        assert_eq!(clustered.x_intercepts(1e-6).len(), 3);
+        let merged = clustered.x_intercepts(1e-2);
+        assert_eq!(merged.len(), 1);
+        assert!((merged[0] - 1f64).abs() < 1e-9);
+
+        let zero = Polynomial::new(vec![0f64], 'x');
+        assert!(zero.x_intercepts(1e-9).is_empty());
+        assert_eq!(zero.y_intercept(), 0f64);
+        assert!(Polynomial::new(vec![1f64, 0f64, 1f64], 'x')
+            .x_intercepts(1e-9)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_closed_form_roots() {
+        let exact = |coefficients: Vec<f64>| -> Vec<f64> {
+            Polynomial::new(coefficients, 'x')
+                .roots()
+                .into_iter()
+                .map(|root| match root {
+                    Root::Exact(value) => value,
+                    Root::Approximate(_) => panic!("expected a closed-form root"),
+                })
+                .collect()
+        };
+        let assert_close = |actual: Vec<f64>, expected: &[f64]| {
+            assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+            for (a, e) in actual.iter().zip(expected) {
+                assert!((a - e).abs() < 1e-12, "{:?} vs {:?}", actual, expected);
+            }
+        };
+
+        assert_close(exact(vec![3f64, -2f64]), &[1.5]);
+        assert_close(exact(vec![1f64, 0f64, 1f64]), &[]);
+        // Tiny root that the naive quadratic formula loses to cancellation
+        assert_close(exact(vec![1f64, 1e8, 1f64]), &[-1e8, -1e-8]);
+        assert_close(exact(vec![-6f64, 11f64, -6f64, 1f64]), &[1f64, 2f64, 3f64]);
+        assert_close(exact(vec![-1f64, 0f64, 0f64, 1f64]), &[1f64]);
+        // Close but distinct non-dyadic roots stay on the closed-form path
+        assert_close(exact(vec![-0.006, 0.11, -0.6, 1f64]), &[0.1, 0.2, 0.3]);
+        assert_close(exact(vec![0.02, -0.3, 1f64]), &[0.1, 0.2]);
+    }
+
+    #[test]
+    fn test_multiple_roots_fall_back_to_numerical() {
+        let from_roots = |roots: &[f64]| {
+            roots
+                .iter()
+                .fold(Polynomial::new(vec![1f64], 'x'), |product, &root| {
+                    product.multiply(Polynomial::new(vec![-root, 1f64], 'x'))
+                })
+        };
+        let cases: Vec<(Vec<f64>, Vec<f64>)> = vec![
+            (vec![1f64, 1f64], vec![1f64]),
+            (vec![0.1, 0.1], vec![0.1]),
+            (vec![1f64, 1f64, -2f64], vec![-2f64, 1f64]),
+            (vec![2f64, 2f64, 2f64], vec![2f64]),
+            (vec![0.1, 0.1, 0.3], vec![0.1, 0.3]),
+            (vec![0.001, 0.001, 5f64], vec![0.001, 5f64]),
+            (vec![0.7, 0.7, 0.2], vec![0.2, 0.7]),
+            (vec![0.1, 0.1, 0.1], vec![0.1]),
+        ];
+        for (roots, expected) in cases {
+            let found = from_roots(&roots).roots();
+            assert_eq!(found.len(), expected.len(), "{:?}: {:?}", roots, found);
+            for (root, e) in found.iter().zip(&expected) {
+                assert!(matches!(root, Root::Approximate(_)), "{:?}", found);
+                assert!((root.value() - e).abs() < 1e-12, "{:?}: {:?}", roots, found);
+            }
+        }
+    }
+
+    #[test]
+    fn test_roots_numerical_fallback() {
+        let roots = test_polynomials::wilkinson(7).roots();
+
+        assert_eq!(roots.len(), 7);
+        for (i, root) in roots.iter().enumerate() {
+            assert!(matches!(root, Root::Approximate(_)));
+            assert!((root.value() - (i + 1) as f64).abs() < 1e-10);
+        }
+        assert!(Polynomial::new(vec![5f64], 'x').roots().is_empty());
+    }
+
+    #[test]
+    fn test_intersections_with() {
+        let cubic = Polynomial::new(vec![0f64, 0f64, 0f64, 1f64], 'x');
+        let line = Polynomial::new(vec![0f64, 1f64], 'x');
+        let points = cubic.intersections_with(&line, 1e-9);
+        assert_eq!(points.len(), 3);
+        for (&(x, y), &expected) in points.iter().zip(&[-1f64, 0f64, 1f64]) {
+            assert!((x - expected).abs() < 1e-12);
+            assert!((y - line.evaluate_at(x)).abs() < 1e-12);
+        }
+
+        // Parallel lines never meet, and a graph has no isolated crossings with itself
+        let shifted = Polynomial::new(vec![1f64, 1f64], 'x');
+        assert!(line.intersections_with(&shifted, 1e-9).is_empty());
+        assert!(cubic.intersections_with(&cubic, 1e-9).is_empty());
+    }
+
+    #[test]
+    fn test_is_nonnegative_on() {
+        // (x - 1)^2 (x + 2) is nonnegative from -2 onwards and touches zero at 1
+        let polynomial = Polynomial::new(vec![2f64, -3f64, 0f64, 1f64], 'x');
+        assert!(polynomial.is_nonnegative_on((-2f64, 5f64)));
+        assert!(polynomial.is_nonnegative_on((5f64, -2f64)));
+        assert!(!polynomial.is_nonnegative_on((-2.5, 0f64)));
+        assert_eq!(polynomial.minimum_on((0f64, 2f64)), (0f64, 1f64));
+
+        let constant = Polynomial::new(vec![-1f64], 'x');
+        assert!(!constant.is_nonnegative_on((0f64, 0f64)));
+    }
+
+    #[test]
+    fn test_refine_from_roots_matches_wilkinson() {
+        let roots: Vec<f64> = (1..=20).rev().map(|i| i as f64).collect();
+        let reconstruction = Polynomial::refine_from_roots(&roots);
+        let expected = test_polynomials::wilkinson(20);
+
+        assert!(reconstruction.residual < 1e-14);
+        assert!(reconstruction
+            .polynomial
+            .approx_eq(&expected, &Tolerance::ulps(64f64)));
+    }
+
+    #[test]
+    fn test_leja_order_spreads_points() {
+        let points: Vec<f64> = (0..=8).map(|i| i as f64 / 8f64).collect();
+        let ordered = leja_order(&points);
+        assert_eq!(&ordered[..3], &[1f64, 0f64, 0.5]);
+        let mut sorted = ordered.clone();
+        sorted.sort_by(f64::total_cmp);
+        assert_eq!(sorted, points);
+
+        assert!(leja_order(&[]).is_empty());
+        assert_eq!(leja_order(&[3f64, 3f64]), vec![3f64, 3f64]);
+        assert_eq!(
+            Polynomial::refine_from_roots(&[]).polynomial.coefficients,
+            vec![1f64]
+        );
+    }
+
+    #[test]
+    fn test_root_sensitivities_match_finite_differences() {
+        // (x - 1)(x - 3)(x + 2)
+        let polynomial = Polynomial::new(vec![6f64, -5f64, -2f64, 1f64], 'x');
+        let root = 3f64;
+        let sensitivities = polynomial.root_sensitivities(root).unwrap();
+        let step = 1e-7;
+        for (i, sensitivity) in sensitivities.iter().enumerate() {
+            let mut perturbed = polynomial.clone();
+            perturbed.coefficients[i] += step;
+            let moved = perturbed.find_root(root, RootMethod::Newton).unwrap();
+            assert!(((moved - root) / step - sensitivity).abs() < 1e-5);
+        }
+
+        let sigmas = [0.01, 0.02, 0f64, 0.005];
+        let expected = sensitivities
+            .iter()
+            .zip(&sigmas)
+            .map(|(s, sigma)| (s * sigma).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let sigma = polynomial.root_uncertainty(root, &sigmas).unwrap();
+        assert!((sigma - expected).abs() < 1e-15);
+
+        let double = Polynomial::new(vec![1f64, -2f64, 1f64], 'x');
+        assert_eq!(
+            double.root_sensitivities(1f64),
+            Err(RootError::ZeroDerivative)
+        );
+        assert_eq!(
+            double.root_uncertainty(1f64, &[1f64]),
+            Err(RootError::ZeroDerivative)
+        );
+    }
+
+    #[test]
+    fn test_roots_batch() {
+        let polynomials = vec![
+            Polynomial::new(vec![5f64], 'x'),
+            Polynomial::new(vec![3f64, -2f64], 'x'),
+            Polynomial::new(vec![0f64, 0f64, 2f64], 'x'),
+            Polynomial::new(vec![2f64, -3f64, 1f64], 'x'),
+            Polynomial::new(vec![1f64, 2f64, 2f64], 'x'),
+            test_polynomials::wilkinson(6),
+            Polynomial::new(vec![1f64, 0f64, 0f64, 0f64, 0f64, 1f64], 'x'),
+        ];
+        let results = roots_batch(&polynomials);
+        assert_eq!(results.len(), polynomials.len());
+        for (polynomial, roots) in polynomials.iter().zip(&results) {
+            let roots = roots.as_ref().unwrap();
+            assert_eq!(roots.len() as isize, polynomial.degree().max(0));
+            for &root in roots {
+                let residual = polynomial.evaluate_generic(root).norm();
+                assert!(residual <= 1e-9 * (1f64 + root.norm()).powi(6));
+            }
+        }
+        assert_eq!(results[1], Ok(vec![Complex::from(1.5)]));
+        let mut real: Vec<f64> = results[5].as_ref().unwrap().iter().map(|r| r.re).collect();
+        real.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (k, root) in real.iter().enumerate() {
+            assert!((root - (k + 1) as f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_roots_batch_matches_sequential_solving() {
+        let polynomials: Vec<Polynomial> = (0..64)
+            .map(|k| Polynomial::new(vec![k as f64, -1f64, 0f64, 1f64, 0.5], 'x'))
+            .collect();
+
+        assert_eq!(
+            roots_batch(&polynomials),
+            roots_batch_sequential(&polynomials)
+        );
+        assert!(roots_batch(&[]).is_empty());
+    }
+}
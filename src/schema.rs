@@ -0,0 +1,72 @@
+//! JSON Schema for the JSON form of polynomials, behind the `schemars` feature.
+//!
+//! The schema describes exactly what [`InputFormat::JsonLines`](crate::parse::InputFormat)
+//! accepts, so services exchanging polynomial payloads can publish it in their API specs:
+//!
+//! ```
+//! use polynom::polynomial::Polynomial;
+//!
+//! let schema = schemars::schema_for!(Polynomial);
+//! assert_eq!(schema.get("title").unwrap(), "Polynomial");
+//! ```
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::polynomial::Polynomial;
+
+impl JsonSchema for Polynomial {
+    fn schema_name() -> Cow<'static, str> {
+        "Polynomial".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "polynom::polynomial::Polynomial".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let coefficients = json_schema!({
+            "description": "Coefficients, lowest degree first",
+            "type": "array",
+            "items": { "type": "number" }
+        });
+
+        json_schema!({
+            "description": "A polynomial, either as its coefficient array or as an object naming the indeterminate",
+            "oneOf": [
+                coefficients,
+                {
+                    "type": "object",
+                    "properties": {
+                        "coefficients": coefficients,
+                        "indeterminate": {
+                            "description": "The variable name used when formatting",
+                            "type": "string",
+                            "minLength": 1,
+                            "maxLength": 1,
+                            "default": "x"
+                        }
+                    },
+                    "required": ["coefficients"],
+                    "additionalProperties": false
+                }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_accepts_both_json_forms() {
+        let schema = schemars::schema_for!(Polynomial);
+        let forms = schema.get("oneOf").unwrap().as_array().unwrap();
+        assert_eq!(forms.len(), 2);
+        assert_eq!(forms[0]["type"], "array");
+        assert_eq!(forms[1]["required"][0], "coefficients");
+        assert_eq!(forms[1]["properties"]["indeterminate"]["default"], "x");
+    }
+}
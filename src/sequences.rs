@@ -0,0 +1,151 @@
+//! Transforms of the coefficient sequence `a_0, a_1, ..., a_n` of a polynomial, for working
+//! with polynomials as truncated generating functions of integer sequences.
+//!
+//! Every transform returns the first `n + 1` terms of the transformed sequence as the
+//! coefficients of a polynomial with the same indeterminate.
+
+use crate::polynomial::Polynomial;
+
+impl Polynomial {
+    /// Returns the binomial transform `b_n = sum_k C(n, k) a_k` of the coefficients.
+    ///
+    /// In generating-function terms this is `B(x) = A(x / (1 - x)) / (1 - x)` truncated to
+    /// the degree of `self`.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // The all-ones sequence transforms into the powers of two
+    /// let ones = Polynomial::new(vec![1f64; 5], 'x');
+    /// assert_eq!(ones.binomial_transform().coefficients, vec![1f64, 2f64, 4f64, 8f64, 16f64]);
+    /// ```
+    pub fn binomial_transform(&self) -> Polynomial {
+        self.signed_binomial_transform(false)
+    }
+
+    /// Returns the inverse binomial transform `a_n = sum_k (-1)^(n - k) C(n, k) b_k`, which
+    /// undoes [`binomial_transform`](Polynomial::binomial_transform)
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// let powers_of_two = Polynomial::new(vec![1f64, 2f64, 4f64, 8f64], 'x');
+    /// assert_eq!(powers_of_two.inverse_binomial_transform().coefficients, vec![1f64; 4]);
+    /// ```
+    pub fn inverse_binomial_transform(&self) -> Polynomial {
+        self.signed_binomial_transform(true)
+    }
+
+    /// Applies the binomial transform, alternating the signs when `alternate` is set.
+    fn signed_binomial_transform(&self, alternate: bool) -> Polynomial {
+        let mut row = vec![1f64];
+        let mut coefficients = Vec::with_capacity(self.coefficients.len());
+        for n in 0..self.coefficients.len() {
+            if n > 0 {
+                // Advance Pascal's triangle to row n
+                row.push(1f64);
+                for k in (1..n).rev() {
+                    row[k] += row[k - 1];
+                }
+            }
+            let term = row
+                .iter()
+                .zip(&self.coefficients)
+                .enumerate()
+                .map(|(k, (binomial, coeff))| {
+                    let sign = if alternate && (n - k) % 2 == 1 {
+                        -1f64
+                    } else {
+                        1f64
+                    };
+                    sign * binomial * coeff
+                })
+                .sum();
+            coefficients.push(term);
+        }
+
+        Polynomial::new(coefficients, self.indeterminate)
+    }
+
+    /// Returns the Euler transform `b` of the sequence `a_1, a_2, ...`, defined by
+    /// `1 + sum b_n x^n = prod_(i >= 1) (1 - x^i)^(-a_i)`.
+    ///
+    /// The constant coefficient `a_0` does not take part and `b_0` is always `1`. If `a_i`
+    /// counts the connected structures of size `i`, `b_n` counts the structures of size `n`
+    /// built as multisets of them.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // One part of each size: b_n counts the partitions of n
+    /// let ones = Polynomial::new(vec![0f64, 1f64, 1f64, 1f64, 1f64, 1f64, 1f64], 'x');
+    /// assert_eq!(
+    ///     ones.euler_transform().coefficients,
+    ///     vec![1f64, 1f64, 2f64, 3f64, 5f64, 7f64, 11f64]
+    /// );
+    /// ```
+    pub fn euler_transform(&self) -> Polynomial {
+        let a = &self.coefficients;
+        let length = a.len();
+        // c_n = sum over the divisors d of n of d a_d
+        let mut c = vec![0f64; length];
+        for (d, &coeff) in a.iter().enumerate().skip(1) {
+            for n in (d..length).step_by(d) {
+                c[n] += d as f64 * coeff;
+            }
+        }
+
+        let mut b = vec![0f64; length];
+        b[0] = 1f64;
+        for n in 1..length {
+            let sum: f64 = (1..=n).map(|k| c[k] * b[n - k]).sum();
+            b[n] = sum / n as f64;
+        }
+
+        Polynomial::new(b, self.indeterminate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial_transform_round_trip() {
+        let polynomial = Polynomial::from_ints(vec![3, -1, 4, 1, -5, 9, 2, -6], 'n');
+        let transformed = polynomial.binomial_transform();
+        assert_eq!(transformed.indeterminate, 'n');
+        assert_eq!(transformed.inverse_binomial_transform(), polynomial);
+
+        // The binomial transform of the Catalan numbers is A007317
+        let catalan = Polynomial::from_ints(vec![1, 1, 2, 5, 14, 42], 'x');
+        assert_eq!(
+            catalan.binomial_transform(),
+            Polynomial::from_ints(vec![1, 2, 5, 15, 51, 188], 'x')
+        );
+        assert_eq!(
+            Polynomial::new(vec![0f64], 'x')
+                .binomial_transform()
+                .degree(),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_euler_transform() {
+        // Rooted trees (A000081) are the Euler transform of themselves shifted by one
+        let trees = Polynomial::from_ints(vec![0, 1, 1, 2, 4, 9, 20, 48, 115], 'x');
+        let transformed = trees.euler_transform();
+        assert_eq!(&transformed.coefficients[..8], &trees.coefficients[1..]);
+
+        // The constant term is ignored
+        let shifted = Polynomial::from_ints(vec![7, 1, 1, 2, 4, 9, 20, 48, 115], 'x');
+        assert_eq!(shifted.euler_transform(), transformed);
+        assert_eq!(
+            Polynomial::new(vec![5f64], 'x')
+                .euler_transform()
+                .coefficients,
+            vec![1f64]
+        );
+    }
+}
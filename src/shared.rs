@@ -0,0 +1,130 @@
+//! Polynomials with copy-on-write coefficient storage.
+
+use std::sync::Arc;
+
+use crate::polynomial::Polynomial;
+
+/// A polynomial whose coefficients live in a shared `Arc<[f64]>`.
+///
+/// Cloning is `O(1)` and every clone reads the same buffer; the first mutation through
+/// [`coefficients_mut`](SharedPolynomial::coefficients_mut) on a shared value copies the
+/// coefficients. Use it to fan one polynomial out to many readers, and convert back into a
+/// [`Polynomial`] for arithmetic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedPolynomial {
+    coefficients: Arc<[f64]>,
+    /// The `char` representation of the indeterminate.
+    pub indeterminate: char,
+}
+
+impl SharedPolynomial {
+    /// Returns the polynomial with the given coefficients, lowest degree first, normalized
+    /// like [`Polynomial::new`]
+    /// # Example
+    /// ```
+    /// use polynom::shared::SharedPolynomial;
+    ///
+    /// let shared = SharedPolynomial::new(vec![1f64, 2f64, 0f64], 'x');
+    /// let copy = shared.clone();
+    /// assert!(SharedPolynomial::ptr_eq(&shared, &copy));
+    /// assert_eq!(copy.coefficients(), &[1f64, 2f64]);
+    /// ```
+    pub fn new(coefficients: Vec<f64>, indeterminate: char) -> SharedPolynomial {
+        SharedPolynomial::from(Polynomial::new(coefficients, indeterminate))
+    }
+
+    /// Returns the coefficients, lowest degree first
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients for modification, first copying them if other clones
+    /// share the buffer
+    /// # Example
+    /// ```
+    /// use polynom::shared::SharedPolynomial;
+    ///
+    /// let original = SharedPolynomial::new(vec![1f64, 2f64], 'x');
+    /// let mut modified = original.clone();
+    /// modified.coefficients_mut()[0] = 5f64;
+    /// assert_eq!(original.coefficients(), &[1f64, 2f64]);
+    /// assert_eq!(modified.coefficients(), &[5f64, 2f64]);
+    /// assert!(!SharedPolynomial::ptr_eq(&original, &modified));
+    /// ```
+    pub fn coefficients_mut(&mut self) -> &mut [f64] {
+        Arc::make_mut(&mut self.coefficients)
+    }
+
+    /// Returns `true` if both values read the same coefficient buffer
+    pub fn ptr_eq(a: &SharedPolynomial, b: &SharedPolynomial) -> bool {
+        Arc::ptr_eq(&a.coefficients, &b.coefficients)
+    }
+
+    /// Returns the degree, ignoring any trailing zeros left by
+    /// [`coefficients_mut`](SharedPolynomial::coefficients_mut); the zero polynomial has
+    /// degree `-1`
+    pub fn degree(&self) -> isize {
+        self.coefficients
+            .iter()
+            .rposition(|&coeff| coeff != 0f64)
+            .map_or(-1, |degree| degree as isize)
+    }
+
+    /// Returns the value at `determinate`, computed with Horner's scheme
+    pub fn evaluate_at(&self, determinate: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0f64, |sum, &coeff| sum * determinate + coeff)
+    }
+
+    /// Returns an owned, normalized copy of the polynomial
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.to_vec(), self.indeterminate)
+    }
+}
+
+impl From<Polynomial> for SharedPolynomial {
+    fn from(polynomial: Polynomial) -> SharedPolynomial {
+        SharedPolynomial {
+            coefficients: Arc::from(polynomial.coefficients),
+            indeterminate: polynomial.indeterminate,
+        }
+    }
+}
+
+impl From<SharedPolynomial> for Polynomial {
+    fn from(shared: SharedPolynomial) -> Polynomial {
+        shared.to_polynomial()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_on_write() {
+        let mut unique = SharedPolynomial::new(vec![1f64, 2f64, 3f64], 'x');
+        let buffer = unique.coefficients().as_ptr();
+        unique.coefficients_mut()[2] = 0f64;
+        // Not shared, so modified in place
+        assert_eq!(unique.coefficients().as_ptr(), buffer);
+        assert_eq!(unique.degree(), 1);
+        assert_eq!(unique.evaluate_at(2f64), 5f64);
+        assert_eq!(
+            Polynomial::from(unique.clone()),
+            Polynomial::new(vec![1f64, 2f64], 'x')
+        );
+
+        let readers: Vec<SharedPolynomial> = (0..8).map(|_| unique.clone()).collect();
+        assert!(readers
+            .iter()
+            .all(|reader| SharedPolynomial::ptr_eq(reader, &unique)));
+        unique.coefficients_mut()[0] = 0f64;
+        unique.coefficients_mut()[1] = 0f64;
+        assert_eq!(unique.degree(), -1);
+        assert_eq!(readers[0].coefficients(), &[1f64, 2f64, 0f64]);
+        assert_eq!(unique.to_polynomial().coefficients, vec![0f64]);
+    }
+}
@@ -0,0 +1,182 @@
+//! Smoothing and differentiation of sampled data with local polynomial fits.
+
+use crate::fit::{fit_chebyshev, vandermonde, FitError};
+use crate::linalg;
+
+/// Returns the Savitzky–Golay filter coefficients for a centred window of odd length `window`.
+///
+/// Convolving unit-spaced samples with the coefficients yields the value (`derivative_order == 0`)
+/// or the requested derivative of the local least-squares polynomial of the given `degree` at the
+/// centre of the window. Divide by `h^derivative_order` for samples spaced `h` apart.
+/// # Example
+/// ```
+/// use polynom::smoothing::savitzky_golay;
+///
+/// let coefficients = savitzky_golay(5, 2, 0).unwrap();
+/// let expected = [-3f64, 12f64, 17f64, 12f64, -3f64];
+/// for (actual, expected) in coefficients.iter().zip(&expected) {
+///     assert!((actual - expected / 35f64).abs() < 1e-12);
+/// }
+/// ```
+pub fn savitzky_golay(
+    window: usize,
+    degree: usize,
+    derivative_order: usize,
+) -> Result<Vec<f64>, FitError> {
+    if window.is_multiple_of(2) {
+        return Err(FitError::InvalidWindow);
+    }
+    if degree >= window {
+        return Err(FitError::InsufficientData);
+    }
+    if derivative_order > degree {
+        return Ok(vec![0f64; window]);
+    }
+
+    let half = (window / 2) as f64;
+    let positions: Vec<f64> = (0..window).map(|i| i as f64 - half).collect();
+    let matrix = vandermonde(&positions, degree);
+    let factorial: f64 = (1..=derivative_order).map(|k| k as f64).product();
+
+    // Column j of the pseudo-inverse is the least-squares response to the unit sample e_j
+    let mut coefficients = Vec::with_capacity(window);
+    for j in 0..window {
+        let mut unit = vec![0f64; window];
+        unit[j] = 1f64;
+        let (solution, _) = linalg::least_squares(&matrix, &unit).ok_or(FitError::Singular)?;
+        coefficients.push(solution[derivative_order] * factorial);
+    }
+
+    Ok(coefficients)
+}
+
+/// Smooths unit-spaced samples by fitting a polynomial of the given `degree` to a moving window of odd length `window`.
+///
+/// Each point is replaced with the value of its local fit. Near the ends of the series the window
+/// is clamped to the data, so every output value comes from a full window of samples.
+/// # Example
+/// ```
+/// use polynom::smoothing::smooth;
+///
+/// let data = vec![1f64, 2f64, 1f64, 2f64, 1f64, 2f64, 1f64];
+/// let smoothed = smooth(&data, 5, 1).unwrap();
+/// assert_eq!(smoothed.len(), data.len());
+/// assert!((smoothed[3] - 1.6).abs() < 1e-12);
+/// ```
+pub fn smooth(data: &[f64], window: usize, degree: usize) -> Result<Vec<f64>, FitError> {
+    if window.is_multiple_of(2) {
+        return Err(FitError::InvalidWindow);
+    }
+    if data.len() < window || degree >= window {
+        return Err(FitError::InsufficientData);
+    }
+
+    let half = window / 2;
+    let positions: Vec<f64> = (0..data.len()).map(|i| i as f64).collect();
+    let mut smoothed = Vec::with_capacity(data.len());
+    let mut current_start = None;
+    let mut local_fit = None;
+
+    for i in 0..data.len() {
+        let start = i.saturating_sub(half).min(data.len() - window);
+        if current_start != Some(start) {
+            let range = start..start + window;
+            local_fit = Some(fit_chebyshev(
+                &positions[range.clone()],
+                &data[range],
+                degree,
+            )?);
+            current_start = Some(start);
+        }
+        if let Some(local_fit) = &local_fit {
+            smoothed.push(local_fit.evaluate_at(i as f64));
+        }
+    }
+
+    Ok(smoothed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_savitzky_golay_smoothing() {
+        let coefficients = savitzky_golay(7, 3, 0).unwrap();
+        let expected = [-2f64, 3f64, 6f64, 7f64, 6f64, 3f64, -2f64];
+
+        for (actual, expected) in coefficients.iter().zip(&expected) {
+            assert!((actual - expected / 21f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_savitzky_golay_first_derivative() {
+        let coefficients = savitzky_golay(5, 2, 1).unwrap();
+        let expected = [-2f64, -1f64, 0f64, 1f64, 2f64];
+
+        for (actual, expected) in coefficients.iter().zip(&expected) {
+            assert!((actual - expected / 10f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_savitzky_golay_second_derivative_of_quadratic() {
+        let coefficients = savitzky_golay(5, 2, 2).unwrap();
+        let samples: Vec<f64> = (-2..=2).map(|x| 3f64 * (x * x) as f64).collect();
+        let second_derivative: f64 = coefficients.iter().zip(&samples).map(|(c, y)| c * y).sum();
+
+        assert!((second_derivative - 6f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_smooth_preserves_polynomials() {
+        let data: Vec<f64> = (0..12).map(|x| (x * x) as f64 - 3f64 * x as f64).collect();
+        let smoothed = smooth(&data, 5, 2).unwrap();
+
+        for (actual, expected) in smoothed.iter().zip(&data) {
+            assert!((actual - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_smooth_matches_savitzky_golay_in_interior() {
+        let data = vec![2f64, 5f64, 3f64, 8f64, 6f64, 9f64, 4f64, 7f64, 1f64];
+        let smoothed = smooth(&data, 5, 2).unwrap();
+        let coefficients = savitzky_golay(5, 2, 0).unwrap();
+
+        for i in 2..data.len() - 2 {
+            let filtered: f64 = coefficients
+                .iter()
+                .zip(&data[i - 2..=i + 2])
+                .map(|(c, y)| c * y)
+                .sum();
+            assert!((smoothed[i] - filtered).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_smooth_errors() {
+        assert_eq!(
+            smooth(&[1f64; 4], 5, 2).unwrap_err(),
+            FitError::InsufficientData
+        );
+        assert_eq!(
+            smooth(&[1f64; 8], 4, 2).unwrap_err(),
+            FitError::InvalidWindow
+        );
+    }
+
+    #[test]
+    fn test_savitzky_golay_errors() {
+        assert_eq!(
+            savitzky_golay(4, 2, 0).unwrap_err(),
+            FitError::InvalidWindow
+        );
+        assert_eq!(
+            savitzky_golay(3, 3, 0).unwrap_err(),
+            FitError::InsufficientData
+        );
+        assert_eq!(savitzky_golay(5, 1, 2).unwrap(), vec![0f64; 5]);
+    }
+}
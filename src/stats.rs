@@ -0,0 +1,157 @@
+//! Statistical distribution helpers used to build confidence intervals.
+
+/// Natural logarithm of the gamma function (Lanczos approximation, g = 7).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1f64 - x);
+    }
+
+    let x = x - 1f64;
+    let mut sum = COEFFICIENTS[0];
+    for (i, coeff) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coeff / (x + i as f64);
+    }
+    let t = x + 7.5;
+
+    0.5 * (2f64 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Continued fraction for the regularized incomplete beta function (modified Lentz).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1f64;
+    let mut d = 1f64 - (a + b) * x / (a + 1f64);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1f64 / d;
+    let mut result = d;
+
+    for m in 1..500 {
+        let m = m as f64;
+        let numerator = m * (b - m) * x / ((a + 2f64 * m - 1f64) * (a + 2f64 * m));
+        d = 1f64 + numerator * d;
+        d = if d.abs() < TINY {
+            1f64 / TINY
+        } else {
+            1f64 / d
+        };
+        c = 1f64 + numerator / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        result *= d * c;
+
+        let numerator = -(a + m) * (a + b + m) * x / ((a + 2f64 * m) * (a + 2f64 * m + 1f64));
+        d = 1f64 + numerator * d;
+        d = if d.abs() < TINY {
+            1f64 / TINY
+        } else {
+            1f64 / d
+        };
+        c = 1f64 + numerator / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        let delta = d * c;
+        result *= delta;
+
+        if (delta - 1f64).abs() < 1e-15 {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`.
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0f64 {
+        return 0f64;
+    }
+    if x >= 1f64 {
+        return 1f64;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1f64 - x).ln()).exp();
+    if x < (a + 1f64) / (a + b + 2f64) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1f64 - front * beta_continued_fraction(b, a, 1f64 - x) / b
+    }
+}
+
+/// Cumulative distribution function of Student's t distribution.
+pub(crate) fn student_t_cdf(t: f64, degrees_of_freedom: f64) -> f64 {
+    let x = degrees_of_freedom / (degrees_of_freedom + t * t);
+    let tail = 0.5 * regularized_incomplete_beta(degrees_of_freedom / 2f64, 0.5, x);
+    if t > 0f64 {
+        1f64 - tail
+    } else {
+        tail
+    }
+}
+
+/// Inverse of [`student_t_cdf`], found by bisection.
+pub(crate) fn student_t_quantile(p: f64, degrees_of_freedom: f64) -> f64 {
+    if p == 0.5 {
+        return 0f64;
+    }
+    if p < 0.5 {
+        return -student_t_quantile(1f64 - p, degrees_of_freedom);
+    }
+
+    let mut low = 0f64;
+    let mut high = 1f64;
+    while student_t_cdf(high, degrees_of_freedom) < p {
+        low = high;
+        high *= 2f64;
+    }
+
+    for _ in 0..200 {
+        let mid = 0.5 * (low + high);
+        if student_t_cdf(mid, degrees_of_freedom) < p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+        if high - low <= f64::EPSILON * high {
+            break;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_gamma() {
+        assert!((ln_gamma(5f64) - 24f64.ln()).abs() < 1e-12);
+        assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_student_t_quantile() {
+        assert!((student_t_quantile(0.975, 10f64) - 2.228_138_851_986_274).abs() < 1e-9);
+        assert!((student_t_quantile(0.95, 1f64) - 6.313_751_514_675_043).abs() < 1e-9);
+        assert!((student_t_quantile(0.025, 10f64) + 2.228_138_851_986_274).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,122 @@
+//! A one-call summary of a polynomial's behaviour over an interval.
+
+use crate::polynomial::Polynomial;
+
+/// The extremes, mean value and zeros of a polynomial over a closed interval, as returned
+/// by [`Polynomial::summarize_on`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeSummary {
+    /// The smallest value on the interval.
+    pub min: f64,
+    /// A point where `min` is attained.
+    pub argmin: f64,
+    /// The largest value on the interval.
+    pub max: f64,
+    /// A point where `max` is attained.
+    pub argmax: f64,
+    /// The mean value, the integral over the interval divided by its length; the value at
+    /// the point for an interval of zero length.
+    pub mean: f64,
+    /// The distinct real roots in the interval in increasing order, including those where
+    /// the graph only touches zero.
+    pub zeros: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Summarizes the polynomial over the closed interval between `a` and `b`.
+    ///
+    /// The extremes are exact up to rounding: they are attained at an endpoint or at a
+    /// critical point, so only those are evaluated. The mean comes from the exact integral.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // x^2 - 1 on [-1, 2]
+    /// let polynomial = Polynomial::new(vec![-1f64, 0f64, 1f64], 'x');
+    /// let summary = polynomial.summarize_on(-1f64, 2f64);
+    /// assert_eq!((summary.min, summary.argmin), (-1f64, 0f64));
+    /// assert_eq!((summary.max, summary.argmax), (3f64, 2f64));
+    /// assert!(summary.mean.abs() < 1e-15);
+    /// assert_eq!(summary.zeros, vec![-1f64, 1f64]);
+    /// ```
+    pub fn summarize_on(&self, a: f64, b: f64) -> RangeSummary {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        let candidates: Vec<(f64, f64)> = vec![low, high]
+            .into_iter()
+            .chain(
+                self.derivative()
+                    .real_roots()
+                    .into_iter()
+                    .filter(|&x| low < x && x < high),
+            )
+            .map(|x| (self.evaluate_at(x), x))
+            .collect();
+        let (min, argmin) =
+            candidates
+                .iter()
+                .cloned()
+                .fold((f64::INFINITY, low), |best, candidate| {
+                    if candidate.0 < best.0 {
+                        candidate
+                    } else {
+                        best
+                    }
+                });
+        let (max, argmax) =
+            candidates
+                .iter()
+                .cloned()
+                .fold((f64::NEG_INFINITY, low), |best, candidate| {
+                    if candidate.0 > best.0 {
+                        candidate
+                    } else {
+                        best
+                    }
+                });
+        let mean = if high > low {
+            self.definite_integral((low, high)) / (high - low)
+        } else {
+            self.evaluate_at(low)
+        };
+        let zeros = self
+            .real_roots()
+            .into_iter()
+            .filter(|&x| low <= x && x <= high)
+            .collect();
+
+        RangeSummary {
+            min,
+            argmin,
+            max,
+            argmax,
+            mean,
+            zeros,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_on() {
+        // (x - 1)^2 (x + 2) touches zero at 1 and crosses at -2
+        let polynomial = Polynomial::new(vec![2f64, -3f64, 0f64, 1f64], 'x');
+        let summary = polynomial.summarize_on(3f64, -3f64);
+        assert_eq!((summary.min, summary.argmin), (-16f64, -3f64));
+        assert_eq!((summary.max, summary.argmax), (20f64, 3f64));
+        assert!((summary.mean - 2f64).abs() < 1e-14);
+        assert_eq!(summary.zeros.len(), 2);
+        assert!((summary.zeros[0] + 2f64).abs() < 1e-12);
+        assert!((summary.zeros[1] - 1f64).abs() < 1e-6);
+
+        // The local maximum at -1 is interior on [-1.5, 0.5]
+        let summary = polynomial.summarize_on(-1.5, 0.5);
+        assert_eq!((summary.max, summary.argmax), (4f64, -1f64));
+        assert!(summary.zeros.is_empty());
+
+        let point = polynomial.summarize_on(2f64, 2f64);
+        assert_eq!((point.min, point.max, point.mean), (4f64, 4f64, 4f64));
+    }
+}
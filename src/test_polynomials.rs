@@ -0,0 +1,171 @@
+//! Polynomials that are known to be numerically difficult, for accuracy tests and benchmarks.
+
+use crate::polynomial::Polynomial;
+use crate::random::RandomSource;
+
+fn from_roots(roots: &[f64]) -> Polynomial {
+    let mut coefficients = vec![1f64];
+    for root in roots {
+        let mut next = vec![0f64; coefficients.len() + 1];
+        for (i, coeff) in coefficients.iter().enumerate() {
+            next[i] -= coeff * root;
+            next[i + 1] += coeff;
+        }
+        coefficients = next;
+    }
+
+    Polynomial::new(coefficients, 'x')
+}
+
+/// Returns Wilkinson's polynomial `(x - 1)(x - 2)...(x - n)`, whose roots are famously sensitive to coefficient perturbations.
+/// # Example
+/// ```
+/// use polynom::test_polynomials::wilkinson;
+///
+/// let polynomial = wilkinson(3);
+/// assert_eq!(polynomial.coefficients, vec![-6f64, 11f64, -6f64, 1f64]);
+/// ```
+pub fn wilkinson(n: usize) -> Polynomial {
+    let roots: Vec<f64> = (1..=n).map(|i| i as f64).collect();
+    from_roots(&roots)
+}
+
+/// Returns the Chebyshev polynomial of the first kind `T_n`, whose coefficients grow like `2^(n-1)`.
+/// # Example
+/// ```
+/// use polynom::test_polynomials::chebyshev;
+///
+/// let polynomial = chebyshev(3);
+/// assert_eq!(polynomial.coefficients, vec![0f64, -3f64, 0f64, 4f64]);
+/// ```
+pub fn chebyshev(n: usize) -> Polynomial {
+    let mut previous = vec![1f64];
+    let mut current = vec![0f64, 1f64];
+    if n == 0 {
+        return Polynomial::new(previous, 'x');
+    }
+
+    for _ in 1..n {
+        let mut next = vec![0f64; current.len() + 1];
+        for (i, coeff) in current.iter().enumerate() {
+            next[i + 1] += 2f64 * coeff;
+        }
+        for (i, coeff) in previous.iter().enumerate() {
+            next[i] -= coeff;
+        }
+        previous = current;
+        current = next;
+    }
+
+    Polynomial::new(current, 'x')
+}
+
+/// Returns `T_n` with every nonzero coefficient scaled by `1 ± relative_perturbation`, alternating in sign by degree.
+/// # Example
+/// ```
+/// use polynom::test_polynomials::perturbed_chebyshev;
+///
+/// let polynomial = perturbed_chebyshev(2, 0.5);
+/// assert_eq!(polynomial.coefficients, vec![-1.5, 0f64, 3f64]);
+/// ```
+pub fn perturbed_chebyshev(n: usize, relative_perturbation: f64) -> Polynomial {
+    let coefficients = chebyshev(n)
+        .coefficients
+        .iter()
+        .enumerate()
+        .map(|(degree, coeff)| {
+            let sign = if degree % 2 == 0 { 1f64 } else { -1f64 };
+            coeff * (1f64 + sign * relative_perturbation)
+        })
+        .collect();
+
+    Polynomial::new(coefficients, 'x')
+}
+
+/// Returns `(x - root)^multiplicity`, a single root of high multiplicity.
+/// # Example
+/// ```
+/// use polynom::test_polynomials::multiple_root;
+///
+/// let polynomial = multiple_root(1f64, 3);
+/// assert_eq!(polynomial.coefficients, vec![-1f64, 3f64, -3f64, 1f64]);
+/// ```
+pub fn multiple_root(root: f64, multiplicity: usize) -> Polynomial {
+    from_roots(&vec![root; multiplicity])
+}
+
+/// Returns a polynomial with `count` simple roots spaced `spacing` apart around `center`.
+/// # Example
+/// ```
+/// use polynom::test_polynomials::clustered_roots;
+///
+/// let polynomial = clustered_roots(1f64, 0.5, 3);
+/// assert_eq!(polynomial.evaluate_at(0.5), 0f64);
+/// assert_eq!(polynomial.evaluate_at(1.5), 0f64);
+/// ```
+pub fn clustered_roots(center: f64, spacing: f64, count: usize) -> Polynomial {
+    let middle = (count as f64 - 1f64) / 2f64;
+    let roots: Vec<f64> = (0..count)
+        .map(|i| center + (i as f64 - middle) * spacing)
+        .collect();
+    from_roots(&roots)
+}
+
+/// Returns a polynomial of exactly `degree` with coefficients drawn uniformly from `[-1, 1)`.
+///
+/// The same `rng` state always produces the same polynomial.
+/// # Example
+/// ```
+/// use polynom::random::SplitMix64;
+/// use polynom::test_polynomials::random;
+///
+/// let a = random(5, &mut SplitMix64::new(1));
+/// let b = random(5, &mut SplitMix64::new(1));
+/// assert_eq!(a.degree(), 5);
+/// assert_eq!(a.coefficients, b.coefficients);
+/// ```
+pub fn random<R: RandomSource + ?Sized>(degree: usize, rng: &mut R) -> Polynomial {
+    let mut coefficients: Vec<f64> = (0..=degree)
+        .map(|_| rng.next_in_range(-1f64, 1f64))
+        .collect();
+    while coefficients[degree] == 0f64 {
+        coefficients[degree] = rng.next_in_range(-1f64, 1f64);
+    }
+
+    Polynomial::new(coefficients, 'x')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wilkinson() {
+        let polynomial = wilkinson(20);
+
+        assert_eq!(polynomial.degree(), 20);
+        assert_eq!(polynomial.coefficients[20], 1f64);
+        assert_eq!(polynomial.coefficients[19], -210f64);
+        assert_eq!(polynomial.coefficients[0], 2_432_902_008_176_640_000f64);
+    }
+
+    #[test]
+    fn test_chebyshev_values() {
+        let polynomial = chebyshev(10);
+
+        for &t in &[-1f64, -0.3, 0f64, 0.7, 1f64] {
+            let expected = (10f64 * t.acos()).cos();
+            assert!((polynomial.evaluate_at(t) - expected).abs() < 1e-12);
+        }
+        assert_eq!(chebyshev(0).coefficients, vec![1f64]);
+    }
+
+    #[test]
+    fn test_multiple_root() {
+        let polynomial = multiple_root(2f64, 5);
+
+        assert_eq!(polynomial.degree(), 5);
+        assert_eq!(polynomial.evaluate_at(2f64), 0f64);
+        assert_eq!(polynomial.evaluate_at(3f64), 1f64);
+    }
+}
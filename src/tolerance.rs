@@ -0,0 +1,371 @@
+//! Configurable tolerances for deciding when floating-point coefficients are "zero" or "equal".
+//!
+//! A [`Tolerance`] combines an absolute and a relative epsilon with a [`ZeroSnapping`] policy.
+//! Routines that need to treat nearly-zero coefficients as zero take one explicitly, so the
+//! same policy can be applied consistently to arithmetic, comparison and degree computations.
+//!
+//! Everything else uses [`Tolerance::default()`], which only treats exact zeros as
+//! negligible: `Polynomial::degree` and `Polynomial::normalize` go through it, and since
+//! every constructor normalizes, so do the operators, `multiply`, `div_rem` and `gcd`.
+
+use std::fmt;
+
+use crate::polynomial::Polynomial;
+
+/// Which coefficients are replaced by exact zeros when a [`Tolerance`] is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroSnapping {
+    /// Coefficients are never changed; the tolerance is only used for comparisons.
+    Never,
+    /// Negligible leading coefficients are dropped, lowering the degree.
+    Leading,
+    /// Every negligible coefficient is set to zero, and negligible leading ones are dropped.
+    All,
+}
+
+/// Absolute and relative epsilons together with a [`ZeroSnapping`] policy.
+///
+/// A value `v` is negligible relative to a scale `s` when `|v| <= absolute + relative * s`.
+/// For polynomials the scale is the largest coefficient magnitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Threshold below which a value is negligible regardless of scale.
+    pub absolute: f64,
+    /// Threshold, as a fraction of the scale, below which a value is negligible.
+    pub relative: f64,
+    /// Which coefficients are snapped to zero.
+    pub snapping: ZeroSnapping,
+}
+
+impl Tolerance {
+    /// Only exact zeros are negligible; this matches the behaviour of the plain operations.
+    pub const EXACT: Tolerance = Tolerance {
+        absolute: 0f64,
+        relative: 0f64,
+        snapping: ZeroSnapping::Leading,
+    };
+
+    /// Returns a tolerance with the given epsilons that drops negligible leading coefficients.
+    pub fn new(absolute: f64, relative: f64) -> Tolerance {
+        Tolerance {
+            absolute,
+            relative,
+            snapping: ZeroSnapping::Leading,
+        }
+    }
+
+    /// Returns a relative tolerance of `ulps` units in the last place of the scale.
+    pub fn ulps(ulps: f64) -> Tolerance {
+        Tolerance::new(0f64, ulps * f64::EPSILON)
+    }
+
+    /// Returns `self` with the snapping policy replaced by `snapping`.
+    pub fn with_snapping(self, snapping: ZeroSnapping) -> Tolerance {
+        Tolerance { snapping, ..self }
+    }
+
+    /// Returns `true` if `value` is negligible relative to `scale`.
+    /// # Example
+    /// ```
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let tolerance = Tolerance::new(1e-12, 1e-9);
+    /// assert!(tolerance.is_negligible(1e-10, 1f64));
+    /// assert!(!tolerance.is_negligible(1e-10, 1e-3));
+    /// ```
+    pub fn is_negligible(&self, value: f64, scale: f64) -> bool {
+        value.abs() <= self.absolute + self.relative * scale.abs()
+    }
+
+    /// Returns `true` if `a` and `b` differ by a negligible amount relative to the larger of them.
+    pub fn approx_eq(&self, a: f64, b: f64) -> bool {
+        a == b || self.is_negligible(a - b, a.abs().max(b.abs()))
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Tolerance {
+        Tolerance::EXACT
+    }
+}
+
+/// The error returned by [`Polynomial::divide_exact`] when the divisor is not a factor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotAFactor {
+    /// The remainder of the division, which was not negligible.
+    pub remainder: Polynomial,
+}
+
+impl fmt::Display for NotAFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "divisor is not a factor: remainder has degree {}",
+            self.remainder.degree()
+        )
+    }
+}
+
+impl std::error::Error for NotAFactor {}
+
+impl Polynomial {
+    /// Returns the largest coefficient magnitude, the scale used by [`Tolerance`].
+    fn coefficient_scale(&self) -> f64 {
+        self.coefficients
+            .iter()
+            .fold(0f64, |largest, coeff| largest.max(coeff.abs()))
+    }
+
+    /// Drops trailing coefficients that are negligible under `tolerance`, leaving `[0]` if
+    /// none remain.
+    pub(crate) fn truncate_negligible(&mut self, tolerance: &Tolerance) {
+        let scale = self.coefficient_scale();
+        self.truncate_trailing(|coeff| tolerance.is_negligible(coeff, scale));
+    }
+
+    /// Applies the snapping policy of `tolerance` to the coefficients
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::{Tolerance, ZeroSnapping};
+    ///
+    /// let polynomial = Polynomial::new(vec![1e-17, 2f64, 1f64, 1e-16], 'x');
+    /// let tolerance = Tolerance::ulps(4f64);
+    /// assert_eq!(polynomial.snap(&tolerance).coefficients, vec![1e-17, 2f64, 1f64]);
+    ///
+    /// let tolerance = tolerance.with_snapping(ZeroSnapping::All);
+    /// assert_eq!(polynomial.snap(&tolerance).coefficients, vec![0f64, 2f64, 1f64]);
+    /// ```
+    pub fn snap(&self, tolerance: &Tolerance) -> Polynomial {
+        let scale = self.coefficient_scale();
+        let mut snapped = self.clone();
+        match tolerance.snapping {
+            ZeroSnapping::Never => return snapped,
+            ZeroSnapping::Leading => {}
+            ZeroSnapping::All => {
+                for coeff in snapped.coefficients.iter_mut() {
+                    if tolerance.is_negligible(*coeff, scale) {
+                        *coeff = 0f64;
+                    }
+                }
+            }
+        }
+        snapped.truncate_negligible(tolerance);
+        snapped
+    }
+
+    /// Returns the degree, ignoring leading coefficients that are negligible under `tolerance`
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let polynomial = Polynomial::new(vec![1f64, 2f64, 1e-20], 'x');
+    /// assert_eq!(polynomial.degree(), 2);
+    /// assert_eq!(polynomial.degree_with(&Tolerance::ulps(1f64)), 1);
+    /// ```
+    pub fn degree_with(&self, tolerance: &Tolerance) -> isize {
+        let scale = self.coefficient_scale();
+        self.coefficients
+            .iter()
+            .rposition(|&coeff| !tolerance.is_negligible(coeff, scale))
+            .map_or(-1, |index| index as isize)
+    }
+
+    /// Returns `true` if every coefficient of `self` and `other` agrees within `tolerance`,
+    /// measured relative to the larger coefficient scale of the two
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let a = Polynomial::new(vec![0.1 + 0.2, 1f64], 'x');
+    /// let b = Polynomial::new(vec![0.3, 1f64], 'x');
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b, &Tolerance::ulps(4f64)));
+    /// ```
+    pub fn approx_eq(&self, other: &Polynomial, tolerance: &Tolerance) -> bool {
+        let scale = self.coefficient_scale().max(other.coefficient_scale());
+        let length = self.coefficients.len().max(other.coefficients.len());
+        (0..length).all(|i| {
+            let a = self.coefficients.get(i).copied().unwrap_or(0f64);
+            let b = other.coefficients.get(i).copied().unwrap_or(0f64);
+            tolerance.is_negligible(a - b, scale)
+        })
+    }
+
+    /// Adds `other` and snaps the result with `tolerance`, so cancellation leaves no residue
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// let a = Polynomial::new(vec![1f64, 0.1 + 0.2], 'x');
+    /// let b = Polynomial::new(vec![1f64, -0.3], 'x');
+    /// assert_eq!(a.add_with(&b, &Tolerance::ulps(4f64)).coefficients, vec![2f64]);
+    /// ```
+    pub fn add_with(&self, other: &Polynomial, tolerance: &Tolerance) -> Polynomial {
+        let scale = self.coefficient_scale().max(other.coefficient_scale());
+        (self.clone() + other.clone()).snap_to_scale(tolerance, scale)
+    }
+
+    /// Subtracts `other` and snaps the result with `tolerance`
+    pub fn sub_with(&self, other: &Polynomial, tolerance: &Tolerance) -> Polynomial {
+        let scale = self.coefficient_scale().max(other.coefficient_scale());
+        (self.clone() - other.clone()).snap_to_scale(tolerance, scale)
+    }
+
+    /// Multiplies by `other` and snaps the result with `tolerance`
+    pub fn multiply_with(&self, other: &Polynomial, tolerance: &Tolerance) -> Polynomial {
+        let scale = self.coefficient_scale() * other.coefficient_scale();
+        self.multiply(other.clone()).snap_to_scale(tolerance, scale)
+    }
+
+    /// Divides by `factor`, returning the quotient only if the remainder is negligible
+    /// under `tolerance` relative to the scale of `self`.
+    ///
+    /// Use this when `factor` is known to divide `self`, such as a factor built from one of
+    /// its roots, so that a wrong factor fails loudly instead of its remainder being
+    /// silently dropped.
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    /// use polynom::tolerance::Tolerance;
+    ///
+    /// // x^2 - 3x + 2 = (x - 1)(x - 2)
+    /// let polynomial = Polynomial::new(vec![2f64, -3f64, 1f64], 'x');
+    /// let quotient = polynomial
+    ///     .divide_exact(&Polynomial::new(vec![-1f64, 1f64], 'x'), &Tolerance::EXACT)
+    ///     .unwrap();
+    /// assert_eq!(quotient.coefficients, vec![-2f64, 1f64]);
+    ///
+    /// let error = polynomial
+    ///     .divide_exact(&Polynomial::new(vec![-3f64, 1f64], 'x'), &Tolerance::ulps(16f64))
+    ///     .unwrap_err();
+    /// assert_eq!(error.remainder.coefficients, vec![2f64]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `factor` is the zero polynomial.
+    pub fn divide_exact(
+        &self,
+        factor: &Polynomial,
+        tolerance: &Tolerance,
+    ) -> Result<Polynomial, NotAFactor> {
+        let (quotient, remainder) = self.div_rem(factor);
+        let scale = self.coefficient_scale();
+        if remainder
+            .coefficients
+            .iter()
+            .all(|&coeff| tolerance.is_negligible(coeff, scale))
+        {
+            Ok(quotient)
+        } else {
+            Err(NotAFactor { remainder })
+        }
+    }
+
+    /// Like [`snap`](Polynomial::snap), but measures negligibility against `scale` (the scale
+    /// of the operands) rather than against the result, whose own scale may have cancelled.
+    fn snap_to_scale(&self, tolerance: &Tolerance, scale: f64) -> Polynomial {
+        let own_scale = self.coefficient_scale();
+        let adjusted = Tolerance {
+            absolute: tolerance.absolute + tolerance.relative * (scale - own_scale).max(0f64),
+            ..*tolerance
+        };
+        self.snap(&adjusted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_tolerance_matches_plain_operations() {
+        let a = Polynomial::new(vec![1f64, 1e-300, 3f64], 'x');
+        let b = Polynomial::new(vec![2f64, 0f64, -3f64], 'x');
+        let tolerance = Tolerance::default();
+
+        assert_eq!(a.add_with(&b, &tolerance), a.clone() + b.clone());
+        assert_eq!(a.sub_with(&b, &tolerance), a.clone() - b.clone());
+        assert_eq!(a.multiply_with(&b, &tolerance), a.multiply(b.clone()));
+        assert_eq!(a.degree_with(&tolerance), a.degree());
+        assert!(!a.approx_eq(&b, &tolerance));
+        assert!(a.approx_eq(&a, &tolerance));
+    }
+
+    #[test]
+    fn test_degree_and_normalize_follow_the_default_tolerance() {
+        let mut polynomial = Polynomial::new(vec![1f64, 2f64], 'x');
+        polynomial.coefficients.extend(vec![0f64, -0f64]);
+        let tolerance = Tolerance::default();
+
+        assert_eq!(polynomial.degree(), polynomial.degree_with(&tolerance));
+        assert_eq!(polynomial.degree(), 1);
+        let snapped = polynomial.snap(&tolerance);
+        polynomial.normalize();
+        assert_eq!(polynomial, snapped);
+
+        polynomial.coefficients.clear();
+        assert_eq!(polynomial.degree(), -1);
+    }
+
+    #[test]
+    fn test_cancellation_is_snapped_against_operand_scale() {
+        // (x + 0.1)(x - 0.1) = x^2 - 0.01, but the x coefficient picks up rounding error
+        let a = Polynomial::new(vec![0.1, 1f64], 'x');
+        let b = Polynomial::new(vec![-0.1 + 1e-17, 1f64], 'x');
+        let tolerance = Tolerance::ulps(8f64).with_snapping(ZeroSnapping::All);
+        let product = a.multiply_with(&b, &tolerance);
+
+        assert_eq!(product.coefficients[1], 0f64);
+        assert_eq!(product.degree(), 2);
+    }
+
+    #[test]
+    fn test_degree_with_zero_polynomial() {
+        let polynomial = Polynomial::new(vec![1e-20, -1e-20], 'x');
+
+        assert_eq!(polynomial.degree_with(&Tolerance::new(1e-15, 0f64)), -1);
+        assert_eq!(
+            polynomial.snap(&Tolerance::new(1e-15, 0f64)).coefficients,
+            vec![0f64]
+        );
+    }
+
+    #[test]
+    fn test_never_snapping_leaves_coefficients() {
+        let polynomial = Polynomial::new(vec![1f64, 1e-20], 'x');
+        let tolerance = Tolerance::ulps(1f64).with_snapping(ZeroSnapping::Never);
+
+        assert_eq!(polynomial.snap(&tolerance), polynomial);
+        assert_eq!(polynomial.degree_with(&tolerance), 0);
+    }
+
+    #[test]
+    fn test_divide_exact_allows_rounding_within_tolerance() {
+        // (x - 0.1)(x - 0.7) with coefficients that do not round-trip exactly
+        let polynomial = Polynomial::new(vec![0.1 * 0.7, -(0.1 + 0.7), 1f64], 'x');
+        let factor = Polynomial::new(vec![-0.1, 1f64], 'x');
+        let quotient = polynomial
+            .divide_exact(&factor, &Tolerance::ulps(16f64))
+            .unwrap();
+        assert!(quotient.approx_eq(
+            &Polynomial::new(vec![-0.7, 1f64], 'x'),
+            &Tolerance::ulps(16f64)
+        ));
+
+        let error = polynomial
+            .divide_exact(
+                &Polynomial::new(vec![-0.2, 1f64], 'x'),
+                &Tolerance::ulps(16f64),
+            )
+            .unwrap_err();
+        assert!((error.remainder.coefficients[0] + 0.05).abs() < 1e-15);
+        assert_eq!(
+            error.to_string(),
+            "divisor is not a factor: remainder has degree 0"
+        );
+    }
+}
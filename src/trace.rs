@@ -0,0 +1,85 @@
+//! Internal logging hooks that forward to `tracing` when the `tracing` feature is enabled.
+//!
+//! Without the feature the macros expand to nothing, so instrumented code pays no cost.
+
+/// Emits a `tracing` debug event.
+macro_rules! debug_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+/// Emits a `tracing` trace-level event, for per-iteration detail.
+macro_rules! trace_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*);
+    };
+}
+
+/// Enters a `tracing` debug span for the rest of the enclosing block.
+macro_rules! debug_span {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($arg)*).entered();
+    };
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::polynomial::Polynomial;
+    use crate::roots::RootMethod;
+
+    #[derive(Default)]
+    struct Counter {
+        spans: AtomicUsize,
+        events: AtomicUsize,
+    }
+
+    struct CountingSubscriber(Arc<Counter>);
+
+    impl Subscriber for CountingSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            let count = self.0.spans.fetch_add(1, Ordering::SeqCst);
+            Id::from_u64(count as u64 + 1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {
+            self.0.events.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_root_finding_emits_spans_and_events() {
+        let counter = Arc::new(Counter::default());
+        let subscriber = CountingSubscriber(Arc::clone(&counter));
+        let polynomial = Polynomial::new(vec![-2f64, 0f64, 1f64], 'x');
+
+        tracing::subscriber::with_default(subscriber, || {
+            polynomial.find_root(1f64, RootMethod::Newton).unwrap();
+            polynomial.real_roots();
+        });
+
+        assert!(counter.spans.load(Ordering::SeqCst) >= 2);
+        assert!(counter.events.load(Ordering::SeqCst) >= 2);
+    }
+}
@@ -0,0 +1,165 @@
+//! Unit-aware polynomials backed by [`uom`], behind the `uom` feature.
+//!
+//! A [`UnitPolynomial<X, Y>`] maps a quantity of type `X` to a quantity of type `Y`, such as
+//! a position polynomial in time. Evaluating it at the wrong kind of quantity, or using the
+//! result as the wrong kind, is a compile error:
+//!
+//! ```compile_fail
+//! use polynom::polynomial::Polynomial;
+//! use polynom::units::UnitPolynomial;
+//! use uom::si::f64::{Length, Time};
+//! use uom::si::length::meter;
+//!
+//! let position: UnitPolynomial<Time, Length> =
+//!     UnitPolynomial::new(Polynomial::new(vec![0f64, 1f64], 't'));
+//! position.evaluate_at(Length::new::<meter>(1f64));
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::Div;
+
+use uom::si::{Dimension, Quantity, SI};
+
+use crate::polynomial::Polynomial;
+
+/// A quantity with an `f64` value in SI base units, such as [`uom::si::f64::Length`].
+pub trait SiQuantity: Sized {
+    /// Returns the quantity whose value in SI base units is `value`.
+    fn from_si(value: f64) -> Self;
+
+    /// Returns the value in SI base units.
+    fn to_si(&self) -> f64;
+}
+
+impl<D> SiQuantity for Quantity<D, SI<f64>, f64>
+where
+    D: Dimension + ?Sized,
+{
+    fn from_si(value: f64) -> Self {
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value,
+        }
+    }
+
+    fn to_si(&self) -> f64 {
+        self.value
+    }
+}
+
+/// The quantity type of the derivative of a `Y` with respect to an `X`, such as velocity
+/// for a length with respect to time.
+pub type Rate<Y, X> = <Y as Div<X>>::Output;
+
+/// A polynomial from quantities of type `X` to quantities of type `Y`.
+///
+/// The coefficient of `x^k` has the units of `Y / X^k` and is stored in SI base units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitPolynomial<X, Y> {
+    /// The polynomial in SI base units, from the value of `X` to the value of `Y`.
+    pub poly: Polynomial,
+    units: PhantomData<fn(X) -> Y>,
+}
+
+impl<X: SiQuantity, Y: SiQuantity> UnitPolynomial<X, Y> {
+    /// Wraps `poly`, whose coefficient of `x^k` is taken in the SI base units of `Y / X^k`
+    pub fn new(poly: Polynomial) -> UnitPolynomial<X, Y> {
+        UnitPolynomial {
+            poly,
+            units: PhantomData,
+        }
+    }
+
+    /// Returns the constant polynomial `c0`
+    pub fn constant(c0: Y) -> UnitPolynomial<X, Y> {
+        UnitPolynomial::new(Polynomial::new(vec![c0.to_si()], 'x'))
+    }
+
+    /// Returns `c0 + c1 x`, with the units of each coefficient checked at compile time
+    pub fn linear(c0: Y, c1: Rate<Y, X>) -> UnitPolynomial<X, Y>
+    where
+        Y: Div<X>,
+        Rate<Y, X>: SiQuantity,
+    {
+        UnitPolynomial::new(Polynomial::new(vec![c0.to_si(), c1.to_si()], 'x'))
+    }
+
+    /// Returns `c0 + c1 x + c2 x^2`, with the units of each coefficient checked at compile time
+    /// # Example
+    /// ```
+    /// use polynom::units::UnitPolynomial;
+    /// use uom::si::acceleration::meter_per_second_squared;
+    /// use uom::si::f64::{Acceleration, Length, Time, Velocity};
+    /// use uom::si::length::meter;
+    /// use uom::si::time::second;
+    /// use uom::si::velocity::meter_per_second;
+    ///
+    /// // x(t) = 1 m + 2 m/s t - 4.9 m/s^2 t^2
+    /// let position: UnitPolynomial<Time, Length> = UnitPolynomial::quadratic(
+    ///     Length::new::<meter>(1f64),
+    ///     Velocity::new::<meter_per_second>(2f64),
+    ///     Acceleration::new::<meter_per_second_squared>(-4.9),
+    /// );
+    /// let height = position.evaluate_at(Time::new::<second>(1f64));
+    /// assert!((height.get::<meter>() + 1.9).abs() < 1e-12);
+    ///
+    /// let velocity = position.derivative().evaluate_at(Time::new::<second>(1f64));
+    /// assert!((velocity.get::<meter_per_second>() + 7.8).abs() < 1e-12);
+    /// ```
+    pub fn quadratic(c0: Y, c1: Rate<Y, X>, c2: Rate<Rate<Y, X>, X>) -> UnitPolynomial<X, Y>
+    where
+        Y: Div<X>,
+        Rate<Y, X>: SiQuantity + Div<X>,
+        Rate<Rate<Y, X>, X>: SiQuantity,
+    {
+        UnitPolynomial::new(Polynomial::new(
+            vec![c0.to_si(), c1.to_si(), c2.to_si()],
+            'x',
+        ))
+    }
+
+    /// Evaluates the polynomial at `x`
+    pub fn evaluate_at(&self, x: X) -> Y {
+        Y::from_si(self.poly.evaluate_at(x.to_si()))
+    }
+
+    /// Returns the derivative, whose values are rates of `Y` per unit of `X`
+    pub fn derivative(&self) -> UnitPolynomial<X, Rate<Y, X>>
+    where
+        Y: Div<X>,
+        Rate<Y, X>: SiQuantity,
+    {
+        UnitPolynomial::new(self.poly.derivative())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::f64::{Length, Time, Velocity};
+    use uom::si::length::{kilometer, meter};
+    use uom::si::time::{hour, second};
+    use uom::si::velocity::kilometer_per_hour;
+
+    #[test]
+    fn test_coefficients_are_converted_to_base_units() {
+        let position: UnitPolynomial<Time, Length> = UnitPolynomial::linear(
+            Length::new::<kilometer>(1f64),
+            Velocity::new::<kilometer_per_hour>(36f64),
+        );
+        assert_eq!(position.poly.coefficients, vec![1000f64, 10f64]);
+
+        let after = position.evaluate_at(Time::new::<hour>(0.5));
+        assert!((after.get::<kilometer>() - 19f64).abs() < 1e-9);
+        assert_eq!(
+            position.derivative().evaluate_at(Time::new::<second>(3f64)),
+            Velocity::new::<kilometer_per_hour>(36f64)
+        );
+        assert_eq!(
+            UnitPolynomial::<Time, Length>::constant(Length::new::<meter>(2f64))
+                .evaluate_at(Time::new::<second>(9f64)),
+            Length::new::<meter>(2f64)
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Normalizing polynomials to a plotting window.
+
+use crate::polynomial::Polynomial;
+
+/// The affine change of coordinates between a data window and the unit display square.
+///
+/// Data point `(x, y)` in `x_window × y_window` maps to display point `(u, v)` in
+/// `[0, 1] × [0, 1]`, with `u = (x - x0) / (x1 - x0)` and `v = (y - y0) / (y1 - y0)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowTransform {
+    /// The data interval `(x0, x1)` mapped onto `u` in `[0, 1]`.
+    pub x_window: (f64, f64),
+    /// The data interval `(y0, y1)` mapped onto `v` in `[0, 1]`.
+    pub y_window: (f64, f64),
+}
+
+impl WindowTransform {
+    /// Returns the transform for the given data windows.
+    ///
+    /// # Panics
+    /// Panics if either window has zero width.
+    pub fn new(x_window: (f64, f64), y_window: (f64, f64)) -> WindowTransform {
+        assert!(
+            x_window.0 != x_window.1 && y_window.0 != y_window.1,
+            "windows must have nonzero width"
+        );
+        WindowTransform { x_window, y_window }
+    }
+
+    /// Maps a data point to display coordinates.
+    pub fn to_display(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            (x - self.x_window.0) / (self.x_window.1 - self.x_window.0),
+            (y - self.y_window.0) / (self.y_window.1 - self.y_window.0),
+        )
+    }
+
+    /// Maps a display point back to data coordinates, inverting [`to_display`](WindowTransform::to_display).
+    pub fn to_data(&self, u: f64, v: f64) -> (f64, f64) {
+        (
+            self.x_window.0 + u * (self.x_window.1 - self.x_window.0),
+            self.y_window.0 + v * (self.y_window.1 - self.y_window.0),
+        )
+    }
+}
+
+impl Polynomial {
+    /// Returns the display polynomial `q` with `q(u) = v` exactly when `p(x) = y` under the
+    /// [`WindowTransform`] for `x_window` and `y_window`, together with that transform.
+    ///
+    /// The window `x_window × y_window` becomes the unit square, so `q` can be plotted
+    /// directly and display coordinates mapped back with [`WindowTransform::to_data`].
+    /// # Example
+    /// ```
+    /// use polynom::polynomial::Polynomial;
+    ///
+    /// // p(x) = x^2 on x in [-2, 2], y in [0, 4]
+    /// let polynomial = Polynomial::new(vec![0f64, 0f64, 1f64], 'x');
+    /// let (display, transform) = polynomial.fit_to_window((-2f64, 2f64), (0f64, 4f64));
+    ///
+    /// assert_eq!(display.evaluate_at(0.5), 0f64);
+    /// assert_eq!(display.evaluate_at(1f64), 1f64);
+    /// assert_eq!(transform.to_data(0.75, display.evaluate_at(0.75)), (1f64, 1f64));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if either window has zero width.
+    pub fn fit_to_window(
+        &self,
+        x_window: (f64, f64),
+        y_window: (f64, f64),
+    ) -> (Polynomial, WindowTransform) {
+        let transform = WindowTransform::new(x_window, y_window);
+        let height = y_window.1 - y_window.0;
+        let mut display = self.map_domain(x_window, (0f64, 1f64));
+        display.coefficients[0] -= y_window.0;
+        let coefficients = display
+            .coefficients
+            .iter()
+            .map(|coeff| coeff / height)
+            .collect();
+
+        (Polynomial::new(coefficients, self.indeterminate), transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_polynomial_commutes_with_transform() {
+        let polynomial = Polynomial::new(vec![1f64, -3f64, 0.5, 0.25], 'x');
+        let (display, transform) = polynomial.fit_to_window((1f64, 5f64), (-10f64, 30f64));
+
+        for &x in &[1f64, 2.5, 4f64, 5f64] {
+            let (u, v) = transform.to_display(x, polynomial.evaluate_at(x));
+            assert!((display.evaluate_at(u) - v).abs() < 1e-12);
+            let (x_back, y_back) = transform.to_data(u, v);
+            assert!((x_back - x).abs() < 1e-12);
+            assert!((y_back - polynomial.evaluate_at(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_degenerate_window() {
+        Polynomial::new(vec![1f64], 'x').fit_to_window((0f64, 1f64), (2f64, 2f64));
+    }
+}